@@ -34,6 +34,12 @@ impl ConfigType for String {
     }
 }
 
+impl ConfigType for Vec<String> {
+    fn get_variant_names() -> String {
+        String::from("[<string>, ...]")
+    }
+}
+
 impl<T: ConfigType> ConfigType for Option<T> {
     fn get_variant_names() -> String {
         String::from("<string> | null")
@@ -131,4 +137,10 @@ create_config! {
     build_on_load: bool, true, false, "build on page load and refresh";
     workspace_root: Option<String>, None: Option<String>, false, "root of the project workspace";
     vcs_link: String, String::new(), false, "link to use for VCS; should use $file and $line.";
+    respect_gitignore: bool, true, false, "hide files ignored by git in directory listings";
+    exclude_globs: Vec<String>, vec![".git".to_owned()], false,
+        "files to hide in directory listings; globs without a `/` match file names,",
+        "others match paths relative to the project directory";
+    max_tree_depth: usize, 16, false,
+        "how deep to expand directories in the tree view; deeper directories are not expanded";
 }
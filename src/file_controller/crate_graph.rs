@@ -0,0 +1,176 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The resolved crate dependency graph, from cargo metadata.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::path::Path;
+
+use cargo_metadata::{Dependency, DependencyKind, Metadata, Package};
+use semver::Version;
+
+use super::paths::LinkPaths;
+use super::results::{CrateEdge, CrateGraph, CrateNode};
+
+// File and directory names are given to the client as `LinkPaths` does, so
+// that the sources of dependencies can be fetched under `/deps`.
+pub fn build(metadata: &Metadata, paths: &LinkPaths) -> CrateGraph {
+    let members: HashSet<&str> = metadata.workspace_members.iter().map(|m| &*m.raw).collect();
+    let packages: HashMap<&str, &Package> = metadata.packages.iter().map(|p| (&*p.id, p)).collect();
+
+    let mut nodes: Vec<CrateNode> = metadata
+        .packages
+        .iter()
+        .map(|p| make_node(p, members.contains(&*p.id), paths))
+        .collect();
+    nodes.sort_by(|a, b| {
+        (!a.workspace, &a.name, &a.version).cmp(&(!b.workspace, &b.name, &b.version))
+    });
+
+    let mut edges = vec![];
+    for node in metadata.resolve.iter().flat_map(|r| &r.nodes) {
+        let from = match packages.get(&*node.id) {
+            Some(p) => p,
+            None => continue,
+        };
+        for dep_id in &node.dependencies {
+            let to = match packages.get(&**dep_id) {
+                Some(p) => p,
+                None => continue,
+            };
+            // A crate can be depended on more than once, e.g., as a normal
+            // and a dev dependency, and we make an edge for each.
+            let declared: Vec<_> = from
+                .dependencies
+                .iter()
+                .filter(|d| resolves_to(d, to))
+                .collect();
+            if declared.is_empty() {
+                edges.push(CrateEdge {
+                    from: from.id.clone(),
+                    to: to.id.clone(),
+                    req: "*".to_owned(),
+                    kind: "normal",
+                    optional: false,
+                    uses_default_features: true,
+                    features: vec![],
+                });
+            }
+            edges.extend(declared.into_iter().map(|d| CrateEdge {
+                from: from.id.clone(),
+                to: to.id.clone(),
+                req: d.req.to_string(),
+                kind: dependency_kind_name(d.kind),
+                optional: d.optional,
+                uses_default_features: d.uses_default_features,
+                features: d.features.clone(),
+            }));
+        }
+    }
+
+    CrateGraph { nodes, edges }
+}
+
+// Workspace members are drawn bold, dev dependencies dashed and build
+// dependencies dotted.
+pub fn to_dot(graph: &CrateGraph) -> String {
+    let mut result = "digraph crates {\n".to_owned();
+    for node in &graph.nodes {
+        let style = if node.workspace { ", style=bold" } else { "" };
+        writeln!(
+            result,
+            "    \"{}\" [label=\"{} {}\"{}];",
+            escape(&node.id),
+            escape(&node.name),
+            escape(&node.version),
+            style
+        )
+        .unwrap();
+    }
+    for edge in &graph.edges {
+        let style = match edge.kind {
+            "dev" => ", style=dashed",
+            "build" => ", style=dotted",
+            _ => "",
+        };
+        writeln!(
+            result,
+            "    \"{}\" -> \"{}\" [label=\"{}\"{}];",
+            escape(&edge.from),
+            escape(&edge.to),
+            escape(&edge.req),
+            style
+        )
+        .unwrap();
+    }
+    result.push_str("}\n");
+    result
+}
+
+fn make_node(package: &Package, workspace: bool, paths: &LinkPaths) -> CrateNode {
+    let targets = &package.targets;
+    let root_file = targets
+        .iter()
+        .find(|t| t.kind.iter().any(|k| k == "lib" || k == "proc-macro"))
+        .or_else(|| targets.first())
+        .map(|t| paths.file_name(Path::new(&t.src_path)));
+    let mut features: Vec<String> = package.features.keys().cloned().collect();
+    features.sort();
+
+    CrateNode {
+        id: package.id.clone(),
+        name: package.name.clone(),
+        version: package.version.clone(),
+        workspace,
+        manifest_dir: Path::new(&package.manifest_path)
+            .parent()
+            .map(|dir| paths.file_name(dir))
+            .unwrap_or_default(),
+        root_file,
+        features,
+    }
+}
+
+// Whether `dep` could have resolved to `package`. The name alone is not
+// enough when more than one version of a crate is in the graph.
+fn resolves_to(dep: &Dependency, package: &Package) -> bool {
+    if dep.name != package.name {
+        return false;
+    }
+    if let Ok(version) = Version::parse(&package.version) {
+        if !dep.req.matches(&version) {
+            return false;
+        }
+    }
+    // Package ids look like `name version (source)`; path dependencies have
+    // no source, and git sources gain a `#revision` once resolved.
+    let source = package
+        .id
+        .rsplitn(2, " (")
+        .next()
+        .unwrap_or("")
+        .trim_end_matches(')');
+    match dep.source {
+        Some(ref s) => source.starts_with(&**s),
+        None => source.starts_with("path+"),
+    }
+}
+
+fn dependency_kind_name(kind: DependencyKind) -> &'static str {
+    match kind {
+        DependencyKind::Development => "dev",
+        DependencyKind::Build => "build",
+        _ => "normal",
+    }
+}
+
+// Escapes `s` for use in a quoted DOT string.
+pub fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,474 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Access to the git repository of the project by running the local `git`.
+// Paths are relative to `dir`, the project directory.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use listings::{GitStatus, GitStatuses};
+
+use super::results::{BlameLine, DiffHunk, DiffLine, DiffLineKind};
+
+// The contents of `path` at revision `rev`.
+pub fn show(dir: &Path, rev: &str, path: &Path) -> Result<String, String> {
+    show_bytes(dir, rev, path).map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+pub fn show_bytes(dir: &Path, rev: &str, path: &Path) -> Result<Vec<u8>, String> {
+    check_rev(rev)?;
+    run_bytes(dir, &["show", &object(rev, path)])
+}
+
+// Whether `path` is a directory at revision `rev`.
+pub fn is_tree(dir: &Path, rev: &str, path: &Path) -> Result<bool, String> {
+    check_rev(rev)?;
+    let kind = run(dir, &["cat-file", "-t", &object(rev, path)])?;
+    Ok(kind.trim() == "tree")
+}
+
+// The entries of the directory `path` at revision `rev`, relative to `path`,
+// and whether each is a directory. If `recurse`, the entries of
+// subdirectories are included too.
+pub fn ls_tree(
+    dir: &Path,
+    rev: &str,
+    path: &Path,
+    recurse: bool,
+) -> Result<Vec<(PathBuf, bool)>, String> {
+    check_rev(rev)?;
+    let object = object(rev, path);
+    // Without `--full-tree`, entries outside the current directory are
+    // skipped, even though we are listing a subtree. Without `-z`, unusual
+    // paths are quoted.
+    let mut args = vec!["ls-tree", "--full-tree", "-z"];
+    if recurse {
+        args.push("-r");
+        args.push("-t");
+    }
+    args.push(&object);
+
+    // Each entry is `<mode> <type> <object>\t<path>\0`.
+    Ok(run(dir, &args)?
+        .split('\0')
+        .filter_map(|line| {
+            let mut parts = line.splitn(2, '\t');
+            let meta = parts.next()?;
+            let entry = parts.next()?;
+            let is_dir = meta.split_whitespace().nth(1) == Some("tree");
+            Some((PathBuf::from(entry), is_dir))
+        })
+        .collect())
+}
+
+// The unified diff of `path` between `from` and `to`, or the working tree if
+// `to` is `None`.
+pub fn diff(dir: &Path, from: &str, to: Option<&str>, path: &Path) -> Result<String, String> {
+    check_rev(from)?;
+    let path = path.display().to_string();
+    let mut args = vec!["diff", "--no-color", "--no-ext-diff", from];
+    if let Some(to) = to {
+        check_rev(to)?;
+        args.push(to);
+    }
+    args.push("--");
+    args.push(&path);
+    run(dir, &args)
+}
+
+// Blame for each line of `path` in the working tree, in order.
+pub fn blame(dir: &Path, path: &Path) -> Result<Vec<BlameLine>, String> {
+    let path = path.display().to_string();
+    let porcelain = run(dir, &["blame", "--porcelain", "--", &path])?;
+    Ok(parse_blame(&porcelain))
+}
+
+// The status of the working tree which contains `dir`, including ignored
+// files.
+pub fn status(dir: &Path) -> Result<GitStatuses, String> {
+    let root = run(dir, &["rev-parse", "--show-toplevel"])?;
+    let root = Path::new(root.trim())
+        .canonicalize()
+        .map_err(|e| e.to_string())?;
+    let porcelain = run(dir, &["status", "--porcelain", "--ignored", "-z"])?;
+
+    // Entries are `XY <path>`, relative to the root of the repository. Renames
+    // and copies are followed by the original path.
+    let mut entries = HashMap::new();
+    let mut fields = porcelain.split('\0');
+    while let Some(entry) = fields.next() {
+        if entry.len() < 4 {
+            continue;
+        }
+        let (code, path) = entry.split_at(3);
+        let status = match code.trim_end() {
+            "??" => GitStatus::Untracked,
+            "!!" => GitStatus::Ignored,
+            c if c.starts_with('A') => GitStatus::Added,
+            c => {
+                if c.starts_with('R') || c.starts_with('C') {
+                    fields.next();
+                }
+                GitStatus::Modified
+            }
+        };
+        entries.insert(root.join(path), status);
+    }
+
+    Ok(GitStatuses::new(entries))
+}
+
+pub fn run(dir: &Path, args: &[&str]) -> Result<String, String> {
+    run_bytes(dir, args).map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+}
+
+fn run_bytes(dir: &Path, args: &[&str]) -> Result<Vec<u8>, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| format!("Could not run git: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_owned());
+    }
+    Ok(output.stdout)
+}
+
+// `./` makes the path relative to `dir` rather than the repository root.
+fn object(rev: &str, path: &Path) -> String {
+    format!("{}:./{}", rev, path.display())
+}
+
+// Parses the hunks of a unified diff of a single file. The text of each line
+// is left as it is in the diff.
+pub fn parse_hunks(diff: &str) -> Vec<DiffHunk> {
+    let mut result: Vec<DiffHunk> = vec![];
+    let mut old_line = 0;
+    let mut new_line = 0;
+
+    for line in diff.lines() {
+        if line.starts_with("@@") {
+            if let Some(hunk) = parse_hunk_header(line) {
+                old_line = hunk.old_start;
+                new_line = hunk.new_start;
+                result.push(hunk);
+            }
+            continue;
+        }
+        let hunk = match result.last_mut() {
+            Some(hunk) => hunk,
+            // The file header.
+            None => continue,
+        };
+
+        let (kind, text) = match line.chars().next() {
+            Some('+') => (DiffLineKind::Added, &line[1..]),
+            Some('-') => (DiffLineKind::Removed, &line[1..]),
+            Some(' ') => (DiffLineKind::Context, &line[1..]),
+            // E.g., `\ No newline at end of file`.
+            _ => continue,
+        };
+        let (old, new) = match kind {
+            DiffLineKind::Added => (None, Some(new_line)),
+            DiffLineKind::Removed => (Some(old_line), None),
+            DiffLineKind::Context => (Some(old_line), Some(new_line)),
+        };
+        if old.is_some() {
+            old_line += 1;
+        }
+        if new.is_some() {
+            new_line += 1;
+        }
+        hunk.lines.push(DiffLine {
+            kind,
+            old_line: old,
+            new_line: new,
+            text: text.to_owned(),
+        });
+    }
+
+    result
+}
+
+// Each line of the file has a header `<commit> <orig line> <line> [<count>]`,
+// followed by details of the commit the first time the commit appears, then
+// the line itself prefixed by a tab.
+fn parse_blame(porcelain: &str) -> Vec<BlameLine> {
+    let mut commits: HashMap<String, BlameLine> = HashMap::new();
+    let mut result = vec![];
+    let mut current: Option<String> = None;
+
+    for line in porcelain.lines() {
+        if line.starts_with('\t') {
+            if let Some(commit) = current.take() {
+                result.extend(commits.get(&commit).cloned());
+            }
+            continue;
+        }
+
+        let (key, value) = match line.find(' ') {
+            Some(i) => (&line[..i], &line[i + 1..]),
+            None => (line, ""),
+        };
+        match current {
+            Some(ref commit) => {
+                let entry = commits.get_mut(commit).unwrap();
+                match key {
+                    "author" => entry.author = value.to_owned(),
+                    "author-time" => entry.date = value.parse().unwrap_or(0),
+                    "summary" => entry.summary = value.to_owned(),
+                    _ => {}
+                }
+            }
+            None => {
+                let commit = key.to_owned();
+                commits.entry(commit.clone()).or_insert_with(|| BlameLine {
+                    commit: commit.clone(),
+                    author: String::new(),
+                    date: 0,
+                    summary: String::new(),
+                });
+                current = Some(commit);
+            }
+        }
+    }
+
+    result
+}
+
+// E.g., `@@ -12,7 +12,8 @@ fn foo() {`.
+fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+    let mut parts = line.splitn(4, ' ');
+    parts.next()?;
+    let (old_start, old_count) = parse_range(parts.next()?.trim_start_matches('-'))?;
+    let (new_start, new_count) = parse_range(parts.next()?.trim_start_matches('+'))?;
+    let header = parts
+        .next()
+        .map_or("", |rest| rest.trim_start_matches("@@"))
+        .trim()
+        .to_owned();
+
+    Some(DiffHunk {
+        old_start,
+        old_count,
+        new_start,
+        new_count,
+        header,
+        lines: vec![],
+    })
+}
+
+// E.g., `12,7`, or `12` for a single line.
+fn parse_range(range: &str) -> Option<(u32, u32)> {
+    let mut parts = range.splitn(2, ',');
+    let start = parts.next()?.parse().ok()?;
+    let count = match parts.next() {
+        Some(count) => count.parse().ok()?,
+        None => 1,
+    };
+    Some((start, count))
+}
+
+// Revisions are passed as arguments to git, so must not look like options.
+fn check_rev(rev: &str) -> Result<(), String> {
+    if rev.is_empty() || rev.starts_with('-') {
+        return Err(format!("Bad revision: {}", rev));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(hunk: &DiffHunk) -> (u32, u32, u32, u32) {
+        (
+            hunk.old_start,
+            hunk.old_count,
+            hunk.new_start,
+            hunk.new_count,
+        )
+    }
+
+    // The kind and old and new line numbers of each line of `hunk`.
+    fn lines(hunk: &DiffHunk) -> Vec<(DiffLineKind, Option<u32>, Option<u32>)> {
+        hunk.lines
+            .iter()
+            .map(|l| (l.kind, l.old_line, l.new_line))
+            .collect()
+    }
+
+    #[test]
+    fn multiple_hunks() {
+        let diff = "\
+diff --git a/src/lib.rs b/src/lib.rs
+index 1234567..89abcde 100644
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,4 @@
+ use std::fmt;
++use std::io;
+ use std::path::Path;
+ use std::process;
+@@ -20,3 +21,2 @@ impl Foo {
+     fn foo() {
+-        bar();
+     }
+";
+        let hunks = parse_hunks(diff);
+        assert_eq!(hunks.len(), 2);
+
+        assert_eq!(range(&hunks[0]), (1, 3, 1, 4));
+        assert_eq!(hunks[0].header, "");
+        assert_eq!(
+            lines(&hunks[0]),
+            vec![
+                (DiffLineKind::Context, Some(1), Some(1)),
+                (DiffLineKind::Added, None, Some(2)),
+                (DiffLineKind::Context, Some(2), Some(3)),
+                (DiffLineKind::Context, Some(3), Some(4)),
+            ]
+        );
+        assert_eq!(hunks[0].lines[1].text, "use std::io;");
+
+        assert_eq!(range(&hunks[1]), (20, 3, 21, 2));
+        assert_eq!(hunks[1].header, "impl Foo {");
+        assert_eq!(
+            lines(&hunks[1]),
+            vec![
+                (DiffLineKind::Context, Some(20), Some(21)),
+                (DiffLineKind::Removed, Some(21), None),
+                (DiffLineKind::Context, Some(22), Some(22)),
+            ]
+        );
+    }
+
+    #[test]
+    fn hunk_without_counts() {
+        let diff = "\
+--- a/README.md
++++ b/README.md
+@@ -1 +1 @@
+-Hello
++Hello, world
+\\ No newline at end of file
+";
+        let hunks = parse_hunks(diff);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(range(&hunks[0]), (1, 1, 1, 1));
+        assert_eq!(
+            lines(&hunks[0]),
+            vec![
+                (DiffLineKind::Removed, Some(1), None),
+                (DiffLineKind::Added, None, Some(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn deletion_only_hunk() {
+        let diff = "\
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -5,2 +4,0 @@ fn main() {
+-    foo();
+-    bar();
+";
+        let hunks = parse_hunks(diff);
+        assert_eq!(hunks.len(), 1);
+        assert_eq!(range(&hunks[0]), (5, 2, 4, 0));
+        assert_eq!(
+            lines(&hunks[0]),
+            vec![
+                (DiffLineKind::Removed, Some(5), None),
+                (DiffLineKind::Removed, Some(6), None),
+            ]
+        );
+        assert_eq!(hunks[0].lines[1].text, "    bar();");
+    }
+
+    // `--porcelain` gives the details of a commit only for its first line.
+    #[test]
+    fn blame_repeated_commit() {
+        let porcelain = "\
+1111111111111111111111111111111111111111 1 1 2
+author Alice
+author-mail <alice@example.com>
+author-time 1500000000
+author-tz +0000
+committer Alice
+committer-mail <alice@example.com>
+committer-time 1500000000
+committer-tz +0000
+summary Initial commit
+boundary
+filename src/lib.rs
+\tfn main() {
+1111111111111111111111111111111111111111 2 2
+\t}
+2222222222222222222222222222222222222222 3 3 1
+author Bob
+author-mail <bob@example.com>
+author-time 1600000000
+author-tz +0100
+committer Bob
+committer-mail <bob@example.com>
+committer-time 1600000000
+committer-tz +0100
+summary Add foo
+previous 1111111111111111111111111111111111111111 src/lib.rs
+filename src/lib.rs
+\tfn foo() {}
+1111111111111111111111111111111111111111 3 4 1
+\t
+";
+        let blame = parse_blame(porcelain);
+        let lines: Vec<_> = blame
+            .iter()
+            .map(|l| (&*l.commit, &*l.author, l.date, &*l.summary))
+            .collect();
+        let first = "1111111111111111111111111111111111111111";
+        let second = "2222222222222222222222222222222222222222";
+        assert_eq!(
+            lines,
+            vec![
+                (first, "Alice", 1500000000, "Initial commit"),
+                (first, "Alice", 1500000000, "Initial commit"),
+                (second, "Bob", 1600000000, "Add foo"),
+                (first, "Alice", 1500000000, "Initial commit"),
+            ]
+        );
+    }
+
+    // Lines changed in the working tree are blamed on the all-zero commit.
+    #[test]
+    fn blame_not_committed() {
+        let porcelain = "\
+0000000000000000000000000000000000000000 1 1 1
+author Not Committed Yet
+author-mail <not.committed.yet>
+author-time 1700000000
+author-tz +0000
+committer Not Committed Yet
+committer-mail <not.committed.yet>
+committer-time 1700000000
+committer-tz +0000
+summary Version of src/lib.rs from src/lib.rs
+filename src/lib.rs
+\tfn main() {}
+";
+        let blame = parse_blame(porcelain);
+        assert_eq!(blame.len(), 1);
+        assert_eq!(blame[0].commit, "0000000000000000000000000000000000000000");
+        assert_eq!(blame[0].author, "Not Committed Yet");
+        assert_eq!(blame[0].date, 1700000000);
+        assert_eq!(blame[0].summary, "Version of src/lib.rs from src/lib.rs");
+    }
+}
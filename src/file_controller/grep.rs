@@ -0,0 +1,224 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Plain text search over the files in the workspace.
+
+use regex::{self, Regex, RegexBuilder};
+use span;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use listings::{GitStatus, GitStatuses, Glob};
+
+use super::Span;
+
+pub fn build_matcher(pattern: &str, is_regex: bool, case_sensitive: bool) -> Result<Regex, String> {
+    let pattern = if is_regex {
+        pattern.to_owned()
+    } else {
+        regex::escape(pattern)
+    };
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!case_sensitive)
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+// Substrings which must appear in any match of `pattern`, used to narrow the
+// set of files to search. We only understand simple regexes; if in doubt we
+// return nothing, which means every file must be searched.
+pub fn required_literals(pattern: &str, is_regex: bool) -> Vec<String> {
+    fn flush(current: &mut String, result: &mut Vec<String>) {
+        if !current.is_empty() {
+            result.push(current.clone());
+            current.clear();
+        }
+    }
+
+    if !is_regex {
+        return vec![pattern.to_owned()];
+    }
+    if pattern.contains('|') || pattern.contains("(?") {
+        return vec![];
+    }
+
+    let mut result = vec![];
+    let mut current = String::new();
+    // Anything inside a group might be optional, so we ignore it.
+    let mut depth = 0;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(e) if e.is_ascii_punctuation() && depth == 0 => current.push(e),
+                // A class (e.g., `\d`) or an escaped char (e.g., `\x41`), skip
+                // any arguments.
+                Some(e) => {
+                    flush(&mut current, &mut result);
+                    let arg_len = match e {
+                        'x' => 2,
+                        'u' => 4,
+                        'U' => 8,
+                        'p' | 'P' => 1,
+                        _ => 0,
+                    };
+                    if arg_len > 0 && chars.peek() == Some(&'{') {
+                        while chars.next().map_or(false, |c| c != '}') {}
+                    } else {
+                        for _ in 0..arg_len {
+                            chars.next();
+                        }
+                    }
+                }
+                None => {}
+            },
+            // The previous char is optional.
+            '*' | '?' | '{' => {
+                current.pop();
+                flush(&mut current, &mut result);
+                if c == '{' {
+                    while chars.next().map_or(false, |c| c != '}') {}
+                }
+            }
+            '[' => {
+                flush(&mut current, &mut result);
+                let mut prev = c;
+                while let Some(c) = chars.next() {
+                    if c == ']' && prev != '\\' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '(' => {
+                flush(&mut current, &mut result);
+                depth += 1;
+            }
+            ')' => depth -= 1,
+            '.' | '^' | '$' | '+' => flush(&mut current, &mut result),
+            _ if depth == 0 => current.push(c),
+            _ => {}
+        }
+    }
+    flush(&mut current, &mut result);
+
+    result
+}
+
+// All files under `root`, skipping build output and hidden directories, and
+// the files which directory listings skip: those matching `exclude`, and
+// those ignored by git if we have `git_status`.
+pub fn workspace_files(
+    root: &Path,
+    exclude: &[Glob],
+    git_status: Option<&GitStatuses>,
+) -> Vec<PathBuf> {
+    // Git statuses are keyed by canonical path.
+    let root = root.canonicalize().unwrap_or_else(|_| root.to_owned());
+    WalkDir::new(&root)
+        .into_iter()
+        .filter_entry(|e| {
+            if e.depth() == 0 {
+                return true;
+            }
+            let name = e.file_name().to_string_lossy();
+            let is_dir = e.file_type().is_dir();
+            if name.starts_with('.') || (is_dir && name == "target") {
+                return false;
+            }
+            let rel_path = e.path().strip_prefix(&root).unwrap_or(e.path());
+            if exclude.iter().any(|g| g.matches(&name, rel_path)) {
+                return false;
+            }
+            git_status.map_or(true, |statuses| {
+                statuses.status(e.path(), is_dir) != Some(GitStatus::Ignored)
+            })
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect()
+}
+
+// Files larger than this are not searched.
+const MAX_FILE_SIZE: u64 = 1 << 20;
+
+pub fn too_large(path: &Path) -> bool {
+    fs::metadata(path).map_or(true, |m| m.len() > MAX_FILE_SIZE)
+}
+
+// Text with NULs is probably binary, even if it is valid UTF-8.
+pub fn is_text(text: &str) -> bool {
+    !text.contains('\0')
+}
+
+// The contents of `path`, or `None` if it is too large or not text.
+pub fn read_text(path: &Path) -> Option<String> {
+    if too_large(path) {
+        return None;
+    }
+    let text = String::from_utf8(fs::read(path).ok()?).ok()?;
+    if !is_text(&text) {
+        return None;
+    }
+    Some(text)
+}
+
+// Returns a span for each match of `matcher` in `text`. Matches do not span
+// lines.
+pub fn find_matches(matcher: &Regex, text: &str, file: &Path) -> Vec<Span> {
+    let mut result = vec![];
+    for (row, line) in text.lines().enumerate() {
+        for m in matcher.find_iter(line) {
+            if m.start() == m.end() {
+                continue;
+            }
+            // Spans use char columns, the regex gives us byte offsets.
+            let col_start = line[..m.start()].chars().count() as u32;
+            let col_end = col_start + m.as_str().chars().count() as u32;
+            result.push(Span::new(
+                span::Row::new_zero_indexed(row as u32),
+                span::Row::new_zero_indexed(row as u32),
+                span::Column::new_zero_indexed(col_start),
+                span::Column::new_zero_indexed(col_end),
+                file,
+            ));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literals_plain() {
+        assert_eq!(required_literals("foo.bar", false), vec!["foo.bar"]);
+    }
+
+    #[test]
+    fn literals_regex() {
+        assert_eq!(required_literals("foo.*bar", true), vec!["foo", "bar"]);
+        assert_eq!(required_literals("fooo?bar", true), vec!["foo", "bar"]);
+        assert_eq!(required_literals(r"foo\.bar", true), vec!["foo.bar"]);
+        assert_eq!(required_literals("foo(bar)?baz", true), vec!["foo", "baz"]);
+        assert!(required_literals("foo|bar", true).is_empty());
+    }
+
+    #[test]
+    fn literals_escapes() {
+        assert_eq!(required_literals(r"\x41bc", true), vec!["bc"]);
+        assert_eq!(required_literals(r"\x{41}bc", true), vec!["bc"]);
+        assert_eq!(required_literals(r"\u0041bc", true), vec!["bc"]);
+        assert_eq!(required_literals(r"\pLbc", true), vec!["bc"]);
+        assert_eq!(required_literals(r"\p{Greek}bc", true), vec!["bc"]);
+        assert_eq!(required_literals(r"ab\dcd", true), vec!["ab", "cd"]);
+    }
+}
@@ -8,13 +8,15 @@
 
 use cargo_metadata;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::env;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::str;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use analysis::{AnalysisHost, Id, Target};
+use analysis::{AnalysisHost, Def, DefKind, Id, Target};
 use config::Config;
+use listings::{DirectoryListing, FileStatsCache, GitStatuses, Glob};
 use span;
 use vfs::Vfs;
 
@@ -23,16 +25,53 @@ use super::highlight;
 // FIXME maximum size and evication policy
 // FIXME keep timestamps and check on every read. Then don't empty on build.
 
+mod crate_graph;
+mod git;
+mod grep;
+mod module_graph;
+mod outline;
+mod paths;
+mod refs;
 mod results;
+mod symbol_index;
+mod trigram;
+use file_controller::module_graph::FileModules;
+use file_controller::outline::FlatItem;
+pub use file_controller::paths::LinkPaths;
+use file_controller::refs::FnExtent;
+use file_controller::results::DiffLineKind;
 use file_controller::results::{
-    DefResult, FileResult, FindResult, LineResult, SearchResult, SymbolResult, CONTEXT_SIZE,
+    ApiItem, CallResult, CrateGraph, DefResult, DiffResult, DocCoverage, FileResult, FindResult,
+    HierarchyItem, LineResult, ModuleDocCoverage, ModuleEdge, ModuleGraph, ModuleNode, ModuleTree,
+    OutlineItem, PackageTree, SearchResult, SymbolMatch, SymbolResult, SymbolRoots, TargetTree,
+    TypeHierarchy, UnusedCrate, UnusedItem, UnusedModule, CONTEXT_SIZE,
 };
+pub use file_controller::results::{BlameLine, RefKind};
+use file_controller::symbol_index::SymbolIndex;
+use file_controller::trigram::TrigramIndex;
 
 pub struct Cache {
     config: Arc<Config>,
     files: Vfs<VfsUserData>,
     analysis: AnalysisHost,
     project_dir: PathBuf,
+    // Rebuilt each time we reload the analysis data.
+    symbol_index: Mutex<SymbolIndex>,
+    // Built with the analysis data, and brought up to date before each text
+    // search.
+    text_index: Mutex<TrigramIndex>,
+    // Computed on demand and cleared each time we reload the analysis data.
+    symbol_roots: Mutex<Option<SymbolRoots>>,
+    metadata: Mutex<Option<Arc<cargo_metadata::Metadata>>>,
+    // Computed on demand and cleared each time we reload the analysis data,
+    // rather than running `git status` for every listing.
+    git_status: Mutex<Option<Arc<GitStatuses>>>,
+    // Computed on demand and cleared each time we reload the analysis data.
+    link_paths: Mutex<Option<Arc<LinkPaths>>>,
+    // Kept across reloads, since entries are checked against the file system.
+    file_stats: FileStatsCache,
+    // Compiled from `config.exclude_globs`.
+    exclude: Vec<Glob>,
 }
 
 type Span = span::Span<span::ZeroIndexed>;
@@ -43,14 +82,62 @@ pub struct Highlighted {
     pub rendered: Option<String>,
 }
 
+// Restricts which references are included in search results. `None` means
+// that results are not filtered on that property.
+#[derive(Debug, Clone, Default)]
+pub struct RefFilter {
+    pub kinds: Option<Vec<RefKind>>,
+    pub workspace: Option<bool>,
+    pub crate_name: Option<String>,
+    pub enclosing_fn: Option<String>,
+}
+
+impl RefFilter {
+    fn matches(&self, kind: RefKind, file: &FileInfo, enclosing_fn: Option<&String>) -> bool {
+        if let Some(ref kinds) = self.kinds {
+            if !kinds.contains(&kind) {
+                return false;
+            }
+        }
+        if let Some(workspace) = self.workspace {
+            if workspace != file.workspace {
+                return false;
+            }
+        }
+        if self.crate_name.is_some() && self.crate_name != file.crate_name {
+            return false;
+        }
+        if self.enclosing_fn.is_some() && self.enclosing_fn.as_ref() != enclosing_fn {
+            return false;
+        }
+        true
+    }
+}
+
+// Data about a file which is shared by all search results in that file.
+struct FileInfo {
+    crate_name: Option<String>,
+    workspace: bool,
+    fns: Vec<FnExtent>,
+}
+
+// The maximum number of matches we return from a text search.
+const MAX_TEXT_SEARCH_RESULTS: usize = 1000;
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "ico", "svg", "apng", "bmp"];
+
 // Our data which we attach to files in the VFS.
 struct VfsUserData {
     highlighted: Option<Highlighted>,
+    blame: Option<Vec<BlameLine>>,
 }
 
 impl VfsUserData {
     fn new() -> Self {
-        VfsUserData { highlighted: None }
+        VfsUserData {
+            highlighted: None,
+            blame: None,
+        }
     }
 }
 
@@ -62,12 +149,20 @@ macro_rules! vfs_err {
 }
 
 impl Cache {
-    pub fn new(config: Arc<Config>) -> Cache {
+    pub fn new(config: Arc<Config>, exclude: Vec<Glob>) -> Cache {
         Cache {
             config,
             files: Vfs::new(),
             analysis: AnalysisHost::new(Target::Debug),
             project_dir: env::current_dir().unwrap(),
+            symbol_index: Mutex::new(SymbolIndex::new()),
+            text_index: Mutex::new(TrigramIndex::new()),
+            symbol_roots: Mutex::new(None),
+            metadata: Mutex::new(None),
+            git_status: Mutex::new(None),
+            link_paths: Mutex::new(None),
+            file_stats: FileStatsCache::new(),
+            exclude,
         }
     }
 
@@ -81,16 +176,176 @@ impl Cache {
         vfs_err!(self.files.load_lines(path, line_start, line_end))
     }
 
-    pub fn get_highlighted(&self, path: &Path) -> Result<Highlighted, String> {
-        fn raw_lines(text: &str) -> Vec<String> {
-            let mut highlighted: Vec<String> = text.lines().map(|s| s.to_owned()).collect();
-            if text.ends_with('\n') {
-                highlighted.push(String::new());
+    // The changes to `path` between two revisions, or between `from` and the
+    // working tree if `to` is `None`. Only the working tree is highlighted
+    // using the analysis data, since it would not match other revisions.
+    pub fn diff(&self, path: &Path, from: &str, to: Option<&str>) -> Result<DiffResult, String> {
+        let diff = git::diff(&self.project_dir, from, to, path)?;
+        let mut hunks = git::parse_hunks(&diff);
+
+        // A side is missing if the file was added or deleted.
+        let old_lines = git::show(&self.project_dir, from, path)
+            .map(|text| self.highlight_revision(path, text))
+            .unwrap_or_default();
+        let new_lines = match to {
+            Some(to) => git::show(&self.project_dir, to, path)
+                .map(|text| self.highlight_revision(path, text))
+                .unwrap_or_default(),
+            None => self
+                .get_highlighted(path)
+                .ok()
+                .and_then(|h| h.source)
+                .unwrap_or_default(),
+        };
+
+        for line in hunks.iter_mut().flat_map(|h| h.lines.iter_mut()) {
+            let highlighted = match (line.kind, line.old_line, line.new_line) {
+                (DiffLineKind::Added, _, Some(n)) => new_lines.get(n as usize - 1),
+                (_, Some(n), _) => old_lines.get(n as usize - 1),
+                _ => None,
+            };
+            if let Some(highlighted) = highlighted {
+                line.text = highlighted.clone();
             }
+        }
+
+        Ok(DiffResult {
+            path: path.display().to_string(),
+            from: from.to_owned(),
+            to: to.map(|to| to.to_owned()),
+            hunks,
+        })
+    }
+
+    // Blame for each line of `path`, in order.
+    pub fn get_blame(&self, path: &Path) -> Result<Vec<BlameLine>, String> {
+        vfs_err!(self.files.load_file(path))?;
+        vfs_err!(self
+            .files
+            .ensure_user_data(path, |_| Ok(VfsUserData::new())))?;
+        let cached = vfs_err!(self.files.with_user_data(path, |u| Ok(u?.1.blame.clone())))?;
+        if let Some(blame) = cached {
+            return Ok(blame);
+        }
+
+        let blame = git::blame(&self.project_dir, path)?;
+        vfs_err!(self.files.with_user_data(path, |u| {
+            u?.1.blame = Some(blame.clone());
+            Ok(())
+        }))?;
+        Ok(blame)
+    }
+
+    // Highlights the text of `path` from some revision other than the working
+    // tree, without analysis data.
+    fn highlight_revision(&self, path: &Path, text: String) -> Vec<String> {
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            return raw_lines(&text);
+        }
+        let ends_with_newline = text.ends_with('\n');
+        let path = self.project_dir.join(path);
+        let mut highlighted: Vec<String> =
+            highlight::highlight_lexical(path.to_str().unwrap().to_owned(), text)
+                .lines()
+                .map(|line| line.replace("<br>", "\n"))
+                .collect();
+        if ends_with_newline {
+            highlighted.push(String::new());
+        }
+        highlighted
+    }
+
+    // Whether we may serve `path`. Relative paths are relative to the project
+    // directory, absolute paths must be in the project or its workspace.
+    pub fn in_project(&self, path: &Path) -> bool {
+        if path.components().any(|c| c == Component::ParentDir) {
+            return false;
+        }
+        !path.is_absolute()
+            || path.starts_with(&self.project_dir)
+            || path.starts_with(self.workspace_root())
+    }
+
+    pub fn exclude_globs(&self) -> &[Glob] {
+        &self.exclude
+    }
+
+    pub fn file_stats(&self) -> &FileStatsCache {
+        &self.file_stats
+    }
 
-            highlighted
+    pub fn git_status(&self) -> Result<Arc<GitStatuses>, String> {
+        let mut statuses = self.git_status.lock().unwrap();
+        if statuses.is_none() {
+            *statuses = Some(Arc::new(git::status(&self.project_dir)?));
         }
+        Ok(statuses.clone().unwrap())
+    }
+
+    pub fn is_dir_at(&self, path: &Path, rev: &str) -> Result<bool, String> {
+        git::is_tree(&self.project_dir, rev, path)
+    }
+
+    pub fn get_listing_at(
+        &self,
+        path: &Path,
+        rev: &str,
+        recurse: bool,
+    ) -> Result<DirectoryListing, String> {
+        let entries = git::ls_tree(&self.project_dir, rev, path, recurse)?;
+        Ok(DirectoryListing::from_entries(path, &entries, recurse))
+    }
 
+    // Like `get_highlighted`, but for `path` at revision `rev`. We only have
+    // analysis data for the working tree, so other revisions are highlighted
+    // lexically unless the file is unchanged.
+    pub fn get_highlighted_at(&self, path: &Path, rev: &str) -> Result<Highlighted, String> {
+        let ext = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+        if IMAGE_EXTENSIONS.contains(&ext) {
+            return Ok(Highlighted {
+                source: None,
+                rendered: Some(format!(
+                    r#"<img src="/raw/{}?rev={}"/>"#,
+                    path.display(),
+                    rev
+                )),
+            });
+        }
+
+        let text = git::show(&self.project_dir, rev, path)?;
+        if let Ok(::vfs::FileContents::Text(current)) = self.files.load_file(path) {
+            if current == text {
+                return self.get_highlighted(path);
+            }
+        }
+
+        Ok(match ext {
+            "rs" => Highlighted {
+                source: Some(self.highlight_revision(path, text)),
+                rendered: None,
+            },
+            "md" | "markdown" => Highlighted {
+                rendered: Some(::comrak::markdown_to_html(&text, &Default::default())),
+                source: Some(raw_lines(&text)),
+            },
+            _ => Highlighted {
+                source: Some(raw_lines(&text)),
+                rendered: None,
+            },
+        })
+    }
+
+    pub fn get_raw_at(&self, path: &Path, rev: &str) -> Result<::vfs::FileContents, String> {
+        let bytes = git::show_bytes(&self.project_dir, rev, path)?;
+        Ok(match String::from_utf8(bytes) {
+            Ok(text) => ::vfs::FileContents::Text(text),
+            Err(e) => ::vfs::FileContents::Binary(e.into_bytes()),
+        })
+    }
+
+    pub fn get_highlighted(&self, path: &Path) -> Result<Highlighted, String> {
+        // Outside the closure, since we may need to run cargo metadata.
+        let link_paths = self.link_paths();
         vfs_err!(self.files.load_file(path))?;
         vfs_err!(self
             .files
@@ -108,7 +363,7 @@ impl Cache {
 
                         let highlighted = highlight::highlight(
                             &self.analysis,
-                            &self.project_dir,
+                            &link_paths,
                             path.to_str().unwrap().to_owned(),
                             text.to_owned(),
                         );
@@ -136,15 +391,7 @@ impl Cache {
                             rendered: Some(::comrak::markdown_to_html(text, &Default::default())),
                             source: Some(raw_lines(text)),
                         });
-                    } else if ext == "png"
-                        || ext == "jpg"
-                        || ext == "jpeg"
-                        || ext == "gif"
-                        || ext == "ico"
-                        || ext == "svg"
-                        || ext == "apng"
-                        || ext == "bmp"
-                    {
+                    } else if IMAGE_EXTENSIONS.iter().any(|i| ext == *i) {
                         if let Ok(path) = path.strip_prefix(&self.project_dir) {
                             u.highlighted = Some(Highlighted {
                                 source: None,
@@ -177,12 +424,7 @@ impl Cache {
 
     pub fn update_analysis(&self) {
         println!("Processing analysis...");
-        let workspace_root = self
-            .config
-            .workspace_root
-            .as_ref()
-            .map(|s| Path::new(s).to_owned())
-            .unwrap_or(self.project_dir.clone());
+        let workspace_root = self.workspace_root();
         self.analysis
             .reload_with_blacklist(
                 &self.project_dir,
@@ -194,60 +436,792 @@ impl Cache {
         // FIXME Possibly extreme, could invalidate by crate or by file. Also, only
         // need to invalidate Rust files.
         self.files.clear();
+        *self.symbol_roots.lock().unwrap() = None;
+        *self.metadata.lock().unwrap() = None;
+        *self.git_status.lock().unwrap() = None;
+        *self.link_paths.lock().unwrap() = None;
+
+        let index = SymbolIndex::build(&self.all_defs(), |def| {
+            Path::new(&def.span.file).starts_with(&workspace_root)
+        });
+        *self.symbol_index.lock().unwrap() = index;
+        self.text_index.lock().unwrap().update(&self.search_files());
 
         println!("done");
     }
 
-    // FIXME we should cache this information rather than compute every time.
-    pub fn get_symbol_roots(&self) -> Result<Vec<SymbolResult>, String> {
-        let all_crates = self
-            .analysis
+    // If `include_deps` is false, `dependencies` will be empty.
+    pub fn get_symbol_roots(&self, include_deps: bool) -> Result<SymbolRoots, String> {
+        let mut roots = self.symbol_roots.lock().unwrap();
+        if roots.is_none() {
+            *roots = Some(self.compute_symbol_roots()?);
+        }
+
+        let mut roots = roots.clone().unwrap();
+        if !include_deps {
+            roots.dependencies.clear();
+        }
+        Ok(roots)
+    }
+
+    fn crate_root(&self, crate_name: &str) -> Result<Id, String> {
+        self.analysis
             .def_roots()
-            .unwrap_or_else(|_| vec![])
+            .map_err(|_| "No analysis data".to_owned())?
             .into_iter()
-            .filter_map(|(id, name)| {
-                let span = self.analysis.get_def(id).ok()?.span;
-                Some(SymbolResult {
+            .find(|&(_, ref name)| name == crate_name)
+            .map(|(id, _)| id)
+            .ok_or_else(|| format!("Unknown crate: {}", crate_name))
+    }
+
+    // The name and version of every package which is not a workspace member.
+    pub fn dependencies(&self) -> Result<Vec<(String, String)>, String> {
+        let metadata = self.metadata()?;
+        let mut result: Vec<(String, String)> = metadata
+            .packages
+            .iter()
+            .filter(|p| !metadata.workspace_members.iter().any(|m| m.raw == p.id))
+            .map(|p| (p.name.clone(), p.version.clone()))
+            .collect();
+        result.sort();
+        result.dedup();
+        Ok(result)
+    }
+
+    // The source directory of a dependency, e.g., in the cargo registry or a
+    // git checkout.
+    pub fn dependency_dir(&self, name: &str, version: &str) -> Result<PathBuf, String> {
+        let metadata = self.metadata()?;
+        metadata
+            .packages
+            .iter()
+            .filter(|p| !metadata.workspace_members.iter().any(|m| m.raw == p.id))
+            .find(|p| p.name == name && p.version == version)
+            .and_then(|p| Path::new(&p.manifest_path).parent().map(|p| p.to_owned()))
+            .ok_or_else(|| format!("Unknown dependency: {} {}", name, version))
+    }
+
+    // If we can't get cargo metadata, no files are treated as dependencies.
+    fn link_paths(&self) -> Arc<LinkPaths> {
+        let mut paths = self.link_paths.lock().unwrap();
+        if paths.is_none() {
+            let deps = self
+                .metadata()
+                .map(|metadata| {
+                    metadata
+                        .packages
+                        .iter()
+                        .filter(|p| !metadata.workspace_members.iter().any(|m| m.raw == p.id))
+                        .filter_map(|p| {
+                            let dir = Path::new(&p.manifest_path).parent()?;
+                            Some((dir.to_owned(), p.name.clone(), p.version.clone()))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+            *paths = Some(Arc::new(LinkPaths::new(&self.project_dir, deps)));
+        }
+        paths.clone().unwrap()
+    }
+
+    // The names of the crates of all targets of workspace members.
+    fn workspace_crates(&self) -> Result<Vec<String>, String> {
+        let metadata = self.workspace_metadata()?;
+        // Analysis uses crate names, which may differ from package names.
+        Ok(metadata
+            .packages
+            .iter()
+            .filter(|p| metadata.workspace_members.iter().any(|m| m.raw == p.id))
+            .flat_map(|p| p.targets.iter().map(|t| t.name.replace("-", "_")))
+            .collect())
+    }
+
+    // The path of the module which contains `id`, starting with `crate_name`.
+    fn module_path(&self, id: Id, crate_name: &str) -> String {
+        let parents = self.analysis.def_parents(id).unwrap_or_else(|_| vec![]);
+        let module_depth = parents.iter().rposition(|&(p, _)| {
+            self.analysis
+                .get_def(p)
+                .map_or(false, |d| d.kind == DefKind::Mod)
+        });
+
+        let mut result = crate_name.to_owned();
+        if let Some(depth) = module_depth {
+            // The first parent is the crate root.
+            for &(_, ref name) in parents.iter().take(depth + 1).skip(1) {
+                result.push_str("::");
+                result.push_str(name);
+            }
+        }
+        result
+    }
+
+    fn compute_symbol_roots(&self) -> Result<SymbolRoots, String> {
+        let member_crates = self.workspace_crates()?;
+
+        let mut roots = SymbolRoots {
+            workspace: vec![],
+            dependencies: vec![],
+        };
+        for (id, name) in self.analysis.def_roots().unwrap_or_else(|_| vec![]) {
+            let def = match self.analysis.get_def(id) {
+                Ok(def) => def,
+                Err(_) => continue,
+            };
+            let mut result = self.make_symbol_result(id, &def);
+            result.name = name;
+            if member_crates.contains(&result.name) {
+                roots.workspace.push(result);
+            } else {
+                roots.dependencies.push(result);
+            }
+        }
+
+        roots.workspace.sort_by(|a, b| a.name.cmp(&b.name));
+        roots.dependencies.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(roots)
+    }
+
+    // Cargo metadata for the workspace, including dependencies.
+    fn metadata(&self) -> Result<Arc<cargo_metadata::Metadata>, String> {
+        let mut metadata = self.metadata.lock().unwrap();
+        if metadata.is_none() {
+            match cargo_metadata::metadata_deps(None, true) {
+                Ok(m) => *metadata = Some(Arc::new(m)),
+                Err(_) => return Err("Could not access cargo metadata".to_owned()),
+            }
+        }
+        Ok(metadata.clone().unwrap())
+    }
+
+    // Cargo metadata for when we only need the workspace members. Resolving
+    // dependencies can fail (e.g., offline, or with a stale lockfile), in
+    // which case we fall back to `--no-deps`.
+    fn workspace_metadata(&self) -> Result<Arc<cargo_metadata::Metadata>, String> {
+        self.metadata().or_else(|_| {
+            cargo_metadata::metadata_deps(None, false)
+                .map(Arc::new)
+                .map_err(|_| "Could not access cargo metadata".to_owned())
+        })
+    }
+
+    pub fn crate_graph(&self) -> Result<CrateGraph, String> {
+        Ok(crate_graph::build(&self.metadata()?, &self.link_paths()))
+    }
+
+    pub fn crate_graph_dot(&self) -> Result<String, String> {
+        self.crate_graph().map(|g| crate_graph::to_dot(&g))
+    }
+
+    // Edges between the modules of a crate, weighted by the number of
+    // references from one module to items defined in the other.
+    pub fn module_graph(&self, crate_name: &str) -> Result<ModuleGraph, String> {
+        let root = self.crate_root(crate_name)?;
+        let defs = self.defs_under(vec![root]);
+        let mut modules: HashSet<Id> = defs
+            .iter()
+            .filter(|&&(_, ref def)| def.kind == DefKind::Mod)
+            .map(|&(id, _)| id)
+            .collect();
+        modules.insert(root);
+
+        let mut file_modules = HashMap::new();
+        let mut weights: HashMap<(Id, Id), usize> = HashMap::new();
+        for &(id, ref def) in &defs {
+            if def.kind == DefKind::Mod || def.kind == DefKind::Local {
+                continue;
+            }
+            let parents = self.analysis.def_parents(id).unwrap_or_else(|_| vec![]);
+            let to = match parents.iter().rev().find(|&&(p, _)| modules.contains(&p)) {
+                Some(&(to, _)) => to,
+                None => continue,
+            };
+
+            for span in self.refs_to(id).unwrap_or_else(|_| vec![]) {
+                let file = file_modules.entry(span.file.clone()).or_insert_with(|| {
+                    let text = match self.files.load_file(&span.file) {
+                        Ok(::vfs::FileContents::Text(text)) => text,
+                        _ => String::new(),
+                    };
+                    let symbols = self.analysis.symbols(&span.file).unwrap_or_else(|_| vec![]);
+                    FileModules::new(&text, &symbols, &modules)
+                });
+                match file.module_of(&span) {
+                    Some(from) if from != to && modules.contains(&from) => {
+                        *weights.entry((from, to)).or_insert(0) += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut nodes: Vec<ModuleNode> = modules
+            .iter()
+            .filter_map(|&id| {
+                let def = self.analysis.get_def(id).ok()?;
+                let name = if id == root {
+                    crate_name.to_owned()
+                } else {
+                    format!("{}::{}", self.module_path(id, crate_name), def.name)
+                };
+                Some(ModuleNode {
                     id: id.to_string(),
                     name,
-                    file_name: self.make_file_path(&span).display().to_string(),
-                    line_start: span.range.row_start.one_indexed().0,
+                    file_name: self.file_name(&def.span),
+                    line_start: def.span.range.row_start.one_indexed().0,
                 })
-            });
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.name.cmp(&b.name));
 
-        // FIXME Unclear ot sure if we should include dep crates or not here.
-        // Need to test on workspace crates. Might be nice to have deps in any
-        // case, in which case we should return the primary crate(s) first.
-        let metadata = match cargo_metadata::metadata_deps(None, false) {
-            Ok(metadata) => metadata,
-            Err(_) => return Err("Could not access cargo metadata".to_owned()),
-        };
+        // Heaviest edges first.
+        let mut edges: Vec<ModuleEdge> = weights
+            .into_iter()
+            .map(|((from, to), weight)| ModuleEdge {
+                from: from.to_string(),
+                to: to.to_string(),
+                weight,
+            })
+            .collect();
+        edges.sort_by(|a, b| (b.weight, &a.from, &a.to).cmp(&(a.weight, &b.from, &b.to)));
+
+        Ok(ModuleGraph {
+            crate_name: crate_name.to_owned(),
+            nodes,
+            edges,
+        })
+    }
 
-        let names: Vec<String> = metadata.packages.into_iter().map(|p| p.name).collect();
+    // Every item of a crate which is reachable from outside it, sorted by path.
+    // Items reachable only through re-exports are not found, since re-exports
+    // are not defs.
+    pub fn api_surface(&self, crate_name: &str) -> Result<Vec<ApiItem>, String> {
+        Ok(self
+            .api_items(crate_name)?
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect())
+    }
 
-        Ok(all_crates.filter(|sr| names.contains(&sr.name)).collect())
+    fn api_items(&self, crate_name: &str) -> Result<Vec<(Id, ApiItem)>, String> {
+        let root = self.crate_root(crate_name)?;
+        Ok(self.api_items_under(root, crate_name))
     }
 
-    // FIXME we should indicate whether the symbol has children or not
-    pub fn get_symbol_children(&self, id: Id) -> Result<Vec<SymbolResult>, String> {
-        self.analysis
-            .for_each_child_def(id, |id, def| {
-                let span = &def.span;
-                SymbolResult {
+    fn api_items_under(&self, root: Id, crate_name: &str) -> Vec<(Id, ApiItem)> {
+        let mut result = vec![];
+        let mut seen = HashSet::new();
+        let mut stack = vec![(root, DefKind::Mod, crate_name.to_owned())];
+        while let Some((id, kind, path)) = stack.pop() {
+            if !seen.insert(id) {
+                continue;
+            }
+
+            let mut children: Vec<Id> = self
+                .analysis
+                .for_each_child_def(id, |id, _| id)
+                .unwrap_or_else(|_| vec![]);
+            match kind {
+                DefKind::Struct | DefKind::Enum | DefKind::Union => {
+                    children.extend(self.impl_symbols(id, true).into_iter().map(|s| s.id))
+                }
+                _ => {}
+            }
+
+            for child in children {
+                let def = match self.analysis.get_def(child) {
+                    Ok(def) => def,
+                    Err(_) => continue,
+                };
+                // Trait items and variants are as public as their parent.
+                let inherits_visibility = kind == DefKind::Trait
+                    || kind == DefKind::Enum
+                    || kind == DefKind::TupleVariant
+                    || kind == DefKind::StructVariant;
+                if !inherits_visibility {
+                    let line = self
+                        .files
+                        .load_line(&def.span.file, def.span.range.row_start)
+                        .unwrap_or_default();
+                    if refs::visibility(&line, &def.span).map_or(true, |v| v != "pub") {
+                        continue;
+                    }
+                }
+
+                let child_path = format!("{}::{}", path, def.name);
+                result.push((
+                    child,
+                    ApiItem {
+                        id: child.to_string(),
+                        path: child_path.clone(),
+                        kind: def_kind_name(def.kind),
+                        signature: if def.value.is_empty() {
+                            None
+                        } else {
+                            Some(def.value.clone())
+                        },
+                        has_docs: !def.docs.trim().is_empty(),
+                        file_name: self.file_name(&def.span),
+                        line_start: def.span.range.row_start.one_indexed().0,
+                    },
+                ));
+                match def.kind {
+                    DefKind::Mod
+                    | DefKind::Struct
+                    | DefKind::Enum
+                    | DefKind::Union
+                    | DefKind::Trait
+                    | DefKind::TupleVariant
+                    | DefKind::StructVariant => stack.push((child, def.kind, child_path)),
+                    _ => {}
+                }
+            }
+        }
+
+        result.sort_by(|a, b| a.1.path.cmp(&b.1.path));
+        result
+    }
+
+    // Counts of documented public items in each workspace target and module.
+    pub fn doc_coverage(&self) -> Result<Vec<DocCoverage>, String> {
+        let metadata = self.workspace_metadata()?;
+        let roots = self.analysis.def_roots().unwrap_or_else(|_| vec![]);
+
+        // A lib and a bin may have the same crate name, so we keep targets
+        // apart by their kind.
+        let mut targets: Vec<(String, &'static str, Id)> = metadata
+            .packages
+            .iter()
+            .filter(|p| metadata.workspace_members.iter().any(|m| m.raw == p.id))
+            .flat_map(|p| &p.targets)
+            .filter_map(|t| {
+                let crate_name = t.name.replace("-", "_");
+                // E.g., a target which has not been built.
+                let root = self.target_root(&roots, &crate_name, Path::new(&t.src_path))?;
+                Some((crate_name, target_kind(&t.kind), root))
+            })
+            .collect();
+        targets.sort_by_key(|&(ref name, kind, _)| (name.clone(), target_kind_order(kind)));
+        targets.dedup_by_key(|t| t.2);
+
+        let mut result = vec![];
+        for (crate_name, target_kind, root) in targets {
+            let items = self.api_items_under(root, &crate_name);
+
+            let mut modules: Vec<ModuleDocCoverage> = vec![];
+            for (id, item) in items {
+                let module_name = self.module_path(id, &crate_name);
+                let index = match modules.iter().position(|m| m.name == module_name) {
+                    Some(index) => index,
+                    None => {
+                        modules.push(ModuleDocCoverage {
+                            name: module_name,
+                            documented: 0,
+                            total: 0,
+                            undocumented: vec![],
+                        });
+                        modules.len() - 1
+                    }
+                };
+                let module = &mut modules[index];
+                module.total += 1;
+                if item.has_docs {
+                    module.documented += 1;
+                } else {
+                    module.undocumented.push(item);
+                }
+            }
+            modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+            result.push(DocCoverage {
+                crate_name,
+                target_kind,
+                documented: modules.iter().map(|m| m.documented).sum(),
+                total: modules.iter().map(|m| m.total).sum(),
+                modules,
+            });
+        }
+
+        Ok(result)
+    }
+
+    // One line per item, for diffing.
+    pub fn api_surface_text(&self, crate_name: &str) -> Result<String, String> {
+        let mut result = String::new();
+        for item in self.api_surface(crate_name)? {
+            result.push_str(item.kind);
+            result.push(' ');
+            result.push_str(&item.path);
+            if let Some(ref signature) = item.signature {
+                result.push_str(": ");
+                result.push_str(&signature.split_whitespace().collect::<Vec<_>>().join(" "));
+            }
+            if !item.has_docs {
+                result.push_str(" [undocumented]");
+            }
+            result.push('\n');
+        }
+        Ok(result)
+    }
+
+    // Items in workspace crates which are never referenced outside their own
+    // definition. Trait impl methods, `main` and tests are never reported since
+    // they are used without being named.
+    pub fn unused(&self, pub_only: bool) -> Result<Vec<UnusedCrate>, String> {
+        let workspace_crates = self.workspace_crates()?;
+        let mut roots: Vec<(Id, String)> = self
+            .analysis
+            .def_roots()
+            .map_err(|_| "No analysis data".to_owned())?
+            .into_iter()
+            .filter(|&(_, ref name)| workspace_crates.contains(name))
+            .collect();
+        roots.sort_by(|a, b| a.1.cmp(&b.1));
+
+        // The text of each file, and the extents of its impls.
+        let mut files = HashMap::new();
+        let mut result = vec![];
+        for (root, crate_name) in roots {
+            let mut modules: Vec<UnusedModule> = vec![];
+            for (id, def) in self.defs_under(vec![root]) {
+                match def.kind {
+                    DefKind::Mod | DefKind::Local => continue,
+                    DefKind::Function if def.name == "main" => continue,
+                    _ => {}
+                }
+
+                let &mut (ref text, ref impls) =
+                    files.entry(def.span.file.clone()).or_insert_with(|| {
+                        let text = match self.files.load_file(&def.span.file) {
+                            Ok(::vfs::FileContents::Text(text)) => text,
+                            _ => String::new(),
+                        };
+                        let impls = outline::impl_extents(&text);
+                        (text, impls)
+                    });
+                let range = def.span.range;
+                let line = text.lines().nth(range.row_start.0 as usize).unwrap_or("");
+                let visibility = refs::visibility(line, &def.span);
+                if pub_only && visibility.as_ref().map_or(true, |v| v != "pub") {
+                    continue;
+                }
+
+                // References from inside the item itself (e.g., recursive
+                // calls) do not count.
+                let start = (range.row_start.0, range.col_start.0);
+                let mut end = start;
+                if def.kind == DefKind::Function || def.kind == DefKind::Method {
+                    if refs::is_test_fn(text, range.row_start.0) || in_trait_impl(impls, start) {
+                        continue;
+                    }
+                    end =
+                        refs::block_end(text, range.row_start.0, range.col_end.0).unwrap_or(start);
+                }
+                let refs = self.refs_to(id).unwrap_or_else(|_| vec![]);
+                let is_used = refs.iter().any(|r| {
+                    let pos = (r.range.row_start.0, r.range.col_start.0);
+                    r.file != def.span.file || pos < start || pos >= end
+                });
+                if is_used {
+                    continue;
+                }
+
+                let item = UnusedItem {
                     id: id.to_string(),
                     name: def.name.clone(),
-                    file_name: self.make_file_path(&span).display().to_string(),
-                    line_start: span.range.row_start.one_indexed().0,
+                    kind: def_kind_name(def.kind),
+                    visibility,
+                    file_name: self.file_name(&def.span),
+                    line_start: range.row_start.one_indexed().0,
+                };
+                let module_name = self.module_path(id, &crate_name);
+                match modules.iter_mut().find(|m| m.name == module_name) {
+                    Some(m) => m.items.push(item),
+                    None => modules.push(UnusedModule {
+                        name: module_name,
+                        items: vec![item],
+                    }),
+                }
+            }
+
+            if modules.is_empty() {
+                continue;
+            }
+            modules.sort_by(|a, b| a.name.cmp(&b.name));
+            for m in &mut modules {
+                m.items.sort_by(|a, b| {
+                    (&a.file_name, a.line_start).cmp(&(&b.file_name, b.line_start))
+                });
+            }
+            result.push(UnusedCrate {
+                crate_name,
+                modules,
+            });
+        }
+
+        Ok(result)
+    }
+
+    // The workspace packages, their targets, and the modules of each target.
+    pub fn crate_tree(&self) -> Result<Vec<PackageTree>, String> {
+        let metadata = self.workspace_metadata()?;
+        let roots = self.analysis.def_roots().unwrap_or_else(|_| vec![]);
+
+        let mut packages: Vec<PackageTree> = metadata
+            .packages
+            .iter()
+            .filter(|p| metadata.workspace_members.iter().any(|m| m.raw == p.id))
+            .map(|p| {
+                let mut targets: Vec<TargetTree> = p
+                    .targets
+                    .iter()
+                    .map(|t| {
+                        // Analysis uses crate names, which may differ from
+                        // target names.
+                        let crate_name = t.name.replace("-", "_");
+                        let root = self
+                            .target_root(&roots, &crate_name, Path::new(&t.src_path))
+                            .map(|id| self.module_tree(id, crate_name));
+                        TargetTree {
+                            name: t.name.clone(),
+                            kind: target_kind(&t.kind),
+                            src_path: self.relative_path(Path::new(&t.src_path)),
+                            root,
+                        }
+                    })
+                    .collect();
+                targets.sort_by_key(|t| (target_kind_order(t.kind), t.name.clone()));
+
+                PackageTree {
+                    id: p.id.clone(),
+                    name: p.name.clone(),
+                    version: p.version.clone(),
+                    manifest_dir: Path::new(&p.manifest_path)
+                        .parent()
+                        .map(|dir| self.relative_path(dir))
+                        .unwrap_or_default(),
+                    targets,
                 }
             })
-            .map_err(|e| e.to_string())
+            .collect();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(packages)
     }
 
-    pub fn id_search(&self, id: Id) -> Result<SearchResult, String> {
-        self.ids_search(vec![id])
+    // A lib and a bin target may have the same crate name, so we prefer the
+    // root whose file is the target's.
+    fn target_root(&self, roots: &[(Id, String)], crate_name: &str, src_path: &Path) -> Option<Id> {
+        let src_path = src_path.canonicalize().ok();
+        let candidates: Vec<Id> = roots
+            .iter()
+            .filter(|&&(_, ref name)| name == crate_name)
+            .map(|&(id, _)| id)
+            .collect();
+        candidates
+            .iter()
+            .cloned()
+            .find(|&id| {
+                self.analysis.get_def(id).ok().map_or(false, |def| {
+                    Path::new(&def.span.file).canonicalize().ok() == src_path
+                })
+            })
+            .or_else(|| {
+                if candidates.len() == 1 {
+                    Some(candidates[0])
+                } else {
+                    None
+                }
+            })
     }
 
-    pub fn ident_search(&self, needle: &str) -> Result<SearchResult, String> {
+    fn module_tree(&self, id: Id, name: String) -> ModuleTree {
+        // Don't query the analysis while it is locked for `for_each_child_def`.
+        let children = self
+            .analysis
+            .for_each_child_def(id, |id, def| {
+                (id, def.kind, def.name.clone(), def.span.clone())
+            })
+            .unwrap_or_else(|_| vec![]);
+
+        let mut files: Vec<String> = children
+            .iter()
+            .map(|&(_, _, _, ref span)| self.file_name(span))
+            .collect();
+        let def = self.analysis.get_def(id).ok();
+        if files.is_empty() {
+            if let Some(ref def) = def {
+                files.push(self.file_name(&def.span));
+            }
+        }
+        files.sort();
+        files.dedup();
+
+        let mut modules: Vec<ModuleTree> = children
+            .into_iter()
+            .filter(|&(_, kind, _, _)| kind == DefKind::Mod)
+            .map(|(id, _, name, _)| self.module_tree(id, name))
+            .collect();
+        modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+        ModuleTree {
+            id: id.to_string(),
+            name,
+            file_name: def
+                .as_ref()
+                .map(|def| self.file_name(&def.span))
+                .unwrap_or_default(),
+            line_start: def.map_or(0, |def| def.span.range.row_start.one_indexed().0),
+            files,
+            children: modules,
+        }
+    }
+
+    pub fn module_graph_dot(&self, crate_name: &str) -> Result<String, String> {
+        self.module_graph(crate_name)
+            .map(|g| module_graph::to_dot(&g))
+    }
+
+    // If `kinds` is given, only children with those kinds (as named by
+    // `def_kind_name`) are returned.
+    pub fn get_symbol_children(
+        &self,
+        id: Id,
+        kinds: Option<&[String]>,
+    ) -> Result<Vec<SymbolResult>, String> {
+        // We can't query the analysis from inside the callback (it holds the
+        // analysis lock), so collect the defs first.
+        let children = self
+            .analysis
+            .for_each_child_def(id, |id, def| (id, def.clone()))
+            .map_err(|e| e.to_string())?;
+        Ok(children
+            .into_iter()
+            .filter(|&(_, ref def)| {
+                kinds.map_or(true, |kinds| {
+                    kinds.iter().any(|k| k == def_kind_name(def.kind))
+                })
+            })
+            .map(|(id, def)| self.make_symbol_result(id, &def))
+            .collect())
+    }
+
+    fn make_symbol_result(&self, id: Id, def: &Def) -> SymbolResult {
+        let file_path = self.make_file_path(&def.span);
+        let visibility = self
+            .files
+            .load_line(&file_path, def.span.range.row_start)
+            .ok()
+            .and_then(|line| refs::visibility(&line, &def.span));
+        let child_count = self
+            .analysis
+            .for_each_child_def(id, |_, _| ())
+            .map(|children| children.len())
+            .unwrap_or(0);
+
+        SymbolResult {
+            id: id.to_string(),
+            name: def.name.clone(),
+            kind: def_kind_name(def.kind),
+            visibility,
+            signature: if def.value.is_empty() {
+                None
+            } else {
+                Some(def.value.clone())
+            },
+            docs_summary: docs_summary(&def.docs),
+            child_count,
+            file_name: self.file_name(&def.span),
+            line_start: def.span.range.row_start.one_indexed().0,
+        }
+    }
+
+    // The items defined in a file, nested by their extent.
+    pub fn outline(&self, path: &Path) -> Result<Vec<OutlineItem>, String> {
+        let text = match vfs_err!(self.files.load_file(path))? {
+            ::vfs::FileContents::Text(text) => text,
+            ::vfs::FileContents::Binary(_) => {
+                return Err(format!("Not a text file: {}", path.display()))
+            }
+        };
+        let symbols = self
+            .analysis
+            .symbols(&self.abs_path(path))
+            .unwrap_or_else(|_| vec![]);
+        let mut items: Vec<FlatItem> = symbols
+            .into_iter()
+            .filter(|s| s.kind != DefKind::Local)
+            .map(|s| {
+                let range = s.span.range;
+                let start = (range.row_start.0, range.col_start.0);
+                let block_end = match s.kind {
+                    DefKind::Function
+                    | DefKind::Method
+                    | DefKind::Struct
+                    | DefKind::Enum
+                    | DefKind::Union
+                    | DefKind::Trait => refs::block_end(&text, start.0, range.col_end.0),
+                    // Out-of-line modules have no block in this file.
+                    DefKind::Mod => refs::inline_mod_end(&text, &s.span),
+                    _ => None,
+                };
+                let end = block_end.unwrap_or((range.row_end.0, range.col_end.0));
+                FlatItem::new(
+                    Some(s.id.to_string()),
+                    s.name,
+                    def_kind_name(s.kind),
+                    start,
+                    end,
+                )
+            })
+            .collect();
+        items.extend(
+            outline::find_impls(&text)
+                .into_iter()
+                .map(|(name, start, end)| FlatItem::new(None, name, "impl", start, end)),
+        );
+
+        Ok(outline::build_tree(items))
+    }
+
+    // Inexact search for defs by name, for jumping to a symbol.
+    pub fn symbol_search(&self, query: &str, limit: usize) -> Result<Vec<SymbolMatch>, String> {
+        let matches = self.symbol_index.lock().unwrap().search(query, limit);
+        Ok(matches
+            .into_iter()
+            .filter_map(|(id, match_kind)| {
+                let def = self.analysis.get_def(id).ok()?;
+                Some(SymbolMatch {
+                    id: id.to_string(),
+                    file_name: self.file_name(&def.span),
+                    line_start: def.span.range.row_start.one_indexed().0,
+                    workspace: def.span.file.starts_with(&self.workspace_root()),
+                    kind: def_kind_name(def.kind),
+                    name: def.name,
+                    qualname: def.qualname,
+                    match_kind,
+                })
+            })
+            .collect())
+    }
+
+    pub fn id_search(&self, id: Id, filter: &RefFilter) -> Result<SearchResult, String> {
+        self.ids_search(vec![id], filter)
+    }
+
+    // `line` and `col` are one-indexed, matching the positions we report in
+    // `LineResult`.
+    pub fn position_search(
+        &self,
+        path: &Path,
+        line: span::Row<span::OneIndexed>,
+        col: span::Column<span::OneIndexed>,
+        filter: &RefFilter,
+    ) -> Result<SearchResult, String> {
+        let id = self.id_at_position(path, line.zero_indexed(), col.zero_indexed())?;
+        self.id_search(id, filter)
+    }
+
+    pub fn ident_search(&self, needle: &str, filter: &RefFilter) -> Result<SearchResult, String> {
         // First see if the needle corresponds to any definitions, if it does, get a list of the
         // ids, otherwise, return an empty search result.
         let ids = match self.analysis.search_for_id(needle) {
@@ -257,7 +1231,436 @@ impl Cache {
             }
         };
 
-        self.ids_search(ids)
+        self.ids_search(ids, filter)
+    }
+
+    // Search for the def(s) with the given path, e.g., `krate::module::Type::method`.
+    // `crate` as the first segment means any workspace crate.
+    pub fn path_search(&self, path: &str, filter: &RefFilter) -> Result<SearchResult, String> {
+        let ids = self.resolve_path(path)?;
+        self.ids_search(ids, filter)
+    }
+
+    // Text (or regex) search over every file in the workspace.
+    pub fn text_search(
+        &self,
+        pattern: &str,
+        is_regex: bool,
+        case_sensitive: bool,
+    ) -> Result<FindResult, String> {
+        let matcher = grep::build_matcher(pattern, is_regex, case_sensitive)?;
+
+        let files = self.search_files();
+        let candidates = {
+            let mut index = self.text_index.lock().unwrap();
+            index.update(&files);
+            index.candidates(&grep::required_literals(pattern, is_regex))
+        };
+
+        let mut spans = vec![];
+        let mut truncated = false;
+        for path in files {
+            if let Some(ref candidates) = candidates {
+                if !candidates.contains(&path) {
+                    continue;
+                }
+            }
+
+            // Search the text in the VFS, since that is where the lines in the
+            // results come from. Only files which the index says might match
+            // are loaded.
+            if grep::too_large(&path) {
+                continue;
+            }
+            let text = match self.files.load_file(&path) {
+                Ok(::vfs::FileContents::Text(ref text)) if grep::is_text(text) => text.clone(),
+                _ => continue,
+            };
+            spans.extend(grep::find_matches(&matcher, &text, &path));
+            if spans.len() > MAX_TEXT_SEARCH_RESULTS {
+                spans.truncate(MAX_TEXT_SEARCH_RESULTS);
+                truncated = true;
+                break;
+            }
+        }
+
+        Ok(FindResult {
+            results: self.make_search_results(spans, None)?,
+            truncated,
+        })
+    }
+
+    // The files in the workspace, skipping those we skip in directory
+    // listings by default.
+    fn search_files(&self) -> Vec<PathBuf> {
+        let git_status = if self.config.respect_gitignore {
+            self.git_status().ok()
+        } else {
+            None
+        };
+        grep::workspace_files(
+            &self.workspace_root(),
+            &self.exclude,
+            git_status.as_ref().map(|s| &**s),
+        )
+    }
+
+    // References to `id`, not including its def. The def is usually the first
+    // reference, but not if it is outside the analysed crates.
+    fn refs_to(&self, id: Id) -> Result<Vec<Span>, String> {
+        let def_span = self.analysis.get_def(id).ok().map(|def| def.span);
+        Ok(self
+            .analysis
+            .find_all_refs_by_id(id)
+            .map_err(|_| "Error finding references".to_owned())?
+            .into_iter()
+            .filter(|span| Some(span) != def_span.as_ref())
+            .collect())
+    }
+
+    // The functions which call `id`, with the locations of the calls.
+    pub fn callers(&self, id: Id) -> Result<Vec<CallResult>, String> {
+        let refs = self.refs_to(id)?;
+        let crate_roots: HashMap<Id, String> = self
+            .analysis
+            .def_roots()
+            .unwrap_or_else(|_| vec![])
+            .into_iter()
+            .collect();
+        let mut file_infos = HashMap::new();
+        let mut order = vec![];
+        let mut calls = HashMap::new();
+
+        for span in refs {
+            let file_path = self.make_file_path(&span);
+            let line = self
+                .files
+                .load_line(&file_path, span.range.row_start)
+                .unwrap_or_default();
+            if refs::classify_ref(&line, &span, false) != RefKind::Call {
+                continue;
+            }
+
+            let info = file_infos
+                .entry(span.file.clone())
+                .or_insert_with(|| self.file_info(&span.file, &crate_roots));
+            if let Some(caller) = refs::enclosing_fn(&info.fns, &span) {
+                if !calls.contains_key(&caller.id) {
+                    order.push(caller.id);
+                }
+                calls.entry(caller.id).or_insert_with(Vec::new).push(span);
+            }
+        }
+
+        let mut result: Vec<CallResult> = order
+            .into_iter()
+            .filter_map(|id| self.make_call_result(id, &calls[&id]))
+            .collect();
+        result.sort_by(|a, b| (&a.file_name, a.line_start).cmp(&(&b.file_name, b.line_start)));
+        Ok(result)
+    }
+
+    // The functions called from the body of `id`, in the order they are first
+    // called.
+    pub fn callees(&self, id: Id) -> Result<Vec<CallResult>, String> {
+        let def = self
+            .analysis
+            .get_def(id)
+            .map_err(|_| format!("Unknown id: {}", id))?;
+        let file_path = self.make_file_path(&def.span);
+        let text = match vfs_err!(self.files.load_file(&file_path))? {
+            ::vfs::FileContents::Text(text) => text,
+            ::vfs::FileContents::Binary(_) => {
+                return Err(format!("Not a text file: {}", file_path.display()))
+            }
+        };
+        let range = def.span.range;
+        let body_start = (range.row_start.0, range.col_end.0);
+        let body_end = refs::block_end(&text, body_start.0, body_start.1)
+            .ok_or_else(|| format!("`{}` has no body", def.name))?;
+
+        let mut order = vec![];
+        let mut calls = HashMap::new();
+        let lines = text
+            .lines()
+            .enumerate()
+            .skip(body_start.0 as usize)
+            .take((body_end.0 - body_start.0) as usize + 1);
+        for (row, line) in lines {
+            let row = row as u32;
+            for (col_start, col_end) in refs::idents(line) {
+                if (row, col_start) < body_start || (row, col_start) >= body_end {
+                    continue;
+                }
+                let span = self.make_span(&def.span.file, row, (col_start, col_end));
+                let callee = match self.analysis.id(&span) {
+                    Ok(callee) => callee,
+                    Err(_) => continue,
+                };
+                match self.analysis.get_def(callee) {
+                    Ok(ref d) if d.kind == DefKind::Function || d.kind == DefKind::Method => {}
+                    _ => continue,
+                }
+                if refs::classify_ref(line, &span, false) != RefKind::Call {
+                    continue;
+                }
+
+                if !calls.contains_key(&callee) {
+                    order.push(callee);
+                }
+                calls.entry(callee).or_insert_with(Vec::new).push(span);
+            }
+        }
+
+        Ok(order
+            .into_iter()
+            .filter_map(|id| self.make_call_result(id, &calls[&id]))
+            .collect())
+    }
+
+    fn make_call_result(&self, id: Id, call_sites: &[Span]) -> Option<CallResult> {
+        let def = self.analysis.get_def(id).ok()?;
+        Some(CallResult {
+            id: id.to_string(),
+            name: def.name.clone(),
+            kind: def_kind_name(def.kind),
+            file_name: self.file_name(&def.span),
+            line_start: def.span.range.row_start.one_indexed().0,
+            call_sites: call_sites
+                .iter()
+                .filter_map(|s| self.make_line_result(&self.make_file_path(s), s).ok())
+                .collect(),
+        })
+    }
+
+    // The implementations of a trait method.
+    pub fn find_method_impls(&self, id: Id) -> Result<FindResult, String> {
+        let def = self
+            .analysis
+            .get_def(id)
+            .map_err(|_| format!("Unknown id: {}", id))?;
+        let trait_id = def
+            .parent
+            .filter(|&p| {
+                self.analysis
+                    .get_def(p)
+                    .map_or(false, |p| p.kind == DefKind::Trait)
+            })
+            .ok_or_else(|| format!("`{}` is not a trait method", def.name))?;
+
+        let spans = self
+            .impl_items(trait_id, &def.name)
+            .into_iter()
+            .filter(|&i| i != id)
+            .filter_map(|i| self.analysis.get_def(i).ok())
+            .filter(|d| d.kind == DefKind::Method || d.kind == DefKind::Function)
+            .map(|d| d.span)
+            .collect();
+        Ok(FindResult {
+            results: self.make_search_results(spans, None)?,
+            truncated: false,
+        })
+    }
+
+    // The declaration in a trait of a method implemented in an impl of the
+    // trait.
+    pub fn find_trait_decl(&self, id: Id) -> Result<FindResult, String> {
+        let def = self
+            .analysis
+            .get_def(id)
+            .map_err(|_| format!("Unknown id: {}", id))?;
+        let text = match vfs_err!(self.files.load_file(&def.span.file))? {
+            ::vfs::FileContents::Text(text) => text,
+            ::vfs::FileContents::Binary(_) => {
+                return Err(format!("Not a text file: {}", def.span.file.display()))
+            }
+        };
+
+        let pos = (def.span.range.row_start.0, def.span.range.col_start.0);
+        let (_, impl_start, _) = outline::find_impls(&text)
+            .into_iter()
+            .filter(|&(_, start, end)| start < pos && pos < end)
+            .max_by_key(|&(_, start, _)| start)
+            .ok_or_else(|| format!("`{}` is not in an impl", def.name))?;
+        let line = text.lines().nth(impl_start.0 as usize).unwrap_or("");
+        let trait_name = refs::impl_header(line)
+            .and_then(|h| h.trait_name)
+            .ok_or_else(|| format!("`{}` is not in a trait impl", def.name))?;
+        let trait_id = self
+            .analysis
+            .id(&self.make_span(&def.span.file, impl_start.0, trait_name))
+            .map_err(|_| "Trait not found".to_owned())?;
+
+        let decls = self
+            .analysis
+            .for_each_child_def(trait_id, |_, d| d.clone())
+            .map_err(|e| e.to_string())?;
+        let spans = decls
+            .into_iter()
+            .filter(|d| d.name == def.name)
+            .map(|d| d.span)
+            .collect();
+        Ok(FindResult {
+            results: self.make_search_results(spans, None)?,
+            truncated: false,
+        })
+    }
+
+    pub fn type_hierarchy(&self, id: Id) -> Result<TypeHierarchy, String> {
+        let def = self
+            .analysis
+            .get_def(id)
+            .map_err(|_| format!("Unknown id: {}", id))?;
+        let mut result = TypeHierarchy {
+            id: id.to_string(),
+            name: def.name.clone(),
+            kind: def_kind_name(def.kind),
+            supertraits: vec![],
+            subtraits: vec![],
+            implementors: vec![],
+            traits: vec![],
+        };
+        let impls = self.analysis.find_impls(id).unwrap_or_else(|_| vec![]);
+
+        match def.kind {
+            DefKind::Trait => {
+                let line = self
+                    .files
+                    .load_line(&def.span.file, def.span.range.row_start)
+                    .unwrap_or_default();
+                result.supertraits = refs::supertraits(&line, def.span.range.col_end.0)
+                    .into_iter()
+                    .filter_map(|cols| {
+                        let span = self.make_span(&def.span.file, def.span.range.row_start.0, cols);
+                        match self.analysis.id(&span) {
+                            Ok(id) => self.def_hierarchy_item(id),
+                            Err(_) => Some(self.hierarchy_item(None, &line, &span, false)),
+                        }
+                    })
+                    .collect();
+                result.subtraits = self.subtraits(id);
+                result.implementors = impls
+                    .iter()
+                    .filter_map(|span| self.impl_hierarchy_item(span, false))
+                    .collect();
+            }
+            DefKind::Struct
+            | DefKind::Enum
+            | DefKind::Union
+            | DefKind::Type
+            | DefKind::ExternType => {
+                let text = match self.files.load_file(&def.span.file) {
+                    Ok(::vfs::FileContents::Text(text)) => text,
+                    _ => String::new(),
+                };
+                let lines: Vec<&str> = text.lines().collect();
+                result.traits = refs::derives(&text, def.span.range.row_start.0)
+                    .into_iter()
+                    .map(|(row, start, end)| {
+                        let span = self.make_span(&def.span.file, row, (start, end));
+                        self.hierarchy_item(None, lines[row as usize], &span, true)
+                    })
+                    .collect();
+                result.traits.extend(
+                    impls
+                        .iter()
+                        .filter_map(|span| self.impl_hierarchy_item(span, true)),
+                );
+            }
+            _ => return Err(format!("`{}` is not a trait or type", def.name)),
+        }
+
+        Ok(result)
+    }
+
+    // Traits which have `id` as a supertrait.
+    fn subtraits(&self, id: Id) -> Vec<HierarchyItem> {
+        let mut symbols = HashMap::new();
+        let mut seen = HashSet::new();
+        let mut result = vec![];
+
+        for span in self.refs_to(id).unwrap_or_else(|_| vec![]) {
+            let line = match self.files.load_line(&span.file, span.range.row_start) {
+                Ok(line) => line,
+                Err(_) => continue,
+            };
+            let cols = (span.range.col_start.0, span.range.col_end.0);
+            let symbols = symbols
+                .entry(span.file.clone())
+                .or_insert_with(|| self.analysis.symbols(&span.file).unwrap_or_else(|_| vec![]));
+            for s in symbols.iter() {
+                let is_subtrait = s.kind == DefKind::Trait
+                    && s.span.range.row_start == span.range.row_start
+                    && s.span.range.col_end <= span.range.col_start
+                    && refs::supertraits(&line, s.span.range.col_end.0).contains(&cols);
+                if is_subtrait && seen.insert(s.id) {
+                    result.extend(self.def_hierarchy_item(s.id));
+                }
+            }
+        }
+
+        result
+    }
+
+    // `of_trait` is true for the trait of the impl, false for the self type.
+    fn impl_hierarchy_item(&self, span: &Span, of_trait: bool) -> Option<HierarchyItem> {
+        let line = self
+            .files
+            .load_line(&span.file, span.range.row_start)
+            .ok()?;
+        let header = refs::impl_header(&line)?;
+        // Inherent impls are not part of the hierarchy.
+        let trait_name = header.trait_name?;
+        let cols = if of_trait { trait_name } else { header.self_ty };
+
+        let name_span = self.make_span(&span.file, span.range.row_start.0, cols);
+        let id = self.analysis.id(&name_span).ok();
+        let mut item = self.hierarchy_item(id, &line, &name_span, false);
+        item.line_start = span.range.row_start.one_indexed().0;
+        Some(item)
+    }
+
+    fn def_hierarchy_item(&self, id: Id) -> Option<HierarchyItem> {
+        let def = self.analysis.get_def(id).ok()?;
+        Some(HierarchyItem {
+            id: Some(id.to_string()),
+            name: def.name.clone(),
+            file_name: self.file_name(&def.span),
+            line_start: def.span.range.row_start.one_indexed().0,
+            derived: false,
+        })
+    }
+
+    // `span` is the name of the item on `line`.
+    fn hierarchy_item(
+        &self,
+        id: Option<Id>,
+        line: &str,
+        span: &Span,
+        derived: bool,
+    ) -> HierarchyItem {
+        let range = span.range;
+        HierarchyItem {
+            id: id.map(|id| id.to_string()),
+            name: line
+                .chars()
+                .skip(range.col_start.0 as usize)
+                .take((range.col_end.0 - range.col_start.0) as usize)
+                .collect(),
+            file_name: self.file_name(span),
+            line_start: range.row_start.one_indexed().0,
+            derived,
+        }
+    }
+
+    fn make_span(&self, file: &Path, row: u32, (col_start, col_end): (u32, u32)) -> Span {
+        Span::new(
+            span::Row::new_zero_indexed(row),
+            span::Row::new_zero_indexed(row),
+            span::Column::new_zero_indexed(col_start),
+            span::Column::new_zero_indexed(col_end),
+            file.to_owned(),
+        )
     }
 
     pub fn find_impls(&self, id: Id) -> Result<FindResult, String> {
@@ -266,11 +1669,12 @@ impl Cache {
             .find_impls(id)
             .map_err(|_| "No impls found".to_owned())?;
         Ok(FindResult {
-            results: self.make_search_results(impls)?,
+            results: self.make_search_results(impls, None)?,
+            truncated: false,
         })
     }
 
-    fn ids_search(&self, ids: Vec<Id>) -> Result<SearchResult, String> {
+    fn ids_search(&self, ids: Vec<Id>, filter: &RefFilter) -> Result<SearchResult, String> {
         let mut defs = Vec::new();
 
         for id in ids {
@@ -287,9 +1691,9 @@ impl Cache {
             let line = self.make_line_result(&def_path, &def_span)?;
 
             defs.push(DefResult {
-                file: def_path.display().to_string(),
+                file: self.file_name(&def_span),
                 line,
-                refs: self.make_search_results(all_refs.collect())?,
+                refs: self.make_search_results(all_refs.collect(), Some((id, filter)))?,
             });
         }
 
@@ -297,6 +1701,252 @@ impl Cache {
         return Ok(SearchResult { defs });
     }
 
+    // Finds the identifier which covers the given position and looks up the
+    // id of the def it refers to (or defines).
+    fn id_at_position(
+        &self,
+        path: &Path,
+        line: span::Row<span::ZeroIndexed>,
+        col: span::Column<span::ZeroIndexed>,
+    ) -> Result<Id, String> {
+        fn is_ident_char(c: char) -> bool {
+            c.is_alphanumeric() || c == '_'
+        }
+
+        let text = vfs_err!(self.files.load_line(path, line))?;
+        let chars: Vec<char> = text.chars().collect();
+        let col = col.0 as usize;
+        if col >= chars.len() || !is_ident_char(chars[col]) {
+            return Err(format!(
+                "No identifier at {}:{}",
+                path.display(),
+                line.0 + 1
+            ));
+        }
+
+        let mut start = col;
+        while start > 0 && is_ident_char(chars[start - 1]) {
+            start -= 1;
+        }
+        let mut end = col;
+        while end < chars.len() && is_ident_char(chars[end]) {
+            end += 1;
+        }
+
+        let span = Span::new(
+            line,
+            line,
+            span::Column::new_zero_indexed(start as u32),
+            span::Column::new_zero_indexed(end as u32),
+            self.abs_path(path),
+        );
+        self.analysis.id(&span).map_err(|_| {
+            format!(
+                "No definition found for `{}`",
+                chars[start..end].iter().collect::<String>()
+            )
+        })
+    }
+
+    fn resolve_path(&self, path: &str) -> Result<Vec<Id>, String> {
+        let mut segments = path.split("::").filter(|s| !s.is_empty());
+        let krate = segments.next().ok_or_else(|| "Empty path".to_owned())?;
+
+        let workspace_root = self.workspace_root();
+        let mut current: Vec<Id> = self
+            .analysis
+            .def_roots()
+            .unwrap_or_else(|_| vec![])
+            .into_iter()
+            .filter(|&(id, ref name)| {
+                if krate == "crate" {
+                    self.analysis
+                        .get_def(id)
+                        .map(|def| def.span.file.starts_with(&workspace_root))
+                        .unwrap_or(false)
+                } else {
+                    name == krate
+                }
+            })
+            .map(|(id, _)| id)
+            .collect();
+
+        for segment in segments {
+            let mut next = vec![];
+            for id in current {
+                let children = self
+                    .analysis
+                    .for_each_child_def(id, |id, def| (id, def.name.clone()))
+                    .unwrap_or_else(|_| vec![]);
+                next.extend(
+                    children
+                        .into_iter()
+                        .filter(|&(_, ref name)| name == segment)
+                        .map(|(id, _)| id),
+                );
+
+                // Methods in impls are not children of the type, so we have
+                // to look inside the impl blocks. For traits, the children are
+                // the declarations, which is what we want.
+                match self.analysis.get_def(id) {
+                    Ok(ref def) if def.kind != DefKind::Trait => {
+                        next.extend(self.impl_items(id, segment))
+                    }
+                    _ => {}
+                }
+            }
+
+            let mut seen = HashSet::new();
+            next.retain(|id| seen.insert(*id));
+            if next.is_empty() {
+                return Err(format!("Could not find `{}` in `{}`", segment, path));
+            }
+            current = next;
+        }
+
+        Ok(current)
+    }
+
+    // Finds items called `name` which are defined in an impl of `id`.
+    fn impl_items(&self, id: Id, name: &str) -> Vec<Id> {
+        self.impl_symbols(id, false)
+            .into_iter()
+            .filter(|s| s.name == name)
+            .map(|s| s.id)
+            .collect()
+    }
+
+    // The defs inside the impls of `id`. If `inherent_only`, impls of traits
+    // are skipped.
+    fn impl_symbols(&self, id: Id, inherent_only: bool) -> Vec<::analysis::SymbolResult> {
+        let mut result = vec![];
+        for span in self.analysis.find_impls(id).unwrap_or_else(|_| vec![]) {
+            let text = match self.files.load_file(&span.file) {
+                Ok(::vfs::FileContents::Text(text)) => text,
+                _ => continue,
+            };
+            if inherent_only {
+                let line = text.lines().nth(span.range.row_start.0 as usize);
+                let header = line.and_then(refs::impl_header);
+                if header.map_or(true, |h| h.trait_name.is_some()) {
+                    continue;
+                }
+            }
+            let start = (span.range.row_start.0, span.range.col_start.0);
+            let end = match refs::block_end(&text, start.0, start.1) {
+                Some(end) => end,
+                None => continue,
+            };
+
+            let symbols = self.analysis.symbols(&span.file).unwrap_or_else(|_| vec![]);
+            result.extend(symbols.into_iter().filter(|s| {
+                let pos = (s.span.range.row_start.0, s.span.range.col_start.0);
+                s.kind != DefKind::Local && start < pos && pos < end
+            }));
+        }
+        result
+    }
+
+    // Every def reachable from a crate root.
+    fn all_defs(&self) -> Vec<(Id, Def)> {
+        let roots = self
+            .analysis
+            .def_roots()
+            .unwrap_or_else(|_| vec![])
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        self.defs_under(roots)
+    }
+
+    // `roots` and all their descendants.
+    fn defs_under(&self, roots: Vec<Id>) -> Vec<(Id, Def)> {
+        let mut result = vec![];
+        let mut seen = HashSet::new();
+        let mut stack = roots;
+
+        while let Some(id) = stack.pop() {
+            if !seen.insert(id) {
+                continue;
+            }
+            if let Ok(def) = self.analysis.get_def(id) {
+                result.push((id, def));
+            }
+            if let Ok(children) = self.analysis.for_each_child_def(id, |id, _| id) {
+                stack.extend(children);
+            }
+        }
+
+        result
+    }
+
+    fn workspace_root(&self) -> PathBuf {
+        self.config
+            .workspace_root
+            .as_ref()
+            .map(|s| Path::new(s).to_owned())
+            .unwrap_or(self.project_dir.clone())
+    }
+
+    fn file_info(&self, file: &Path, crate_roots: &HashMap<Id, String>) -> FileInfo {
+        let symbols = self.analysis.symbols(file).unwrap_or_else(|_| vec![]);
+
+        // Any def in the file will do to find the crate, all defs in a file
+        // share the same crate root.
+        let crate_name = symbols.first().and_then(|s| {
+            let root = self
+                .analysis
+                .def_parents(s.id)
+                .ok()
+                .and_then(|parents| parents.first().map(|&(id, _)| id))
+                .unwrap_or(s.id);
+            crate_roots.get(&root).cloned()
+        });
+
+        let text = match self.files.load_file(file) {
+            Ok(::vfs::FileContents::Text(text)) => text,
+            _ => String::new(),
+        };
+        let fns = symbols
+            .into_iter()
+            .filter(|s| s.kind == DefKind::Function || s.kind == DefKind::Method)
+            .filter_map(|s| {
+                let range = s.span.range;
+                let end = refs::block_end(&text, range.row_start.0, range.col_end.0)?;
+                Some(FnExtent {
+                    id: s.id,
+                    name: s.name,
+                    start: (range.row_start.0, range.col_start.0),
+                    end,
+                })
+            })
+            .collect();
+
+        FileInfo {
+            crate_name,
+            workspace: file.starts_with(&self.workspace_root()),
+            fns,
+        }
+    }
+
+    // The analysis data uses absolute paths.
+    fn abs_path(&self, path: &Path) -> PathBuf {
+        let abs_path = self.project_dir.join(path);
+        abs_path.canonicalize().unwrap_or(abs_path)
+    }
+
+    fn relative_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.project_dir)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
+    // The file name to give to the client, see `LinkPaths`.
+    fn file_name(&self, span: &Span) -> String {
+        self.link_paths().file_name(Path::new(&span.file))
+    }
+
     fn make_file_path(&self, span: &Span) -> PathBuf {
         let file_path = Path::new(&span.file);
         file_path
@@ -312,7 +1962,12 @@ impl Cache {
                 ..
             }) => {
                 let line = span.range.row_start.0 as i32;
-                let text = lines[line as usize].clone();
+                // The span may be out of date, e.g., if it comes from the
+                // analysis data and the file has been edited since.
+                let text = match lines.get(line as usize) {
+                    Some(text) => text.clone(),
+                    None => return Err(format!("Error finding text for {:?}", span)),
+                };
 
                 let mut ctx_start = line - CONTEXT_SIZE;
                 if ctx_start < 0 {
@@ -342,28 +1997,66 @@ impl Cache {
         self.files.load_file(path)
     }
 
-    // Sorts a set of search results into buckets by file.
-    fn make_search_results(&self, raw: Vec<Span>) -> Result<Vec<FileResult>, String> {
+    // Sorts a set of search results into buckets by file. If `refs_of` is
+    // given, then the results are references to that def, and we classify
+    // them and apply the filter.
+    fn make_search_results(
+        &self,
+        raw: Vec<Span>,
+        refs_of: Option<(Id, &RefFilter)>,
+    ) -> Result<Vec<FileResult>, String> {
+        let impls = match refs_of {
+            Some((id, _)) => self.analysis.find_impls(id).unwrap_or_else(|_| vec![]),
+            None => vec![],
+        };
+        let crate_roots: HashMap<Id, String> = self
+            .analysis
+            .def_roots()
+            .unwrap_or_else(|_| vec![])
+            .into_iter()
+            .collect();
+        let link_paths = self.link_paths();
+        let mut file_infos = HashMap::new();
         let mut file_buckets = HashMap::new();
 
         for span in &raw {
+            let info = file_infos
+                .entry(span.file.clone())
+                .or_insert_with(|| self.file_info(&span.file, &crate_roots));
             let file_path = self.make_file_path(span);
-            let line = match self.make_line_result(&file_path, span) {
+            let mut line = match self.make_line_result(&file_path, span) {
                 Ok(l) => l,
                 Err(_) => continue,
             };
+            line.enclosing_fn = refs::enclosing_fn(&info.fns, span).map(|f| f.name.clone());
+
+            if let Some((_, filter)) = refs_of {
+                let text = self
+                    .files
+                    .load_line(&file_path, span.range.row_start)
+                    .unwrap_or_default();
+                let kind = refs::classify_ref(&text, span, impls.contains(span));
+                line.kind = Some(kind);
+                if !filter.matches(kind, info, line.enclosing_fn.as_ref()) {
+                    continue;
+                }
+            }
+
             file_buckets
-                .entry(file_path.display().to_string())
-                .or_insert_with(|| vec![])
+                .entry(link_paths.file_name(Path::new(&span.file)))
+                .or_insert_with(|| (info.crate_name.clone(), info.workspace, vec![]))
+                .2
                 .push(line);
         }
 
         let mut result = vec![];
-        for (file_path, mut lines) in file_buckets.into_iter() {
+        for (file_path, (crate_name, workspace, mut lines)) in file_buckets.into_iter() {
             lines.sort();
             let per_file = FileResult {
                 file_name: file_path,
                 lines: lines,
+                crate_name,
+                workspace,
             };
             result.push(per_file);
         }
@@ -371,3 +2064,70 @@ impl Cache {
         Ok(result)
     }
 }
+
+fn raw_lines(text: &str) -> Vec<String> {
+    let mut highlighted: Vec<String> = text.lines().map(|s| s.to_owned()).collect();
+    if text.ends_with('\n') {
+        highlighted.push(String::new());
+    }
+
+    highlighted
+}
+
+// The first non-empty line of a doc comment.
+fn docs_summary(docs: &str) -> Option<String> {
+    docs.lines()
+        .map(|l| l.trim())
+        .find(|l| !l.is_empty())
+        .map(|l| l.to_owned())
+}
+
+fn def_kind_name(kind: DefKind) -> &'static str {
+    match kind {
+        DefKind::Enum => "enum",
+        DefKind::TupleVariant | DefKind::StructVariant => "variant",
+        DefKind::Tuple => "tuple",
+        DefKind::Struct => "struct",
+        DefKind::Union => "union",
+        DefKind::Trait => "trait",
+        DefKind::Function => "fn",
+        DefKind::Method => "method",
+        DefKind::Macro => "macro",
+        DefKind::Mod => "mod",
+        DefKind::Type => "type",
+        DefKind::Local => "local",
+        DefKind::Static => "static",
+        DefKind::Const => "const",
+        DefKind::Field => "field",
+        DefKind::ExternType => "extern type",
+    }
+}
+
+// Cargo gives a target several kinds if it is a library with more than one
+// crate type.
+fn target_kind(kinds: &[String]) -> &'static str {
+    match kinds.first().map(|k| &**k) {
+        Some("bin") => "bin",
+        Some("test") => "test",
+        Some("example") => "example",
+        Some("bench") => "bench",
+        Some("custom-build") => "build",
+        _ => "lib",
+    }
+}
+
+fn target_kind_order(kind: &str) -> usize {
+    ["lib", "bin", "test", "example", "bench", "build"]
+        .iter()
+        .position(|k| *k == kind)
+        .unwrap_or(0)
+}
+
+// Whether the position `pos` is inside an impl of a trait.
+fn in_trait_impl(impls: &[((u32, u32), (u32, u32), bool)], pos: (u32, u32)) -> bool {
+    impls
+        .iter()
+        .filter(|&&(start, end, _)| start < pos && pos < end)
+        .max_by_key(|&&(start, _, _)| start)
+        .map_or(false, |&(_, _, is_trait)| is_trait)
+}
@@ -0,0 +1,95 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Which modules of a crate refer to which others. The graph itself is computed
+// by `Cache::module_graph`, this module finds the module which contains a
+// reference.
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use analysis::{DefKind, Id, SymbolResult};
+
+use super::crate_graph::escape;
+use super::refs;
+use super::results::ModuleGraph;
+use super::Span;
+
+// The modules of a single file.
+pub struct FileModules {
+    // The module the file is the source of.
+    file_module: Option<Id>,
+    // Modules declared inline in the file, with their extents.
+    inline: Vec<(Id, (u32, u32), (u32, u32))>,
+}
+
+impl FileModules {
+    // `modules` are the modules we are interested in, other modules are
+    // ignored.
+    pub fn new(text: &str, symbols: &[SymbolResult], modules: &HashSet<Id>) -> FileModules {
+        let inline: Vec<_> = symbols
+            .iter()
+            .filter(|s| s.kind == DefKind::Mod)
+            .filter_map(|s| {
+                let end = refs::inline_mod_end(text, &s.span)?;
+                let start = (s.span.range.row_start.0, s.span.range.col_start.0);
+                Some((s.id, start, end))
+            })
+            .collect();
+
+        // Items outside any inline module have the file's module as parent.
+        let file_module = symbols
+            .iter()
+            .filter(|s| !inline.iter().any(|i| contains(i, &s.span)))
+            .filter_map(|s| s.parent)
+            .find(|p| modules.contains(p));
+
+        FileModules {
+            file_module,
+            inline,
+        }
+    }
+
+    pub fn module_of(&self, span: &Span) -> Option<Id> {
+        self.inline
+            .iter()
+            .filter(|i| contains(i, span))
+            .max_by_key(|&&(_, start, _)| start)
+            .map(|&(id, _, _)| id)
+            .or(self.file_module)
+    }
+}
+
+// Edges are labelled with their weight, the number of references.
+pub fn to_dot(graph: &ModuleGraph) -> String {
+    let mut result = format!("digraph \"{}\" {{\n", escape(&graph.crate_name));
+    for node in &graph.nodes {
+        writeln!(
+            result,
+            "    \"{}\" [label=\"{}\"];",
+            node.id,
+            escape(&node.name)
+        )
+        .unwrap();
+    }
+    for edge in &graph.edges {
+        writeln!(
+            result,
+            "    \"{}\" -> \"{}\" [label=\"{}\"];",
+            edge.from, edge.to, edge.weight
+        )
+        .unwrap();
+    }
+    result.push_str("}\n");
+    result
+}
+
+fn contains(&(_, start, end): &(Id, (u32, u32), (u32, u32)), span: &Span) -> bool {
+    let pos = (span.range.row_start.0, span.range.col_start.0);
+    start <= pos && pos < end
+}
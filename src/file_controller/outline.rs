@@ -0,0 +1,157 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Builds the outline of items in a file. Items are nested by their extent in
+// the source rather than by the def tree, since impls are not defs.
+
+use std::iter::Peekable;
+use std::vec::IntoIter;
+
+use super::refs;
+use super::results::OutlineItem;
+
+// An item which has not yet been nested. Positions are zero-indexed.
+pub struct FlatItem {
+    pub start: (u32, u32),
+    pub end: (u32, u32),
+    pub item: OutlineItem,
+}
+
+impl FlatItem {
+    pub fn new(
+        id: Option<String>,
+        name: String,
+        kind: &'static str,
+        start: (u32, u32),
+        end: (u32, u32),
+    ) -> FlatItem {
+        FlatItem {
+            start,
+            end,
+            item: OutlineItem {
+                id,
+                name,
+                kind,
+                line_start: start.0 + 1,
+                column_start: start.1 + 1,
+                line_end: end.0 + 1,
+                column_end: end.1 + 1,
+                children: vec![],
+            },
+        }
+    }
+}
+
+// Finds `impl` blocks in `text`, returning the header (e.g., `impl Foo for Bar`)
+// and extent of each.
+pub fn find_impls(text: &str) -> Vec<(String, (u32, u32), (u32, u32))> {
+    let code_lines = refs::code_lines(text);
+    let mut result = vec![];
+    for (row, line) in text.lines().enumerate() {
+        // Skip impls in comments and strings.
+        if !code_lines[row] {
+            continue;
+        }
+        let trimmed = line.trim_start();
+        let header = if trimmed.starts_with("unsafe impl") {
+            &trimmed["unsafe ".len()..]
+        } else {
+            trimmed
+        };
+        let is_impl = header.starts_with("impl")
+            && header["impl".len()..]
+                .chars()
+                .next()
+                .map_or(false, |c| c == '<' || c.is_whitespace());
+        if !is_impl {
+            continue;
+        }
+
+        let col = line.len() - trimmed.len();
+        let col = line[..col].chars().count() as u32;
+        let start = (row as u32, col);
+        let end = match refs::block_end(text, start.0, start.1) {
+            Some(end) => end,
+            None => continue,
+        };
+        let name = header
+            .split(|c| c == '{')
+            .next()
+            .unwrap_or(header)
+            .split(" where")
+            .next()
+            .unwrap_or(header)
+            .trim();
+        result.push((name.to_owned(), start, end));
+    }
+    result
+}
+
+// The extent of each impl in `text`, and whether it implements a trait.
+pub fn impl_extents(text: &str) -> Vec<((u32, u32), (u32, u32), bool)> {
+    let lines: Vec<&str> = text.lines().collect();
+    find_impls(text)
+        .into_iter()
+        .map(|(_, start, end)| {
+            let is_trait = lines
+                .get(start.0 as usize)
+                .and_then(|line| refs::impl_header(line))
+                .map_or(false, |header| header.trait_name.is_some());
+            (start, end, is_trait)
+        })
+        .collect()
+}
+
+// Nests items by their extents.
+pub fn build_tree(mut items: Vec<FlatItem>) -> Vec<OutlineItem> {
+    items.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+    nest(&mut items.into_iter().peekable(), None)
+}
+
+fn nest(items: &mut Peekable<IntoIter<FlatItem>>, end: Option<(u32, u32)>) -> Vec<OutlineItem> {
+    let mut result = vec![];
+    while let Some(start) = items.peek().map(|i| i.start) {
+        if end.map_or(false, |end| start >= end) {
+            break;
+        }
+        let flat = items.next().unwrap();
+        let mut item = flat.item;
+        item.children = nest(items, Some(flat.end));
+        result.push(item);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impls() {
+        let text = "impl Foo {\n}\nunsafe impl<T> Send for Bar<T> where T: Send {}\nfn f() {}";
+        let impls = find_impls(text);
+        assert_eq!(impls.len(), 2);
+        assert_eq!(impls[0], ("impl Foo".to_owned(), (0, 0), (1, 1)));
+        assert_eq!(impls[1].0, "impl<T> Send for Bar<T>");
+    }
+
+    #[test]
+    fn extents() {
+        let text = "impl Foo {\n}\nimpl<T> Bar for Baz<T> {\n    fn f() {}\n}";
+        assert_eq!(
+            impl_extents(text),
+            vec![((0, 0), (1, 1), false), ((2, 0), (4, 1), true)]
+        );
+    }
+
+    #[test]
+    fn impls_in_comments_and_strings() {
+        let text = "/*\nimpl Foo {}\n*/\nconst S: &str = \"\nimpl Bar {}\n\";\n/// impl Baz {}";
+        assert!(find_impls(text).is_empty());
+    }
+}
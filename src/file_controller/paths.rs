@@ -0,0 +1,126 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The file names we give to the client for the absolute paths in the analysis
+// data. Workspace files are relative to the project directory, and the
+// sources of dependencies are under `/deps/:crate/:version/`. Anything else
+// (e.g., the standard library) stays absolute, and can't be fetched.
+
+use std::cmp::Reverse;
+use std::path::{Path, PathBuf};
+
+#[derive(Default)]
+pub struct LinkPaths {
+    project_dir: PathBuf,
+    // The source directory of each dependency and its `/deps` prefix, longest
+    // directory first so that nested packages (e.g., in a git checkout) match
+    // before their parents.
+    deps: Vec<(PathBuf, String)>,
+}
+
+impl LinkPaths {
+    // `deps` is the source directory, name and version of each dependency.
+    pub fn new(project_dir: &Path, deps: Vec<(PathBuf, String, String)>) -> LinkPaths {
+        let mut deps: Vec<(PathBuf, String)> = deps
+            .into_iter()
+            .map(|(dir, name, version)| (dir, format!("/deps/{}/{}", name, version)))
+            .collect();
+        deps.sort_by_key(|&(ref dir, _)| Reverse(dir.components().count()));
+        LinkPaths {
+            project_dir: project_dir.to_owned(),
+            deps,
+        }
+    }
+
+    pub fn file_name(&self, path: &Path) -> String {
+        if let Ok(rel) = path.strip_prefix(&self.project_dir) {
+            return rel.display().to_string();
+        }
+        self.dep_file_name(path)
+            .unwrap_or_else(|| path.display().to_string())
+    }
+
+    // `None` if `path` is not in a dependency.
+    pub fn dep_file_name(&self, path: &Path) -> Option<String> {
+        self.deps.iter().find_map(|&(ref dir, ref prefix)| {
+            let rel = path.strip_prefix(dir).ok()?;
+            let mut result = prefix.clone();
+            for c in rel.components() {
+                result.push('/');
+                result.push_str(&c.as_os_str().to_string_lossy());
+            }
+            Some(result)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths() -> LinkPaths {
+        LinkPaths::new(
+            Path::new("/work/proj"),
+            vec![
+                (
+                    PathBuf::from("/cargo/git/repo"),
+                    "repo".to_owned(),
+                    "0.1.0".to_owned(),
+                ),
+                (
+                    PathBuf::from("/cargo/git/repo/member"),
+                    "member".to_owned(),
+                    "0.2.0".to_owned(),
+                ),
+                (
+                    PathBuf::from("/cargo/registry/serde-1.0.0"),
+                    "serde".to_owned(),
+                    "1.0.0".to_owned(),
+                ),
+            ],
+        )
+    }
+
+    #[test]
+    fn workspace_files() {
+        assert_eq!(
+            paths().file_name(Path::new("/work/proj/src/lib.rs")),
+            "src/lib.rs"
+        );
+    }
+
+    #[test]
+    fn dependency_files() {
+        let paths = paths();
+        assert_eq!(
+            paths.file_name(Path::new("/cargo/registry/serde-1.0.0/src/lib.rs")),
+            "/deps/serde/1.0.0/src/lib.rs"
+        );
+        assert_eq!(
+            paths.file_name(Path::new("/cargo/git/repo/member/src/lib.rs")),
+            "/deps/member/0.2.0/src/lib.rs"
+        );
+        assert_eq!(
+            paths.file_name(Path::new("/cargo/git/repo/src/lib.rs")),
+            "/deps/repo/0.1.0/src/lib.rs"
+        );
+    }
+
+    #[test]
+    fn other_files() {
+        let paths = paths();
+        assert_eq!(
+            paths.file_name(Path::new("/rust/src/libstd/lib.rs")),
+            "/rust/src/libstd/lib.rs"
+        );
+        assert_eq!(
+            paths.dep_file_name(Path::new("/work/proj/src/lib.rs")),
+            None
+        );
+    }
+}
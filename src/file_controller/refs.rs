@@ -0,0 +1,651 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Source-level heuristics. The analysis data tells us where a reference is,
+// but not how it is used or which function it lives in, nor the visibility of
+// a def, so we work that out from the text.
+
+use analysis::Id;
+
+use super::results::RefKind;
+use super::Span;
+
+// The extent of a function, from the start of its name to its closing brace.
+// Rows and columns are zero-indexed.
+#[derive(Debug, Clone)]
+pub struct FnExtent {
+    pub id: Id,
+    pub name: String,
+    pub start: (u32, u32),
+    pub end: (u32, u32),
+}
+
+impl FnExtent {
+    fn contains(&self, span: &Span) -> bool {
+        let pos = (span.range.row_start.0, span.range.col_start.0);
+        self.start <= pos && pos < self.end
+    }
+}
+
+// Returns the innermost function which contains `span`.
+pub fn enclosing_fn<'a>(fns: &'a [FnExtent], span: &Span) -> Option<&'a FnExtent> {
+    fns.iter()
+        .filter(|f| f.contains(span))
+        .max_by_key(|f| f.start)
+}
+
+// Finds the end of the first block after `row`:`col`, e.g., the body of a
+// function or an impl. Returns `None` if there is a `;` before the block (e.g.,
+// a required trait method). A `;` inside brackets, e.g., in `[u8; 4]`, does not
+// count.
+pub fn block_end(text: &str, row: u32, col: u32) -> Option<(u32, u32)> {
+    let mut depth = 0;
+    // Parentheses and square brackets.
+    let mut bracket_depth = 0;
+    let mut in_str = false;
+    let mut block_comments = 0;
+
+    for (r, line) in text.lines().enumerate().skip(row as usize) {
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = if r == row as usize { col as usize } else { 0 };
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).cloned();
+            if block_comments > 0 {
+                if c == '*' && next == Some('/') {
+                    block_comments -= 1;
+                    i += 1;
+                } else if c == '/' && next == Some('*') {
+                    block_comments += 1;
+                    i += 1;
+                }
+            } else if in_str {
+                if c == '\\' {
+                    i += 1;
+                } else if c == '"' {
+                    in_str = false;
+                }
+            } else {
+                match c {
+                    '/' if next == Some('/') => break,
+                    '/' if next == Some('*') => {
+                        block_comments += 1;
+                        i += 1;
+                    }
+                    '"' => in_str = true,
+                    // Skip char literals, but not lifetimes.
+                    '\'' if next == Some('\\') => {
+                        i += 2;
+                        while i < chars.len() && chars[i] != '\'' {
+                            i += 1;
+                        }
+                    }
+                    '\'' if chars.get(i + 2) == Some(&'\'') => i += 2,
+                    '(' | '[' => bracket_depth += 1,
+                    ')' | ']' if bracket_depth > 0 => bracket_depth -= 1,
+                    '{' => depth += 1,
+                    '}' if depth > 0 => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some((r as u32, i as u32 + 1));
+                        }
+                    }
+                    ';' if depth == 0 && bracket_depth == 0 => return None,
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+    }
+
+    None
+}
+
+// The end of the body of an inline module (`mod foo { ... }`) whose name is
+// at `span`. Returns `None` if the module is in its own file (`mod foo;`).
+pub fn inline_mod_end(text: &str, span: &Span) -> Option<(u32, u32)> {
+    let range = span.range;
+    let line = text.lines().nth(range.row_start.0 as usize)?;
+    let prefix: String = line.chars().take(range.col_start.0 as usize).collect();
+    let prefix = prefix.trim_end();
+    let is_mod = prefix.ends_with("mod")
+        && !prefix[..prefix.len() - 3]
+            .chars()
+            .next_back()
+            .map_or(false, |c| c.is_alphanumeric() || c == '_');
+    if !is_mod {
+        return None;
+    }
+    // The block may start on the next line.
+    let rest: String = line.chars().skip(range.col_end.0 as usize).collect();
+    let rest = rest.trim_start();
+    if !rest.is_empty() && !rest.starts_with('{') {
+        return None;
+    }
+    block_end(text, range.row_start.0, range.col_end.0)
+}
+
+// Whether each line of `text` starts outside of any comment or string literal.
+pub fn code_lines(text: &str) -> Vec<bool> {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+
+    let mut result = vec![];
+    let mut block_comments = 0;
+    let mut in_str = false;
+    // The number of `#`s which end the raw string we are in.
+    let mut raw_str: Option<usize> = None;
+
+    for line in text.lines() {
+        result.push(block_comments == 0 && !in_str && raw_str.is_none());
+
+        let chars: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let next = chars.get(i + 1).cloned();
+            if block_comments > 0 {
+                if c == '*' && next == Some('/') {
+                    block_comments -= 1;
+                    i += 1;
+                } else if c == '/' && next == Some('*') {
+                    block_comments += 1;
+                    i += 1;
+                }
+            } else if let Some(hashes) = raw_str {
+                let closing = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+                if c == '"' && closing >= hashes {
+                    raw_str = None;
+                    i += hashes;
+                }
+            } else if in_str {
+                if c == '\\' {
+                    i += 1;
+                } else if c == '"' {
+                    in_str = false;
+                }
+            } else {
+                // `r"..."` or `br#"..."#`, but not an identifier ending in `r`.
+                let prefix_start = if i > 0 && chars[i - 1] == 'b' {
+                    i - 1
+                } else {
+                    i
+                };
+                let is_raw_start = c == 'r'
+                    && (prefix_start == 0 || !is_ident_char(chars[prefix_start - 1]))
+                    && (next == Some('"') || next == Some('#'));
+                match c {
+                    '/' if next == Some('/') => break,
+                    '/' if next == Some('*') => {
+                        block_comments += 1;
+                        i += 1;
+                    }
+                    'r' if is_raw_start => {
+                        let hashes = chars[i + 1..].iter().take_while(|&&c| c == '#').count();
+                        if chars.get(i + 1 + hashes) == Some(&'"') {
+                            raw_str = Some(hashes);
+                            i += 1 + hashes;
+                        }
+                    }
+                    '"' => in_str = true,
+                    // Skip char literals, but not lifetimes.
+                    '\'' if next == Some('\\') => {
+                        i += 2;
+                        while i < chars.len() && chars[i] != '\'' {
+                            i += 1;
+                        }
+                    }
+                    '\'' if chars.get(i + 2) == Some(&'\'') => i += 2,
+                    _ => {}
+                }
+            }
+            i += 1;
+        }
+    }
+
+    result
+}
+
+// Whether the function declared on `row` has a `#[test]` attribute.
+pub fn is_test_fn(text: &str, row: u32) -> bool {
+    let lines: Vec<&str> = text.lines().take(row as usize).collect();
+    lines
+        .iter()
+        .rev()
+        .map(|l| l.trim())
+        .take_while(|l| l.starts_with('#') || l.starts_with("//"))
+        .any(|l| l == "#[test]")
+}
+
+// The start and end columns of each identifier in `line`, ignoring any line
+// comment.
+pub fn idents(line: &str) -> Vec<(u32, u32)> {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+
+    let mut result = vec![];
+    let mut start: Option<usize> = None;
+    let mut prev = ' ';
+    let mut col = 0;
+    for c in line.chars() {
+        if c == '/' && prev == '/' {
+            break;
+        }
+        match (start, is_ident_char(c)) {
+            (None, true) if !c.is_numeric() => start = Some(col),
+            (Some(s), false) => {
+                result.push((s as u32, col as u32));
+                start = None;
+            }
+            _ => {}
+        }
+        prev = c;
+        col += 1;
+    }
+    if let Some(s) = start {
+        result.push((s as u32, col as u32));
+    }
+    result
+}
+
+// The columns of the last segment of the trait and self type paths in an impl
+// header, e.g., `Bar` and `Baz` in `impl<T> foo::Bar<T> for Baz<T> {`.
+pub struct ImplHeader {
+    pub trait_name: Option<(u32, u32)>,
+    pub self_ty: (u32, u32),
+}
+
+// Parses an impl header which starts on `line`. Headers split over several
+// lines are not handled.
+pub fn impl_header(line: &str) -> Option<ImplHeader> {
+    let chars: Vec<char> = line.chars().collect();
+    let depths = nesting_depths(&chars);
+    let idents = idents(line);
+    let text = |&(start, end): &(u32, u32)| -> String {
+        chars[start as usize..end as usize].iter().collect()
+    };
+
+    let impl_kw = idents.iter().position(|i| text(i) == "impl")?;
+    let mut start = idents[impl_kw].1 as usize;
+    // Skip the generics of the impl.
+    while start < chars.len() && chars[start].is_whitespace() {
+        start += 1;
+    }
+    if chars.get(start) == Some(&'<') {
+        let depth = depths[start];
+        start += 1;
+        while start < chars.len() && depths[start] > depth {
+            start += 1;
+        }
+        // Skip the closing `>`.
+        start += 1;
+    }
+
+    let mut for_kw = None;
+    let mut end = chars.len();
+    for i in &idents[impl_kw + 1..] {
+        if (i.0 as usize) < start || depths[i.0 as usize] > 0 {
+            continue;
+        }
+        match &*text(i) {
+            "for" if for_kw.is_none() => for_kw = Some(*i),
+            "where" => {
+                end = i.0 as usize;
+                break;
+            }
+            _ => {}
+        }
+    }
+    if let Some(brace) = (start..end).find(|&i| chars[i] == '{' && depths[i] == 0) {
+        end = brace;
+    }
+
+    match for_kw {
+        Some((for_start, for_end)) => Some(ImplHeader {
+            trait_name: last_segment(&chars, &depths, start, for_start as usize),
+            self_ty: last_segment(&chars, &depths, for_end as usize, end)?,
+        }),
+        None => Some(ImplHeader {
+            trait_name: None,
+            self_ty: last_segment(&chars, &depths, start, end)?,
+        }),
+    }
+}
+
+// The columns of each trait in the supertrait bounds of a trait declared on
+// `line`. `name_end` is the column after the trait's name.
+pub fn supertraits(line: &str, name_end: u32) -> Vec<(u32, u32)> {
+    let chars: Vec<char> = line.chars().collect();
+    let depths = nesting_depths(&chars);
+
+    let mut start = name_end as usize;
+    while start < chars.len() && (depths[start] > 0 || chars[start] != ':') {
+        if chars[start] == '{' || chars[start] == ';' {
+            return vec![];
+        }
+        start += 1;
+    }
+    if start >= chars.len() {
+        return vec![];
+    }
+    start += 1;
+
+    let mut end = start;
+    while end < chars.len() && (depths[end] > 0 || (chars[end] != '{' && chars[end] != ';')) {
+        end += 1;
+    }
+    let header: String = chars[start..end].iter().collect();
+    if let Some(w) = header.find(" where") {
+        end = start + header[..w].chars().count();
+    }
+
+    let mut result = vec![];
+    let mut bound_start = start;
+    for i in start..=end {
+        if i == end || (chars[i] == '+' && depths[i] == 0) {
+            let is_maybe = chars[bound_start..i]
+                .iter()
+                .find(|c| !c.is_whitespace())
+                .map_or(false, |&c| c == '?');
+            if !is_maybe {
+                result.extend(last_segment(&chars, &depths, bound_start, i));
+            }
+            bound_start = i + 1;
+        }
+    }
+    result
+}
+
+// The traits named in `#[derive(...)]` attributes on the def at `row`, as
+// (row, start column, end column).
+pub fn derives(text: &str, row: u32) -> Vec<(u32, u32, u32)> {
+    let lines: Vec<&str> = text.lines().take(row as usize).collect();
+    let mut result = vec![];
+    for (r, line) in lines.iter().enumerate().rev() {
+        let trimmed = line.trim_start();
+        if !trimmed.starts_with('#') && !trimmed.starts_with("//") {
+            break;
+        }
+        if !trimmed.starts_with("#[derive(") {
+            continue;
+        }
+
+        let chars: Vec<char> = line.chars().collect();
+        let open = match chars.iter().position(|&c| c == '(') {
+            Some(open) => open as u32,
+            None => continue,
+        };
+        let traits = idents(line).into_iter().filter(|&(start, end)| {
+            let followed_by_path = chars[end as usize..].starts_with(&[':', ':']);
+            start > open && !followed_by_path
+        });
+        result.splice(0..0, traits.map(|(start, end)| (r as u32, start, end)));
+    }
+    result
+}
+
+// The depth of brackets of any kind at each char. Brackets are at the depth
+// of the text outside them.
+fn nesting_depths(chars: &[char]) -> Vec<u32> {
+    let mut depth = 0u32;
+    let mut prev = ' ';
+    chars
+        .iter()
+        .map(|&c| {
+            let result = match c {
+                '<' | '(' | '[' => {
+                    depth += 1;
+                    depth - 1
+                }
+                // `->` is not a closing bracket.
+                '>' if prev == '-' => depth,
+                '>' | ')' | ']' => {
+                    depth = depth.saturating_sub(1);
+                    depth
+                }
+                _ => depth,
+            };
+            prev = c;
+            result
+        })
+        .collect()
+}
+
+// The last segment of the outermost path between columns `start` and `end`,
+// e.g., `Vec` in `&'a mut std::vec::Vec<T>`.
+fn last_segment(chars: &[char], depths: &[u32], start: usize, end: usize) -> Option<(u32, u32)> {
+    const KEYWORDS: &[&str] = &["dyn", "impl", "mut", "const", "unsafe", "for"];
+
+    let line: String = chars.iter().collect();
+    let candidates: Vec<(u32, u32)> = idents(&line)
+        .into_iter()
+        .filter(|&(s, e)| {
+            let s = s as usize;
+            let word: String = chars[s..e as usize].iter().collect();
+            start <= s && s < end && (s == 0 || chars[s - 1] != '\'') && !KEYWORDS.contains(&&*word)
+        })
+        .collect();
+    candidates
+        .iter()
+        .filter(|&&(s, _)| depths[s as usize] == 0)
+        .last()
+        .or_else(|| candidates.last())
+        .cloned()
+}
+
+// Guesses how a reference is used from the line of source it is on.
+pub fn classify_ref(line: &str, span: &Span, is_impl: bool) -> RefKind {
+    if is_impl {
+        return RefKind::Impl;
+    }
+
+    let trimmed = line.trim_start();
+    if trimmed.starts_with("use ")
+        || trimmed.starts_with("pub use ")
+        || trimmed.starts_with("pub(crate) use ")
+        || trimmed.starts_with("extern crate ")
+    {
+        return RefKind::Import;
+    }
+
+    let chars: Vec<char> = line.chars().collect();
+    let start = (span.range.col_start.0 as usize).min(chars.len());
+    let end = (span.range.col_end.0 as usize).min(chars.len());
+    let before: String = chars[..start].iter().collect();
+    let after: String = chars[end..].iter().collect();
+    let after = after.trim_start();
+
+    if after.starts_with('(') || after.starts_with('!') || after.starts_with("::<") {
+        return RefKind::Call;
+    }
+
+    const ASSIGN_OPS: &[&str] = &["+=", "-=", "*=", "/=", "%=", "^=", "&=", "|=", "<<=", ">>="];
+    let is_assign = after.starts_with('=') && !after.starts_with("==") && !after.starts_with("=>");
+    if is_assign
+        || ASSIGN_OPS.iter().any(|op| after.starts_with(op))
+        || before.trim_end().ends_with("&mut")
+    {
+        return RefKind::Write;
+    }
+
+    RefKind::Read
+}
+
+// Finds the visibility qualifier (e.g., `pub(crate)`) before the name of a
+// def, if there is one on the same line.
+pub fn visibility(line: &str, def_span: &Span) -> Option<String> {
+    let chars: Vec<char> = line.chars().collect();
+    let end = (def_span.range.col_start.0 as usize).min(chars.len());
+    let prefix: String = chars[..end].iter().collect();
+
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let start = prefix.match_indices("pub").map(|(i, _)| i).find(|&i| {
+        let before = prefix[..i].chars().next_back();
+        let after = prefix[i + 3..].chars().next();
+        !before.map_or(false, is_ident_char) && !after.map_or(false, is_ident_char)
+    })?;
+
+    let rest = &prefix[start..];
+    let qualifier = if rest[3..].trim_start().starts_with('(') {
+        match rest.find(')') {
+            Some(close) => &rest[..close + 1],
+            None => &rest[..3],
+        }
+    } else {
+        &rest[..3]
+    };
+    Some(qualifier.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use span::{Column, Row};
+    use std::path::PathBuf;
+
+    fn span(row: u32, start: u32, end: u32) -> Span {
+        Span::new(
+            Row::new_zero_indexed(row),
+            Row::new_zero_indexed(row),
+            Column::new_zero_indexed(start),
+            Column::new_zero_indexed(end),
+            PathBuf::from("lib.rs"),
+        )
+    }
+
+    #[test]
+    fn block_end_simple() {
+        assert_eq!(block_end("fn f() {}", 0, 0), Some((0, 9)));
+        assert_eq!(
+            block_end("fn f() {\n    g();\n}\nfn g() {}", 0, 0),
+            Some((2, 1))
+        );
+    }
+
+    #[test]
+    fn block_end_no_body() {
+        assert_eq!(block_end("fn f(&self);\nfn g() {}", 0, 0), None);
+    }
+
+    #[test]
+    fn block_end_brackets() {
+        assert_eq!(block_end("fn f(x: [u8; 32]) {}", 0, 0), Some((0, 20)));
+        assert_eq!(
+            block_end("fn g() -> [u8; 4] {\n    [0; 4]\n}", 0, 0),
+            Some((2, 1))
+        );
+        assert_eq!(block_end("fn h(f: fn(u8); u8) {}", 0, 0), Some((0, 22)));
+    }
+
+    #[test]
+    fn block_end_strings_and_comments() {
+        let text = "fn f() {\n    let s = \"}\"; // }\n    let c = '}'; /* } */\n}";
+        assert_eq!(block_end(text, 0, 0), Some((3, 1)));
+    }
+
+    #[test]
+    fn inline_mod() {
+        assert_eq!(inline_mod_end("mod foo {\n}", &span(0, 4, 7)), Some((1, 1)));
+        assert_eq!(
+            inline_mod_end("pub mod foo\n{\n}", &span(0, 8, 11)),
+            Some((2, 1))
+        );
+        assert_eq!(inline_mod_end("mod foo;\nfn f() {}", &span(0, 4, 7)), None);
+        assert_eq!(inline_mod_end("fn mod_x() {}", &span(0, 3, 8)), None);
+    }
+
+    #[test]
+    fn code_lines_comments_and_strings() {
+        let text = "a\n/* b\nc */ d\n\"e\nf\"\nr#\"g\"\nh\"#\n// i\nj";
+        assert_eq!(
+            code_lines(text),
+            vec![true, true, false, true, false, true, false, true, true]
+        );
+        let text = "let bar = 1;\nlet c = '\"';\nx";
+        assert_eq!(code_lines(text), vec![true, true, true]);
+    }
+
+    #[test]
+    fn classify_ref_kinds() {
+        assert_eq!(
+            classify_ref("    foo(1);", &span(0, 4, 7), false),
+            RefKind::Call
+        );
+        assert_eq!(
+            classify_ref("    foo!(1);", &span(0, 4, 7), false),
+            RefKind::Call
+        );
+        assert_eq!(
+            classify_ref("    x = 1;", &span(0, 4, 5), false),
+            RefKind::Write
+        );
+        assert_eq!(
+            classify_ref("    x += 1;", &span(0, 4, 5), false),
+            RefKind::Write
+        );
+        assert_eq!(
+            classify_ref("    g(&mut x);", &span(0, 11, 12), false),
+            RefKind::Write
+        );
+        assert_eq!(
+            classify_ref("    if x == 1 {", &span(0, 7, 8), false),
+            RefKind::Read
+        );
+        assert_eq!(
+            classify_ref("    match x => 1,", &span(0, 10, 11), false),
+            RefKind::Read
+        );
+        assert_eq!(
+            classify_ref("use foo::bar;", &span(0, 9, 12), false),
+            RefKind::Import
+        );
+        assert_eq!(
+            classify_ref("impl Foo for Bar {", &span(0, 5, 8), true),
+            RefKind::Impl
+        );
+    }
+
+    #[test]
+    fn visibility_qualifiers() {
+        assert_eq!(
+            visibility("pub fn foo() {", &span(0, 7, 10)),
+            Some("pub".to_owned())
+        );
+        assert_eq!(
+            visibility("    pub(crate) fn foo() {", &span(0, 18, 21)),
+            Some("pub(crate)".to_owned())
+        );
+        assert_eq!(
+            visibility("pub(in foo::bar) struct Foo;", &span(0, 24, 27)),
+            Some("pub(in foo::bar)".to_owned())
+        );
+        assert_eq!(visibility("fn foo() {", &span(0, 3, 6)), None);
+        assert_eq!(visibility("fn public() {", &span(0, 3, 9)), None);
+    }
+
+    #[test]
+    fn impl_header_inherent() {
+        let header = impl_header("impl Foo {").unwrap();
+        assert_eq!(header.trait_name, None);
+        assert_eq!(header.self_ty, (5, 8));
+    }
+
+    #[test]
+    fn impl_header_trait() {
+        let header = impl_header("impl<T> foo::Bar<T> for Baz<T> {").unwrap();
+        assert_eq!(header.trait_name, Some((13, 16)));
+        assert_eq!(header.self_ty, (24, 27));
+
+        let header = impl_header("impl<'a> Iterator for &'a mut Vec<u8> where T: X {").unwrap();
+        assert_eq!(header.trait_name, Some((9, 17)));
+        assert_eq!(header.self_ty, (30, 33));
+    }
+
+    #[test]
+    fn impl_header_not_impl() {
+        assert!(impl_header("fn foo() {").is_none());
+    }
+}
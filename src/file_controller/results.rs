@@ -7,6 +7,7 @@
 // except according to those terms.
 
 use super::Span;
+use std::str::FromStr;
 
 // The number of lines before and after a result line to use as context.
 pub const CONTEXT_SIZE: i32 = 3;
@@ -27,6 +28,10 @@ pub struct DefResult {
 pub struct FileResult {
     pub file_name: String,
     pub lines: Vec<LineResult>,
+    // The crate which the file belongs to, if we can tell.
+    pub crate_name: Option<String>,
+    // True if the file is part of the workspace, false for dependencies.
+    pub workspace: bool,
 }
 
 #[derive(Serialize, Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -37,6 +42,10 @@ pub struct LineResult {
     pub line: String,
     pub pre_context: String,
     pub post_context: String,
+    // Only set for references to a def, not for defs or other results.
+    pub kind: Option<RefKind>,
+    // The name of the function containing this line, if any.
+    pub enclosing_fn: Option<String>,
 }
 
 impl LineResult {
@@ -48,6 +57,32 @@ impl LineResult {
             line,
             pre_context,
             post_context,
+            kind: None,
+            enclosing_fn: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RefKind {
+    Read,
+    Write,
+    Call,
+    Import,
+    Impl,
+}
+
+impl FromStr for RefKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<RefKind, String> {
+        match s {
+            "read" => Ok(RefKind::Read),
+            "write" => Ok(RefKind::Write),
+            "call" => Ok(RefKind::Call),
+            "import" => Ok(RefKind::Import),
+            "impl" => Ok(RefKind::Impl),
+            _ => Err(format!("Unknown reference kind: {}", s)),
         }
     }
 }
@@ -55,12 +90,293 @@ impl LineResult {
 #[derive(Serialize, Debug, Clone)]
 pub struct FindResult {
     pub results: Vec<FileResult>,
+    // Set if there were more results than we return.
+    pub truncated: bool,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum MatchKind {
+    Exact,
+    Prefix,
+    CamelHump,
+    Substring,
+    Fuzzy,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SymbolMatch {
+    pub id: String,
+    pub name: String,
+    pub qualname: String,
+    pub kind: &'static str,
+    pub file_name: String,
+    pub line_start: u32,
+    pub workspace: bool,
+    pub match_kind: MatchKind,
 }
 
 #[derive(Serialize, Debug, Clone)]
 pub struct SymbolResult {
     pub id: String,
     pub name: String,
+    pub kind: &'static str,
+    // E.g., `pub` or `pub(crate)`, `None` if there is no visibility qualifier.
+    pub visibility: Option<String>,
+    pub signature: Option<String>,
+    pub docs_summary: Option<String>,
+    pub child_count: usize,
     pub file_name: String,
     pub line_start: u32,
 }
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SymbolRoots {
+    pub workspace: Vec<SymbolResult>,
+    pub dependencies: Vec<SymbolResult>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct OutlineItem {
+    // `None` for impls, which are not defs.
+    pub id: Option<String>,
+    pub name: String,
+    pub kind: &'static str,
+    pub line_start: u32,
+    pub column_start: u32,
+    pub line_end: u32,
+    pub column_end: u32,
+    pub children: Vec<OutlineItem>,
+}
+
+// A caller or callee of a function. Callers and callees are not nested; to
+// expand a node, query for its callers or callees by id.
+#[derive(Serialize, Debug, Clone)]
+pub struct CallResult {
+    pub id: String,
+    pub name: String,
+    pub kind: &'static str,
+    pub file_name: String,
+    pub line_start: u32,
+    // Where the calls are made, i.e., in the caller for callers and in the
+    // queried function for callees.
+    pub call_sites: Vec<LineResult>,
+}
+
+// The traits and types related to a trait or type. For a trait, `supertraits`,
+// `subtraits` and `implementors` are filled in, for a type, `traits`.
+#[derive(Serialize, Debug, Clone)]
+pub struct TypeHierarchy {
+    pub id: String,
+    pub name: String,
+    pub kind: &'static str,
+    pub supertraits: Vec<HierarchyItem>,
+    pub subtraits: Vec<HierarchyItem>,
+    pub implementors: Vec<HierarchyItem>,
+    pub traits: Vec<HierarchyItem>,
+}
+
+// `file_name` and `line_start` locate the impl block (or derive attribute)
+// for implementations, and the def for supertraits and subtraits. `id` is
+// `None` if we could not find the def, e.g., for derived traits.
+#[derive(Serialize, Debug, Clone)]
+pub struct HierarchyItem {
+    pub id: Option<String>,
+    pub name: String,
+    pub file_name: String,
+    pub line_start: u32,
+    pub derived: bool,
+}
+
+// Ids are cargo package ids.
+#[derive(Serialize, Debug, Clone)]
+pub struct CrateGraph {
+    pub nodes: Vec<CrateNode>,
+    pub edges: Vec<CrateEdge>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CrateNode {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub workspace: bool,
+    pub manifest_dir: String,
+    // The root of the library target, or of the first target if there is no
+    // library.
+    pub root_file: Option<String>,
+    // The features the crate declares.
+    pub features: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct CrateEdge {
+    pub from: String,
+    pub to: String,
+    pub req: String,
+    // "normal", "dev" or "build".
+    pub kind: &'static str,
+    pub optional: bool,
+    pub uses_default_features: bool,
+    // Features requested by the depending crate.
+    pub features: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModuleGraph {
+    pub crate_name: String,
+    pub nodes: Vec<ModuleNode>,
+    pub edges: Vec<ModuleEdge>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModuleNode {
+    pub id: String,
+    // The path of the module, starting with the crate name.
+    pub name: String,
+    pub file_name: String,
+    pub line_start: u32,
+}
+
+// `from` refers to items defined in `to`, `weight` times.
+#[derive(Serialize, Debug, Clone)]
+pub struct ModuleEdge {
+    pub from: String,
+    pub to: String,
+    pub weight: usize,
+}
+
+// A workspace package, as a tree of targets and their modules. Paths are
+// relative to the project directory where possible.
+#[derive(Serialize, Debug, Clone)]
+pub struct PackageTree {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub manifest_dir: String,
+    pub targets: Vec<TargetTree>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct TargetTree {
+    pub name: String,
+    // "lib", "bin", "test", "example", "bench" or "build".
+    pub kind: &'static str,
+    pub src_path: String,
+    // `None` if we have no analysis data for the target, e.g., for tests.
+    pub root: Option<ModuleTree>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModuleTree {
+    pub id: String,
+    pub name: String,
+    pub file_name: String,
+    pub line_start: u32,
+    // The files which contain the module's items, usually just one.
+    pub files: Vec<String>,
+    pub children: Vec<ModuleTree>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UnusedCrate {
+    pub crate_name: String,
+    pub modules: Vec<UnusedModule>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UnusedModule {
+    // The path of the module, starting with the crate name.
+    pub name: String,
+    pub items: Vec<UnusedItem>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct UnusedItem {
+    pub id: String,
+    pub name: String,
+    pub kind: &'static str,
+    pub visibility: Option<String>,
+    pub file_name: String,
+    pub line_start: u32,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ApiItem {
+    pub id: String,
+    // The path of the item, starting with the crate name.
+    pub path: String,
+    pub kind: &'static str,
+    pub signature: Option<String>,
+    pub has_docs: bool,
+    pub file_name: String,
+    pub line_start: u32,
+}
+
+// Public items are those in the API surface of a crate. `file_name` and
+// `line_start` of undocumented items locate them in the source view.
+#[derive(Serialize, Debug, Clone)]
+pub struct DocCoverage {
+    pub crate_name: String,
+    // "lib", "bin", etc., as for `TargetTree`.
+    pub target_kind: &'static str,
+    pub documented: usize,
+    pub total: usize,
+    pub modules: Vec<ModuleDocCoverage>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ModuleDocCoverage {
+    pub name: String,
+    pub documented: usize,
+    pub total: usize,
+    pub undocumented: Vec<ApiItem>,
+}
+
+// `to` is `None` for the working tree.
+#[derive(Serialize, Debug, Clone)]
+pub struct DiffResult {
+    pub path: String,
+    pub from: String,
+    pub to: Option<String>,
+    pub hunks: Vec<DiffHunk>,
+}
+
+// Line numbers are one-indexed.
+#[derive(Serialize, Debug, Clone)]
+pub struct DiffHunk {
+    pub old_start: u32,
+    pub old_count: u32,
+    pub new_start: u32,
+    pub new_count: u32,
+    // The text after the line numbers in the hunk header, usually the
+    // enclosing item.
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+// `text` is highlighted from the old side for removed and context lines, and
+// from the new side for added lines.
+#[derive(Serialize, Debug, Clone)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub old_line: Option<u32>,
+    pub new_line: Option<u32>,
+    pub text: String,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+// The commit which last changed a line. `date` is the author time, in seconds
+// since the Unix epoch.
+#[derive(Serialize, Debug, Clone)]
+pub struct BlameLine {
+    pub commit: String,
+    pub author: String,
+    pub date: u64,
+    pub summary: String,
+}
@@ -0,0 +1,229 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// An index of def names for inexact identifier search.
+
+use analysis::{Def, DefKind, Id};
+
+use super::results::MatchKind;
+
+pub struct SymbolIndex {
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    id: Id,
+    name: String,
+    lowered: String,
+    // The first letter of each word in the name, lowercased, e.g., `hm` for
+    // `HashMap` or `hash_map`.
+    humps: String,
+    kind: DefKind,
+    workspace: bool,
+}
+
+impl SymbolIndex {
+    pub fn new() -> SymbolIndex {
+        SymbolIndex { entries: vec![] }
+    }
+
+    // `is_workspace` says whether a def is in a workspace crate.
+    pub fn build<F>(defs: &[(Id, Def)], is_workspace: F) -> SymbolIndex
+    where
+        F: Fn(&Def) -> bool,
+    {
+        let entries = defs
+            .iter()
+            .filter(|&&(_, ref def)| def.kind != DefKind::Local && !def.name.is_empty())
+            .map(|&(id, ref def)| Entry {
+                id,
+                name: def.name.clone(),
+                lowered: def.name.to_lowercase(),
+                humps: humps(&def.name),
+                kind: def.kind,
+                workspace: is_workspace(def),
+            })
+            .collect();
+        SymbolIndex { entries }
+    }
+
+    // Returns at most `limit` matching defs, best matches first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<(Id, MatchKind)> {
+        let lowered = query.to_lowercase();
+        let mut matches: Vec<_> = self
+            .entries
+            .iter()
+            .filter_map(|e| e.match_kind(query, &lowered).map(|m| (m, e)))
+            .collect();
+
+        matches.sort_by(|&(m1, e1), &(m2, e2)| e1.rank(m1).cmp(&e2.rank(m2)));
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(m, e)| (e.id, m))
+            .collect()
+    }
+}
+
+impl Entry {
+    fn rank(&self, match_kind: MatchKind) -> (MatchKind, u8, bool, usize, &str) {
+        (
+            match_kind,
+            kind_rank(self.kind),
+            !self.workspace,
+            self.name.len(),
+            &self.name,
+        )
+    }
+
+    fn match_kind(&self, query: &str, lowered: &str) -> Option<MatchKind> {
+        if self.name == query {
+            Some(MatchKind::Exact)
+        } else if self.lowered.starts_with(lowered) {
+            Some(MatchKind::Prefix)
+        } else if self.humps.starts_with(lowered) {
+            Some(MatchKind::CamelHump)
+        } else if self.lowered.contains(lowered) {
+            Some(MatchKind::Substring)
+        } else if is_subsequence(lowered, &self.lowered) {
+            Some(MatchKind::Fuzzy)
+        } else {
+            None
+        }
+    }
+}
+
+fn humps(name: &str) -> String {
+    let mut result = String::new();
+    let mut prev: Option<char> = None;
+    for c in name.chars() {
+        let starts_word = match prev {
+            None => true,
+            Some('_') => c != '_',
+            Some(p) => c.is_uppercase() && !p.is_uppercase(),
+        };
+        if starts_word {
+            result.extend(c.to_lowercase());
+        }
+        prev = Some(c);
+    }
+    result
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
+
+// Lower is better. Types and functions are what people usually want to open.
+fn kind_rank(kind: DefKind) -> u8 {
+    match kind {
+        DefKind::Struct
+        | DefKind::Enum
+        | DefKind::Union
+        | DefKind::Trait
+        | DefKind::Type
+        | DefKind::ExternType => 0,
+        DefKind::Function | DefKind::Method | DefKind::Macro => 1,
+        DefKind::Mod => 2,
+        DefKind::Const | DefKind::Static => 3,
+        DefKind::TupleVariant | DefKind::StructVariant | DefKind::Tuple => 4,
+        DefKind::Field | DefKind::Local => 5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(entries: &[(&str, DefKind, bool)]) -> SymbolIndex {
+        let entries = entries
+            .iter()
+            .enumerate()
+            .map(|(i, &(name, kind, workspace))| Entry {
+                id: Id::new(i as u64),
+                name: name.to_owned(),
+                lowered: name.to_lowercase(),
+                humps: humps(name),
+                kind,
+                workspace,
+            })
+            .collect();
+        SymbolIndex { entries }
+    }
+
+    fn search(index: &SymbolIndex, query: &str, limit: usize) -> Vec<(String, MatchKind)> {
+        index
+            .search(query, limit)
+            .into_iter()
+            .map(|(id, m)| {
+                let entry = index.entries.iter().find(|e| e.id == id).unwrap();
+                (entry.name.clone(), m)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn word_humps() {
+        assert_eq!(humps("HashMap"), "hm");
+        assert_eq!(humps("hash_map"), "hm");
+        assert_eq!(humps("to_string"), "ts");
+        assert_eq!(humps("new"), "n");
+    }
+
+    #[test]
+    fn subsequence() {
+        assert!(is_subsequence("hmp", "hashmap"));
+        assert!(is_subsequence("", "hashmap"));
+        assert!(!is_subsequence("hpm", "hashmap"));
+        assert!(!is_subsequence("hashmaps", "hashmap"));
+    }
+
+    #[test]
+    fn match_order() {
+        let index = index(&[
+            ("mxaxp", DefKind::Function, true),
+            ("HashMap", DefKind::Struct, true),
+            ("MutAsPtr", DefKind::Trait, true),
+            ("map_values", DefKind::Function, true),
+            ("MapIter", DefKind::Struct, true),
+            ("Map", DefKind::Struct, true),
+            ("unrelated", DefKind::Struct, true),
+        ]);
+        assert_eq!(
+            search(&index, "Map", 10),
+            vec![
+                ("Map".to_owned(), MatchKind::Exact),
+                ("MapIter".to_owned(), MatchKind::Prefix),
+                ("map_values".to_owned(), MatchKind::Prefix),
+                ("MutAsPtr".to_owned(), MatchKind::CamelHump),
+                ("HashMap".to_owned(), MatchKind::Substring),
+                ("mxaxp".to_owned(), MatchKind::Fuzzy),
+            ]
+        );
+    }
+
+    #[test]
+    fn tie_breaks() {
+        let index = index(&[
+            ("FooBar", DefKind::Struct, true),
+            ("Foo", DefKind::Struct, false),
+            ("Foo", DefKind::Function, true),
+            ("Foo", DefKind::Struct, true),
+        ]);
+        // Match kind, then types before functions, then workspace defs, then
+        // shorter names.
+        let ids: Vec<Id> = index
+            .search("Foo", 10)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        assert_eq!(ids, vec![Id::new(3), Id::new(1), Id::new(2), Id::new(0)]);
+        assert_eq!(index.search("Foo", 2).len(), 2);
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2018 The Rustw Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// A trigram index of the files in the workspace, so that text search only has
+// to look at files which could possibly match.
+//
+// Trigrams are case-folded ASCII only, so a lookup gives a superset of the
+// files which match, whatever the case sensitivity of the search.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::grep;
+
+type Trigram = [u8; 3];
+
+pub struct TrigramIndex {
+    files: HashMap<PathBuf, IndexedFile>,
+    postings: HashMap<Trigram, HashSet<PathBuf>>,
+}
+
+struct IndexedFile {
+    modified: Option<SystemTime>,
+    trigrams: HashSet<Trigram>,
+}
+
+impl TrigramIndex {
+    pub fn new() -> TrigramIndex {
+        TrigramIndex {
+            files: HashMap::new(),
+            postings: HashMap::new(),
+        }
+    }
+
+    // Brings the index up to date with `files`. Only files which are new or
+    // have been modified since we last saw them are re-read.
+    pub fn update(&mut self, files: &[PathBuf]) {
+        let current: HashSet<&PathBuf> = files.iter().collect();
+        let removed: Vec<PathBuf> = self
+            .files
+            .keys()
+            .filter(|p| !current.contains(p))
+            .cloned()
+            .collect();
+        for path in removed {
+            self.remove(&path);
+        }
+
+        for path in files {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            if let Some(file) = self.files.get(path) {
+                if modified.is_some() && file.modified == modified {
+                    continue;
+                }
+            }
+
+            self.remove(path);
+            // Skips the same large and binary files as text search. We read
+            // from disk rather than the VFS so that indexing doesn't keep every
+            // file in memory.
+            if let Some(text) = grep::read_text(path) {
+                let trigrams = trigrams(&text);
+                for t in &trigrams {
+                    self.postings
+                        .entry(*t)
+                        .or_insert_with(HashSet::new)
+                        .insert(path.clone());
+                }
+                self.files
+                    .insert(path.clone(), IndexedFile { modified, trigrams });
+            }
+        }
+    }
+
+    // The files which might contain all of `literals`, or `None` if the
+    // literals are too short to narrow the search.
+    pub fn candidates(&self, literals: &[String]) -> Option<HashSet<PathBuf>> {
+        let mut result: Option<HashSet<PathBuf>> = None;
+        for t in literals.iter().flat_map(|l| trigrams(l)) {
+            let files = self.postings.get(&t).cloned().unwrap_or_default();
+            result = Some(match result {
+                Some(r) => r.intersection(&files).cloned().collect(),
+                None => files,
+            });
+        }
+        result
+    }
+
+    fn remove(&mut self, path: &Path) {
+        if let Some(file) = self.files.remove(path) {
+            for t in &file.trigrams {
+                if let Some(paths) = self.postings.get_mut(t) {
+                    paths.remove(path);
+                }
+            }
+        }
+    }
+}
+
+fn trigrams(text: &str) -> HashSet<Trigram> {
+    text.as_bytes()
+        .windows(3)
+        .filter(|w| w.iter().all(|b| b.is_ascii()))
+        .map(|w| {
+            [
+                w[0].to_ascii_lowercase(),
+                w[1].to_ascii_lowercase(),
+                w[2].to_ascii_lowercase(),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    use super::grep::required_literals;
+
+    #[test]
+    fn candidates_for_literals() {
+        let dir = env::temp_dir().join(format!("rustw-trigram-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let hello = dir.join("hello.rs");
+        let goodbye = dir.join("goodbye.rs");
+        fs::write(&hello, "fn hello_world() {}\n").unwrap();
+        fs::write(&goodbye, "struct Goodbye;\n").unwrap();
+
+        let mut index = TrigramIndex::new();
+        index.update(&[hello.clone(), goodbye.clone()]);
+        let candidates =
+            |pattern, is_regex| index.candidates(&required_literals(pattern, is_regex));
+
+        // Too short, or no literals, so every file must be searched.
+        assert_eq!(candidates("he", false), None);
+        assert_eq!(candidates("hello|goodbye", true), None);
+        assert_eq!(candidates(r"\w+", true), None);
+
+        let expected: HashSet<PathBuf> = vec![hello.clone()].into_iter().collect();
+        assert_eq!(candidates("HELLO", false), Some(expected.clone()));
+        assert_eq!(candidates("hel+o_world", true), Some(expected));
+        assert_eq!(candidates("zzz", false), Some(HashSet::new()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -11,7 +11,7 @@
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::io::{self, Write};
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::time::Instant;
 
 use rustc_parse::lexer;
@@ -27,6 +27,8 @@ use syntax_pos::FileName;
 
 use analysis::AnalysisHost;
 use analysis::DefKind;
+use analysis::Target;
+use file_controller::LinkPaths;
 
 type Span = span::Span<span::ZeroIndexed>;
 
@@ -36,7 +38,7 @@ pub fn with_globals<R>(f: impl FnOnce() -> R) -> R {
 
 pub fn highlight<'a>(
     analysis: &'a AnalysisHost,
-    project_path: &'a Path,
+    paths: &'a LinkPaths,
     file_name: String,
     file_text: String,
 ) -> String {
@@ -48,7 +50,7 @@ pub fn highlight<'a>(
             .source_map()
             .new_source_file(FileName::Real(PathBuf::from(&file_name)), file_text);
 
-        let mut out = Highlighter::new(analysis, project_path, sess.source_map());
+        let mut out = Highlighter::new(analysis, paths, sess.source_map());
 
         let t_start = Instant::now();
 
@@ -67,24 +69,31 @@ pub fn highlight<'a>(
     })
 }
 
+// Highlights using only the lexer, for text which the analysis data does not
+// describe.
+pub fn highlight_lexical(file_name: String, file_text: String) -> String {
+    let analysis = AnalysisHost::new(Target::Debug);
+    highlight(&analysis, &LinkPaths::default(), file_name, file_text)
+}
+
 struct Highlighter<'a> {
     buf: Vec<u8>,
     analysis: &'a AnalysisHost,
     source_map: &'a SourceMap,
-    project_path: &'a Path,
+    paths: &'a LinkPaths,
 }
 
 impl<'a> Highlighter<'a> {
     fn new(
         analysis: &'a AnalysisHost,
-        project_path: &'a Path,
+        paths: &'a LinkPaths,
         source_map: &'a SourceMap,
     ) -> Highlighter<'a> {
         Highlighter {
             buf: vec![],
             analysis,
             source_map,
-            project_path,
+            paths,
         }
     }
 
@@ -93,7 +102,7 @@ impl<'a> Highlighter<'a> {
             if span == &def_span {
                 None
             } else {
-                Some(loc_for_span(&def_span, self.project_path))
+                Some(loc_for_span(&def_span, self.paths))
             }
         })
     }
@@ -111,7 +120,8 @@ impl<'a> Highlighter<'a> {
 
 fn file_path_for_loc(loc: &Loc) -> PathBuf {
     match loc.file.name {
-        FileName::Real(ref path) => path.canonicalize().unwrap(),
+        // The file may not exist if we're highlighting another revision.
+        FileName::Real(ref path) => path.canonicalize().unwrap_or_else(|_| path.clone()),
         ref f => panic!("Expected real path, found {:?}", f),
     }
 }
@@ -159,13 +169,8 @@ fn push_char(buf: &mut Vec<u8>, c: char) -> io::Result<()> {
     }
 }
 
-fn loc_for_span(span: &Span, project_path: &Path) -> String {
-    let file_name = Path::new(&span.file)
-        .strip_prefix(project_path)
-        .ok()
-        .unwrap_or(&span.file)
-        .to_str()
-        .unwrap();
+fn loc_for_span(span: &Span, paths: &LinkPaths) -> String {
+    let file_name = paths.file_name(&span.file);
     format!(
         "{}:{}:{}:{}:{}",
         file_name,
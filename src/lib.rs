@@ -17,12 +17,15 @@ extern crate futures;
 extern crate hyper;
 #[macro_use]
 extern crate log;
+extern crate percent_encoding;
+extern crate regex;
 extern crate rls_analysis as analysis;
 extern crate rls_blacklist as blacklist;
 extern crate rls_span as span;
 extern crate rls_vfs as vfs;
 extern crate rustc_parse;
 extern crate rustdoc_highlight;
+extern crate semver;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -33,6 +36,7 @@ extern crate syntax_expand;
 extern crate syntax_pos;
 extern crate toml;
 extern crate url;
+extern crate walkdir;
 
 use config::Config;
 use hyper::server::Http;
@@ -7,7 +7,14 @@
 // except according to those terms.
 
 use std::cmp::{Ord, Ordering, PartialOrd};
+use std::collections::{HashMap, HashSet};
+use std::fs::{DirEntry, File};
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
 
 #[derive(Serialize, Debug, Clone)]
 pub struct DirectoryListing {
@@ -20,6 +27,20 @@ pub struct Listing {
     pub kind: ListingKind,
     pub name: String,
     pub path: String,
+    // Only set if we were asked for git status, and the file is not clean.
+    pub git_status: Option<GitStatus>,
+    // Only set if we were asked for file stats, and never for directories.
+    pub stats: Option<FileStats>,
+}
+
+#[derive(Serialize, Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct FileStats {
+    pub size: u64,
+    // Seconds since the Unix epoch.
+    pub modified: u64,
+    // `None` for binary files.
+    pub lines: Option<usize>,
+    pub language: Option<&'static str>,
 }
 
 #[derive(Serialize, Debug, Clone, Eq, PartialEq)]
@@ -29,6 +50,288 @@ pub enum ListingKind {
     File,
 }
 
+#[derive(Serialize, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum GitStatus {
+    Modified,
+    Added,
+    Untracked,
+    Ignored,
+}
+
+// The status of every file which is not clean in a git working tree.
+pub struct GitStatuses {
+    // Keyed by absolute path. Untracked and ignored directories are not
+    // expanded.
+    entries: HashMap<PathBuf, GitStatus>,
+}
+
+impl GitStatuses {
+    pub fn new(entries: HashMap<PathBuf, GitStatus>) -> GitStatuses {
+        GitStatuses { entries }
+    }
+
+    // `path` must be absolute. A directory is modified if anything it contains
+    // is not clean (other than ignored files).
+    pub fn status(&self, path: &Path, is_dir: bool) -> Option<GitStatus> {
+        if let Some(&status) = self.entries.get(path) {
+            return Some(status);
+        }
+        let in_dir = path
+            .ancestors()
+            .skip(1)
+            .find_map(|a| match self.entries.get(a) {
+                Some(&GitStatus::Untracked) => Some(GitStatus::Untracked),
+                Some(&GitStatus::Ignored) => Some(GitStatus::Ignored),
+                _ => None,
+            });
+        if in_dir.is_some() {
+            return in_dir;
+        }
+        let has_changes = is_dir
+            && self
+                .entries
+                .iter()
+                .any(|(p, &s)| s != GitStatus::Ignored && p.starts_with(path));
+        if has_changes {
+            Some(GitStatus::Modified)
+        } else {
+            None
+        }
+    }
+}
+
+// Stats for files we have listed, so that we only count lines again if a file
+// changes. Entries are dropped when a listing shows their file has gone, and
+// the whole cache is dropped if it gets too big.
+#[derive(Default)]
+pub struct FileStatsCache {
+    entries: Mutex<HashMap<PathBuf, CachedStats>>,
+}
+
+struct CachedStats {
+    // The full modification time, `FileStats::modified` is only to the second.
+    modified: Option<SystemTime>,
+    stats: FileStats,
+}
+
+const MAX_CACHED_STATS: usize = 10_000;
+// Larger files are not read to count lines.
+const MAX_COUNTED_SIZE: u64 = 1 << 20;
+
+impl FileStatsCache {
+    pub fn new() -> FileStatsCache {
+        FileStatsCache {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, entry: &DirEntry) -> Option<FileStats> {
+        let metadata = entry.metadata().ok()?;
+        let size = metadata.len();
+        let modified = metadata.modified().ok();
+
+        let path = entry.path();
+        if let Some(cached) = self.entries.lock().unwrap().get(&path) {
+            if modified.is_some() && cached.modified == modified && cached.stats.size == size {
+                return Some(cached.stats.clone());
+            }
+        }
+
+        // Don't hold the lock while we read the file.
+        let stats = FileStats {
+            size,
+            modified: modified
+                .unwrap_or(UNIX_EPOCH)
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            lines: count_lines(&path, size),
+            language: language(&path),
+        };
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= MAX_CACHED_STATS {
+            entries.clear();
+        }
+        entries.insert(
+            path,
+            CachedStats {
+                modified,
+                stats: stats.clone(),
+            },
+        );
+        Some(stats)
+    }
+
+    // Forget any files in `dir` which are not in `files`, a listing of `dir`.
+    fn evict_missing(&self, dir: &Path, files: &[Listing]) {
+        let listed: HashSet<&Path> = files.iter().map(|f| Path::new(&f.path)).collect();
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|path, _| path.parent() != Some(dir) || listed.contains(&**path));
+    }
+}
+
+// `None` if the file is too large or looks binary. Reads the file in chunks,
+// stopping at the first one which looks binary.
+fn count_lines(path: &Path, size: u64) -> Option<usize> {
+    if size > MAX_COUNTED_SIZE {
+        return None;
+    }
+    let mut file = File::open(path).ok()?;
+    let mut buf = [0; 8192];
+    let mut lines = 0;
+    let mut last = b'\n';
+    loop {
+        let len = file.read(&mut buf).ok()?;
+        if len == 0 {
+            break;
+        }
+        let chunk = &buf[..len];
+        if chunk.contains(&0) {
+            return None;
+        }
+        lines += chunk.iter().filter(|&&b| b == b'\n').count();
+        last = chunk[len - 1];
+    }
+    if last != b'\n' {
+        lines += 1;
+    }
+    Some(lines)
+}
+
+fn language(path: &Path) -> Option<&'static str> {
+    if path.file_name().map_or(false, |n| n == "Cargo.lock") {
+        return Some("TOML");
+    }
+    let ext = path.extension()?.to_str()?;
+    Some(match ext {
+        "rs" => "Rust",
+        "toml" => "TOML",
+        "md" | "markdown" => "Markdown",
+        "json" => "JSON",
+        "yml" | "yaml" => "YAML",
+        "js" => "JavaScript",
+        "ts" | "tsx" => "TypeScript",
+        "html" | "htm" => "HTML",
+        "css" => "CSS",
+        "sh" => "Shell",
+        "py" => "Python",
+        "c" | "h" => "C",
+        "cc" | "cpp" | "hpp" => "C++",
+        "txt" => "Text",
+        _ => return None,
+    })
+}
+
+// How files are ordered in a listing. Directories always come first.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SortBy {
+    Name,
+    Size,
+    Modified,
+}
+
+impl Default for SortBy {
+    fn default() -> SortBy {
+        SortBy::Name
+    }
+}
+
+impl SortBy {
+    pub fn parse(s: &str) -> Option<SortBy> {
+        match s {
+            "name" => Some(SortBy::Name),
+            "size" => Some(SortBy::Size),
+            "mtime" => Some(SortBy::Modified),
+            _ => None,
+        }
+    }
+
+    fn compare(self, a: &Listing, b: &Listing) -> Ordering {
+        let by_stats = match self {
+            SortBy::Name => Ordering::Equal,
+            SortBy::Size => a
+                .stats
+                .as_ref()
+                .map(|s| s.size)
+                .cmp(&b.stats.as_ref().map(|s| s.size)),
+            SortBy::Modified => a
+                .stats
+                .as_ref()
+                .map(|s| s.modified)
+                .cmp(&b.stats.as_ref().map(|s| s.modified)),
+        };
+        by_stats.then_with(|| a.name.cmp(&b.name))
+    }
+}
+
+// A glob for excluding files from listings. `*` and `?` do not match `/`, but
+// `**` does.
+pub struct Glob {
+    regex: Regex,
+    // Whether to match the path of a file, rather than its name.
+    match_path: bool,
+}
+
+impl Glob {
+    pub fn new(glob: &str) -> Result<Glob, String> {
+        let glob = glob.trim_start_matches("./").trim_end_matches('/');
+        if glob.is_empty() {
+            return Err("empty glob".to_owned());
+        }
+        let mut pattern = "^".to_owned();
+        let mut chars = glob.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    pattern.push_str(".*");
+                }
+                '*' => pattern.push_str("[^/]*"),
+                '?' => pattern.push_str("[^/]"),
+                c => pattern.push_str(&::regex::escape(&c.to_string())),
+            }
+        }
+        pattern.push('$');
+
+        Ok(Glob {
+            regex: Regex::new(&pattern).map_err(|e| e.to_string())?,
+            match_path: glob.contains('/'),
+        })
+    }
+
+    // `path` is relative to the project directory.
+    pub fn matches(&self, name: &str, path: &Path) -> bool {
+        if self.match_path {
+            let path = path.strip_prefix(".").unwrap_or(path);
+            path.to_str().map_or(false, |p| self.regex.is_match(p))
+        } else {
+            self.regex.is_match(name)
+        }
+    }
+}
+
+// How to list a directory.
+#[derive(Default)]
+pub struct ListingOptions<'a> {
+    // Used to annotate listings with their status (if `annotate_status`) and
+    // to hide ignored files.
+    pub git_status: Option<&'a GitStatuses>,
+    pub annotate_status: bool,
+    // Requires `git_status`.
+    pub hide_ignored: bool,
+    pub exclude: &'a [Glob],
+    // When recursing, directories deeper than this are listed but not
+    // expanded.
+    pub max_depth: Option<usize>,
+    // Include stats for each file. Sorting by size or modification time
+    // requires stats.
+    pub stats: Option<&'a FileStatsCache>,
+    pub sort: SortBy,
+    pub descending: bool,
+}
+
 impl PartialOrd for ListingKind {
     fn partial_cmp(&self, other: &ListingKind) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -49,14 +352,108 @@ impl Ord for ListingKind {
 }
 
 impl DirectoryListing {
-    pub fn from_path(path: &Path, recurse: bool) -> Result<DirectoryListing, String> {
+    pub fn from_path(
+        path: &Path,
+        recurse: bool,
+        options: &ListingOptions,
+    ) -> Result<DirectoryListing, String> {
         Ok(DirectoryListing {
             path: path.to_owned(),
-            files: Self::list_files(path, recurse)?,
+            files: Self::list_files(path, recurse, options, 0)?,
         })
     }
 
-    fn list_files(path: &Path, recurse: bool) -> Result<Vec<Listing>, String> {
+    // Moves the listing and everything in it from under `from` to under `to`,
+    // e.g., to hide where a directory is on disk.
+    pub fn rebase(&mut self, from: &Path, to: &Path) {
+        fn rebase_path(path: &Path, from: &Path, to: &Path) -> PathBuf {
+            match path.strip_prefix(from) {
+                Ok(rest) => to.join(rest),
+                Err(_) => path.to_owned(),
+            }
+        }
+
+        fn rebase_files(files: &mut [Listing], from: &Path, to: &Path) {
+            for file in files {
+                file.path = rebase_path(Path::new(&file.path), from, to)
+                    .to_str()
+                    .unwrap()
+                    .to_owned();
+                if let ListingKind::DirectoryTree(ref mut nested) = file.kind {
+                    rebase_files(nested, from, to);
+                }
+            }
+        }
+
+        self.path = rebase_path(&self.path, from, to);
+        rebase_files(&mut self.files, from, to);
+    }
+
+    // `entries` are paths relative to `path`, and whether each is a directory,
+    // e.g., from a git tree. If `recurse`, they should include the entries of
+    // all subdirectories.
+    pub fn from_entries(
+        path: &Path,
+        entries: &[(PathBuf, bool)],
+        recurse: bool,
+    ) -> DirectoryListing {
+        // Entries keyed by their parent directory.
+        let mut children: HashMap<&Path, Vec<(&Path, bool)>> = HashMap::new();
+        for &(ref entry, is_dir) in entries {
+            if let Some(parent) = entry.parent() {
+                children
+                    .entry(parent)
+                    .or_insert_with(Vec::new)
+                    .push((entry, is_dir));
+            }
+        }
+
+        DirectoryListing {
+            path: path.to_owned(),
+            files: Self::entry_files(path, Path::new(""), &children, recurse),
+        }
+    }
+
+    fn entry_files(
+        root: &Path,
+        dir: &Path,
+        children: &HashMap<&Path, Vec<(&Path, bool)>>,
+        recurse: bool,
+    ) -> Vec<Listing> {
+        let entries = match children.get(dir) {
+            Some(entries) => entries,
+            None => return vec![],
+        };
+        let mut files: Vec<Listing> = entries
+            .iter()
+            .map(|&(entry, is_dir)| {
+                let kind = if !is_dir {
+                    ListingKind::File
+                } else if recurse {
+                    ListingKind::DirectoryTree(Self::entry_files(root, entry, children, true))
+                } else {
+                    ListingKind::Directory
+                };
+                Listing {
+                    kind,
+                    name: entry.file_name().unwrap().to_str().unwrap().to_owned(),
+                    path: root.join(entry).to_str().unwrap().to_owned(),
+                    git_status: None,
+                    stats: None,
+                }
+            })
+            .collect();
+
+        files.sort();
+        files
+    }
+
+    fn list_files(
+        path: &Path,
+        recurse: bool,
+        options: &ListingOptions,
+        depth: usize,
+    ) -> Result<Vec<Listing>, String> {
         let mut files = vec![];
         let dir = match path.read_dir() {
             Ok(d) => d,
@@ -66,20 +463,45 @@ impl DirectoryListing {
             if let Ok(entry) = entry {
                 let name = entry.file_name().to_str().unwrap().to_owned();
                 let path = entry.path().to_str().unwrap().to_owned();
+                if options
+                    .exclude
+                    .iter()
+                    .any(|g| g.matches(&name, &entry.path()))
+                {
+                    continue;
+                }
                 if let Ok(file_type) = entry.file_type() {
+                    let git_status = options.git_status.and_then(|statuses| {
+                        let abs_path = entry.path().canonicalize().ok()?;
+                        statuses.status(&abs_path, file_type.is_dir())
+                    });
+                    if options.hide_ignored && git_status == Some(GitStatus::Ignored) {
+                        continue;
+                    }
+                    let git_status = if options.annotate_status {
+                        git_status
+                    } else {
+                        None
+                    };
+
                     if file_type.is_dir() {
-                        if recurse {
-                            let nested = Self::list_files(&entry.path(), true)?;
+                        let expand = recurse && options.max_depth.map_or(true, |max| depth < max);
+                        if expand {
+                            let nested = Self::list_files(&entry.path(), true, options, depth + 1)?;
                             files.push(Listing {
                                 kind: ListingKind::DirectoryTree(nested),
                                 name,
                                 path,
+                                git_status,
+                                stats: None,
                             });
                         } else {
                             files.push(Listing {
                                 kind: ListingKind::Directory,
                                 name,
                                 path,
+                                git_status,
+                                stats: None,
                             });
                         }
                     } else if file_type.is_file() {
@@ -87,13 +509,207 @@ impl DirectoryListing {
                             kind: ListingKind::File,
                             name,
                             path,
+                            git_status,
+                            stats: options.stats.and_then(|cache| cache.get(&entry)),
                         });
                     }
                 }
             }
         }
 
-        files.sort();
+        if let Some(cache) = options.stats {
+            cache.evict_missing(path, &files);
+        }
+
+        files.sort_by(|a, b| {
+            let ordering = options.sort.compare(a, b);
+            let ordering = if options.descending {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+            a.kind.cmp(&b.kind).then(ordering)
+        });
         Ok(files)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use std::process;
+
+    fn statuses() -> GitStatuses {
+        let mut entries = HashMap::new();
+        entries.insert(PathBuf::from("/proj/src/lib.rs"), GitStatus::Modified);
+        entries.insert(PathBuf::from("/proj/src/new.rs"), GitStatus::Added);
+        entries.insert(PathBuf::from("/proj/scratch"), GitStatus::Untracked);
+        entries.insert(PathBuf::from("/proj/tools/gen.sh"), GitStatus::Untracked);
+        entries.insert(PathBuf::from("/proj/target"), GitStatus::Ignored);
+        entries.insert(PathBuf::from("/proj/docs/notes.bak"), GitStatus::Ignored);
+        GitStatuses::new(entries)
+    }
+
+    #[test]
+    fn status_of_entries() {
+        let statuses = statuses();
+        assert_eq!(
+            statuses.status(Path::new("/proj/src/lib.rs"), false),
+            Some(GitStatus::Modified)
+        );
+        assert_eq!(
+            statuses.status(Path::new("/proj/src/new.rs"), false),
+            Some(GitStatus::Added)
+        );
+        assert_eq!(statuses.status(Path::new("/proj/src/main.rs"), false), None);
+    }
+
+    // Untracked and ignored directories are listed by git without their
+    // contents.
+    #[test]
+    fn status_in_untracked_or_ignored_dir() {
+        let statuses = statuses();
+        assert_eq!(
+            statuses.status(Path::new("/proj/scratch"), true),
+            Some(GitStatus::Untracked)
+        );
+        assert_eq!(
+            statuses.status(Path::new("/proj/scratch/a/b.rs"), false),
+            Some(GitStatus::Untracked)
+        );
+        assert_eq!(
+            statuses.status(Path::new("/proj/scratch/a"), true),
+            Some(GitStatus::Untracked)
+        );
+        assert_eq!(
+            statuses.status(Path::new("/proj/target/debug"), true),
+            Some(GitStatus::Ignored)
+        );
+        assert_eq!(
+            statuses.status(Path::new("/proj/target/debug/proj"), false),
+            Some(GitStatus::Ignored)
+        );
+        // Only a whole component is an ancestor.
+        assert_eq!(
+            statuses.status(Path::new("/proj/scratchpad.rs"), false),
+            None
+        );
+    }
+
+    #[test]
+    fn status_of_dirs() {
+        let statuses = statuses();
+        assert_eq!(
+            statuses.status(Path::new("/proj/src"), true),
+            Some(GitStatus::Modified)
+        );
+        // Untracked files make their parents modified, but ignored files do
+        // not.
+        assert_eq!(
+            statuses.status(Path::new("/proj/tools"), true),
+            Some(GitStatus::Modified)
+        );
+        assert_eq!(statuses.status(Path::new("/proj/docs"), true), None);
+        assert_eq!(statuses.status(Path::new("/proj/tests"), true), None);
+        // Files are never modified by their (non-existent) contents.
+        assert_eq!(statuses.status(Path::new("/proj/README.md"), false), None);
+    }
+
+    // Whether `glob` matches the file at `path`, relative to the project.
+    fn glob_matches(glob: &str, path: &str) -> bool {
+        let path = Path::new(path);
+        let name = path.file_name().unwrap().to_str().unwrap();
+        Glob::new(glob).unwrap().matches(name, path)
+    }
+
+    #[test]
+    fn glob_star() {
+        assert!(glob_matches("*.rs", "lib.rs"));
+        assert!(glob_matches("*.rs", "src/a/lib.rs"));
+        assert!(!glob_matches("*.rs", "lib.rs.bak"));
+        assert!(glob_matches("src/*.rs", "src/lib.rs"));
+        assert!(!glob_matches("src/*.rs", "src/a/lib.rs"));
+    }
+
+    #[test]
+    fn glob_double_star() {
+        assert!(glob_matches("src/**", "src/lib.rs"));
+        assert!(glob_matches("src/**", "src/a/b/lib.rs"));
+        assert!(glob_matches("src/**/gen.rs", "src/a/b/gen.rs"));
+        assert!(!glob_matches("src/**/gen.rs", "tests/a/gen.rs"));
+    }
+
+    #[test]
+    fn glob_question_mark() {
+        assert!(glob_matches("lib?.rs", "lib1.rs"));
+        assert!(!glob_matches("lib?.rs", "lib.rs"));
+        assert!(!glob_matches("lib?.rs", "lib12.rs"));
+        assert!(glob_matches("docs/?/index.md", "docs/a/index.md"));
+        assert!(!glob_matches("docs/?/index.md", "docs/ab/index.md"));
+    }
+
+    #[test]
+    fn glob_anchoring() {
+        assert!(glob_matches("target", "target"));
+        assert!(glob_matches("target", "a/target"));
+        assert!(!glob_matches("target", "mytarget"));
+        assert!(!glob_matches("target", "target2"));
+        // Globs with a `/` match the whole path from the project directory.
+        assert!(glob_matches("src/gen", "src/gen"));
+        assert!(!glob_matches("src/gen", "a/src/gen"));
+        assert!(!glob_matches("src/gen", "src/generated"));
+    }
+
+    #[test]
+    fn glob_dot_prefix() {
+        assert!(glob_matches("./target/", "target"));
+        assert!(glob_matches("./src/gen/*.rs", "src/gen/a.rs"));
+        assert!(glob_matches("./src/gen/*.rs", "./src/gen/a.rs"));
+        assert!(glob_matches("src/gen/*.rs", "./src/gen/a.rs"));
+        assert!(Glob::new("./").is_err());
+    }
+
+    #[test]
+    fn counted_lines() {
+        let dir = env::temp_dir().join(format!("rustw-listings-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let count = |name: &str, contents: &[u8]| {
+            let path = dir.join(name);
+            fs::write(&path, contents).unwrap();
+            count_lines(&path, contents.len() as u64)
+        };
+
+        assert_eq!(count("empty.txt", b""), Some(0));
+        assert_eq!(count("lines.rs", b"fn main() {\n}\n"), Some(2));
+        // A last line without a newline still counts.
+        assert_eq!(count("unterminated.rs", b"fn main() {\n}"), Some(2));
+
+        // A NUL byte anywhere, even after the first chunk, means binary.
+        assert_eq!(count("binary.png", b"\x89PNG\r\n\x1a\n\0\0"), None);
+        let mut late_nul = vec![b'a'; 10000];
+        late_nul[9000] = 0;
+        assert_eq!(count("late.bin", &late_nul), None);
+
+        let at_limit = vec![b'\n'; MAX_COUNTED_SIZE as usize];
+        assert_eq!(
+            count("at_limit.txt", &at_limit),
+            Some(MAX_COUNTED_SIZE as usize)
+        );
+        let too_large = vec![b'\n'; MAX_COUNTED_SIZE as usize + 1];
+        assert_eq!(count("too_large.txt", &too_large), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn parse_sort() {
+        assert_eq!(SortBy::parse("name"), Some(SortBy::Name));
+        assert_eq!(SortBy::parse("size"), Some(SortBy::Size));
+        assert_eq!(SortBy::parse("mtime"), Some(SortBy::Modified));
+        assert_eq!(SortBy::parse(""), None);
+        assert_eq!(SortBy::parse("Name"), None);
+        assert_eq!(SortBy::parse("modified"), None);
+    }
+}
@@ -13,10 +13,10 @@
 use analysis;
 use build::{self, BuildArgs};
 use config::Config;
-use file_controller::Cache;
+use file_controller::{BlameLine, Cache, RefFilter, RefKind};
 use futures;
 use futures::Future;
-use listings::{DirectoryListing, Listing};
+use listings::{DirectoryListing, Glob, Listing, ListingKind, ListingOptions, SortBy};
 
 use std::fmt;
 use std::path::{Path, PathBuf};
@@ -34,6 +34,7 @@ use hyper::server::Request;
 use hyper::server::Response;
 use hyper::server::Service;
 use hyper::StatusCode;
+use percent_encoding::percent_decode_str;
 use serde_json;
 use span;
 
@@ -59,7 +60,7 @@ impl Server {
 
         let mut instance = Server {
             builder: build::Builder::new(config.clone(), build_args),
-            file_cache: Arc::new(Cache::new(config.clone())),
+            file_cache: Arc::new(Cache::new(config.clone(), exclude_globs(&config))),
             config,
             status: Status::new(),
         };
@@ -209,17 +210,78 @@ impl Server {
                 path
             };
 
-            self.handle_src(req, arg, recurse)
+            let blame = parse_query_value(query, "blame=").map_or(false, |b| b == "true");
+            let rev = parse_query_value(query, "rev=");
+            let git_status = parse_query_value(query, "git_status=").map_or(false, |s| s == "true");
+            let hide_ignored = parse_query_value(query, "hide_ignored=")
+                .map_or(self.config.respect_gitignore, |h| h == "true");
+            let stats = parse_query_value(query, "stats=").map_or(false, |s| s == "true");
+            let sort = parse_query_value(query, "sort=");
+            let descending = parse_query_value(query, "order=").map_or(false, |o| o == "desc");
+            match sort
+                .as_ref()
+                .map_or(Some(SortBy::Name), |s| SortBy::parse(s))
+            {
+                Some(sort_by) => {
+                    let args = SrcArgs {
+                        recurse,
+                        blame,
+                        rev: rev.as_ref().map(|r| &**r),
+                        git_status,
+                        hide_ignored,
+                        stats,
+                        sort: sort_by,
+                        descending,
+                    };
+                    self.handle_src(req, arg, &args)
+                }
+                None => self.handle_error(
+                    req,
+                    StatusCode::BadRequest,
+                    format!("Bad sort: {}", sort.unwrap()),
+                ),
+            }
+        } else if path[0] == DEPS_REQUEST {
+            self.handle_deps(req, &path[1..])
+        } else if path[0] == BLAME_REQUEST {
+            self.handle_blame(req, &path[1..])
+        } else if path[0] == OUTLINE_REQUEST {
+            self.handle_outline(req, &path[1..])
         } else if path[0] == PLAIN_TEXT {
             self.handle_plain_text(req, query)
         } else if path[0] == RAW_REQUEST {
-            self.handle_raw(req, &path[1..])
+            let rev = parse_query_value(query, "rev=");
+            self.handle_raw(req, &path[1..], rev.as_ref().map(|r| &**r))
         } else if path[0] == SEARCH_REQUEST {
             self.handle_search(req, query)
+        } else if path[0] == SYMBOL_SEARCH {
+            self.handle_symbol_search(req, query)
+        } else if path[0] == GREP_REQUEST {
+            self.handle_grep(req, query)
+        } else if path[0] == REFERENCES_REQUEST {
+            self.handle_references(req, query)
         } else if path[0] == FIND_REQUEST {
             self.handle_find(req, query)
+        } else if path[0] == CALLERS_REQUEST || path[0] == CALLEES_REQUEST {
+            self.handle_call_hierarchy(req, query, path[0] == CALLERS_REQUEST)
+        } else if path[0] == TYPE_HIERARCHY {
+            self.handle_type_hierarchy(req, query)
+        } else if path[0] == CRATE_GRAPH {
+            self.handle_crate_graph(req, query)
+        } else if path[0] == MODULE_GRAPH {
+            self.handle_module_graph(req, query)
+        } else if path[0] == CRATE_TREE {
+            self.handle_crate_tree(req)
+        } else if path[0] == UNUSED_REQUEST {
+            self.handle_unused(req, query)
+        } else if path[0] == API_SURFACE {
+            self.handle_api_surface(req, query)
+        } else if path[0] == DOC_COVERAGE {
+            self.handle_doc_coverage(req)
+        } else if path[0] == DIFF_REQUEST {
+            self.handle_diff(req, query)
         } else if path[0] == SYMBOL_ROOTS {
-            return Box::new(self.handle_sym_roots(req));
+            return Box::new(self.handle_sym_roots(req, query));
         } else if path[0] == SYMBOL_CHILDREN {
             self.handle_sym_childen(req, query)
         } else if !self.config.demo_mode && path[0] == EDIT_REQUEST {
@@ -278,21 +340,24 @@ impl Server {
         self.handle_error(req, StatusCode::NotFound, "Page not found".to_owned())
     }
 
-    fn handle_raw(&self, req: Request, path: &[&str]) -> Response {
-        for p in path {
-            // In demo mode this might reveal the contents of the server outside
-            // the source directory (really, rustw should run in a sandbox, but
-            // hey, FIXME).
-            if p.contains("..") || *p == "/" {
-                return self.handle_error(
-                    req,
-                    StatusCode::InternalServerError,
-                    "Bad path, found `..`".to_owned(),
-                );
-            }
+    fn handle_raw(&self, req: Request, path: &[&str], rev: Option<&str>) -> Response {
+        if is_bad_path(path) {
+            return self.handle_error(
+                req,
+                StatusCode::InternalServerError,
+                "Bad path, found `..`".to_owned(),
+            );
         }
 
-        match self.file_cache.get_raw(&path.iter().collect::<PathBuf>()) {
+        let path_buf = path.iter().collect::<PathBuf>();
+        let contents = match rev {
+            Some(rev) => self.file_cache.get_raw_at(&path_buf, rev),
+            None => self
+                .file_cache
+                .get_raw(&path_buf)
+                .map_err(|e| e.to_string()),
+        };
+        match contents {
             Ok(::vfs::FileContents::Text(text)) => {
                 let mut res = Response::new();
                 res.headers_mut().set(ContentType::plaintext());
@@ -302,11 +367,11 @@ impl Server {
                 let res = Response::new();
                 res.with_body(bin)
             }
-            Err(e) => self.handle_error(req, StatusCode::NotFound, e.to_string()),
+            Err(e) => self.handle_error(req, StatusCode::NotFound, e),
         }
     }
 
-    fn handle_src(&self, req: Request, mut path: &[&str], recurse: bool) -> Response {
+    fn handle_src(&self, req: Request, path: &[&str], args: &SrcArgs) -> Response {
         use file_controller::Highlighted;
 
         fn path_parts(path: &Path) -> Vec<String> {
@@ -315,43 +380,60 @@ impl Server {
                 .collect()
         }
 
-        for p in path {
-            // In demo mode this might reveal the contents of the server outside
-            // the source directory (really, rustw should run in a sandbox, but
-            // hey, FIXME).
-            if p.contains("..") || *p == "/" {
-                return self.handle_error(
-                    req,
-                    StatusCode::InternalServerError,
-                    "Bad path, found `..`".to_owned(),
-                );
-            }
+        if is_bad_path(path) {
+            return self.handle_error(
+                req,
+                StatusCode::InternalServerError,
+                "Bad path, found `..`".to_owned(),
+            );
         }
 
-        let mut path_buf = PathBuf::new();
-        if path[0].is_empty() {
-            path_buf.push("/");
-            path = &path[1..];
-        }
-        for p in path {
-            if cfg!(windows) {
-                let mut chars = p.chars();
-                match (chars.next(), chars.next(), chars.next()) {
-                    (Some(drive_letter), Some(colon), None)
-                        if drive_letter.is_ascii_alphabetic() && colon == ':' =>
-                    {
-                        path_buf.push(&[drive_letter, colon, '\\'].iter().collect::<String>());
-                    }
-                    _ => path_buf.push(p),
-                }
-            } else {
-                path_buf.push(p);
-            }
+        let path_buf = make_path_buf(path);
+        if !self.file_cache.in_project(&path_buf) {
+            return self.handle_error(
+                req,
+                StatusCode::Forbidden,
+                format!("Not in the project: {}", path_buf.display()),
+            );
         }
 
+        let SrcArgs { recurse, rev, .. } = *args;
+        let is_dir = match rev {
+            Some(rev) => match self.file_cache.is_dir_at(&path_buf, rev) {
+                Ok(is_dir) => is_dir,
+                Err(msg) => return self.handle_error(req, StatusCode::NotFound, msg),
+            },
+            None => path_buf.is_dir(),
+        };
+
         // FIXME should cache directory listings too
-        if path_buf.is_dir() {
-            match DirectoryListing::from_path(&path_buf, recurse) {
+        if is_dir {
+            let listing = match rev {
+                Some(rev) => self.file_cache.get_listing_at(&path_buf, rev, recurse),
+                None => {
+                    let statuses = if args.git_status || args.hide_ignored {
+                        self.file_cache.git_status().ok()
+                    } else {
+                        None
+                    };
+                    let options = ListingOptions {
+                        git_status: statuses.as_ref().map(|s| &**s),
+                        annotate_status: args.git_status,
+                        hide_ignored: args.hide_ignored,
+                        exclude: self.file_cache.exclude_globs(),
+                        max_depth: Some(self.config.max_tree_depth),
+                        stats: if args.stats || args.sort != SortBy::Name {
+                            Some(self.file_cache.file_stats())
+                        } else {
+                            None
+                        },
+                        sort: args.sort,
+                        descending: args.descending,
+                    };
+                    DirectoryListing::from_path(&path_buf, recurse, &options)
+                }
+            };
+            match listing {
                 Ok(listing) => {
                     let mut res = Response::new();
                     res.headers_mut().set(ContentType::json());
@@ -365,7 +447,11 @@ impl Server {
                 Err(msg) => self.handle_error(req, StatusCode::InternalServerError, msg),
             }
         } else {
-            match self.file_cache.get_highlighted(&path_buf) {
+            let highlighted = match rev {
+                Some(rev) => self.file_cache.get_highlighted_at(&path_buf, rev),
+                None => self.file_cache.get_highlighted(&path_buf),
+            };
+            match highlighted {
                 Ok(Highlighted {
                     ref source,
                     ref rendered,
@@ -373,10 +459,16 @@ impl Server {
                     let mut res = Response::new();
                     res.headers_mut().set(ContentType::json());
                     let path = path_parts(&path_buf);
+                    let blame = if args.blame && rev.is_none() {
+                        self.file_cache.get_blame(&path_buf).ok()
+                    } else {
+                        None
+                    };
                     let result = SourceResult::File {
                         path,
                         lines: source.as_ref().map(|s| s.as_ref()),
                         rendered: rendered.as_ref().map(|s| s.as_ref()),
+                        blame,
                     };
                     res.with_body(serde_json::to_string(&result).unwrap())
                 }
@@ -385,6 +477,193 @@ impl Server {
         }
     }
 
+    // Dependency sources, at `/deps/:crate/:version/:path`. Only the source
+    // directories of packages from cargo metadata are accessible. `/deps` lists
+    // the crates and `/deps/:crate` their versions.
+    fn handle_deps(&self, req: Request, path: &[&str]) -> Response {
+        use file_controller::Highlighted;
+
+        if is_bad_path(path) {
+            return self.handle_error(
+                req,
+                StatusCode::InternalServerError,
+                "Bad path, found `..`".to_owned(),
+            );
+        }
+
+        // Ignore a trailing slash.
+        let path: Vec<&str> = path.iter().cloned().filter(|p| !p.is_empty()).collect();
+        // Paths are absolute, like those from `/src`, so that the client can
+        // tell them apart from paths in the project.
+        let crumbs: Vec<String> = vec!["/", DEPS_REQUEST]
+            .into_iter()
+            .chain(path.iter().cloned())
+            .map(|p| p.to_owned())
+            .collect();
+
+        let dependencies = match self.file_cache.dependencies() {
+            Ok(deps) => deps,
+            Err(msg) => return self.handle_error(req, StatusCode::InternalServerError, msg),
+        };
+        let make_dir = |name: &str, path: String| Listing {
+            kind: ListingKind::Directory,
+            name: name.to_owned(),
+            path,
+            git_status: None,
+            stats: None,
+        };
+
+        let result = match path.len() {
+            0 => {
+                let mut files: Vec<Listing> = dependencies
+                    .iter()
+                    .map(|&(ref name, _)| make_dir(name, format!("/{}/{}", DEPS_REQUEST, name)))
+                    .collect();
+                files.dedup();
+                SourceResult::Directory {
+                    path: crumbs,
+                    files,
+                }
+            }
+            1 => {
+                let files: Vec<Listing> = dependencies
+                    .iter()
+                    .filter(|&&(ref name, _)| name == path[0])
+                    .map(|&(ref name, ref version)| {
+                        make_dir(version, format!("/{}/{}/{}", DEPS_REQUEST, name, version))
+                    })
+                    .collect();
+                if files.is_empty() {
+                    return self.handle_error(
+                        req,
+                        StatusCode::NotFound,
+                        format!("Unknown dependency: {}", path[0]),
+                    );
+                }
+                SourceResult::Directory {
+                    path: crumbs,
+                    files,
+                }
+            }
+            _ => {
+                let dir = match self.file_cache.dependency_dir(path[0], path[1]) {
+                    Ok(dir) => dir,
+                    Err(msg) => return self.handle_error(req, StatusCode::NotFound, msg),
+                };
+                let file_path = dir.join(make_path_buf(&path[2..]));
+                if !file_path.exists() {
+                    return self.handle_error(
+                        req,
+                        StatusCode::NotFound,
+                        format!("Not found: {}", path.join("/")),
+                    );
+                }
+
+                if file_path.is_dir() {
+                    let options = ListingOptions {
+                        exclude: self.file_cache.exclude_globs(),
+                        ..ListingOptions::default()
+                    };
+                    let mut listing = match DirectoryListing::from_path(&file_path, false, &options)
+                    {
+                        Ok(listing) => listing,
+                        Err(msg) => {
+                            return self.handle_error(req, StatusCode::InternalServerError, msg)
+                        }
+                    };
+                    listing.rebase(
+                        &dir,
+                        &Path::new("/")
+                            .join(DEPS_REQUEST)
+                            .join(path[0])
+                            .join(path[1]),
+                    );
+                    SourceResult::Directory {
+                        path: crumbs,
+                        files: listing.files,
+                    }
+                } else {
+                    match self.file_cache.get_highlighted(&file_path) {
+                        Ok(Highlighted { source, rendered }) => {
+                            let mut res = Response::new();
+                            res.headers_mut().set(ContentType::json());
+                            let result = SourceResult::File {
+                                path: crumbs,
+                                lines: source.as_ref().map(|s| s.as_ref()),
+                                rendered: rendered.as_ref().map(|s| s.as_ref()),
+                                blame: None,
+                            };
+                            return res.with_body(serde_json::to_string(&result).unwrap());
+                        }
+                        Err(msg) => {
+                            return self.handle_error(req, StatusCode::InternalServerError, msg)
+                        }
+                    }
+                }
+            }
+        };
+
+        let mut res = Response::new();
+        res.headers_mut().set(ContentType::json());
+        res.with_body(serde_json::to_string(&result).unwrap())
+    }
+
+    fn handle_blame(&self, req: Request, path: &[&str]) -> Response {
+        if is_bad_path(path) {
+            return self.handle_error(
+                req,
+                StatusCode::InternalServerError,
+                "Bad path, found `..`".to_owned(),
+            );
+        }
+
+        let path_buf = make_path_buf(path);
+        if !self.file_cache.in_project(&path_buf) {
+            return self.handle_error(
+                req,
+                StatusCode::Forbidden,
+                format!("Not in the project: {}", path_buf.display()),
+            );
+        }
+
+        match self.file_cache.get_blame(&path_buf) {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(msg) => self.handle_error(req, StatusCode::InternalServerError, msg),
+        }
+    }
+
+    fn handle_outline(&self, req: Request, path: &[&str]) -> Response {
+        if is_bad_path(path) {
+            return self.handle_error(
+                req,
+                StatusCode::InternalServerError,
+                "Bad path, found `..`".to_owned(),
+            );
+        }
+
+        let path_buf = make_path_buf(path);
+        if !self.file_cache.in_project(&path_buf) {
+            return self.handle_error(
+                req,
+                StatusCode::Forbidden,
+                format!("Not in the project: {}", path_buf.display()),
+            );
+        }
+
+        match self.file_cache.outline(&path_buf) {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(msg) => self.handle_error(req, StatusCode::InternalServerError, msg),
+        }
+    }
+
     fn handle_config(&self, _req: Request) -> Response {
         let text = serde_json::to_string(&*self.config).unwrap();
         let mut res = Response::new();
@@ -436,13 +715,19 @@ impl Server {
     }
 
     fn handle_search(&self, _req: Request, query: Option<&str>) -> Response {
+        let filter = match parse_ref_filter(query) {
+            Ok(f) => f,
+            Err(s) => return self.handle_error(_req, StatusCode::InternalServerError, s),
+        };
+
         match (
             parse_query_value(query, "needle="),
             parse_query_value(query, "id="),
+            parse_query_value(query, "path="),
         ) {
-            (Some(needle), None) => {
+            (Some(needle), None, None) => {
                 // Identifier search.
-                match self.file_cache.ident_search(&needle) {
+                match self.file_cache.ident_search(&needle, &filter) {
                     Ok(data) => {
                         let mut res = Response::new();
                         res.headers_mut().set(ContentType::json());
@@ -453,7 +738,7 @@ impl Server {
                     }
                 }
             }
-            (None, Some(id)) => {
+            (None, Some(id), None) => {
                 // Search by id.
                 let id = match u64::from_str(&id) {
                     Ok(l) => l,
@@ -465,7 +750,20 @@ impl Server {
                         );
                     }
                 };
-                match self.file_cache.id_search(analysis::Id::new(id)) {
+                match self.file_cache.id_search(analysis::Id::new(id), &filter) {
+                    Ok(data) => {
+                        let mut res = Response::new();
+                        res.headers_mut().set(ContentType::json());
+                        return res.with_body(serde_json::to_string(&data).unwrap());
+                    }
+                    Err(s) => {
+                        return self.handle_error(_req, StatusCode::InternalServerError, s);
+                    }
+                }
+            }
+            (None, None, Some(path)) => {
+                // Search by qualified path.
+                match self.file_cache.path_search(&path, &filter) {
                     Ok(data) => {
                         let mut res = Response::new();
                         res.headers_mut().set(ContentType::json());
@@ -486,9 +784,150 @@ impl Server {
         }
     }
 
+    fn handle_symbol_search(&self, _req: Request, query: Option<&str>) -> Response {
+        let limit = match parse_query_value(query, "limit=") {
+            Some(limit) => match usize::from_str(&limit) {
+                Ok(l) => l,
+                Err(_) => {
+                    return self.handle_error(
+                        _req,
+                        StatusCode::InternalServerError,
+                        format!("Bad limit: {}", limit),
+                    );
+                }
+            },
+            None => DEFAULT_SYMBOL_SEARCH_LIMIT,
+        };
+
+        match parse_query_value(query, "query=") {
+            Some(ref needle) if !needle.is_empty() => {
+                match self.file_cache.symbol_search(needle, limit) {
+                    Ok(data) => {
+                        let mut res = Response::new();
+                        res.headers_mut().set(ContentType::json());
+                        return res.with_body(serde_json::to_string(&data).unwrap());
+                    }
+                    Err(s) => {
+                        return self.handle_error(_req, StatusCode::InternalServerError, s);
+                    }
+                }
+            }
+            _ => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad search string".to_owned(),
+                );
+            }
+        }
+    }
+
+    fn handle_grep(&self, _req: Request, query: Option<&str>) -> Response {
+        let is_regex = parse_query_value(query, "regex=").map_or(false, |s| s == "true");
+        let case_sensitive = parse_query_value(query, "case=").map_or(true, |s| s != "false");
+
+        match parse_query_value(query, "pattern=") {
+            Some(ref pattern) if !pattern.is_empty() => {
+                let pattern = percent_decode_str(pattern).decode_utf8_lossy();
+                match self
+                    .file_cache
+                    .text_search(&pattern, is_regex, case_sensitive)
+                {
+                    Ok(data) => {
+                        let mut res = Response::new();
+                        res.headers_mut().set(ContentType::json());
+                        return res.with_body(serde_json::to_string(&data).unwrap());
+                    }
+                    Err(s) => {
+                        return self.handle_error(_req, StatusCode::InternalServerError, s);
+                    }
+                }
+            }
+            _ => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad search string".to_owned(),
+                );
+            }
+        }
+    }
+
+    fn handle_references(&self, _req: Request, query: Option<&str>) -> Response {
+        let filter = match parse_ref_filter(query) {
+            Ok(f) => f,
+            Err(s) => return self.handle_error(_req, StatusCode::InternalServerError, s),
+        };
+
+        match (
+            parse_query_value(query, "file="),
+            parse_query_value(query, "line="),
+            parse_query_value(query, "col="),
+        ) {
+            (Some(file_name), Some(line), Some(col)) => {
+                let line = match u32::from_str(&line) {
+                    Ok(l) if l > 0 => l,
+                    _ => {
+                        return self.handle_error(
+                            _req,
+                            StatusCode::InternalServerError,
+                            format!("Bad line number: {}", line),
+                        );
+                    }
+                };
+                let col = match u32::from_str(&col) {
+                    Ok(c) if c > 0 => c,
+                    _ => {
+                        return self.handle_error(
+                            _req,
+                            StatusCode::InternalServerError,
+                            format!("Bad column number: {}", col),
+                        );
+                    }
+                };
+                let file_name = percent_decode_str(&file_name)
+                    .decode_utf8_lossy()
+                    .into_owned();
+                if !self.file_cache.in_project(Path::new(&file_name)) {
+                    return self.handle_error(
+                        _req,
+                        StatusCode::Forbidden,
+                        format!("Not in the project: {}", file_name),
+                    );
+                }
+                match self.file_cache.position_search(
+                    Path::new(&file_name),
+                    span::Row::new_one_indexed(line),
+                    span::Column::new_one_indexed(col),
+                    &filter,
+                ) {
+                    Ok(data) => {
+                        let mut res = Response::new();
+                        res.headers_mut().set(ContentType::json());
+                        return res.with_body(serde_json::to_string(&data).unwrap());
+                    }
+                    Err(s) => {
+                        return self.handle_error(_req, StatusCode::InternalServerError, s);
+                    }
+                }
+            }
+            _ => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad query string".to_owned(),
+                );
+            }
+        }
+    }
+
     fn handle_find(&self, _req: Request, query: Option<&str>) -> Response {
-        match parse_query_value(query, "impls=") {
-            Some(id) => {
+        // `impls=` must be checked last since it is a suffix of `method_impls=`.
+        let arg = ["method_impls=", "trait_decl=", "impls="]
+            .iter()
+            .find_map(|key| parse_query_value(query, key).map(|value| (*key, value)));
+        match arg {
+            Some((key, id)) => {
                 let id = match u64::from_str(&id) {
                     Ok(l) => l,
                     Err(_) => {
@@ -499,7 +938,13 @@ impl Server {
                         );
                     }
                 };
-                match self.file_cache.find_impls(analysis::Id::new(id)) {
+                let id = analysis::Id::new(id);
+                let result = match key {
+                    "method_impls=" => self.file_cache.find_method_impls(id),
+                    "trait_decl=" => self.file_cache.find_trait_decl(id),
+                    _ => self.file_cache.find_impls(id),
+                };
+                match result {
                     Ok(data) => {
                         let mut res = Response::new();
                         res.headers_mut().set(ContentType::json());
@@ -520,15 +965,261 @@ impl Server {
         }
     }
 
-    fn handle_sym_roots(&self, _req: Request) -> impl Future<Item = Response, Error = Error> {
+    // The callers of the function `id=`, or its callees if `callers` is false.
+    fn handle_call_hierarchy(&self, _req: Request, query: Option<&str>, callers: bool) -> Response {
+        match parse_query_value(query, "id=") {
+            Some(id) => {
+                let id = match u64::from_str(&id) {
+                    Ok(l) => analysis::Id::new(l),
+                    Err(_) => {
+                        return self.handle_error(
+                            _req,
+                            StatusCode::InternalServerError,
+                            format!("Bad id: {}", id),
+                        );
+                    }
+                };
+                let result = if callers {
+                    self.file_cache.callers(id)
+                } else {
+                    self.file_cache.callees(id)
+                };
+                match result {
+                    Ok(data) => {
+                        let mut res = Response::new();
+                        res.headers_mut().set(ContentType::json());
+                        return res.with_body(serde_json::to_string(&data).unwrap());
+                    }
+                    Err(s) => {
+                        return self.handle_error(_req, StatusCode::InternalServerError, s);
+                    }
+                }
+            }
+            _ => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad query string".to_owned(),
+                );
+            }
+        }
+    }
+
+    fn handle_type_hierarchy(&self, _req: Request, query: Option<&str>) -> Response {
+        match parse_query_value(query, "id=") {
+            Some(id) => {
+                let id = match u64::from_str(&id) {
+                    Ok(l) => analysis::Id::new(l),
+                    Err(_) => {
+                        return self.handle_error(
+                            _req,
+                            StatusCode::InternalServerError,
+                            format!("Bad id: {}", id),
+                        );
+                    }
+                };
+                match self.file_cache.type_hierarchy(id) {
+                    Ok(data) => {
+                        let mut res = Response::new();
+                        res.headers_mut().set(ContentType::json());
+                        return res.with_body(serde_json::to_string(&data).unwrap());
+                    }
+                    Err(s) => {
+                        return self.handle_error(_req, StatusCode::InternalServerError, s);
+                    }
+                }
+            }
+            _ => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad query string".to_owned(),
+                );
+            }
+        }
+    }
+
+    // `format=dot` gives the graph in Graphviz format rather than json.
+    fn handle_crate_graph(&self, _req: Request, query: Option<&str>) -> Response {
+        if parse_query_value(query, "format=").map_or(false, |f| f == "dot") {
+            return match self.file_cache.crate_graph_dot() {
+                Ok(dot) => {
+                    let mut res = Response::new();
+                    res.headers_mut().set(ContentType::plaintext());
+                    res.with_body(dot)
+                }
+                Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+            };
+        }
+
+        match self.file_cache.crate_graph() {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+        }
+    }
+
+    // `format=dot` gives the graph in Graphviz format rather than json.
+    fn handle_module_graph(&self, _req: Request, query: Option<&str>) -> Response {
+        let crate_name = match parse_query_value(query, "crate=") {
+            Some(c) => c,
+            None => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad query string".to_owned(),
+                );
+            }
+        };
+
+        if parse_query_value(query, "format=").map_or(false, |f| f == "dot") {
+            return match self.file_cache.module_graph_dot(&crate_name) {
+                Ok(dot) => {
+                    let mut res = Response::new();
+                    res.headers_mut().set(ContentType::plaintext());
+                    res.with_body(dot)
+                }
+                Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+            };
+        }
+
+        match self.file_cache.module_graph(&crate_name) {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+        }
+    }
+
+    fn handle_crate_tree(&self, _req: Request) -> Response {
+        match self.file_cache.crate_tree() {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+        }
+    }
+
+    // `pub=true` restricts the report to public items.
+    fn handle_unused(&self, _req: Request, query: Option<&str>) -> Response {
+        let pub_only = parse_query_value(query, "pub=").map_or(false, |p| p == "true");
+        match self.file_cache.unused(pub_only) {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+        }
+    }
+
+    // `format=text` gives one line per item rather than json.
+    fn handle_api_surface(&self, _req: Request, query: Option<&str>) -> Response {
+        let crate_name = match parse_query_value(query, "crate=") {
+            Some(c) => c,
+            None => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad query string".to_owned(),
+                );
+            }
+        };
+
+        if parse_query_value(query, "format=").map_or(false, |f| f == "text") {
+            return match self.file_cache.api_surface_text(&crate_name) {
+                Ok(text) => {
+                    let mut res = Response::new();
+                    res.headers_mut().set(ContentType::plaintext());
+                    res.with_body(text)
+                }
+                Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+            };
+        }
+
+        match self.file_cache.api_surface(&crate_name) {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+        }
+    }
+
+    fn handle_doc_coverage(&self, _req: Request) -> Response {
+        match self.file_cache.doc_coverage() {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+        }
+    }
+
+    // `from` defaults to `HEAD`, and `to` to the working tree.
+    fn handle_diff(&self, _req: Request, query: Option<&str>) -> Response {
+        let path = match parse_query_value(query, "path=") {
+            Some(p) => percent_decode_str(&p).decode_utf8_lossy().into_owned(),
+            None => {
+                return self.handle_error(
+                    _req,
+                    StatusCode::InternalServerError,
+                    "Bad query string".to_owned(),
+                );
+            }
+        };
+        let segments: Vec<&str> = path.split('/').collect();
+        if is_bad_path(&segments) || path.starts_with('/') {
+            trace!("handle_diff: bad path: `{}`", path);
+            return self.handle_error(_req, StatusCode::InternalServerError, "Bad path".to_owned());
+        }
+        let from = parse_query_value(query, "from=").unwrap_or_else(|| "HEAD".to_owned());
+        let to = parse_query_value(query, "to=");
+
+        match self
+            .file_cache
+            .diff(Path::new(&path), &from, to.as_ref().map(|t| &**t))
+        {
+            Ok(data) => {
+                let mut res = Response::new();
+                res.headers_mut().set(ContentType::json());
+                res.with_body(serde_json::to_string(&data).unwrap())
+            }
+            Err(s) => self.handle_error(_req, StatusCode::InternalServerError, s),
+        }
+    }
+
+    // By default, returns a list of the workspace crates. With `deps=true`,
+    // returns an object with the workspace and dependency crates in separate
+    // lists.
+    fn handle_sym_roots(
+        &self,
+        _req: Request,
+        query: Option<&str>,
+    ) -> impl Future<Item = Response, Error = Error> {
+        let include_deps = parse_query_value(query, "deps=").map_or(false, |s| s == "true");
         let file_cache = self.file_cache.clone();
         self.status
             .block()
-            .then(move |_| match file_cache.get_symbol_roots() {
+            .then(move |_| match file_cache.get_symbol_roots(include_deps) {
                 Ok(data) => {
                     let mut res = Response::new();
                     res.headers_mut().set(ContentType::json());
-                    futures::future::ok(res.with_body(serde_json::to_string(&data).unwrap()))
+                    let body = if include_deps {
+                        serde_json::to_string(&data)
+                    } else {
+                        serde_json::to_string(&data.workspace)
+                    };
+                    futures::future::ok(res.with_body(body.unwrap()))
                 }
                 Err(s) => futures::future::ok(
                     Response::new()
@@ -551,7 +1242,12 @@ impl Server {
                         );
                     }
                 };
-                match self.file_cache.get_symbol_children(analysis::Id::new(id)) {
+                let kinds = parse_query_value(query, "kind=")
+                    .map(|kinds| kinds.split(',').map(|k| k.to_owned()).collect::<Vec<_>>());
+                match self
+                    .file_cache
+                    .get_symbol_children(analysis::Id::new(id), kinds.as_ref().map(|k| &**k))
+                {
                     Ok(data) => {
                         let mut res = Response::new();
                         res.headers_mut().set(ContentType::json());
@@ -589,6 +1285,14 @@ impl Server {
                     }
                 };
 
+                if !self.file_cache.in_project(Path::new(&file_name)) {
+                    return self.handle_error(
+                        _req,
+                        StatusCode::Forbidden,
+                        format!("Not in the project: {}", file_name),
+                    );
+                }
+
                 // Hard-coded 2 lines of context before and after target line.
                 let line_start = line.saturating_sub(3);
                 let line_end = line + 2;
@@ -625,6 +1329,24 @@ impl Server {
     }
 }
 
+// Options for `/src` and `/tree`.
+struct SrcArgs<'a> {
+    recurse: bool,
+    // Include blame for each line of a file.
+    blame: bool,
+    // Look up the path at this git revision rather than in the working tree.
+    // Blame and git status are not included.
+    rev: Option<&'a str>,
+    // Annotate directory listings with git status.
+    git_status: bool,
+    hide_ignored: bool,
+    // Include the size, modification time, line count and language of files.
+    stats: bool,
+    // Sorting by size or modification time implies `stats`.
+    sort: SortBy,
+    descending: bool,
+}
+
 // The below data types are used to pass data to the client.
 
 #[derive(Serialize, Debug)]
@@ -633,6 +1355,7 @@ pub enum SourceResult<'a> {
         path: Vec<String>,
         lines: Option<&'a [String]>,
         rendered: Option<&'a str>,
+        blame: Option<Vec<BlameLine>>,
     },
     Directory {
         path: Vec<String>,
@@ -648,6 +1371,38 @@ pub struct TextResult<'a> {
     line_end: usize,
 }
 
+// In demo mode this might reveal the contents of the server outside the source
+// directory (really, rustw should run in a sandbox, but hey, FIXME).
+fn is_bad_path(path: &[&str]) -> bool {
+    path.iter().any(|p| p.contains("..") || *p == "/")
+}
+
+// Makes a path from the segments of a URL path. A leading empty segment means
+// the path is absolute.
+fn make_path_buf(mut path: &[&str]) -> PathBuf {
+    let mut path_buf = PathBuf::new();
+    if path.first().map_or(false, |p| p.is_empty()) {
+        path_buf.push("/");
+        path = &path[1..];
+    }
+    for p in path {
+        if cfg!(windows) {
+            let mut chars = p.chars();
+            match (chars.next(), chars.next(), chars.next()) {
+                (Some(drive_letter), Some(colon), None)
+                    if drive_letter.is_ascii_alphabetic() && colon == ':' =>
+                {
+                    path_buf.push(&[drive_letter, colon, '\\'].iter().collect::<String>());
+                }
+                _ => path_buf.push(p),
+            }
+        } else {
+            path_buf.push(p);
+        }
+    }
+    path_buf
+}
+
 pub fn parse_location_string(input: &str) -> [String; 5] {
     let mut args = input.split(':').map(|s| s.to_owned());
     [
@@ -677,15 +1432,76 @@ fn parse_query_value(query: Option<&str>, key: &str) -> Option<String> {
     }
 }
 
+// Parses the optional filters for references, shared by `/search` and
+// `/references`. `kind` is a comma-separated list of reference kinds and
+// `scope` is either `workspace` or `deps`.
+fn parse_ref_filter(query: Option<&str>) -> Result<RefFilter, String> {
+    let kinds = match parse_query_value(query, "kind=") {
+        Some(kinds) => Some(
+            kinds
+                .split(',')
+                .map(RefKind::from_str)
+                .collect::<Result<Vec<_>, _>>()?,
+        ),
+        None => None,
+    };
+    let workspace = match parse_query_value(query, "scope=") {
+        Some(ref s) if s == "workspace" => Some(true),
+        Some(ref s) if s == "deps" => Some(false),
+        Some(s) => return Err(format!("Bad scope: {}", s)),
+        None => None,
+    };
+
+    Ok(RefFilter {
+        kinds,
+        workspace,
+        crate_name: parse_query_value(query, "crate="),
+        enclosing_fn: parse_query_value(query, "fn="),
+    })
+}
+
+// Invalid globs are reported when the server starts and then ignored.
+fn exclude_globs(config: &Config) -> Vec<Glob> {
+    config
+        .exclude_globs
+        .iter()
+        .filter_map(|g| match Glob::new(g) {
+            Ok(glob) => Some(glob),
+            Err(msg) => {
+                println!("Ignoring bad exclude glob `{}`: {}", g, msg);
+                None
+            }
+        })
+        .collect()
+}
+
 const STATIC_REQUEST: &str = "static";
 const RAW_REQUEST: &str = "raw";
 const SOURCE_REQUEST: &str = "src";
 const TREE_REQUEST: &str = "tree";
+const OUTLINE_REQUEST: &str = "outline";
 const PLAIN_TEXT: &str = "plain_text";
 const CONFIG_REQUEST: &str = "config";
 const EDIT_REQUEST: &str = "edit";
 const SEARCH_REQUEST: &str = "search";
+const REFERENCES_REQUEST: &str = "references";
 const FIND_REQUEST: &str = "find";
+const GREP_REQUEST: &str = "grep";
 const GET_STATUS: &str = "status";
 const SYMBOL_ROOTS: &str = "symbol_roots";
+const CALLERS_REQUEST: &str = "callers";
+const CALLEES_REQUEST: &str = "callees";
+const TYPE_HIERARCHY: &str = "type_hierarchy";
+const CRATE_GRAPH: &str = "crate_graph";
+const MODULE_GRAPH: &str = "module_graph";
+const CRATE_TREE: &str = "crate_tree";
+const UNUSED_REQUEST: &str = "unused";
+const API_SURFACE: &str = "api_surface";
+const DOC_COVERAGE: &str = "doc_coverage";
+const DIFF_REQUEST: &str = "diff";
+const BLAME_REQUEST: &str = "blame";
+const DEPS_REQUEST: &str = "deps";
 const SYMBOL_CHILDREN: &str = "symbol_children";
+const SYMBOL_SEARCH: &str = "symbol_search";
+
+const DEFAULT_SYMBOL_SEARCH_LIMIT: usize = 50;
@@ -50,9 +50,11 @@ export class ContentPanel extends React.Component {
 
         const app = this.props.app;
         const self = this;
+        // Links into dependencies are to their own endpoint.
+        const url = path.startsWith('/deps/') ? path.substring(1) : 'src/' + path;
 
         request(
-            'src/' + path,
+            url,
             function(json) {
                 if (json.Directory) {
                     self.setState({ page: Page.SOURCE_DIR, params: { path: json.Directory.path, files: json.Directory.files }});
@@ -71,7 +73,7 @@ export class ContentPanel extends React.Component {
                     console.log(json);
                 }
             },
-            'Error with source request for ' + '/src' + path,
+            'Error with source request for ' + '/' + url,
             app
         );
     }
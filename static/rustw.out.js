@@ -49,5 +49,5 @@ object-assign
 Object.defineProperty(t,"__esModule",{value:!0});var n=null,r=!1,o=3,i=-1,a=-1,s=!1,u=!1;function l(){if(!s){var e=n.expirationTime;u?k():u=!0,x(p,e)}}function c(){var e=n,t=n.next;if(n===t)n=null;else{var r=n.previous;n=r.next=t,t.previous=r}e.next=e.previous=null,r=e.callback,t=e.expirationTime,e=e.priorityLevel;var i=o,s=a;o=e,a=t;try{var u=r()}finally{o=i,a=s}if("function"==typeof u)if(u={callback:u,priorityLevel:e,expirationTime:t,next:null,previous:null},null===n)n=u.next=u.previous=u;else{r=null,e=n;do{if(e.expirationTime>=t){r=e;break}e=e.next}while(e!==n);null===r?r=n:r===n&&(n=u,l()),(t=r.previous).next=r.previous=u,u.next=r,u.previous=t}}function f(){if(-1===i&&null!==n&&1===n.priorityLevel){s=!0;try{do{c()}while(null!==n&&1===n.priorityLevel)}finally{s=!1,null!==n?l():u=!1}}}function p(e){s=!0;var o=r;r=e;try{if(e)for(;null!==n;){var i=t.unstable_now();if(!(n.expirationTime<=i))break;do{c()}while(null!==n&&n.expirationTime<=i)}else if(null!==n)do{c()}while(null!==n&&!S())}finally{s=!1,r=o,null!==n?l():u=!1,f()}}var h,d,m=Date,g="function"==typeof setTimeout?setTimeout:void 0,v="function"==typeof clearTimeout?clearTimeout:void 0,y="function"==typeof requestAnimationFrame?requestAnimationFrame:void 0,b="function"==typeof cancelAnimationFrame?cancelAnimationFrame:void 0;function _(e){h=y(function(t){v(d),e(t)}),d=g(function(){b(h),e(t.unstable_now())},100)}if("object"==typeof performance&&"function"==typeof performance.now){var w=performance;t.unstable_now=function(){return w.now()}}else t.unstable_now=function(){return m.now()};var x,k,S,C=null;if("undefined"!=typeof window?C=window:void 0!==e&&(C=e),C&&C._schedMock){var E=C._schedMock;x=E[0],k=E[1],S=E[2],t.unstable_now=E[3]}else if("undefined"==typeof window||"function"!=typeof MessageChannel){var T=null,O=function(e){if(null!==T)try{T(e)}finally{T=null}};x=function(e){null!==T?setTimeout(x,0,e):(T=e,setTimeout(O,0,!1))},k=function(){T=null},S=function(){return!1}}else{"undefined"!=typeof console&&("function"!=typeof y&&console.error("This browser doesn't support requestAnimationFrame. Make sure that you load a polyfill in older browsers. https://fb.me/react-polyfills"),"function"!=typeof b&&console.error("This browser doesn't support cancelAnimationFrame. Make sure that you load a polyfill in older browsers. https://fb.me/react-polyfills"));var A=null,P=!1,j=-1,R=!1,N=!1,M=0,L=33,I=33;S=function(){return M<=t.unstable_now()};var D=new MessageChannel,F=D.port2;D.port1.onmessage=function(){P=!1;var e=A,n=j;A=null,j=-1;var r=t.unstable_now(),o=!1;if(0>=M-r){if(!(-1!==n&&n<=r))return R||(R=!0,_(U)),A=e,void(j=n);o=!0}if(null!==e){N=!0;try{e(o)}finally{N=!1}}};var U=function(e){if(null!==A){_(U);var t=e-M+I;t<I&&L<I?(8>t&&(t=8),I=t<L?L:t):L=t,M=e+I,P||(P=!0,F.postMessage(void 0))}else R=!1};x=function(e,t){A=e,j=t,N||0>t?F.postMessage(void 0):R||(R=!0,_(U))},k=function(){A=null,P=!1,j=-1}}t.unstable_ImmediatePriority=1,t.unstable_UserBlockingPriority=2,t.unstable_NormalPriority=3,t.unstable_IdlePriority=5,t.unstable_LowPriority=4,t.unstable_runWithPriority=function(e,n){switch(e){case 1:case 2:case 3:case 4:case 5:break;default:e=3}var r=o,a=i;o=e,i=t.unstable_now();try{return n()}finally{o=r,i=a,f()}},t.unstable_scheduleCallback=function(e,r){var a=-1!==i?i:t.unstable_now();if("object"==typeof r&&null!==r&&"number"==typeof r.timeout)r=a+r.timeout;else switch(o){case 1:r=a+-1;break;case 2:r=a+250;break;case 5:r=a+1073741823;break;case 4:r=a+1e4;break;default:r=a+5e3}if(e={callback:e,priorityLevel:o,expirationTime:r,next:null,previous:null},null===n)n=e.next=e.previous=e,l();else{a=null;var s=n;do{if(s.expirationTime>r){a=s;break}s=s.next}while(s!==n);null===a?a=n:a===n&&(n=e,l()),(r=a.previous).next=a.previous=e,e.next=a,e.previous=r}return e},t.unstable_cancelCallback=function(e){var t=e.next;if(null!==t){if(t===e)n=null;else{e===n&&(n=t);var r=e.previous;r.next=t,t.previous=r}e.next=e.previous=null}},t.unstable_wrapCallback=function(e){var n=o;return function(){var r=o,a=i;o=n,i=t.unstable_now();try{return e.apply(this,arguments)}finally{o=r,i=a,f()}}},t.unstable_getCurrentPriorityLevel=function(){return o},t.unstable_shouldYield=function(){return!r&&(null!==n&&n.expirationTime<a||S())},t.unstable_continueExecution=function(){null!==n&&l()},t.unstable_pauseExecution=function(){},t.unstable_getFirstCallbackNode=function(){return n}}).call(this,n(8))},function(e,t,n){"use strict";var r=n(175);function o(){}e.exports=function(){function e(e,t,n,o,i,a){if(a!==r){var s=new Error("Calling PropTypes validators directly is not supported by the `prop-types` package. Use PropTypes.checkPropTypes() to call them. Read more at http://fb.me/use-check-prop-types");throw s.name="Invariant Violation",s}}function t(){return e}e.isRequired=e;var n={array:e,bool:e,func:e,number:e,object:e,string:e,symbol:e,any:e,arrayOf:t,element:e,instanceOf:t,node:e,objectOf:t,oneOf:t,oneOfType:t,shape:t,exact:t};return n.checkPropTypes=o,n.PropTypes=n,n}},function(e,t,n){"use strict";e.exports="SECRET_DO_NOT_PASS_THIS_OR_YOU_WILL_BE_FIRED"},function(e,t){e.exports=Array.isArray||function(e){return"[object Array]"==Object.prototype.toString.call(e)}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.Sidebar=void 0;var r,o=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},i=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),a=n(1),s=(r=a)&&r.__esModule?r:{default:r},u=n(425),l=n(178),c=n(180),f=n(149);function p(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function h(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function d(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}t.Sidebar=function(e){function t(e){p(this,t);var n=h(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.state={symbols:null,tabIndex:0,searchTerm:""},n}return d(t,s.default.Component),i(t,[{key:"componentDidUpdate",value:function(e){if(this.props.search!=e.search){var t="";this.props.search.searchTerm&&(t=this.props.search.searchTerm),this.setState({tabIndex:0,searchTerm:t})}}},{key:"render",value:function(){var e=this,t={searchTerm:this.state.searchTerm,onKeyPress:function(t){13===t.which&&e.props.app.getSearch(t.currentTarget.value)},onChange:function(t){e.setState({searchTerm:t.target.value})}};return s.default.createElement("div",{className:"div_sidebar"},s.default.createElement(u.Tabs,{selectedIndex:this.state.tabIndex,className:"div_side_tabbar",selectedTabClassName:"selected",onSelect:function(t){return e.setState({tabIndex:t})}},s.default.createElement(u.TabList,{className:"div_sidebar_tabs"},s.default.createElement(u.Tab,{className:"div_sidebar_tab"},"search"),s.default.createElement(u.Tab,{className:"div_sidebar_tab"},"files"),s.default.createElement(u.Tab,{className:"div_sidebar_tab"},"symbols")),s.default.createElement(u.TabPanel,{className:"div_sidebar_main"},s.default.createElement(l.SearchPanel,o({app:this.props.app},this.props.search,{searchController:t}))),s.default.createElement(u.TabPanel,{className:"div_sidebar_main"},s.default.createElement(c.TreePanel,{app:this.props.app,tree:this.props.fileTreeData})),s.default.createElement(u.TabPanel,{className:"div_sidebar_main"},s.default.createElement(f.SymbolPanel,{app:this.props.app,symbols:this.props.symbols}))),s.default.createElement(m,{status:this.props.status}))}}]),t}();var m=function(e){function t(){return p(this,t),h(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return d(t,s.default.Component),i(t,[{key:"render",value:function(){var e="";return this.props.status&&(e=this.props.status),s.default.createElement("div",{id:"div_status_display"},"Status: ",e)}}]),t}()},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.SearchPanel=void 0;var r,o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),i=n(1),a=(r=i)&&r.__esModule?r:{default:r},s=n(179);t.SearchPanel=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,a.default.Component),o(t,[{key:"render",value:function(){var e=null;return this.props.defs||this.props.refs?e=a.default.createElement(s.SearchResults,{app:this.props.app,defs:this.props.defs,refs:this.props.refs}):this.props.results&&(e=a.default.createElement(s.FindResults,{app:this.props.app,results:this.props.results})),a.default.createElement("div",null,a.default.createElement(u,{app:this.props.app,searchController:this.props.searchController}),a.default.createElement("div",{id:"div_search_results"},e))}}]),t}();function u(e){return a.default.createElement("div",null,a.default.createElement("input",{id:"search_box",value:e.searchController.searchTerm,placeholder:"identifier search",autoComplete:"off",onKeyPress:e.searchController.onKeyPress,onChange:e.searchController.onChange}))}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});var r=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}();t.FindResults=function(e){return e.results?a.default.createElement("div",{className:"div_search_defs"},a.default.createElement("div",{className:"div_search_title"},"Search results"),a.default.createElement(f,{app:e.app,input:e.results,kind:"result"})):d()},t.SearchResults=function(e){if(e.defs){var t=-1,n=e.defs.map(function(n){return t+=1,a.default.createElement(h,{app:e.app,input:n,key:n.file+"-"+t})});return a.default.createElement("div",null,a.default.createElement("div",{className:"div_search_title"},"Search results"),n)}return d()};var o,i=n(1),a=(o=i)&&o.__esModule?o:{default:o},s=n(28);function u(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function l(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function c(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}var f=function(e){function t(){return u(this,t),l(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return c(t,a.default.Component),r(t,[{key:"componentDidMount",value:function(){this.postRender()}},{key:"componentDidUpdate",value:function(){this.postRender()}},{key:"postRender",value:function(){$(".div_search_results .src_link").removeClass("src_link"),m(this.props.input,this.props.kind)}},{key:"render",value:function(){var e=this.props,t=e.input,n=e.kind,r=this,o=-1,i=t.map(function(e){return o+=1,a.default.createElement(p,{lines:e.lines,file_name:e.file_name,app:r.props.app,kind:n,count:o,key:n+"-"+e.file_name})});return a.default.createElement("div",{className:"div_search_results"},i)}}]),t}(),p=function(e){function t(e){u(this,t);var n=l(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.state={peekContext:null},n}return c(t,a.default.Component),r(t,[{key:"render",value:function(){var e=this,t=this.props,n=t.lines,r=t.file_name,o=t.kind,i=t.count,s=t.app,u=this,l=n.map(function(t){var n="snippet_line_number_"+o+"_"+i+"_"+t.line_start,l="snippet_line_"+o+"_"+i+"_"+t.line_start,c=t.line,f=c.trimLeft(),p=(c.length-f.length)/4,h=c.length-f.length-p;f=f.padStart(f.length+p);var d=null;return e.state.peekContext&&(d=a.default.createElement(g,{line:t.line,preContext:e.state.peekContext.pre,postContext:e.state.peekContext.post})),a.default.createElement("div",{key:o+"-"+i+"-"+t.line_start},a.default.createElement("span",{className:"div_span_src_number"},a.default.createElement("div",{className:"span_src_number",id:n,onClick:function(e){var n={line_start:t.line_start,line_end:t.line_start,column_start:0,column_end:0};s.loadSource(r,n),e.preventDefault(),e.stopPropagation()}},t.line_start)),a.default.createElement("span",{className:"div_span_src"},a.default.createElement("div",{className:"span_src",id:l,onClick:function(e){var n={line_start:t.line_start,line_end:t.line_end,column_start:t.column_start,column_end:t.column_end};s.loadSource(r,n),e.preventDefault(),e.stopPropagation()},onMouseOver:function(e){u.setState({peekContext:{pre:t.pre_context,post:t.post_context}}),e.preventDefault(),e.stopPropagation()},onMouseOut:function(e){u.setState({peekContext:null}),e.preventDefault(),e.stopPropagation()},dangerouslySetInnerHTML:{__html:f},"data-adjust":h})),d,a.default.createElement("br",null))});return a.default.createElement("div",null,a.default.createElement("div",{className:"div_search_file_link",onClick:function(t){e.props.app.loadSource(r),t.preventDefault(),t.stopPropagation()}},r),a.default.createElement("div",{className:"div_all_span_src"},l))}}]),t}(),h=function(e){function t(){return u(this,t),l(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return c(t,a.default.Component),r(t,[{key:"componentDidMount",value:function(){this.postRender()}},{key:"componentDidUpdate",value:function(){this.postRender()}},{key:"postRender",value:function(){$(".div_search_group .src_link").removeClass("src_link"),m([{file_name:this.props.input.file,lines:[this.props.input.line]}],"def")}},{key:"render",value:function(){var e=a.default.createElement(p,{lines:[this.props.input.line],file_name:this.props.input.file,app:this.props.app,count:"0",kind:"def"}),t=a.default.createElement(f,{input:this.props.input.refs,app:this.props.app,kind:"ref"});return a.default.createElement("div",{className:"div_search_group"},e,a.default.createElement("div",{className:"div_search_title"},"References"),t)}}]),t}();function d(){return a.default.createElement("span",{className:"div_search_no_results"},"No results found")}function m(e,t){e.map(function(e,n){e.lines.map(function(e){e.line_end=e.line_start,(0,s.highlight_spans)(e,null,"snippet_line_"+t+"_"+n+"_","selected_search")})})}function g(e){var t=e.preContext+'\n<span class="search_context_highlight">'+e.line+"</span>\n"+e.postContext;return a.default.createElement("div",{className:"div_search_context_box",dangerouslySetInnerHTML:{__html:t}})}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.TreePanel=void 0;var r,o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),i=n(1),a=(r=i)&&r.__esModule?r:{default:r},s=n(106);t.TreePanel=function(e){function t(e){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);var n,r=function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return r.state={data:(n=r.props.tree.Directory,{name:n.path[n.path.length-1],toggled:!0,children:n.files.map(u),path:n.path.join("/")})},r.onToggle=r.onToggle.bind(r),r}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,a.default.Component),o(t,[{key:"onToggle",value:function(e,t){var n=this.state.cursor;n&&(n.active=!1),e.active=!0,e.children?e.toggled=t:this.props.app.loadSource(e.path),this.setState({cursor:e})}},{key:"render",value:function(){return a.default.createElement(s.Treebeard,{data:this.state.data,onToggle:this.onToggle,style:l})}}]),t}();function u(e){var t=null;return e.kind.DirectoryTree&&(t=e.kind.DirectoryTree.map(u)),{name:e.name,toggled:!1,children:t,path:e.path}}var l={tree:{base:{listStyle:"none",margin:0,padding:0},node:{container:{link:{cursor:"pointer",position:"relative",padding:"0px 5px",display:"block"},activeLink:{background:"#31363F"}},base:{position:"relative"},link:{cursor:"pointer",position:"relative",padding:"0px 5px",display:"block"},activeLink:{background:"#B1B6BF"},toggle:{base:{position:"relative",display:"inline-block",verticalAlign:"top",marginLeft:"-5px",height:"24px",width:"24px"},wrapper:{position:"absolute",top:"50%",left:"50%",margin:"-7px 0 0 -7px",height:"14px"},height:14,width:14,arrow:{strokeWidth:0}},header:{base:{display:"inline-block",verticalAlign:"top"},connector:{width:"2px",height:"12px",borderLeft:"solid 2px black",borderBottom:"solid 2px black",position:"absolute",top:"0px",left:"-21px"},title:{lineHeight:"24px",verticalAlign:"middle"}},subtree:{listStyle:"none",paddingLeft:"19px"},loading:{color:"#E2C089"}}}}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.theme=t.animations=t.decorators=t.Treebeard=void 0;var r=s(n(182)),o=s(n(146)),i=s(n(148)),a=s(n(147));function s(e){return e&&e.__esModule?e:{default:e}}t.Treebeard=r.default,t.decorators=o.default,t.animations=i.default,t.theme=a.default},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});var r=m(n(37)),o=m(n(42)),i=m(n(43)),a=m(n(44)),s=m(n(46)),u=m(n(1)),l=m(n(0)),c=m(n(104)),f=m(n(220)),p=m(n(146)),h=m(n(147)),d=m(n(148));function m(e){return e&&e.__esModule?e:{default:e}}var g=(0,c.default)("ul",{shouldForwardProp:function(e){return-1!==["className","children"].indexOf(e)}})(function(e){return e.style}),v=function(e){function t(){return(0,o.default)(this,t),(0,a.default)(this,(t.__proto__||(0,r.default)(t)).apply(this,arguments))}return(0,s.default)(t,e),(0,i.default)(t,[{key:"render",value:function(){var e=this,t=this.props,n=t.animations,r=t.decorators,o=t.data,i=t.onToggle,a=t.style,s=o;return Array.isArray(s)||(s=[s]),u.default.createElement(g,{style:a.tree.base,ref:function(t){return e.treeBaseRef=t}},s.map(function(e,t){return u.default.createElement(f.default,{animations:n,decorators:r,key:e.id||t,node:e,onToggle:i,style:a.tree.node})}))}}]),t}(u.default.Component);v.propTypes={style:l.default.object,data:l.default.oneOfType([l.default.object,l.default.array]).isRequired,animations:l.default.oneOfType([l.default.object,l.default.bool]),onToggle:l.default.func,decorators:l.default.object},v.defaultProps={style:h.default,animations:d.default,decorators:p.default},t.default=v},function(e,t,n){n(184),e.exports=n(6).Object.getPrototypeOf},function(e,t,n){var r=n(38),o=n(107);n(108)("getPrototypeOf",function(){return function(e){return o(r(e))}})},function(e,t){e.exports=function(e){if("function"!=typeof e)throw TypeError(e+" is not a function!");return e}},function(e,t,n){e.exports={default:n(187),__esModule:!0}},function(e,t,n){n(188);var r=n(6).Object;e.exports=function(e,t,n){return r.defineProperty(e,t,n)}},function(e,t,n){var r=n(14);r(r.S+r.F*!n(16),"Object",{defineProperty:n(15).f})},function(e,t,n){e.exports={default:n(190),__esModule:!0}},function(e,t,n){n(191),n(199),e.exports=n(67).f("iterator")},function(e,t,n){"use strict";var r=n(192)(!0);n(113)(String,"String",function(e){this._t=String(e),this._i=0},function(){var e,t=this._t,n=this._i;return n>=t.length?{value:void 0,done:!0}:(e=r(t,n),this._i+=e.length,{value:e,done:!1})})},function(e,t,n){var r=n(62),o=n(58);e.exports=function(e){return function(t,n){var i,a,s=String(o(t)),u=r(n),l=s.length;return u<0||u>=l?e?"":void 0:(i=s.charCodeAt(u))<55296||i>56319||u+1===l||(a=s.charCodeAt(u+1))<56320||a>57343?e?s.charAt(u):i:e?s.slice(u,u+2):a-56320+(i-55296<<10)+65536}}},function(e,t,n){"use strict";var r=n(64),o=n(41),i=n(66),a={};n(19)(a,n(23)("iterator"),function(){return this}),e.exports=function(e,t,n){e.prototype=r(a,{next:o(1,n)}),i(e,t+" Iterator")}},function(e,t,n){var r=n(15),o=n(29),i=n(30);e.exports=n(16)?Object.defineProperties:function(e,t){o(e);for(var n,a=i(t),s=a.length,u=0;s>u;)r.f(e,n=a[u++],t[n]);return e}},function(e,t,n){var r=n(22),o=n(196),i=n(197);e.exports=function(e){return function(t,n,a){var s,u=r(t),l=o(u.length),c=i(a,l);if(e&&n!=n){for(;l>c;)if((s=u[c++])!=s)return!0}else for(;l>c;c++)if((e||c in u)&&u[c]===n)return e||c||0;return!e&&-1}}},function(e,t,n){var r=n(62),o=Math.min;e.exports=function(e){return e>0?o(r(e),9007199254740991):0}},function(e,t,n){var r=n(62),o=Math.max,i=Math.min;e.exports=function(e,t){return(e=r(e))<0?o(e+t,0):i(e,t)}},function(e,t,n){var r=n(9).document;e.exports=r&&r.documentElement},function(e,t,n){n(200);for(var r=n(9),o=n(19),i=n(63),a=n(23)("toStringTag"),s="CSSRuleList,CSSStyleDeclaration,CSSValueList,ClientRectList,DOMRectList,DOMStringList,DOMTokenList,DataTransferItemList,FileList,HTMLAllCollection,HTMLCollection,HTMLFormElement,HTMLSelectElement,MediaList,MimeTypeArray,NamedNodeMap,NodeList,PaintRequestList,Plugin,PluginArray,SVGLengthList,SVGNumberList,SVGPathSegList,SVGPointList,SVGStringList,SVGTransformList,SourceBufferList,StyleSheetList,TextTrackCueList,TextTrackList,TouchList".split(","),u=0;u<s.length;u++){var l=s[u],c=r[l],f=c&&c.prototype;f&&!f[a]&&o(f,a,l),i[l]=i.Array}},function(e,t,n){"use strict";var r=n(201),o=n(202),i=n(63),a=n(22);e.exports=n(113)(Array,"Array",function(e,t){this._t=a(e),this._i=0,this._k=t},function(){var e=this._t,t=this._k,n=this._i++;return!e||n>=e.length?(this._t=void 0,o(1)):o(0,"keys"==t?n:"values"==t?e[n]:[n,e[n]])},"values"),i.Arguments=i.Array,r("keys"),r("values"),r("entries")},function(e,t){e.exports=function(){}},function(e,t){e.exports=function(e,t){return{value:t,done:!!e}}},function(e,t,n){e.exports={default:n(204),__esModule:!0}},function(e,t,n){n(205),n(210),n(211),n(212),e.exports=n(6).Symbol},function(e,t,n){"use strict";var r=n(9),o=n(13),i=n(16),a=n(14),s=n(114),u=n(206).KEY,l=n(21),c=n(60),f=n(66),p=n(40),h=n(23),d=n(67),m=n(68),g=n(207),v=n(208),y=n(29),b=n(20),_=n(22),w=n(61),x=n(41),k=n(64),S=n(209),C=n(119),E=n(15),T=n(30),O=C.f,A=E.f,P=S.f,j=r.Symbol,R=r.JSON,N=R&&R.stringify,M=h("_hidden"),L=h("toPrimitive"),I={}.propertyIsEnumerable,D=c("symbol-registry"),F=c("symbols"),U=c("op-symbols"),q=Object.prototype,B="function"==typeof j,V=r.QObject,z=!V||!V.prototype||!V.prototype.findChild,H=i&&l(function(){return 7!=k(A({},"a",{get:function(){return A(this,"a",{value:7}).a}})).a})?function(e,t,n){var r=O(q,t);r&&delete q[t],A(e,t,n),r&&e!==q&&A(q,t,r)}:A,W=function(e){var t=F[e]=k(j.prototype);return t._k=e,t},$=B&&"symbol"==typeof j.iterator?function(e){return"symbol"==typeof e}:function(e){return e instanceof j},G=function(e,t,n){return e===q&&G(U,t,n),y(e),t=w(t,!0),y(n),o(F,t)?(n.enumerable?(o(e,M)&&e[M][t]&&(e[M][t]=!1),n=k(n,{enumerable:x(0,!1)})):(o(e,M)||A(e,M,x(1,{})),e[M][t]=!0),H(e,t,n)):A(e,t,n)},Y=function(e,t){y(e);for(var n,r=g(t=_(t)),o=0,i=r.length;i>o;)G(e,n=r[o++],t[n]);return e},Q=function(e){var t=I.call(this,e=w(e,!0));return!(this===q&&o(F,e)&&!o(U,e))&&(!(t||!o(this,e)||!o(F,e)||o(this,M)&&this[M][e])||t)},X=function(e,t){if(e=_(e),t=w(t,!0),e!==q||!o(F,t)||o(U,t)){var n=O(e,t);return!n||!o(F,t)||o(e,M)&&e[M][t]||(n.enumerable=!0),n}},K=function(e){for(var t,n=P(_(e)),r=[],i=0;n.length>i;)o(F,t=n[i++])||t==M||t==u||r.push(t);return r},J=function(e){for(var t,n=e===q,r=P(n?U:_(e)),i=[],a=0;r.length>a;)!o(F,t=r[a++])||n&&!o(q,t)||i.push(F[t]);return i};B||(s((j=function(){if(this instanceof j)throw TypeError("Symbol is not a constructor!");var e=p(arguments.length>0?arguments[0]:void 0),t=function(n){this===q&&t.call(U,n),o(this,M)&&o(this[M],e)&&(this[M][e]=!1),H(this,e,x(1,n))};return i&&z&&H(q,e,{configurable:!0,set:t}),W(e)}).prototype,"toString",function(){return this._k}),C.f=X,E.f=G,n(118).f=S.f=K,n(45).f=Q,n(69).f=J,i&&!n(39)&&s(q,"propertyIsEnumerable",Q,!0),d.f=function(e){return W(h(e))}),a(a.G+a.W+a.F*!B,{Symbol:j});for(var Z="hasInstance,isConcatSpreadable,iterator,match,replace,search,species,split,toPrimitive,toStringTag,unscopables".split(","),ee=0;Z.length>ee;)h(Z[ee++]);for(var te=T(h.store),ne=0;te.length>ne;)m(te[ne++]);a(a.S+a.F*!B,"Symbol",{for:function(e){return o(D,e+="")?D[e]:D[e]=j(e)},keyFor:function(e){if(!$(e))throw TypeError(e+" is not a symbol!");for(var t in D)if(D[t]===e)return t},useSetter:function(){z=!0},useSimple:function(){z=!1}}),a(a.S+a.F*!B,"Object",{create:function(e,t){return void 0===t?k(e):Y(k(e),t)},defineProperty:G,defineProperties:Y,getOwnPropertyDescriptor:X,getOwnPropertyNames:K,getOwnPropertySymbols:J}),R&&a(a.S+a.F*(!B||l(function(){var e=j();return"[null]"!=N([e])||"{}"!=N({a:e})||"{}"!=N(Object(e))})),"JSON",{stringify:function(e){for(var t,n,r=[e],o=1;arguments.length>o;)r.push(arguments[o++]);if(n=t=r[1],(b(t)||void 0!==e)&&!$(e))return v(t)||(t=function(e,t){if("function"==typeof n&&(t=n.call(this,e,t)),!$(t))return t}),r[1]=t,N.apply(R,r)}}),j.prototype[L]||n(19)(j.prototype,L,j.prototype.valueOf),f(j,"Symbol"),f(Math,"Math",!0),f(r.JSON,"JSON",!0)},function(e,t,n){var r=n(40)("meta"),o=n(20),i=n(13),a=n(15).f,s=0,u=Object.isExtensible||function(){return!0},l=!n(21)(function(){return u(Object.preventExtensions({}))}),c=function(e){a(e,r,{value:{i:"O"+ ++s,w:{}}})},f=e.exports={KEY:r,NEED:!1,fastKey:function(e,t){if(!o(e))return"symbol"==typeof e?e:("string"==typeof e?"S":"P")+e;if(!i(e,r)){if(!u(e))return"F";if(!t)return"E";c(e)}return e[r].i},getWeak:function(e,t){if(!i(e,r)){if(!u(e))return!0;if(!t)return!1;c(e)}return e[r].w},onFreeze:function(e){return l&&f.NEED&&u(e)&&!i(e,r)&&c(e),e}}},function(e,t,n){var r=n(30),o=n(69),i=n(45);e.exports=function(e){var t=r(e),n=o.f;if(n)for(var a,s=n(e),u=i.f,l=0;s.length>l;)u.call(e,a=s[l++])&&t.push(a);return t}},function(e,t,n){var r=n(117);e.exports=Array.isArray||function(e){return"Array"==r(e)}},function(e,t,n){var r=n(22),o=n(118).f,i={}.toString,a="object"==typeof window&&window&&Object.getOwnPropertyNames?Object.getOwnPropertyNames(window):[];e.exports.f=function(e){return a&&"[object Window]"==i.call(e)?function(e){try{return o(e)}catch(e){return a.slice()}}(e):o(r(e))}},function(e,t){},function(e,t,n){n(68)("asyncIterator")},function(e,t,n){n(68)("observable")},function(e,t,n){e.exports={default:n(214),__esModule:!0}},function(e,t,n){n(215),e.exports=n(6).Object.setPrototypeOf},function(e,t,n){var r=n(14);r(r.S,"Object",{setPrototypeOf:n(216).set})},function(e,t,n){var r=n(20),o=n(29),i=function(e,t){if(o(e),!r(t)&&null!==t)throw TypeError(t+": can't set as prototype!")};e.exports={set:Object.setPrototypeOf||("__proto__"in{}?function(e,t,r){try{(r=n(109)(Function.call,n(119).f(Object.prototype,"__proto__").set,2))(e,[]),t=!(e instanceof Array)}catch(e){t=!0}return function(e,n){return i(e,n),t?e.__proto__=n:r(e,n),e}}({},!1):void 0),check:i}},function(e,t,n){e.exports={default:n(218),__esModule:!0}},function(e,t,n){n(219);var r=n(6).Object;e.exports=function(e,t){return r.create(e,t)}},function(e,t,n){var r=n(14);r(r.S,"Object",{create:n(64)})},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});var r=g(n(221)),o=g(n(225)),i=g(n(70)),a=g(n(37)),s=g(n(42)),u=g(n(43)),l=g(n(44)),c=g(n(46)),f=g(n(1)),p=g(n(0)),h=n(120),d=g(n(104)),m=g(n(348));function g(e){return e&&e.__esModule?e:{default:e}}var v=(0,d.default)("li",{shouldForwardProp:function(e){return-1!==["className","children","ref"].indexOf(e)}})(function(e){return e.style}),y=(0,d.default)("ul",{shouldForwardProp:function(e){return-1!==["className","children","ref"].indexOf(e)}})(function(e){return e.style}),b=function(e){function t(){(0,s.default)(this,t);var e=(0,l.default)(this,(t.__proto__||(0,a.default)(t)).call(this));return e.onClick=e.onClick.bind(e),e}return(0,c.default)(t,e),(0,u.default)(t,[{key:"onClick",value:function(){var e=this.props,t=e.node,n=e.onToggle,r=t.toggled;n&&n(t,!r)}},{key:"animations",value:function(){var e=this.props,t=e.animations,n=e.node;if(!1===t)return!1;var r=(0,i.default)({},t,n.animations);return{toggle:r.toggle(this.props),drawer:r.drawer(this.props)}}},{key:"decorators",value:function(){var e=this.props,t=e.decorators,n=e.node.decorators||{};return(0,i.default)({},t,n)}},{key:"render",value:function(){var e=this,t=this.props.style,n=this.decorators(),r=this.animations();return f.default.createElement(v,{innerRef:function(t){return e.topLevelRef=t},style:t.base},this.renderHeader(n,r),this.renderDrawer(n,r))}},{key:"renderDrawer",value:function(e,t){var n=this,i=this.props.node.toggled;if(!t&&!i)return null;if(!t&&i)return this.renderChildren(e,t);var a=t.drawer,s=(a.animation,a.duration,(0,o.default)(a,["animation","duration"]));return f.default.createElement(h.VelocityTransitionGroup,(0,r.default)({},s,{ref:function(e){return n.velocityRef=e}}),i?this.renderChildren(e,t):null)}},{key:"renderHeader",value:function(e,t){var n=this.props,r=n.node,o=n.style;return f.default.createElement(m.default,{animations:t,decorators:e,node:(0,i.default)({},r),onClick:this.onClick,style:o})}},{key:"renderChildren",value:function(e){var n=this,o=this.props,i=o.animations,a=o.decorators,s=o.node,u=o.style;if(s.loading)return this.renderLoading(e);var l=s.children;return Array.isArray(l)||(l=l?[l]:[]),f.default.createElement(y,{style:u.subtree,ref:function(e){return n.subtreeRef=e}},l.map(function(e,o){return f.default.createElement(t,(0,r.default)({},n._eventBubbles(),{animations:i,decorators:a,key:e.id||o,node:e,style:u}))}))}},{key:"renderLoading",value:function(e){var t=this.props.style;return f.default.createElement(y,{style:t.subtree},f.default.createElement("li",null,f.default.createElement(e.Loading,{style:t.loading})))}},{key:"_eventBubbles",value:function(){return{onToggle:this.props.onToggle}}}]),t}(f.default.Component);b.propTypes={style:p.default.object.isRequired,node:p.default.object.isRequired,decorators:p.default.object.isRequired,animations:p.default.oneOfType([p.default.object,p.default.bool]).isRequired,onToggle:p.default.func},t.default=b},function(e,t,n){"use strict";t.__esModule=!0;var r,o=n(70),i=(r=o)&&r.__esModule?r:{default:r};t.default=i.default||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}},function(e,t,n){n(223),e.exports=n(6).Object.assign},function(e,t,n){var r=n(14);r(r.S+r.F,"Object",{assign:n(224)})},function(e,t,n){"use strict";var r=n(30),o=n(69),i=n(45),a=n(38),s=n(116),u=Object.assign;e.exports=!u||n(21)(function(){var e={},t={},n=Symbol(),r="abcdefghijklmnopqrst";return e[n]=7,r.split("").forEach(function(e){t[e]=e}),7!=u({},e)[n]||Object.keys(u({},t)).join("")!=r})?function(e,t){for(var n=a(e),u=arguments.length,l=1,c=o.f,f=i.f;u>l;)for(var p,h=s(arguments[l++]),d=c?r(h).concat(c(h)):r(h),m=d.length,g=0;m>g;)f.call(h,p=d[g++])&&(n[p]=h[p]);return n}:u},function(e,t,n){"use strict";t.__esModule=!0,t.default=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}},function(e,t,n){"use strict";var r=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}();var o={forEach:n(71),isEqual:n(128),keys:n(17),omit:n(135)},i=n(1),a=n(36),s=n(0),u=n(92),l=function(e){function t(e){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);var n=function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n._animationTimeout=null,n.runAnimation=n.runAnimation.bind(n),n}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,i.Component),r(t,[{key:"componentDidMount",value:function(){this.runAnimation(),!0!==this.props.runOnMount&&this._finishAnimation()}},{key:"componentDidUpdate",value:function(e){o.isEqual(e.animation,this.props.animation)||("stop"===this.props.interruptBehavior?this._stopAnimation():"finish"===this.props.interruptBehavior&&this._finishAnimation(),this._scheduleAnimation())}},{key:"componentWillUnmount",value:function(){this._stopAnimation(),this._clearVelocityCache(this._getDOMTarget()),this._animationTimeout&&clearTimeout(this._animationTimeout)}},{key:"runAnimation",value:function(e){if(e=e||{},this._animationTimeout=null,null!=this.props.animation){e.stop?u(this._getDOMTarget(),"stop",!0):e.finish&&u(this._getDOMTarget(),"finishAll",!0);var n=o.omit(this.props,o.keys(t.propTypes));u(this._getDOMTarget(),this.props.animation,n)}}},{key:"_scheduleAnimation",value:function(){this._animationTimeout||(this._animationTimeout=setTimeout(this.runAnimation,0))}},{key:"_getDOMTarget",value:function(){var e=a.findDOMNode(this);return"children"===this.props.targetQuerySelector?e.children:null!=this.props.targetQuerySelector?e.querySelectorAll(this.props.targetQuerySelector):e}},{key:"_finishAnimation",value:function(){u(this._getDOMTarget(),"finishAll",!0)}},{key:"_stopAnimation",value:function(){u(this._getDOMTarget(),"stop",!0)}},{key:"_clearVelocityCache",value:function(e){e.length?o.forEach(e,this._clearVelocityCache):u.Utilities.removeData(e,["velocity","fxqueue"])}},{key:"render",value:function(){return this.props.children}}]),t}();l.propTypes={animation:s.any,children:s.element.isRequired,runOnMount:s.bool,targetQuerySelector:s.string,interruptBehavior:s.string},l.defaultProps={animation:null,runOnMount:!1,targetQuerySelector:null,interruptBehavior:"stop"},e.exports=l},function(e,t,n){var r=n(228),o=n(17);e.exports=function(e,t){return e&&r(e,t,o)}},function(e,t,n){var r=n(229)();e.exports=r},function(e,t){e.exports=function(e){return function(t,n,r){for(var o=-1,i=Object(t),a=r(t),s=a.length;s--;){var u=a[e?s:++o];if(!1===n(i[u],u,i))break}return t}}},function(e,t){e.exports=function(e,t){for(var n=-1,r=Array(e);++n<e;)r[n]=t(n);return r}},function(e,t,n){var r=n(24),o=n(10),i="[object Arguments]";e.exports=function(e){return o(e)&&r(e)==i}},function(e,t,n){var r=n(25),o=Object.prototype,i=o.hasOwnProperty,a=o.toString,s=r?r.toStringTag:void 0;e.exports=function(e){var t=i.call(e,s),n=e[s];try{e[s]=void 0;var r=!0}catch(e){}var o=a.call(e);return r&&(t?e[s]=n:delete e[s]),o}},function(e,t){var n=Object.prototype.toString;e.exports=function(e){return n.call(e)}},function(e,t){e.exports=function(){return!1}},function(e,t,n){var r=n(24),o=n(75),i=n(10),a={};a["[object Float32Array]"]=a["[object Float64Array]"]=a["[object Int8Array]"]=a["[object Int16Array]"]=a["[object Int32Array]"]=a["[object Uint8Array]"]=a["[object Uint8ClampedArray]"]=a["[object Uint16Array]"]=a["[object Uint32Array]"]=!0,a["[object Arguments]"]=a["[object Array]"]=a["[object ArrayBuffer]"]=a["[object Boolean]"]=a["[object DataView]"]=a["[object Date]"]=a["[object Error]"]=a["[object Function]"]=a["[object Map]"]=a["[object Number]"]=a["[object Object]"]=a["[object RegExp]"]=a["[object Set]"]=a["[object String]"]=a["[object WeakMap]"]=!1,e.exports=function(e){return i(e)&&o(e.length)&&!!a[r(e)]}},function(e,t,n){var r=n(78),o=n(237),i=Object.prototype.hasOwnProperty;e.exports=function(e){if(!r(e))return o(e);var t=[];for(var n in Object(e))i.call(e,n)&&"constructor"!=n&&t.push(n);return t}},function(e,t,n){var r=n(126)(Object.keys,Object);e.exports=r},function(e,t,n){var r=n(31);e.exports=function(e,t){return function(n,o){if(null==n)return n;if(!r(n))return e(n,o);for(var i=n.length,a=t?i:-1,s=Object(n);(t?a--:++a<i)&&!1!==o(s[a],a,s););return n}}},function(e,t,n){var r=n(47);e.exports=function(e){return"function"==typeof e?e:r}},function(e,t,n){var r=n(80),o=n(130),i=n(272),a=n(275),s=n(53),u=n(5),l=n(73),c=n(125),f=1,p="[object Arguments]",h="[object Array]",d="[object Object]",m=Object.prototype.hasOwnProperty;e.exports=function(e,t,n,g,v,y){var b=u(e),_=u(t),w=b?h:s(e),x=_?h:s(t),k=(w=w==p?d:w)==d,S=(x=x==p?d:x)==d,C=w==x;if(C&&l(e)){if(!l(t))return!1;b=!0,k=!1}if(C&&!k)return y||(y=new r),b||c(e)?o(e,t,n,g,v,y):i(e,t,w,n,g,v,y);if(!(n&f)){var E=k&&m.call(e,"__wrapped__"),T=S&&m.call(t,"__wrapped__");if(E||T){var O=E?e.value():e,A=T?t.value():t;return y||(y=new r),v(O,A,n,g,y)}}return!!C&&(y||(y=new r),a(e,t,n,g,v,y))}},function(e,t){e.exports=function(){this.__data__=[],this.size=0}},function(e,t,n){var r=n(49),o=Array.prototype.splice;e.exports=function(e){var t=this.__data__,n=r(t,e);return!(n<0||(n==t.length-1?t.pop():o.call(t,n,1),--this.size,0))}},function(e,t,n){var r=n(49);e.exports=function(e){var t=this.__data__,n=r(t,e);return n<0?void 0:t[n][1]}},function(e,t,n){var r=n(49);e.exports=function(e){return r(this.__data__,e)>-1}},function(e,t,n){var r=n(49);e.exports=function(e,t){var n=this.__data__,o=r(n,e);return o<0?(++this.size,n.push([e,t])):n[o][1]=t,this}},function(e,t,n){var r=n(48);e.exports=function(){this.__data__=new r,this.size=0}},function(e,t){e.exports=function(e){var t=this.__data__,n=t.delete(e);return this.size=t.size,n}},function(e,t){e.exports=function(e){return this.__data__.get(e)}},function(e,t){e.exports=function(e){return this.__data__.has(e)}},function(e,t,n){var r=n(48),o=n(81),i=n(82),a=200;e.exports=function(e,t){var n=this.__data__;if(n instanceof r){var s=n.__data__;if(!o||s.length<a-1)return s.push([e,t]),this.size=++n.size,this;n=this.__data__=new i(s)}return n.set(e,t),this.size=n.size,this}},function(e,t,n){var r=n(127),o=n(252),i=n(11),a=n(129),s=/^\[object .+?Constructor\]$/,u=Function.prototype,l=Object.prototype,c=u.toString,f=l.hasOwnProperty,p=RegExp("^"+c.call(f).replace(/[\\^$.*+?()[\]{}|]/g,"\\$&").replace(/hasOwnProperty|(function).*?(?=\\\()| for .+?(?=\\\])/g,"$1.*?")+"$");e.exports=function(e){return!(!i(e)||o(e))&&(r(e)?p:s).test(a(e))}},function(e,t,n){var r,o=n(253),i=(r=/[^.]+$/.exec(o&&o.keys&&o.keys.IE_PROTO||""))?"Symbol(src)_1."+r:"";e.exports=function(e){return!!i&&i in e}},function(e,t,n){var r=n(7)["__core-js_shared__"];e.exports=r},function(e,t){e.exports=function(e,t){return null==e?void 0:e[t]}},function(e,t,n){var r=n(256),o=n(48),i=n(81);e.exports=function(){this.size=0,this.__data__={hash:new r,map:new(i||o),string:new r}}},function(e,t,n){var r=n(257),o=n(258),i=n(259),a=n(260),s=n(261);function u(e){var t=-1,n=null==e?0:e.length;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}u.prototype.clear=r,u.prototype.delete=o,u.prototype.get=i,u.prototype.has=a,u.prototype.set=s,e.exports=u},function(e,t,n){var r=n(51);e.exports=function(){this.__data__=r?r(null):{},this.size=0}},function(e,t){e.exports=function(e){var t=this.has(e)&&delete this.__data__[e];return this.size-=t?1:0,t}},function(e,t,n){var r=n(51),o="__lodash_hash_undefined__",i=Object.prototype.hasOwnProperty;e.exports=function(e){var t=this.__data__;if(r){var n=t[e];return n===o?void 0:n}return i.call(t,e)?t[e]:void 0}},function(e,t,n){var r=n(51),o=Object.prototype.hasOwnProperty;e.exports=function(e){var t=this.__data__;return r?void 0!==t[e]:o.call(t,e)}},function(e,t,n){var r=n(51),o="__lodash_hash_undefined__";e.exports=function(e,t){var n=this.__data__;return this.size+=this.has(e)?0:1,n[e]=r&&void 0===t?o:t,this}},function(e,t,n){var r=n(52);e.exports=function(e){var t=r(this,e).delete(e);return this.size-=t?1:0,t}},function(e,t){e.exports=function(e){var t=typeof e;return"string"==t||"number"==t||"symbol"==t||"boolean"==t?"__proto__"!==e:null===e}},function(e,t,n){var r=n(52);e.exports=function(e){return r(this,e).get(e)}},function(e,t,n){var r=n(52);e.exports=function(e){return r(this,e).has(e)}},function(e,t,n){var r=n(52);e.exports=function(e,t){var n=r(this,e),o=n.size;return n.set(e,t),this.size+=n.size==o?0:1,this}},function(e,t,n){var r=n(82),o=n(268),i=n(269);function a(e){var t=-1,n=null==e?0:e.length;for(this.__data__=new r;++t<n;)this.add(e[t])}a.prototype.add=a.prototype.push=o,a.prototype.has=i,e.exports=a},function(e,t){var n="__lodash_hash_undefined__";e.exports=function(e){return this.__data__.set(e,n),this}},function(e,t){e.exports=function(e){return this.__data__.has(e)}},function(e,t){e.exports=function(e,t){for(var n=-1,r=null==e?0:e.length;++n<r;)if(t(e[n],n,e))return!0;return!1}},function(e,t){e.exports=function(e,t){return e.has(t)}},function(e,t,n){var r=n(25),o=n(131),i=n(50),a=n(130),s=n(273),u=n(274),l=1,c=2,f="[object Boolean]",p="[object Date]",h="[object Error]",d="[object Map]",m="[object Number]",g="[object RegExp]",v="[object Set]",y="[object String]",b="[object Symbol]",_="[object ArrayBuffer]",w="[object DataView]",x=r?r.prototype:void 0,k=x?x.valueOf:void 0;e.exports=function(e,t,n,r,x,S,C){switch(n){case w:if(e.byteLength!=t.byteLength||e.byteOffset!=t.byteOffset)return!1;e=e.buffer,t=t.buffer;case _:return!(e.byteLength!=t.byteLength||!S(new o(e),new o(t)));case f:case p:case m:return i(+e,+t);case h:return e.name==t.name&&e.message==t.message;case g:case y:return e==t+"";case d:var E=s;case v:var T=r&l;if(E||(E=u),e.size!=t.size&&!T)return!1;var O=C.get(e);if(O)return O==t;r|=c,C.set(e,t);var A=a(E(e),E(t),r,x,S,C);return C.delete(e),A;case b:if(k)return k.call(e)==k.call(t)}return!1}},function(e,t){e.exports=function(e){var t=-1,n=Array(e.size);return e.forEach(function(e,r){n[++t]=[r,e]}),n}},function(e,t){e.exports=function(e){var t=-1,n=Array(e.size);return e.forEach(function(e){n[++t]=e}),n}},function(e,t,n){var r=n(132),o=1,i=Object.prototype.hasOwnProperty;e.exports=function(e,t,n,a,s,u){var l=n&o,c=r(e),f=c.length;if(f!=r(t).length&&!l)return!1;for(var p=f;p--;){var h=c[p];if(!(l?h in t:i.call(t,h)))return!1}var d=u.get(e);if(d&&u.get(t))return d==t;var m=!0;u.set(e,t),u.set(t,e);for(var g=l;++p<f;){var v=e[h=c[p]],y=t[h];if(a)var b=l?a(y,v,h,t,e,u):a(v,y,h,e,t,u);if(!(void 0===b?v===y||s(v,y,n,a,u):b)){m=!1;break}g||(g="constructor"==h)}if(m&&!g){var _=e.constructor,w=t.constructor;_!=w&&"constructor"in e&&"constructor"in t&&!("function"==typeof _&&_ instanceof _&&"function"==typeof w&&w instanceof w)&&(m=!1)}return u.delete(e),u.delete(t),m}},function(e,t){e.exports=function(e,t){for(var n=-1,r=null==e?0:e.length,o=0,i=[];++n<r;){var a=e[n];t(a,n,e)&&(i[o++]=a)}return i}},function(e,t,n){var r=n(18)(n(7),"DataView");e.exports=r},function(e,t,n){var r=n(18)(n(7),"Promise");e.exports=r},function(e,t,n){var r=n(18)(n(7),"Set");e.exports=r},function(e,t,n){var r=n(18)(n(7),"WeakMap");e.exports=r},function(e,t,n){var r=n(80),o=n(121),i=n(136),a=n(282),s=n(283),u=n(286),l=n(287),c=n(288),f=n(289),p=n(132),h=n(140),d=n(53),m=n(290),g=n(291),v=n(296),y=n(5),b=n(73),_=n(298),w=n(11),x=n(300),k=n(17),S=1,C=2,E=4,T="[object Arguments]",O="[object Function]",A="[object GeneratorFunction]",P="[object Object]",j={};j[T]=j["[object Array]"]=j["[object ArrayBuffer]"]=j["[object DataView]"]=j["[object Boolean]"]=j["[object Date]"]=j["[object Float32Array]"]=j["[object Float64Array]"]=j["[object Int8Array]"]=j["[object Int16Array]"]=j["[object Int32Array]"]=j["[object Map]"]=j["[object Number]"]=j[P]=j["[object RegExp]"]=j["[object Set]"]=j["[object String]"]=j["[object Symbol]"]=j["[object Uint8Array]"]=j["[object Uint8ClampedArray]"]=j["[object Uint16Array]"]=j["[object Uint32Array]"]=!0,j["[object Error]"]=j[O]=j["[object WeakMap]"]=!1,e.exports=function e(t,n,R,N,M,L){var I,D=n&S,F=n&C,U=n&E;if(R&&(I=M?R(t,N,M,L):R(t)),void 0!==I)return I;if(!w(t))return t;var q=y(t);if(q){if(I=m(t),!D)return l(t,I)}else{var B=d(t),V=B==O||B==A;if(b(t))return u(t,D);if(B==P||B==T||V&&!M){if(I=F||V?{}:v(t),!D)return F?f(t,s(I,t)):c(t,a(I,t))}else{if(!j[B])return M?t:{};I=g(t,B,D)}}L||(L=new r);var z=L.get(t);if(z)return z;L.set(t,I),x(t)?t.forEach(function(r){I.add(e(r,n,R,r,t,L))}):_(t)&&t.forEach(function(r,o){I.set(o,e(r,n,R,o,t,L))});var H=U?F?h:p:F?keysIn:k,W=q?void 0:H(t);return o(W||t,function(r,o){W&&(r=t[o=r]),i(I,o,e(r,n,R,o,t,L))}),I}},function(e,t,n){var r=n(27),o=n(17);e.exports=function(e,t){return e&&r(t,o(t),e)}},function(e,t,n){var r=n(27),o=n(86);e.exports=function(e,t){return e&&r(t,o(t),e)}},function(e,t,n){var r=n(11),o=n(78),i=n(285),a=Object.prototype.hasOwnProperty;e.exports=function(e){if(!r(e))return i(e);var t=o(e),n=[];for(var s in e)("constructor"!=s||!t&&a.call(e,s))&&n.push(s);return n}},function(e,t){e.exports=function(e){var t=[];if(null!=e)for(var n in Object(e))t.push(n);return t}},function(e,t,n){(function(e){var r=n(7),o=t&&!t.nodeType&&t,i=o&&"object"==typeof e&&e&&!e.nodeType&&e,a=i&&i.exports===o?r.Buffer:void 0,s=a?a.allocUnsafe:void 0;e.exports=function(e,t){if(t)return e.slice();var n=e.length,r=s?s(n):new e.constructor(n);return e.copy(r),r}}).call(this,n(26)(e))},function(e,t){e.exports=function(e,t){var n=-1,r=e.length;for(t||(t=Array(r));++n<r;)t[n]=e[n];return t}},function(e,t,n){var r=n(27),o=n(84);e.exports=function(e,t){return r(e,o(e),t)}},function(e,t,n){var r=n(27),o=n(139);e.exports=function(e,t){return r(e,o(e),t)}},function(e,t){var n=Object.prototype.hasOwnProperty;e.exports=function(e){var t=e.length,r=new e.constructor(t);return t&&"string"==typeof e[0]&&n.call(e,"index")&&(r.index=e.index,r.input=e.input),r}},function(e,t,n){var r=n(88),o=n(292),i=n(293),a=n(294),s=n(295),u="[object Boolean]",l="[object Date]",c="[object Map]",f="[object Number]",p="[object RegExp]",h="[object Set]",d="[object String]",m="[object Symbol]",g="[object ArrayBuffer]",v="[object DataView]",y="[object Float32Array]",b="[object Float64Array]",_="[object Int8Array]",w="[object Int16Array]",x="[object Int32Array]",k="[object Uint8Array]",S="[object Uint8ClampedArray]",C="[object Uint16Array]",E="[object Uint32Array]";e.exports=function(e,t,n){var T=e.constructor;switch(t){case g:return r(e);case u:case l:return new T(+e);case v:return o(e,n);case y:case b:case _:case w:case x:case k:case S:case C:case E:return s(e,n);case c:return new T;case f:case d:return new T(e);case p:return i(e);case h:return new T;case m:return a(e)}}},function(e,t,n){var r=n(88);e.exports=function(e,t){var n=t?r(e.buffer):e.buffer;return new e.constructor(n,e.byteOffset,e.byteLength)}},function(e,t){var n=/\w*$/;e.exports=function(e){var t=new e.constructor(e.source,n.exec(e));return t.lastIndex=e.lastIndex,t}},function(e,t,n){var r=n(25),o=r?r.prototype:void 0,i=o?o.valueOf:void 0;e.exports=function(e){return i?Object(i.call(e)):{}}},function(e,t,n){var r=n(88);e.exports=function(e,t){var n=t?r(e.buffer):e.buffer;return new e.constructor(n,e.byteOffset,e.length)}},function(e,t,n){var r=n(297),o=n(87),i=n(78);e.exports=function(e){return"function"!=typeof e.constructor||i(e)?{}:r(o(e))}},function(e,t,n){var r=n(11),o=Object.create,i=function(){function e(){}return function(t){if(!r(t))return{};if(o)return o(t);e.prototype=t;var n=new e;return e.prototype=void 0,n}}();e.exports=i},function(e,t,n){var r=n(299),o=n(76),i=n(77),a=i&&i.isMap,s=a?o(a):r;e.exports=s},function(e,t,n){var r=n(53),o=n(10),i="[object Map]";e.exports=function(e){return o(e)&&r(e)==i}},function(e,t,n){var r=n(301),o=n(76),i=n(77),a=i&&i.isSet,s=a?o(a):r;e.exports=s},function(e,t,n){var r=n(53),o=n(10),i="[object Set]";e.exports=function(e){return o(e)&&r(e)==i}},function(e,t,n){var r=n(54),o=n(308),i=n(309),a=n(32);e.exports=function(e,t){return t=r(t,e),null==(e=i(e,t))||delete e[a(o(t))]}},function(e,t,n){var r=n(304),o=/[^.[\]]+|\[(?:(-?\d+(?:\.\d+)?)|(["'])((?:(?!\2)[^\\]|\\.)*?)\2)\]|(?=(?:\.|\[\])(?:\.|\[\]|$))/g,i=/\\(\\)?/g,a=r(function(e){var t=[];return 46===e.charCodeAt(0)&&t.push(""),e.replace(o,function(e,n,r,o){t.push(r?o.replace(i,"$1"):n||e)}),t});e.exports=a},function(e,t,n){var r=n(305),o=500;e.exports=function(e){var t=r(e,function(e){return n.size===o&&n.clear(),e}),n=t.cache;return t}},function(e,t,n){var r=n(82),o="Expected a function";function i(e,t){if("function"!=typeof e||null!=t&&"function"!=typeof t)throw new TypeError(o);var n=function(){var r=arguments,o=t?t.apply(this,r):r[0],i=n.cache;if(i.has(o))return i.get(o);var a=e.apply(this,r);return n.cache=i.set(o,a)||i,a};return n.cache=new(i.Cache||r),n}i.Cache=r,e.exports=i},function(e,t,n){var r=n(307);e.exports=function(e){return null==e?"":r(e)}},function(e,t,n){var r=n(25),o=n(85),i=n(5),a=n(90),s=1/0,u=r?r.prototype:void 0,l=u?u.toString:void 0;e.exports=function e(t){if("string"==typeof t)return t;if(i(t))return o(t,e)+"";if(a(t))return l?l.call(t):"";var n=t+"";return"0"==n&&1/t==-s?"-0":n}},function(e,t){e.exports=function(e){var t=null==e?0:e.length;return t?e[t-1]:void 0}},function(e,t,n){var r=n(91),o=n(310);e.exports=function(e,t){return t.length<2?e:r(e,o(t,0,-1))}},function(e,t){e.exports=function(e,t,n){var r=-1,o=e.length;t<0&&(t=-t>o?0:o+t),(n=n>o?o:n)<0&&(n+=o),o=t>n?0:n-t>>>0,t>>>=0;for(var i=Array(o);++r<o;)i[r]=e[r+t];return i}},function(e,t,n){var r=n(312);e.exports=function(e){return r(e)?void 0:e}},function(e,t,n){var r=n(24),o=n(87),i=n(10),a="[object Object]",s=Function.prototype,u=Object.prototype,l=s.toString,c=u.hasOwnProperty,f=l.call(Object);e.exports=function(e){if(!i(e)||r(e)!=a)return!1;var t=o(e);if(null===t)return!0;var n=c.call(t,"constructor")&&t.constructor;return"function"==typeof n&&n instanceof n&&l.call(n)==f}},function(e,t,n){var r=n(314),o=n(141),i=n(142);e.exports=function(e){return i(o(e,void 0,r),e+"")}},function(e,t,n){var r=n(315);e.exports=function(e){return null!=e&&e.length?r(e,1):[]}},function(e,t,n){var r=n(83),o=n(316);e.exports=function e(t,n,i,a,s){var u=-1,l=t.length;for(i||(i=o),s||(s=[]);++u<l;){var c=t[u];n>0&&i(c)?n>1?e(c,n-1,i,a,s):r(s,c):a||(s[s.length]=c)}return s}},function(e,t,n){var r=n(25),o=n(72),i=n(5),a=r?r.isConcatSpreadable:void 0;e.exports=function(e){return i(e)||o(e)||!!(a&&e&&e[a])}},function(e,t){e.exports=function(e,t,n){switch(n.length){case 0:return e.call(t);case 1:return e.call(t,n[0]);case 2:return e.call(t,n[0],n[1]);case 3:return e.call(t,n[0],n[1],n[2])}return e.apply(t,n)}},function(e,t,n){var r=n(319),o=n(138),i=n(47),a=o?function(e,t){return o(e,"toString",{configurable:!0,enumerable:!1,value:r(t),writable:!0})}:i;e.exports=a},function(e,t){e.exports=function(e){return function(){return e}}},function(e,t){var n=800,r=16,o=Date.now;e.exports=function(e){var t=0,i=0;return function(){var a=o(),s=r-(a-i);if(i=a,s>0){if(++t>=n)return arguments[0]}else t=0;return e.apply(void 0,arguments)}}},function(e,t,n){var r,o;/*! VelocityJS.org (1.5.2). (C) 2014 Julian Shapiro. MIT @license: en.wikipedia.org/wiki/MIT_License */
 /*! VelocityJS.org jQuery Shim (1.0.1). (C) 2014 The jQuery Foundation. MIT @license: en.wikipedia.org/wiki/MIT_License. */ /*! VelocityJS.org (1.5.2). (C) 2014 Julian Shapiro. MIT @license: en.wikipedia.org/wiki/MIT_License */
 /*! VelocityJS.org jQuery Shim (1.0.1). (C) 2014 The jQuery Foundation. MIT @license: en.wikipedia.org/wiki/MIT_License. */
-!function(e){"use strict";if(!e.jQuery){var t=function(e,n){return new t.fn.init(e,n)};t.isWindow=function(e){return e&&e===e.window},t.type=function(e){return e?"object"==typeof e||"function"==typeof e?r[i.call(e)]||"object":typeof e:e+""},t.isArray=Array.isArray||function(e){return"array"===t.type(e)},t.isPlainObject=function(e){var n;if(!e||"object"!==t.type(e)||e.nodeType||t.isWindow(e))return!1;try{if(e.constructor&&!o.call(e,"constructor")&&!o.call(e.constructor.prototype,"isPrototypeOf"))return!1}catch(e){return!1}for(n in e);return void 0===n||o.call(e,n)},t.each=function(e,t,n){var r=0,o=e.length,i=u(e);if(n){if(i)for(;r<o&&!1!==t.apply(e[r],n);r++);else for(r in e)if(e.hasOwnProperty(r)&&!1===t.apply(e[r],n))break}else if(i)for(;r<o&&!1!==t.call(e[r],r,e[r]);r++);else for(r in e)if(e.hasOwnProperty(r)&&!1===t.call(e[r],r,e[r]))break;return e},t.data=function(e,r,o){if(void 0===o){var i=e[t.expando],a=i&&n[i];if(void 0===r)return a;if(a&&r in a)return a[r]}else if(void 0!==r){var s=e[t.expando]||(e[t.expando]=++t.uuid);return n[s]=n[s]||{},n[s][r]=o,o}},t.removeData=function(e,r){var o=e[t.expando],i=o&&n[o];i&&(r?t.each(r,function(e,t){delete i[t]}):delete n[o])},t.extend=function(){var e,n,r,o,i,a,s=arguments[0]||{},u=1,l=arguments.length,c=!1;for("boolean"==typeof s&&(c=s,s=arguments[u]||{},u++),"object"!=typeof s&&"function"!==t.type(s)&&(s={}),u===l&&(s=this,u--);u<l;u++)if(i=arguments[u])for(o in i)i.hasOwnProperty(o)&&(e=s[o],s!==(r=i[o])&&(c&&r&&(t.isPlainObject(r)||(n=t.isArray(r)))?(n?(n=!1,a=e&&t.isArray(e)?e:[]):a=e&&t.isPlainObject(e)?e:{},s[o]=t.extend(c,a,r)):void 0!==r&&(s[o]=r)));return s},t.queue=function(e,n,r){if(e){n=(n||"fx")+"queue";var o,i,a,s=t.data(e,n);return r?(!s||t.isArray(r)?s=t.data(e,n,(a=i||[],(o=r)&&(u(Object(o))?function(e,t){for(var n=+t.length,r=0,o=e.length;r<n;)e[o++]=t[r++];if(n!=n)for(;void 0!==t[r];)e[o++]=t[r++];e.length=o}(a,"string"==typeof o?[o]:o):[].push.call(a,o)),a)):s.push(r),s):s||[]}},t.dequeue=function(e,n){t.each(e.nodeType?[e]:e,function(e,r){n=n||"fx";var o=t.queue(r,n),i=o.shift();"inprogress"===i&&(i=o.shift()),i&&("fx"===n&&o.unshift("inprogress"),i.call(r,function(){t.dequeue(r,n)}))})},t.fn=t.prototype={init:function(e){if(e.nodeType)return this[0]=e,this;throw new Error("Not a DOM node.")},offset:function(){var t=this[0].getBoundingClientRect?this[0].getBoundingClientRect():{top:0,left:0};return{top:t.top+(e.pageYOffset||document.scrollTop||0)-(document.clientTop||0),left:t.left+(e.pageXOffset||document.scrollLeft||0)-(document.clientLeft||0)}},position:function(){var e=this[0],n=function(e){for(var t=e.offsetParent;t&&"html"!==t.nodeName.toLowerCase()&&t.style&&"static"===t.style.position.toLowerCase();)t=t.offsetParent;return t||document}(e),r=this.offset(),o=/^(?:body|html)$/i.test(n.nodeName)?{top:0,left:0}:t(n).offset();return r.top-=parseFloat(e.style.marginTop)||0,r.left-=parseFloat(e.style.marginLeft)||0,n.style&&(o.top+=parseFloat(n.style.borderTopWidth)||0,o.left+=parseFloat(n.style.borderLeftWidth)||0),{top:r.top-o.top,left:r.left-o.left}}};var n={};t.expando="velocity"+(new Date).getTime(),t.uuid=0;for(var r={},o=r.hasOwnProperty,i=r.toString,a="Boolean Number String Function Array Date RegExp Object Error".split(" "),s=0;s<a.length;s++)r["[object "+a[s]+"]"]=a[s].toLowerCase();t.fn.init.prototype=t.fn,e.Velocity={Utilities:t}}function u(e){var n=e.length,r=t.type(e);return"function"!==r&&!t.isWindow(e)&&(!(1!==e.nodeType||!n)||("array"===r||0===n||"number"==typeof n&&n>0&&n-1 in e))}}(window),function(i){"use strict";"object"==typeof e.exports?e.exports=i():void 0===(o="function"==typeof(r=i)?r.call(t,n,t,e):r)||(e.exports=o)}(function(){"use strict";return function(e,t,n,r){var o,i=function(){if(n.documentMode)return n.documentMode;for(var e=7;e>4;e--){var t=n.createElement("div");if(t.innerHTML="\x3c!--[if IE "+e+"]><span></span><![endif]--\x3e",t.getElementsByTagName("span").length)return t=null,e}return r}(),a=(o=0,t.webkitRequestAnimationFrame||t.mozRequestAnimationFrame||function(e){var t,n=(new Date).getTime();return t=Math.max(0,16-(n-o)),o=n+t,setTimeout(function(){e(n+t)},t)}),s=function(){var e=t.performance||{};if("function"!=typeof e.now){var n=e.timing&&e.timing.navigationStart?e.timing.navigationStart:(new Date).getTime();e.now=function(){return(new Date).getTime()-n}}return e}();var u=function(){var e=Array.prototype.slice;try{return e.call(n.documentElement),e}catch(t){return function(t,n){var r=this.length;if("number"!=typeof t&&(t=0),"number"!=typeof n&&(n=r),this.slice)return e.call(this,t,n);var o,i=[],a=t>=0?t:Math.max(0,r+t),s=(n<0?r+n:Math.min(n,r))-a;if(s>0)if(i=new Array(s),this.charAt)for(o=0;o<s;o++)i[o]=this.charAt(a+o);else for(o=0;o<s;o++)i[o]=this[a+o];return i}}}(),l=function(){return Array.prototype.includes?function(e,t){return e.includes(t)}:Array.prototype.indexOf?function(e,t){return e.indexOf(t)>=0}:function(e,t){for(var n=0;n<e.length;n++)if(e[n]===t)return!0;return!1}};function c(e){return p.isWrapped(e)?e=u.call(e):p.isNode(e)&&(e=[e]),e}var f,p={isNumber:function(e){return"number"==typeof e},isString:function(e){return"string"==typeof e},isArray:Array.isArray||function(e){return"[object Array]"===Object.prototype.toString.call(e)},isFunction:function(e){return"[object Function]"===Object.prototype.toString.call(e)},isNode:function(e){return e&&e.nodeType},isWrapped:function(e){return e&&e!==t&&p.isNumber(e.length)&&!p.isString(e)&&!p.isFunction(e)&&!p.isNode(e)&&(0===e.length||p.isNode(e[0]))},isSVG:function(e){return t.SVGElement&&e instanceof t.SVGElement},isEmptyObject:function(e){for(var t in e)if(e.hasOwnProperty(t))return!1;return!0}},h=!1;if(e.fn&&e.fn.jquery?(f=e,h=!0):f=t.Velocity.Utilities,i<=8&&!h)throw new Error("Velocity: IE8 and below require jQuery to be loaded before Velocity.");if(!(i<=7)){var d=400,m="swing",g={State:{isMobile:/Android|webOS|iPhone|iPad|iPod|BlackBerry|IEMobile|Opera Mini/i.test(t.navigator.userAgent),isAndroid:/Android/i.test(t.navigator.userAgent),isGingerbread:/Android 2\.3\.[3-7]/i.test(t.navigator.userAgent),isChrome:t.chrome,isFirefox:/Firefox/i.test(t.navigator.userAgent),prefixElement:n.createElement("div"),prefixMatches:{},scrollAnchor:null,scrollPropertyLeft:null,scrollPropertyTop:null,isTicking:!1,calls:[],delayedElements:{count:0}},CSS:{},Utilities:f,Redirects:{},Easings:{},Promise:t.Promise,defaults:{queue:"",duration:d,easing:m,begin:r,complete:r,progress:r,display:r,visibility:r,loop:!1,delay:!1,mobileHA:!0,_cacheValues:!0,promiseRejectEmpty:!0},init:function(e){f.data(e,"velocity",{isSVG:p.isSVG(e),isAnimating:!1,computedStyle:null,tweensContainer:null,rootPropertyValueCache:{},transformCache:{}})},hook:null,mock:!1,version:{major:1,minor:5,patch:2},debug:!1,timestamp:!0,pauseAll:function(e){var t=(new Date).getTime();f.each(g.State.calls,function(t,n){if(n){if(e!==r&&(n[2].queue!==e||!1===n[2].queue))return!0;n[5]={resume:!1}}}),f.each(g.State.delayedElements,function(e,n){n&&k(n,t)})},resumeAll:function(e){var t=(new Date).getTime();f.each(g.State.calls,function(t,n){if(n){if(e!==r&&(n[2].queue!==e||!1===n[2].queue))return!0;n[5]&&(n[5].resume=!0)}}),f.each(g.State.delayedElements,function(e,n){n&&S(n,t)})}};t.pageYOffset!==r?(g.State.scrollAnchor=t,g.State.scrollPropertyLeft="pageXOffset",g.State.scrollPropertyTop="pageYOffset"):(g.State.scrollAnchor=n.documentElement||n.body.parentNode||n.body,g.State.scrollPropertyLeft="scrollLeft",g.State.scrollPropertyTop="scrollTop");var v=function(){function e(e){return-e.tension*e.x-e.friction*e.v}function t(t,n,r){var o={x:t.x+r.dx*n,v:t.v+r.dv*n,tension:t.tension,friction:t.friction};return{dx:o.v,dv:e(o)}}function n(n,r){var o={dx:n.v,dv:e(n)},i=t(n,.5*r,o),a=t(n,.5*r,i),s=t(n,r,a),u=1/6*(o.dx+2*(i.dx+a.dx)+s.dx),l=1/6*(o.dv+2*(i.dv+a.dv)+s.dv);return n.x=n.x+u*r,n.v=n.v+l*r,n}return function e(t,r,o){var i,a,s,u={x:-1,v:0,tension:null,friction:null},l=[0],c=0;for(t=parseFloat(t)||500,r=parseFloat(r)||20,o=o||null,u.tension=t,u.friction=r,a=(i=null!==o)?(c=e(t,r))/o*.016:.016;s=n(s||u,a),l.push(1+s.x),c+=16,Math.abs(s.x)>1e-4&&Math.abs(s.v)>1e-4;);return i?function(e){return l[e*(l.length-1)|0]}:c}}();g.Easings={linear:function(e){return e},swing:function(e){return.5-Math.cos(e*Math.PI)/2},spring:function(e){return 1-Math.cos(4.5*e*Math.PI)*Math.exp(6*-e)}},f.each([["ease",[.25,.1,.25,1]],["ease-in",[.42,0,1,1]],["ease-out",[0,0,.58,1]],["ease-in-out",[.42,0,.58,1]],["easeInSine",[.47,0,.745,.715]],["easeOutSine",[.39,.575,.565,1]],["easeInOutSine",[.445,.05,.55,.95]],["easeInQuad",[.55,.085,.68,.53]],["easeOutQuad",[.25,.46,.45,.94]],["easeInOutQuad",[.455,.03,.515,.955]],["easeInCubic",[.55,.055,.675,.19]],["easeOutCubic",[.215,.61,.355,1]],["easeInOutCubic",[.645,.045,.355,1]],["easeInQuart",[.895,.03,.685,.22]],["easeOutQuart",[.165,.84,.44,1]],["easeInOutQuart",[.77,0,.175,1]],["easeInQuint",[.755,.05,.855,.06]],["easeOutQuint",[.23,1,.32,1]],["easeInOutQuint",[.86,0,.07,1]],["easeInExpo",[.95,.05,.795,.035]],["easeOutExpo",[.19,1,.22,1]],["easeInOutExpo",[1,0,0,1]],["easeInCirc",[.6,.04,.98,.335]],["easeOutCirc",[.075,.82,.165,1]],["easeInOutCirc",[.785,.135,.15,.86]]],function(e,t){g.Easings[t[0]]=C.apply(null,t[1])});var y=g.CSS={RegEx:{isHex:/^#([A-f\d]{3}){1,2}$/i,valueUnwrap:/^[A-z]+\((.*)\)$/i,wrappedValueAlreadyExtracted:/[0-9.]+ [0-9.]+ [0-9.]+( [0-9.]+)?/,valueSplit:/([A-z]+\(.+\))|(([A-z0-9#-.]+?)(?=\s|$))/gi},Lists:{colors:["fill","stroke","stopColor","color","backgroundColor","borderColor","borderTopColor","borderRightColor","borderBottomColor","borderLeftColor","outlineColor"],transformsBase:["translateX","translateY","scale","scaleX","scaleY","skewX","skewY","rotateZ"],transforms3D:["transformPerspective","translateZ","scaleZ","rotateX","rotateY"],units:["%","em","ex","ch","rem","vw","vh","vmin","vmax","cm","mm","Q","in","pc","pt","px","deg","grad","rad","turn","s","ms"],colorNames:{aliceblue:"240,248,255",antiquewhite:"250,235,215",aquamarine:"127,255,212",aqua:"0,255,255",azure:"240,255,255",beige:"245,245,220",bisque:"255,228,196",black:"0,0,0",blanchedalmond:"255,235,205",blueviolet:"138,43,226",blue:"0,0,255",brown:"165,42,42",burlywood:"222,184,135",cadetblue:"95,158,160",chartreuse:"127,255,0",chocolate:"210,105,30",coral:"255,127,80",cornflowerblue:"100,149,237",cornsilk:"255,248,220",crimson:"220,20,60",cyan:"0,255,255",darkblue:"0,0,139",darkcyan:"0,139,139",darkgoldenrod:"184,134,11",darkgray:"169,169,169",darkgrey:"169,169,169",darkgreen:"0,100,0",darkkhaki:"189,183,107",darkmagenta:"139,0,139",darkolivegreen:"85,107,47",darkorange:"255,140,0",darkorchid:"153,50,204",darkred:"139,0,0",darksalmon:"233,150,122",darkseagreen:"143,188,143",darkslateblue:"72,61,139",darkslategray:"47,79,79",darkturquoise:"0,206,209",darkviolet:"148,0,211",deeppink:"255,20,147",deepskyblue:"0,191,255",dimgray:"105,105,105",dimgrey:"105,105,105",dodgerblue:"30,144,255",firebrick:"178,34,34",floralwhite:"255,250,240",forestgreen:"34,139,34",fuchsia:"255,0,255",gainsboro:"220,220,220",ghostwhite:"248,248,255",gold:"255,215,0",goldenrod:"218,165,32",gray:"128,128,128",grey:"128,128,128",greenyellow:"173,255,47",green:"0,128,0",honeydew:"240,255,240",hotpink:"255,105,180",indianred:"205,92,92",indigo:"75,0,130",ivory:"255,255,240",khaki:"240,230,140",lavenderblush:"255,240,245",lavender:"230,230,250",lawngreen:"124,252,0",lemonchiffon:"255,250,205",lightblue:"173,216,230",lightcoral:"240,128,128",lightcyan:"224,255,255",lightgoldenrodyellow:"250,250,210",lightgray:"211,211,211",lightgrey:"211,211,211",lightgreen:"144,238,144",lightpink:"255,182,193",lightsalmon:"255,160,122",lightseagreen:"32,178,170",lightskyblue:"135,206,250",lightslategray:"119,136,153",lightsteelblue:"176,196,222",lightyellow:"255,255,224",limegreen:"50,205,50",lime:"0,255,0",linen:"250,240,230",magenta:"255,0,255",maroon:"128,0,0",mediumaquamarine:"102,205,170",mediumblue:"0,0,205",mediumorchid:"186,85,211",mediumpurple:"147,112,219",mediumseagreen:"60,179,113",mediumslateblue:"123,104,238",mediumspringgreen:"0,250,154",mediumturquoise:"72,209,204",mediumvioletred:"199,21,133",midnightblue:"25,25,112",mintcream:"245,255,250",mistyrose:"255,228,225",moccasin:"255,228,181",navajowhite:"255,222,173",navy:"0,0,128",oldlace:"253,245,230",olivedrab:"107,142,35",olive:"128,128,0",orangered:"255,69,0",orange:"255,165,0",orchid:"218,112,214",palegoldenrod:"238,232,170",palegreen:"152,251,152",paleturquoise:"175,238,238",palevioletred:"219,112,147",papayawhip:"255,239,213",peachpuff:"255,218,185",peru:"205,133,63",pink:"255,192,203",plum:"221,160,221",powderblue:"176,224,230",purple:"128,0,128",red:"255,0,0",rosybrown:"188,143,143",royalblue:"65,105,225",saddlebrown:"139,69,19",salmon:"250,128,114",sandybrown:"244,164,96",seagreen:"46,139,87",seashell:"255,245,238",sienna:"160,82,45",silver:"192,192,192",skyblue:"135,206,235",slateblue:"106,90,205",slategray:"112,128,144",snow:"255,250,250",springgreen:"0,255,127",steelblue:"70,130,180",tan:"210,180,140",teal:"0,128,128",thistle:"216,191,216",tomato:"255,99,71",turquoise:"64,224,208",violet:"238,130,238",wheat:"245,222,179",whitesmoke:"245,245,245",white:"255,255,255",yellowgreen:"154,205,50",yellow:"255,255,0"}},Hooks:{templates:{textShadow:["Color X Y Blur","black 0px 0px 0px"],boxShadow:["Color X Y Blur Spread","black 0px 0px 0px 0px"],clip:["Top Right Bottom Left","0px 0px 0px 0px"],backgroundPosition:["X Y","0% 0%"],transformOrigin:["X Y Z","50% 50% 0px"],perspectiveOrigin:["X Y","50% 50%"]},registered:{},register:function(){for(var e=0;e<y.Lists.colors.length;e++){var t="color"===y.Lists.colors[e]?"0 0 0 1":"255 255 255 1";y.Hooks.templates[y.Lists.colors[e]]=["Red Green Blue Alpha",t]}var n,r,o;if(i)for(n in y.Hooks.templates)if(y.Hooks.templates.hasOwnProperty(n)){o=(r=y.Hooks.templates[n])[0].split(" ");var a=r[1].match(y.RegEx.valueSplit);"Color"===o[0]&&(o.push(o.shift()),a.push(a.shift()),y.Hooks.templates[n]=[o.join(" "),a.join(" ")])}for(n in y.Hooks.templates)if(y.Hooks.templates.hasOwnProperty(n))for(var s in o=(r=y.Hooks.templates[n])[0].split(" "))if(o.hasOwnProperty(s)){var u=n+o[s],l=s;y.Hooks.registered[u]=[n,l]}},getRoot:function(e){var t=y.Hooks.registered[e];return t?t[0]:e},getUnit:function(e,t){var n=(e.substr(t||0,5).match(/^[a-z%]+/)||[])[0]||"";return n&&l(y.Lists.units,n)?n:""},fixColors:function(e){return e.replace(/(rgba?\(\s*)?(\b[a-z]+\b)/g,function(e,t,n){return y.Lists.colorNames.hasOwnProperty(n)?(t||"rgba(")+y.Lists.colorNames[n]+(t?"":",1)"):t+n})},cleanRootPropertyValue:function(e,t){return y.RegEx.valueUnwrap.test(t)&&(t=t.match(y.RegEx.valueUnwrap)[1]),y.Values.isCSSNullValue(t)&&(t=y.Hooks.templates[e][1]),t},extractValue:function(e,t){var n=y.Hooks.registered[e];if(n){var r=n[0],o=n[1];return(t=y.Hooks.cleanRootPropertyValue(r,t)).toString().match(y.RegEx.valueSplit)[o]}return t},injectValue:function(e,t,n){var r=y.Hooks.registered[e];if(r){var o,i=r[0],a=r[1];return(o=(n=y.Hooks.cleanRootPropertyValue(i,n)).toString().match(y.RegEx.valueSplit))[a]=t,o.join(" ")}return n}},Normalizations:{registered:{clip:function(e,t,n){switch(e){case"name":return"clip";case"extract":var r;return r=y.RegEx.wrappedValueAlreadyExtracted.test(n)?n:(r=n.toString().match(y.RegEx.valueUnwrap))?r[1].replace(/,(\s+)?/g," "):n;case"inject":return"rect("+n+")"}},blur:function(e,t,n){switch(e){case"name":return g.State.isFirefox?"filter":"-webkit-filter";case"extract":var r=parseFloat(n);if(!r&&0!==r){var o=n.toString().match(/blur\(([0-9]+[A-z]+)\)/i);r=o?o[1]:0}return r;case"inject":return parseFloat(n)?"blur("+n+")":"none"}},opacity:function(e,t,n){if(i<=8)switch(e){case"name":return"filter";case"extract":var r=n.toString().match(/alpha\(opacity=(.*)\)/i);return n=r?r[1]/100:1;case"inject":return t.style.zoom=1,parseFloat(n)>=1?"":"alpha(opacity="+parseInt(100*parseFloat(n),10)+")"}else switch(e){case"name":return"opacity";case"extract":case"inject":return n}}},register:function(){i&&!(i>9)||g.State.isGingerbread||(y.Lists.transformsBase=y.Lists.transformsBase.concat(y.Lists.transforms3D));for(var e=0;e<y.Lists.transformsBase.length;e++)!function(){var t=y.Lists.transformsBase[e];y.Normalizations.registered[t]=function(e,n,o){switch(e){case"name":return"transform";case"extract":return x(n)===r||x(n).transformCache[t]===r?/^scale/i.test(t)?1:0:x(n).transformCache[t].replace(/[()]/g,"");case"inject":var i=!1;switch(t.substr(0,t.length-1)){case"translate":i=!/(%|px|em|rem|vw|vh|\d)$/i.test(o);break;case"scal":case"scale":g.State.isAndroid&&x(n).transformCache[t]===r&&o<1&&(o=1),i=!/(\d)$/i.test(o);break;case"skew":case"rotate":i=!/(deg|\d)$/i.test(o)}return i||(x(n).transformCache[t]="("+o+")"),x(n).transformCache[t]}}}();for(var t=0;t<y.Lists.colors.length;t++)!function(){var e=y.Lists.colors[t];y.Normalizations.registered[e]=function(t,n,o){switch(t){case"name":return e;case"extract":var a;if(y.RegEx.wrappedValueAlreadyExtracted.test(o))a=o;else{var s,u={black:"rgb(0, 0, 0)",blue:"rgb(0, 0, 255)",gray:"rgb(128, 128, 128)",green:"rgb(0, 128, 0)",red:"rgb(255, 0, 0)",white:"rgb(255, 255, 255)"};/^[A-z]+$/i.test(o)?s=u[o]!==r?u[o]:u.black:y.RegEx.isHex.test(o)?s="rgb("+y.Values.hexToRgb(o).join(" ")+")":/^rgba?\(/i.test(o)||(s=u.black),a=(s||o).toString().match(y.RegEx.valueUnwrap)[1].replace(/,(\s+)?/g," ")}return(!i||i>8)&&3===a.split(" ").length&&(a+=" 1"),a;case"inject":return/^rgb/.test(o)?o:(i<=8?4===o.split(" ").length&&(o=o.split(/\s+/).slice(0,3).join(" ")):3===o.split(" ").length&&(o+=" 1"),(i<=8?"rgb":"rgba")+"("+o.replace(/\s+/g,",").replace(/\.(\d)+(?=,)/g,"")+")")}}}();function n(e,t,n){if("border-box"===y.getPropertyValue(t,"boxSizing").toString().toLowerCase()===(n||!1)){var r,o,i=0,a="width"===e?["Left","Right"]:["Top","Bottom"],s=["padding"+a[0],"padding"+a[1],"border"+a[0]+"Width","border"+a[1]+"Width"];for(r=0;r<s.length;r++)o=parseFloat(y.getPropertyValue(t,s[r])),isNaN(o)||(i+=o);return n?-i:i}return 0}function o(e,t){return function(r,o,i){switch(r){case"name":return e;case"extract":return parseFloat(i)+n(e,o,t);case"inject":return parseFloat(i)-n(e,o,t)+"px"}}}y.Normalizations.registered.innerWidth=o("width",!0),y.Normalizations.registered.innerHeight=o("height",!0),y.Normalizations.registered.outerWidth=o("width"),y.Normalizations.registered.outerHeight=o("height")}},Names:{camelCase:function(e){return e.replace(/-(\w)/g,function(e,t){return t.toUpperCase()})},SVGAttribute:function(e){var t="width|height|x|y|cx|cy|r|rx|ry|x1|x2|y1|y2";return(i||g.State.isAndroid&&!g.State.isChrome)&&(t+="|transform"),new RegExp("^("+t+")$","i").test(e)},prefixCheck:function(e){if(g.State.prefixMatches[e])return[g.State.prefixMatches[e],!0];for(var t=["","Webkit","Moz","ms","O"],n=0,r=t.length;n<r;n++){var o;if(o=0===n?e:t[n]+e.replace(/^\w/,function(e){return e.toUpperCase()}),p.isString(g.State.prefixElement.style[o]))return g.State.prefixMatches[e]=o,[o,!0]}return[e,!1]}},Values:{hexToRgb:function(e){var t;return e=e.replace(/^#?([a-f\d])([a-f\d])([a-f\d])$/i,function(e,t,n,r){return t+t+n+n+r+r}),(t=/^#?([a-f\d]{2})([a-f\d]{2})([a-f\d]{2})$/i.exec(e))?[parseInt(t[1],16),parseInt(t[2],16),parseInt(t[3],16)]:[0,0,0]},isCSSNullValue:function(e){return!e||/^(none|auto|transparent|(rgba\(0, ?0, ?0, ?0\)))$/i.test(e)},getUnitType:function(e){return/^(rotate|skew)/i.test(e)?"deg":/(^(scale|scaleX|scaleY|scaleZ|alpha|flexGrow|flexHeight|zIndex|fontWeight)$)|((opacity|red|green|blue|alpha)$)/i.test(e)?"":"px"},getDisplayType:function(e){var t=e&&e.tagName.toString().toLowerCase();return/^(b|big|i|small|tt|abbr|acronym|cite|code|dfn|em|kbd|strong|samp|var|a|bdo|br|img|map|object|q|script|span|sub|sup|button|input|label|select|textarea)$/i.test(t)?"inline":/^(li)$/i.test(t)?"list-item":/^(tr)$/i.test(t)?"table-row":/^(table)$/i.test(t)?"table":/^(tbody)$/i.test(t)?"table-row-group":"block"},addClass:function(e,t){if(e)if(e.classList)e.classList.add(t);else if(p.isString(e.className))e.className+=(e.className.length?" ":"")+t;else{var n=e.getAttribute(i<=7?"className":"class")||"";e.setAttribute("class",n+(n?" ":"")+t)}},removeClass:function(e,t){if(e)if(e.classList)e.classList.remove(t);else if(p.isString(e.className))e.className=e.className.toString().replace(new RegExp("(^|\\s)"+t.split(" ").join("|")+"(\\s|$)","gi")," ");else{var n=e.getAttribute(i<=7?"className":"class")||"";e.setAttribute("class",n.replace(new RegExp("(^|s)"+t.split(" ").join("|")+"(s|$)","gi")," "))}}},getPropertyValue:function(e,n,o,a){function s(e,n){var o=0;if(i<=8)o=f.css(e,n);else{var u=!1;/^(width|height)$/.test(n)&&0===y.getPropertyValue(e,"display")&&(u=!0,y.setPropertyValue(e,"display",y.Values.getDisplayType(e)));var l,c=function(){u&&y.setPropertyValue(e,"display","none")};if(!a){if("height"===n&&"border-box"!==y.getPropertyValue(e,"boxSizing").toString().toLowerCase()){var p=e.offsetHeight-(parseFloat(y.getPropertyValue(e,"borderTopWidth"))||0)-(parseFloat(y.getPropertyValue(e,"borderBottomWidth"))||0)-(parseFloat(y.getPropertyValue(e,"paddingTop"))||0)-(parseFloat(y.getPropertyValue(e,"paddingBottom"))||0);return c(),p}if("width"===n&&"border-box"!==y.getPropertyValue(e,"boxSizing").toString().toLowerCase()){var h=e.offsetWidth-(parseFloat(y.getPropertyValue(e,"borderLeftWidth"))||0)-(parseFloat(y.getPropertyValue(e,"borderRightWidth"))||0)-(parseFloat(y.getPropertyValue(e,"paddingLeft"))||0)-(parseFloat(y.getPropertyValue(e,"paddingRight"))||0);return c(),h}}l=x(e)===r?t.getComputedStyle(e,null):x(e).computedStyle?x(e).computedStyle:x(e).computedStyle=t.getComputedStyle(e,null),"borderColor"===n&&(n="borderTopColor"),""!==(o=9===i&&"filter"===n?l.getPropertyValue(n):l[n])&&null!==o||(o=e.style[n]),c()}if("auto"===o&&/^(top|right|bottom|left)$/i.test(n)){var d=s(e,"position");("fixed"===d||"absolute"===d&&/top|left/i.test(n))&&(o=f(e).position()[n]+"px")}return o}var u;if(y.Hooks.registered[n]){var l=n,c=y.Hooks.getRoot(l);o===r&&(o=y.getPropertyValue(e,y.Names.prefixCheck(c)[0])),y.Normalizations.registered[c]&&(o=y.Normalizations.registered[c]("extract",e,o)),u=y.Hooks.extractValue(l,o)}else if(y.Normalizations.registered[n]){var p,h;"transform"!==(p=y.Normalizations.registered[n]("name",e))&&(h=s(e,y.Names.prefixCheck(p)[0]),y.Values.isCSSNullValue(h)&&y.Hooks.templates[n]&&(h=y.Hooks.templates[n][1])),u=y.Normalizations.registered[n]("extract",e,h)}if(!/^[\d-]/.test(u)){var d=x(e);if(d&&d.isSVG&&y.Names.SVGAttribute(n))if(/^(height|width)$/i.test(n))try{u=e.getBBox()[n]}catch(e){u=0}else u=e.getAttribute(n);else u=s(e,y.Names.prefixCheck(n)[0])}return y.Values.isCSSNullValue(u)&&(u=0),g.debug>=2&&console.log("Get "+n+": "+u),u},setPropertyValue:function(e,n,r,o,a){var s=n;if("scroll"===n)a.container?a.container["scroll"+a.direction]=r:"Left"===a.direction?t.scrollTo(r,a.alternateValue):t.scrollTo(a.alternateValue,r);else if(y.Normalizations.registered[n]&&"transform"===y.Normalizations.registered[n]("name",e))y.Normalizations.registered[n]("inject",e,r),s="transform",r=x(e).transformCache[n];else{if(y.Hooks.registered[n]){var u=n,l=y.Hooks.getRoot(n);o=o||y.getPropertyValue(e,l),r=y.Hooks.injectValue(u,r,o),n=l}if(y.Normalizations.registered[n]&&(r=y.Normalizations.registered[n]("inject",e,r),n=y.Normalizations.registered[n]("name",e)),s=y.Names.prefixCheck(n)[0],i<=8)try{e.style[s]=r}catch(e){g.debug&&console.log("Browser does not support ["+r+"] for ["+s+"]")}else{var c=x(e);c&&c.isSVG&&y.Names.SVGAttribute(n)?e.setAttribute(n,r):e.style[s]=r}g.debug>=2&&console.log("Set "+n+" ("+s+"): "+r)}return[s,r]},flushTransformCache:function(e){var t="",n=x(e);if((i||g.State.isAndroid&&!g.State.isChrome)&&n&&n.isSVG){var r=function(t){return parseFloat(y.getPropertyValue(e,t))},o={translate:[r("translateX"),r("translateY")],skewX:[r("skewX")],skewY:[r("skewY")],scale:1!==r("scale")?[r("scale"),r("scale")]:[r("scaleX"),r("scaleY")],rotate:[r("rotateZ"),0,0]};f.each(x(e).transformCache,function(e){/^translate/i.test(e)?e="translate":/^scale/i.test(e)?e="scale":/^rotate/i.test(e)&&(e="rotate"),o[e]&&(t+=e+"("+o[e].join(" ")+") ",delete o[e])})}else{var a,s;f.each(x(e).transformCache,function(n){if(a=x(e).transformCache[n],"transformPerspective"===n)return s=a,!0;9===i&&"rotateZ"===n&&(n="rotate"),t+=n+a+" "}),s&&(t="perspective"+s+" "+t)}y.setPropertyValue(e,"transform",t)}};y.Hooks.register(),y.Normalizations.register(),g.hook=function(e,t,n){var o;return e=c(e),f.each(e,function(e,i){if(x(i)===r&&g.init(i),n===r)o===r&&(o=y.getPropertyValue(i,t));else{var a=y.setPropertyValue(i,t,n);"transform"===a[0]&&g.CSS.flushTransformCache(i),o=a}}),o};var b=function(){var e;function o(){return i?_.promise||null:a}var i,a,s,u,h,m,v=arguments[0]&&(arguments[0].p||f.isPlainObject(arguments[0].properties)&&!arguments[0].properties.names||p.isString(arguments[0].properties));p.isWrapped(this)?(i=!1,s=0,u=this,a=this):(i=!0,s=1,u=v?arguments[0].elements||arguments[0].e:arguments[0]);var _={promise:null,resolver:null,rejecter:null};if(i&&g.Promise&&(_.promise=new g.Promise(function(e,t){_.resolver=e,_.rejecter=t})),v?(h=arguments[0].properties||arguments[0].p,m=arguments[0].options||arguments[0].o):(h=arguments[s],m=arguments[s+1]),u=c(u)){var w,C=u.length,A=0;if(!/^(stop|finish|finishAll|pause|resume)$/i.test(h)&&!f.isPlainObject(m)){m={};for(var P=s+1;P<arguments.length;P++)p.isArray(arguments[P])||!/^(fast|normal|slow)$/i.test(arguments[P])&&!/^\d/.test(arguments[P])?p.isString(arguments[P])||p.isArray(arguments[P])?m.easing=arguments[P]:p.isFunction(arguments[P])&&(m.complete=arguments[P]):m.duration=arguments[P]}switch(h){case"scroll":w="scroll";break;case"reverse":w="reverse";break;case"pause":var j=(new Date).getTime();return f.each(u,function(e,t){k(t,j)}),f.each(g.State.calls,function(e,t){var n=!1;t&&f.each(t[1],function(e,o){var i=m===r?"":m;return!0!==i&&t[2].queue!==i&&(m!==r||!1!==t[2].queue)||(f.each(u,function(e,r){if(r===o)return t[5]={resume:!1},n=!0,!1}),!n&&void 0)})}),o();case"resume":return f.each(u,function(e,t){S(t)}),f.each(g.State.calls,function(e,t){var n=!1;t&&f.each(t[1],function(e,o){var i=m===r?"":m;return!0!==i&&t[2].queue!==i&&(m!==r||!1!==t[2].queue)||(!t[5]||(f.each(u,function(e,r){if(r===o)return t[5].resume=!0,n=!0,!1}),!n&&void 0))})}),o();case"finish":case"finishAll":case"stop":f.each(u,function(e,t){x(t)&&x(t).delayTimer&&(clearTimeout(x(t).delayTimer.setTimeout),x(t).delayTimer.next&&x(t).delayTimer.next(),delete x(t).delayTimer),"finishAll"!==h||!0!==m&&!p.isString(m)||(f.each(f.queue(t,p.isString(m)?m:""),function(e,t){p.isFunction(t)&&t()}),f.queue(t,p.isString(m)?m:"",[]))});var R=[];return f.each(g.State.calls,function(e,t){t&&f.each(t[1],function(n,o){var i=m===r?"":m;if(!0!==i&&t[2].queue!==i&&(m!==r||!1!==t[2].queue))return!0;f.each(u,function(n,r){if(r===o)if((!0===m||p.isString(m))&&(f.each(f.queue(r,p.isString(m)?m:""),function(e,t){p.isFunction(t)&&t(null,!0)}),f.queue(r,p.isString(m)?m:"",[])),"stop"===h){var a=x(r);a&&a.tweensContainer&&(!0===i||""===i)&&f.each(a.tweensContainer,function(e,t){t.endValue=t.currentValue}),R.push(e)}else"finish"!==h&&"finishAll"!==h||(t[2].duration=1)})})}),"stop"===h&&(f.each(R,function(e,t){O(t,!0)}),_.promise&&_.resolver(u)),o();default:if(!f.isPlainObject(h)||p.isEmptyObject(h)){if(p.isString(h)&&g.Redirects[h]){var N=(e=f.extend({},m)).duration,M=e.delay||0;return!0===e.backwards&&(u=f.extend(!0,[],u).reverse()),f.each(u,function(t,n){parseFloat(e.stagger)?e.delay=M+parseFloat(e.stagger)*t:p.isFunction(e.stagger)&&(e.delay=M+e.stagger.call(n,t,C)),e.drag&&(e.duration=parseFloat(N)||(/^(callout|transition)/.test(h)?1e3:d),e.duration=Math.max(e.duration*(e.backwards?1-t/C:(t+1)/C),.75*e.duration,200)),g.Redirects[h].call(n,n,e||{},t,C,u,_.promise?_:r)}),o()}var L="Velocity: First argument ("+h+") was not a property map, a known action, or a registered redirect. Aborting.";return _.promise?_.rejecter(new Error(L)):t.console&&console.log(L),o()}w="start"}var I={lastParent:null,lastPosition:null,lastFontSize:null,lastPercentToPxWidth:null,lastPercentToPxHeight:null,lastEmToPx:null,remToPx:null,vwToPx:null,vhToPx:null},D=[];f.each(u,function(e,o){p.isNode(o)&&function(e,o){var i,a,s=f.extend({},g.defaults,m),c={};switch(x(e)===r&&g.init(e),parseFloat(s.delay)&&!1!==s.queue&&f.queue(e,s.queue,function(t,n){if(!0===n)return!0;g.velocityQueueEntryFlag=!0;var r=g.State.delayedElements.count++;g.State.delayedElements[r]=e;var o,i=(o=r,function(){g.State.delayedElements[o]=!1,t()});x(e).delayBegin=(new Date).getTime(),x(e).delay=parseFloat(s.delay),x(e).delayTimer={setTimeout:setTimeout(t,parseFloat(s.delay)),next:i}}),s.duration.toString().toLowerCase()){case"fast":s.duration=200;break;case"normal":s.duration=d;break;case"slow":s.duration=600;break;default:s.duration=parseFloat(s.duration)||1}function v(a){var d,v;if(s.begin&&0===A)try{s.begin.call(u,u)}catch(e){setTimeout(function(){throw e},1)}if("scroll"===w){var b,k,S,O=/^x$/i.test(s.axis)?"Left":"Top",P=parseFloat(s.offset)||0;s.container?p.isWrapped(s.container)||p.isNode(s.container)?(s.container=s.container[0]||s.container,S=(b=s.container["scroll"+O])+f(e).position()[O.toLowerCase()]+P):s.container=null:(b=g.State.scrollAnchor[g.State["scrollProperty"+O]],k=g.State.scrollAnchor[g.State["scrollProperty"+("Left"===O?"Top":"Left")]],S=f(e).offset()[O.toLowerCase()]+P),c={scroll:{rootPropertyValue:!1,startValue:b,currentValue:b,endValue:S,unitType:"",easing:s.easing,scrollData:{container:s.container,direction:O,alternateValue:k}},element:e},g.debug&&console.log("tweensContainer (scroll): ",c.scroll,e)}else if("reverse"===w){if(!(d=x(e)))return;if(!d.tweensContainer)return void f.dequeue(e,s.queue);for(var j in"none"===d.opts.display&&(d.opts.display="auto"),"hidden"===d.opts.visibility&&(d.opts.visibility="visible"),d.opts.loop=!1,d.opts.begin=null,d.opts.complete=null,m.easing||delete s.easing,m.duration||delete s.duration,s=f.extend({},d.opts,s),v=f.extend(!0,{},d?d.tweensContainer:null))if(v.hasOwnProperty(j)&&"element"!==j){var R=v[j].startValue;v[j].startValue=v[j].currentValue=v[j].endValue,v[j].endValue=R,p.isEmptyObject(m)||(v[j].easing=s.easing),g.debug&&console.log("reverse tweensContainer ("+j+"): "+JSON.stringify(v[j]),e)}c=v}else if("start"===w){(d=x(e))&&d.tweensContainer&&!0===d.isAnimating&&(v=d.tweensContainer);var N=function(t,n){var r,i,a;return p.isFunction(t)&&(t=t.call(e,o,C)),p.isArray(t)?(r=t[0],!p.isArray(t[1])&&/^[\d-]/.test(t[1])||p.isFunction(t[1])||y.RegEx.isHex.test(t[1])?a=t[1]:p.isString(t[1])&&!y.RegEx.isHex.test(t[1])&&g.Easings[t[1]]||p.isArray(t[1])?(i=n?t[1]:E(t[1],s.duration),a=t[2]):a=t[1]||t[2]):r=t,n||(i=i||s.easing),p.isFunction(r)&&(r=r.call(e,o,C)),p.isFunction(a)&&(a=a.call(e,o,C)),[r||0,i,a]},M=function(o,a){var u,l=y.Hooks.getRoot(o),h=!1,m=a[0],b=a[1],_=a[2];if(d&&d.isSVG||"tween"===l||!1!==y.Names.prefixCheck(l)[1]||y.Normalizations.registered[l]!==r){(s.display!==r&&null!==s.display&&"none"!==s.display||s.visibility!==r&&"hidden"!==s.visibility)&&/opacity|filter/.test(o)&&!_&&0!==m&&(_=0),s._cacheValues&&v&&v[o]?(_===r&&(_=v[o].endValue+v[o].unitType),h=d.rootPropertyValueCache[l]):y.Hooks.registered[o]?_===r?(h=y.getPropertyValue(e,l),_=y.getPropertyValue(e,o,h)):h=y.Hooks.templates[l][1]:_===r&&(_=y.getPropertyValue(e,o));var w,x,k,S=!1,C=function(e,t){var n,r;return r=(t||"0").toString().toLowerCase().replace(/[%A-z]+$/,function(e){return n=e,""}),n||(n=y.Values.getUnitType(e)),[r,n]};if(_!==m&&p.isString(_)&&p.isString(m)){u="";var E=0,T=0,O=[],A=[],P=0,j=0,R=0;for(_=y.Hooks.fixColors(_),m=y.Hooks.fixColors(m);E<_.length&&T<m.length;){var N=_[E],M=m[T];if(/[\d\.-]/.test(N)&&/[\d\.-]/.test(M)){for(var L=N,D=M,F=".",U=".";++E<_.length;){if((N=_[E])===F)F="..";else if(!/\d/.test(N))break;L+=N}for(;++T<m.length;){if((M=m[T])===U)U="..";else if(!/\d/.test(M))break;D+=M}var q=y.Hooks.getUnit(_,E),B=y.Hooks.getUnit(m,T);if(E+=q.length,T+=B.length,q===B)L===D?u+=L+q:(u+="{"+O.length+(j?"!":"")+"}"+q,O.push(parseFloat(L)),A.push(parseFloat(D)));else{var V=parseFloat(L),z=parseFloat(D);u+=(P<5?"calc":"")+"("+(V?"{"+O.length+(j?"!":"")+"}":"0")+q+" + "+(z?"{"+(O.length+(V?1:0))+(j?"!":"")+"}":"0")+B+")",V&&(O.push(V),A.push(0)),z&&(O.push(0),A.push(z))}}else{if(N!==M){P=0;break}u+=N,E++,T++,0===P&&"c"===N||1===P&&"a"===N||2===P&&"l"===N||3===P&&"c"===N||P>=4&&"("===N?P++:(P&&P<5||P>=4&&")"===N&&--P<5)&&(P=0),0===j&&"r"===N||1===j&&"g"===N||2===j&&"b"===N||3===j&&"a"===N||j>=3&&"("===N?(3===j&&"a"===N&&(R=1),j++):R&&","===N?++R>3&&(j=R=0):(R&&j<(R?5:4)||j>=(R?4:3)&&")"===N&&--j<(R?5:4))&&(j=R=0)}}E===_.length&&T===m.length||(g.debug&&console.error('Trying to pattern match mis-matched strings ["'+m+'", "'+_+'"]'),u=r),u&&(O.length?(g.debug&&console.log('Pattern found "'+u+'" -> ',O,A,"["+_+","+m+"]"),_=O,m=A,x=k=""):u=r)}if(u||(_=(w=C(o,_))[0],k=w[1],m=(w=C(o,m))[0].replace(/^([+-\/*])=/,function(e,t){return S=t,""}),x=w[1],_=parseFloat(_)||0,m=parseFloat(m)||0,"%"===x&&(/^(fontSize|lineHeight)$/.test(o)?(m/=100,x="em"):/^scale/.test(o)?(m/=100,x=""):/(Red|Green|Blue)$/i.test(o)&&(m=m/100*255,x=""))),/[\/*]/.test(S))x=k;else if(k!==x&&0!==_)if(0===m)x=k;else{i=i||function(){var r={myParent:e.parentNode||n.body,position:y.getPropertyValue(e,"position"),fontSize:y.getPropertyValue(e,"fontSize")},o=r.position===I.lastPosition&&r.myParent===I.lastParent,i=r.fontSize===I.lastFontSize;I.lastParent=r.myParent,I.lastPosition=r.position,I.lastFontSize=r.fontSize;var a={};if(i&&o)a.emToPx=I.lastEmToPx,a.percentToPxWidth=I.lastPercentToPxWidth,a.percentToPxHeight=I.lastPercentToPxHeight;else{var s=d&&d.isSVG?n.createElementNS("http://www.w3.org/2000/svg","rect"):n.createElement("div");g.init(s),r.myParent.appendChild(s),f.each(["overflow","overflowX","overflowY"],function(e,t){g.CSS.setPropertyValue(s,t,"hidden")}),g.CSS.setPropertyValue(s,"position",r.position),g.CSS.setPropertyValue(s,"fontSize",r.fontSize),g.CSS.setPropertyValue(s,"boxSizing","content-box"),f.each(["minWidth","maxWidth","width","minHeight","maxHeight","height"],function(e,t){g.CSS.setPropertyValue(s,t,"100%")}),g.CSS.setPropertyValue(s,"paddingLeft","100em"),a.percentToPxWidth=I.lastPercentToPxWidth=(parseFloat(y.getPropertyValue(s,"width",null,!0))||1)/100,a.percentToPxHeight=I.lastPercentToPxHeight=(parseFloat(y.getPropertyValue(s,"height",null,!0))||1)/100,a.emToPx=I.lastEmToPx=(parseFloat(y.getPropertyValue(s,"paddingLeft"))||1)/100,r.myParent.removeChild(s)}return null===I.remToPx&&(I.remToPx=parseFloat(y.getPropertyValue(n.body,"fontSize"))||16),null===I.vwToPx&&(I.vwToPx=parseFloat(t.innerWidth)/100,I.vhToPx=parseFloat(t.innerHeight)/100),a.remToPx=I.remToPx,a.vwToPx=I.vwToPx,a.vhToPx=I.vhToPx,g.debug>=1&&console.log("Unit ratios: "+JSON.stringify(a),e),a}();var H=/margin|padding|left|right|width|text|word|letter/i.test(o)||/X$/.test(o)||"x"===o?"x":"y";switch(k){case"%":_*="x"===H?i.percentToPxWidth:i.percentToPxHeight;break;case"px":break;default:_*=i[k+"ToPx"]}switch(x){case"%":_*=1/("x"===H?i.percentToPxWidth:i.percentToPxHeight);break;case"px":break;default:_*=1/i[x+"ToPx"]}}switch(S){case"+":m=_+m;break;case"-":m=_-m;break;case"*":m*=_;break;case"/":m=_/m}c[o]={rootPropertyValue:h,startValue:_,currentValue:_,endValue:m,unitType:x,easing:b},u&&(c[o].pattern=u),g.debug&&console.log("tweensContainer ("+o+"): "+JSON.stringify(c[o]),e)}else g.debug&&console.log("Skipping ["+l+"] due to a lack of browser support.")};for(var L in h)if(h.hasOwnProperty(L)){var F=y.Names.camelCase(L),U=N(h[L]);if(l(y.Lists.colors,F)){var q=U[0],B=U[1],V=U[2];if(y.RegEx.isHex.test(q)){for(var z=["Red","Green","Blue"],H=y.Values.hexToRgb(q),W=V?y.Values.hexToRgb(V):r,$=0;$<z.length;$++){var G=[H[$]];B&&G.push(B),W!==r&&G.push(W[$]),M(F+z[$],G)}continue}}M(F,U)}c.element=e}c.element&&(y.Values.addClass(e,"velocity-animating"),D.push(c),(d=x(e))&&(""===s.queue&&(d.tweensContainer=c,d.opts=s),d.isAnimating=!0),A===C-1?(g.State.calls.push([D,u,s,null,_.resolver,null,0]),!1===g.State.isTicking&&(g.State.isTicking=!0,T())):A++)}if(!1!==g.mock&&(!0===g.mock?s.duration=s.delay=1:(s.duration*=parseFloat(g.mock)||1,s.delay*=parseFloat(g.mock)||1)),s.easing=E(s.easing,s.duration),s.begin&&!p.isFunction(s.begin)&&(s.begin=null),s.progress&&!p.isFunction(s.progress)&&(s.progress=null),s.complete&&!p.isFunction(s.complete)&&(s.complete=null),s.display!==r&&null!==s.display&&(s.display=s.display.toString().toLowerCase(),"auto"===s.display&&(s.display=g.CSS.Values.getDisplayType(e))),s.visibility!==r&&null!==s.visibility&&(s.visibility=s.visibility.toString().toLowerCase()),s.mobileHA=s.mobileHA&&g.State.isMobile&&!g.State.isGingerbread,!1===s.queue)if(s.delay){var b=g.State.delayedElements.count++;g.State.delayedElements[b]=e;var k=(a=b,function(){g.State.delayedElements[a]=!1,v()});x(e).delayBegin=(new Date).getTime(),x(e).delay=parseFloat(s.delay),x(e).delayTimer={setTimeout:setTimeout(v,parseFloat(s.delay)),next:k}}else v();else f.queue(e,s.queue,function(e,t){if(!0===t)return _.promise&&_.resolver(u),!0;g.velocityQueueEntryFlag=!0,v()});""!==s.queue&&"fx"!==s.queue||"inprogress"===f.queue(e)[0]||f.dequeue(e)}(o,e)}),(e=f.extend({},g.defaults,m)).loop=parseInt(e.loop,10);var F=2*e.loop-1;if(e.loop)for(var U=0;U<F;U++){var q={delay:e.delay,progress:e.progress};U===F-1&&(q.display=e.display,q.visibility=e.visibility,q.complete=e.complete),b(u,"reverse",q)}return o()}_.promise&&(h&&m&&!1===m.promiseRejectEmpty?_.resolver():_.rejecter())};(g=f.extend(b,g)).animate=b;var _=t.requestAnimationFrame||a;if(!g.State.isMobile&&n.hidden!==r){var w=function(){n.hidden?(_=function(e){return setTimeout(function(){e(!0)},16)},T()):_=t.requestAnimationFrame||a};w(),n.addEventListener("visibilitychange",w)}return e.Velocity=g,e!==t&&(e.fn.velocity=b,e.fn.velocity.defaults=g.defaults),f.each(["Down","Up"],function(e,t){g.Redirects["slide"+t]=function(e,n,o,i,a,s){var u=f.extend({},n),l=u.begin,c=u.complete,p={},h={height:"",marginTop:"",marginBottom:"",paddingTop:"",paddingBottom:""};u.display===r&&(u.display="Down"===t?"inline"===g.CSS.Values.getDisplayType(e)?"inline-block":"block":"none"),u.begin=function(){for(var n in 0===o&&l&&l.call(a,a),h)if(h.hasOwnProperty(n)){p[n]=e.style[n];var r=y.getPropertyValue(e,n);h[n]="Down"===t?[r,0]:[0,r]}p.overflow=e.style.overflow,e.style.overflow="hidden"},u.complete=function(){for(var t in p)p.hasOwnProperty(t)&&(e.style[t]=p[t]);o===i-1&&(c&&c.call(a,a),s&&s.resolver(a))},g(e,h,u)}}),f.each(["In","Out"],function(e,t){g.Redirects["fade"+t]=function(e,n,o,i,a,s){var u=f.extend({},n),l=u.complete,c={opacity:"In"===t?1:0};0!==o&&(u.begin=null),u.complete=o!==i-1?null:function(){l&&l.call(a,a),s&&s.resolver(a)},u.display===r&&(u.display="In"===t?"auto":"none"),g(this,c,u)}}),g}function x(e){var t=f.data(e,"velocity");return null===t?r:t}function k(e,t){var n=x(e);n&&n.delayTimer&&!n.delayPaused&&(n.delayRemaining=n.delay-t+n.delayBegin,n.delayPaused=!0,clearTimeout(n.delayTimer.setTimeout))}function S(e,t){var n=x(e);n&&n.delayTimer&&n.delayPaused&&(n.delayPaused=!1,n.delayTimer.setTimeout=setTimeout(n.delayTimer.next,n.delayRemaining))}function C(e,n,r,o){var i=4,a=.001,s=1e-7,u=10,l=11,c=1/(l-1),f="Float32Array"in t;if(4!==arguments.length)return!1;for(var p=0;p<4;++p)if("number"!=typeof arguments[p]||isNaN(arguments[p])||!isFinite(arguments[p]))return!1;e=Math.min(e,1),r=Math.min(r,1),e=Math.max(e,0),r=Math.max(r,0);var h=f?new Float32Array(l):new Array(l);function d(e,t){return 1-3*t+3*e}function m(e,t){return 3*t-6*e}function g(e){return 3*e}function v(e,t,n){return((d(t,n)*e+m(t,n))*e+g(t))*e}function y(e,t,n){return 3*d(t,n)*e*e+2*m(t,n)*e+g(t)}function b(t){for(var n=0,o=1,f=l-1;o!==f&&h[o]<=t;++o)n+=c;var p=n+(t-h[--o])/(h[o+1]-h[o])*c,d=y(p,e,r);return d>=a?function(t,n){for(var o=0;o<i;++o){var a=y(n,e,r);if(0===a)return n;n-=(v(n,e,r)-t)/a}return n}(t,p):0===d?p:function(t,n,o){var i,a,l=0;do{(i=v(a=n+(o-n)/2,e,r)-t)>0?o=a:n=a}while(Math.abs(i)>s&&++l<u);return a}(t,n,n+c)}var _=!1;function w(){_=!0,e===n&&r===o||function(){for(var t=0;t<l;++t)h[t]=v(t*c,e,r)}()}var x=function(t){return _||w(),e===n&&r===o?t:0===t?0:1===t?1:v(b(t),n,o)};x.getControlPoints=function(){return[{x:e,y:n},{x:r,y:o}]};var k="generateBezier("+[e,n,r,o]+")";return x.toString=function(){return k},x}function E(e,t){var n=e;return p.isString(e)?g.Easings[e]||(n=!1):n=p.isArray(e)&&1===e.length?function(e){return function(t){return Math.round(t*e)*(1/e)}}.apply(null,e):p.isArray(e)&&2===e.length?v.apply(null,e.concat([t])):!(!p.isArray(e)||4!==e.length)&&C.apply(null,e),!1===n&&(n=g.Easings[g.defaults.easing]?g.defaults.easing:m),n}function T(e){if(e){var t=g.timestamp&&!0!==e?e:s.now(),n=g.State.calls.length;n>1e4&&(g.State.calls=function(e){for(var t=-1,n=e?e.length:0,r=[];++t<n;){var o=e[t];o&&r.push(o)}return r}(g.State.calls),n=g.State.calls.length);for(var o=0;o<n;o++)if(g.State.calls[o]){var a=g.State.calls[o],u=a[0],l=a[2],c=a[3],h=!c,d=null,m=a[5],v=a[6];if(c||(c=g.State.calls[o][3]=t-16),m){if(!0!==m.resume)continue;c=a[3]=Math.round(t-v-16),a[5]=null}v=a[6]=t-c;for(var b=Math.min(v/l.duration,1),w=0,k=u.length;w<k;w++){var S=u[w],C=S.element;if(x(C)){var E=!1;if(l.display!==r&&null!==l.display&&"none"!==l.display){if("flex"===l.display){f.each(["-webkit-box","-moz-box","-ms-flexbox","-webkit-flex"],function(e,t){y.setPropertyValue(C,"display",t)})}y.setPropertyValue(C,"display",l.display)}for(var A in l.visibility!==r&&"hidden"!==l.visibility&&y.setPropertyValue(C,"visibility",l.visibility),S)if(S.hasOwnProperty(A)&&"element"!==A){var P,j=S[A],R=p.isString(j.easing)?g.Easings[j.easing]:j.easing;if(p.isString(j.pattern)){var N=1===b?function(e,t,n){var r=j.endValue[t];return n?Math.round(r):r}:function(e,t,n){var r=j.startValue[t],o=j.endValue[t]-r,i=r+o*R(b,l,o);return n?Math.round(i):i};P=j.pattern.replace(/{(\d+)(!)?}/g,N)}else if(1===b)P=j.endValue;else{var M=j.endValue-j.startValue;P=j.startValue+M*R(b,l,M)}if(!h&&P===j.currentValue)continue;if(j.currentValue=P,"tween"===A)d=P;else{var L;if(y.Hooks.registered[A]){L=y.Hooks.getRoot(A);var I=x(C).rootPropertyValueCache[L];I&&(j.rootPropertyValue=I)}var D=y.setPropertyValue(C,A,j.currentValue+(i<9&&0===parseFloat(P)?"":j.unitType),j.rootPropertyValue,j.scrollData);y.Hooks.registered[A]&&(y.Normalizations.registered[L]?x(C).rootPropertyValueCache[L]=y.Normalizations.registered[L]("extract",null,D[1]):x(C).rootPropertyValueCache[L]=D[1]),"transform"===D[0]&&(E=!0)}}l.mobileHA&&x(C).transformCache.translate3d===r&&(x(C).transformCache.translate3d="(0px, 0px, 0px)",E=!0),E&&y.flushTransformCache(C)}}l.display!==r&&"none"!==l.display&&(g.State.calls[o][2].display=!1),l.visibility!==r&&"hidden"!==l.visibility&&(g.State.calls[o][2].visibility=!1),l.progress&&l.progress.call(a[1],a[1],b,Math.max(0,c+l.duration-t),c,d),1===b&&O(o)}}g.State.isTicking&&_(T)}function O(e,t){if(!g.State.calls[e])return!1;for(var n=g.State.calls[e][0],o=g.State.calls[e][1],i=g.State.calls[e][2],a=g.State.calls[e][4],s=!1,u=0,l=n.length;u<l;u++){var c=n[u].element;t||i.loop||("none"===i.display&&y.setPropertyValue(c,"display",i.display),"hidden"===i.visibility&&y.setPropertyValue(c,"visibility",i.visibility));var p=x(c);if(!0!==i.loop&&(f.queue(c)[1]===r||!/\.velocityQueueEntryFlag/i.test(f.queue(c)[1]))&&p){p.isAnimating=!1,p.rootPropertyValueCache={};var h=!1;f.each(y.Lists.transforms3D,function(e,t){var n=/^scale/.test(t)?1:0,o=p.transformCache[t];p.transformCache[t]!==r&&new RegExp("^\\("+n+"[^.]").test(o)&&(h=!0,delete p.transformCache[t])}),i.mobileHA&&(h=!0,delete p.transformCache.translate3d),h&&y.flushTransformCache(c),y.Values.removeClass(c,"velocity-animating")}if(!t&&i.complete&&!i.loop&&u===l-1)try{i.complete.call(o,o)}catch(e){setTimeout(function(){throw e},1)}a&&!0!==i.loop&&a(o),p&&!0===i.loop&&!t&&(f.each(p.tweensContainer,function(e,t){if(/^rotate/.test(e)&&(parseFloat(t.startValue)-parseFloat(t.endValue))%360==0){var n=t.startValue;t.startValue=t.endValue,t.endValue=n}/^backgroundPosition/.test(e)&&100===parseFloat(t.endValue)&&"%"===t.unitType&&(t.endValue=0,t.startValue=100)}),g(c,"reverse",{loop:!0,delay:i.delay})),!1!==i.queue&&f.dequeue(c,i.queue)}g.State.calls[e]=!1;for(var d=0,m=g.State.calls.length;d<m;d++)if(!1!==g.State.calls[d]){s=!0;break}!1===s&&(g.State.isTicking=!1,delete g.State.calls,g.State.calls=[])}jQuery.fn.velocity=jQuery.fn.animate}(window.jQuery||window.Zepto||window,window,window?window.document:void 0)})},function(e,t,n){"use strict";var r=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}();function i(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function a(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function s(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}var u={each:n(323),extend:n(324),forEach:n(71),isEqual:n(128),keys:n(17),omit:n(135),map:n(329)},l=n(1),c=n(36),f=n(0),p=n(343),h=n(345).default,d=n(92),m="undefined"!=typeof window&&(window.requestAnimationFrame||window.webkitRequestAnimationFrame||window.mozRequestAnimationFrame||function(e){window.setTimeout(e,0)});m="undefined"!=typeof window&&m.bind(window);var g="undefined"!=typeof window&&(window.cancelAnimationFrame||window.webkitCancelAnimationFrame||window.mozCancelAnimationFrame||function(e){window.clearTimeout(e)});g="undefined"!=typeof window&&g.bind(window);var v=function(e){function t(){var e,n,r;i(this,t);for(var o=arguments.length,s=Array(o),u=0;u<o;u++)s[u]=arguments[u];return n=r=a(this,(e=t.__proto__||Object.getPrototypeOf(t)).call.apply(e,[this].concat(s))),r.lastState="appear",r.componentWillEnter=function(e,t){r.lastState=t?"appear":"enter"},r.componentWillExit=function(){r.lastState="exit"},r.endListener=function(e,t){switch(r.lastState){case"appear":r.props.willAppearFunc(e,t);break;case"enter":r.props.willEnterFunc(e,t);break;case"exit":r.props.willLeaveFunc(e,t)}},a(r,n)}return s(t,l.Component),o(t,[{key:"componentWillUnmount",value:function(){d.Utilities.removeData(c.findDOMNode(this),["velocity","fxqueue"])}},{key:"render",value:function(){var e=u.omit(this.props,u.keys(t.propTypes));return l.createElement(h,r({},e,{timeout:null,addEndListener:this.endListener,appear:!0,onEnter:this.componentWillEnter,onExit:this.componentWillExit}),this.props.children)}}]),t}();v.propTypes={children:f.element.isRequired,willAppearFunc:f.func.isRequired,willEnterFunc:f.func.isRequired,willLeaveFunc:f.func.isRequired};var y=function(e){function t(e){i(this,t);var n=a(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n._scheduledAnimationFrame=null,n._scheduledAnimationRunFrames=[],n._entering=[],n._leaving=[],n._timers=[],n._unmounted=!1,n.childWillAppear=n.childWillAppear.bind(n),n.childWillEnter=n.childWillEnter.bind(n),n.childWillLeave=n.childWillLeave.bind(n),n._runAnimations=n._runAnimations.bind(n),n._wrapChild=n._wrapChild.bind(n),n}return s(t,l.Component),o(t,[{key:"componentWillUnmount",value:function(){this._scheduledAnimationFrame&&g(this._scheduledAnimationFrame),u.forEach(this._timers,function(e){clearTimeout(e)}),u.forEach(this._scheduledAnimationRunFrames,function(e){g(e)}),this._unmounted=!0}},{key:"render",value:function(){var e=u.omit(this.props,u.keys(t.propTypes));return l.createElement(p,e,this.constructor.disabledForTest||d.velocityReactServerShim?this.props.children:l.Children.map(this.props.children,this._wrapChild))}},{key:"childWillAppear",value:function(e,t){var n=this;if(this.props.runOnMount)this.childWillEnter(e,t);else{this._finishAnimation(e,this.props.enter);var r=setTimeout(function(){var e=n._timers.indexOf(r);e>=0&&n._timers.splice(e,1),t()},0);this._timers.push(r)}}},{key:"childWillEnter",value:function(e,t){this._shortCircuitAnimation(this.props.enter,t)||(this._finishAnimation(e,this.props.leave,{begin:void 0,complete:void 0}),u.forEach(this.props.enterHideStyle,function(t,n){d.CSS.setPropertyValue(e,n,t)}),this._entering.push({node:e,doneFn:t}),this._schedule())}},{key:"childWillLeave",value:function(e,t){this._shortCircuitAnimation(this.props.leave,t)||(this._leaving.push({node:e,doneFn:t}),this._schedule())}},{key:"_shortCircuitAnimation",value:function(e,t){return!(!document.hidden&&null!=this._parseAnimationProp(e).animation)&&(t(),!0)}},{key:"_schedule",value:function(){this._scheduledAnimationFrame||(this._scheduledAnimationFrame=m(this._runAnimations))}},{key:"_runAnimations",value:function(){this._scheduledAnimationFrame=null,this._runAnimation(!0,this._entering,this.props.enter),this._runAnimation(!1,this._leaving,this.props.leave),this._entering=[],this._leaving=[]}},{key:"_parseAnimationProp",value:function(e){var t,n,r;return"string"==typeof e?(t=e,r=null,n={}):(t=null!=e?e.animation:null,r=null!=e?e.style:null,n=u.omit(e,"animation","style")),{animation:t,style:r,opts:n}}},{key:"_runAnimation",value:function(e,t,n){var r=this;if(0!==t.length){var o=u.map(t,"node"),i=u.map(t,"doneFn"),a=this._parseAnimationProp(n),s=a.animation,l=a.style,c=a.opts;e&&(u.isEqual(this.props.enterShowStyle,{display:""})&&(/^(fade|slide)/.test(s)||/In$/.test(s))||(l=u.extend({},this.props.enterShowStyle,l))),null!=l&&u.each(l,function(e,t){d.hook(o,t,e)});var f,p=function(){r._unmounted||i.map(function(e){e()})};if(e?(p(),p=null):d(o,"stop"),p&&c.complete){var h=c.complete;f=function(){p(),h.apply(this,arguments)}}else f=p||c.complete;var g=m(function(){var e=r._scheduledAnimationRunFrames.indexOf(g);e>=0&&r._scheduledAnimationRunFrames.splice(e,1),d(o,s,u.extend({},c,{complete:f}))});this._scheduledAnimationRunFrames.push(g)}}},{key:"_finishAnimation",value:function(e,t,n){var r=this._parseAnimationProp(t),o=r.animation,i=r.style,a=u.extend({},r.opts,n);null!=i&&u.each(i,function(t,n){d.hook(e,n,t)}),null!=o&&(d(e,o,a),d(e,"finishAll",!0))}},{key:"_wrapChild",value:function(e){return e?l.createElement(v,{key:e.key,willAppearFunc:this.childWillAppear,willEnterFunc:this.childWillEnter,willLeaveFunc:this.childWillLeave},e):null}}]),t}();y.disabledForTest=!1,y.propTypes={runOnMount:f.bool,enter:f.any,leave:f.any,children:f.any,enterHideStyle:f.object,enterShowStyle:f.object},y.defaultProps={runOnMount:!1,enter:null,leave:null,enterHideStyle:{display:"none"},enterShowStyle:{display:""}},e.exports=y},function(e,t,n){e.exports=n(71)},function(e,t,n){e.exports=n(325)},function(e,t,n){var r=n(27),o=n(326),i=n(86),a=o(function(e,t){r(t,i(t),e)});e.exports=a},function(e,t,n){var r=n(327),o=n(328);e.exports=function(e){return r(function(t,n){var r=-1,i=n.length,a=i>1?n[i-1]:void 0,s=i>2?n[2]:void 0;for(a=e.length>3&&"function"==typeof a?(i--,a):void 0,s&&o(n[0],n[1],s)&&(a=i<3?void 0:a,i=1),t=Object(t);++r<i;){var u=n[r];u&&e(t,u,r,a)}return t})}},function(e,t,n){var r=n(47),o=n(141),i=n(142);e.exports=function(e,t){return i(o(e,t,r),e+"")}},function(e,t,n){var r=n(50),o=n(31),i=n(74),a=n(11);e.exports=function(e,t,n){if(!a(n))return!1;var s=typeof t;return!!("number"==s?o(n)&&i(t,n.length):"string"==s&&t in n)&&r(n[t],e)}},function(e,t,n){var r=n(85),o=n(330),i=n(342),a=n(5);e.exports=function(e,t){return(a(e)?r:i)(e,o(t,3))}},function(e,t,n){var r=n(331),o=n(334),i=n(47),a=n(5),s=n(339);e.exports=function(e){return"function"==typeof e?e:null==e?i:"object"==typeof e?a(e)?o(e[0],e[1]):r(e):s(e)}},function(e,t,n){var r=n(332),o=n(333),i=n(144);e.exports=function(e){var t=o(e);return 1==t.length&&t[0][2]?i(t[0][0],t[0][1]):function(n){return n===e||r(n,e,t)}}},function(e,t,n){var r=n(80),o=n(79),i=1,a=2;e.exports=function(e,t,n,s){var u=n.length,l=u,c=!s;if(null==e)return!l;for(e=Object(e);u--;){var f=n[u];if(c&&f[2]?f[1]!==e[f[0]]:!(f[0]in e))return!1}for(;++u<l;){var p=(f=n[u])[0],h=e[p],d=f[1];if(c&&f[2]){if(void 0===h&&!(p in e))return!1}else{var m=new r;if(s)var g=s(h,d,p,e,t,m);if(!(void 0===g?o(d,h,i|a,s,m):g))return!1}}return!0}},function(e,t,n){var r=n(143),o=n(17);e.exports=function(e){for(var t=o(e),n=t.length;n--;){var i=t[n],a=e[i];t[n]=[i,a,r(a)]}return t}},function(e,t,n){var r=n(79),o=n(335),i=n(336),a=n(89),s=n(143),u=n(144),l=n(32),c=1,f=2;e.exports=function(e,t){return a(e)&&s(t)?u(l(e),t):function(n){var a=o(n,e);return void 0===a&&a===t?i(n,e):r(t,a,c|f)}}},function(e,t,n){var r=n(91);e.exports=function(e,t,n){var o=null==e?void 0:r(e,t);return void 0===o?n:o}},function(e,t,n){var r=n(337),o=n(338);e.exports=function(e,t){return null!=e&&o(e,t,r)}},function(e,t){e.exports=function(e,t){return null!=e&&t in Object(e)}},function(e,t,n){var r=n(54),o=n(72),i=n(5),a=n(74),s=n(75),u=n(32);e.exports=function(e,t,n){for(var l=-1,c=(t=r(t,e)).length,f=!1;++l<c;){var p=u(t[l]);if(!(f=null!=e&&n(e,p)))break;e=e[p]}return f||++l!=c?f:!!(c=null==e?0:e.length)&&s(c)&&a(p,c)&&(i(e)||o(e))}},function(e,t,n){var r=n(340),o=n(341),i=n(89),a=n(32);e.exports=function(e){return i(e)?r(a(e)):o(e)}},function(e,t){e.exports=function(e){return function(t){return null==t?void 0:t[e]}}},function(e,t,n){var r=n(91);e.exports=function(e){return function(t){return r(t,e)}}},function(e,t,n){var r=n(122),o=n(31);e.exports=function(e,t){var n=-1,i=o(e)?Array(e.length):[];return r(e,function(e,r,o){i[++n]=t(e,r,o)}),i}},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=s(n(0)),o=s(n(1)),i=n(145),a=n(344);function s(e){return e&&e.__esModule?e:{default:e}}function u(){return(u=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}function l(e){if(void 0===e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}var c=Object.values||function(e){return Object.keys(e).map(function(t){return e[t]})},f=function(e){var t,n;function r(t,n){var r,o=(r=e.call(this,t,n)||this).handleExited.bind(l(l(r)));return r.state={handleExited:o,firstRender:!0},r}n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var i=r.prototype;return i.getChildContext=function(){return{transitionGroup:{isMounting:!this.appeared}}},i.componentDidMount=function(){this.appeared=!0,this.mounted=!0},i.componentWillUnmount=function(){this.mounted=!1},r.getDerivedStateFromProps=function(e,t){var n=t.children,r=t.handleExited;return{children:t.firstRender?(0,a.getInitialChildMapping)(e,r):(0,a.getNextChildMapping)(e,n,r),firstRender:!1}},i.handleExited=function(e,t){var n=(0,a.getChildMapping)(this.props.children);e.key in n||(e.props.onExited&&e.props.onExited(t),this.mounted&&this.setState(function(t){var n=u({},t.children);return delete n[e.key],{children:n}}))},i.render=function(){var e=this.props,t=e.component,n=e.childFactory,r=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(e,["component","childFactory"]),i=c(this.state.children).map(n);return delete r.appear,delete r.enter,delete r.exit,null===t?i:o.default.createElement(t,r,i)},r}(o.default.Component);f.childContextTypes={transitionGroup:r.default.object.isRequired},f.propTypes={},f.defaultProps={component:"div",childFactory:function(e){return e}};var p=(0,i.polyfill)(f);t.default=p,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.getChildMapping=o,t.mergeChildMappings=i,t.getInitialChildMapping=function(e,t){return o(e.children,function(n){return(0,r.cloneElement)(n,{onExited:t.bind(null,n),in:!0,appear:a(n,"appear",e),enter:a(n,"enter",e),exit:a(n,"exit",e)})})},t.getNextChildMapping=function(e,t,n){var s=o(e.children),u=i(t,s);return Object.keys(u).forEach(function(o){var i=u[o];if((0,r.isValidElement)(i)){var l=o in t,c=o in s,f=t[o],p=(0,r.isValidElement)(f)&&!f.props.in;!c||l&&!p?c||!l||p?c&&l&&(0,r.isValidElement)(f)&&(u[o]=(0,r.cloneElement)(i,{onExited:n.bind(null,i),in:f.props.in,exit:a(i,"exit",e),enter:a(i,"enter",e)})):u[o]=(0,r.cloneElement)(i,{in:!1}):u[o]=(0,r.cloneElement)(i,{onExited:n.bind(null,i),in:!0,exit:a(i,"exit",e),enter:a(i,"enter",e)})}}),u};var r=n(1);function o(e,t){var n=Object.create(null);return e&&r.Children.map(e,function(e){return e}).forEach(function(e){n[e.key]=function(e){return t&&(0,r.isValidElement)(e)?t(e):e}(e)}),n}function i(e,t){function n(n){return n in t?t[n]:e[n]}e=e||{},t=t||{};var r,o=Object.create(null),i=[];for(var a in e)a in t?i.length&&(o[a]=i,i=[]):i.push(a);var s={};for(var u in t){if(o[u])for(r=0;r<o[u].length;r++){var l=o[u][r];s[o[u][r]]=n(l)}s[u]=n(u)}for(r=0;r<i.length;r++)s[i[r]]=n(i[r]);return s}function a(e,t,n){return null!=n[t]?n[t]:e.props[t]}},function(e,t,n){"use strict";t.__esModule=!0,t.default=t.EXITING=t.ENTERED=t.ENTERING=t.EXITED=t.UNMOUNTED=void 0;var r=function(e){if(e&&e.__esModule)return e;var t={};if(null!=e)for(var n in e)if(Object.prototype.hasOwnProperty.call(e,n)){var r=Object.defineProperty&&Object.getOwnPropertyDescriptor?Object.getOwnPropertyDescriptor(e,n):{};r.get||r.set?Object.defineProperty(t,n,r):t[n]=e[n]}return t.default=e,t}(n(0)),o=s(n(1)),i=s(n(36)),a=n(145);n(346);function s(e){return e&&e.__esModule?e:{default:e}}var u="unmounted";t.UNMOUNTED=u;var l="exited";t.EXITED=l;var c="entering";t.ENTERING=c;var f="entered";t.ENTERED=f;t.EXITING="exiting";var p=function(e){var t,n;function r(t,n){var r;r=e.call(this,t,n)||this;var o,i=n.transitionGroup,a=i&&!i.isMounting?t.enter:t.appear;return r.appearStatus=null,t.in?a?(o=l,r.appearStatus=c):o=f:o=t.unmountOnExit||t.mountOnEnter?u:l,r.state={status:o},r.nextCallback=null,r}n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var a=r.prototype;return a.getChildContext=function(){return{transitionGroup:null}},r.getDerivedStateFromProps=function(e,t){return e.in&&t.status===u?{status:l}:null},a.componentDidMount=function(){this.updateStatus(!0,this.appearStatus)},a.componentDidUpdate=function(e){var t=null;if(e!==this.props){var n=this.state.status;this.props.in?n!==c&&n!==f&&(t=c):n!==c&&n!==f||(t="exiting")}this.updateStatus(!1,t)},a.componentWillUnmount=function(){this.cancelNextCallback()},a.getTimeouts=function(){var e,t,n,r=this.props.timeout;return e=t=n=r,null!=r&&"number"!=typeof r&&(e=r.exit,t=r.enter,n=r.appear),{exit:e,enter:t,appear:n}},a.updateStatus=function(e,t){if(void 0===e&&(e=!1),null!==t){this.cancelNextCallback();var n=i.default.findDOMNode(this);t===c?this.performEnter(n,e):this.performExit(n)}else this.props.unmountOnExit&&this.state.status===l&&this.setState({status:u})},a.performEnter=function(e,t){var n=this,r=this.props.enter,o=this.context.transitionGroup?this.context.transitionGroup.isMounting:t,i=this.getTimeouts();t||r?(this.props.onEnter(e,o),this.safeSetState({status:c},function(){n.props.onEntering(e,o),n.onTransitionEnd(e,i.enter,function(){n.safeSetState({status:f},function(){n.props.onEntered(e,o)})})})):this.safeSetState({status:f},function(){n.props.onEntered(e)})},a.performExit=function(e){var t=this,n=this.props.exit,r=this.getTimeouts();n?(this.props.onExit(e),this.safeSetState({status:"exiting"},function(){t.props.onExiting(e),t.onTransitionEnd(e,r.exit,function(){t.safeSetState({status:l},function(){t.props.onExited(e)})})})):this.safeSetState({status:l},function(){t.props.onExited(e)})},a.cancelNextCallback=function(){null!==this.nextCallback&&(this.nextCallback.cancel(),this.nextCallback=null)},a.safeSetState=function(e,t){t=this.setNextCallback(t),this.setState(e,t)},a.setNextCallback=function(e){var t=this,n=!0;return this.nextCallback=function(r){n&&(n=!1,t.nextCallback=null,e(r))},this.nextCallback.cancel=function(){n=!1},this.nextCallback},a.onTransitionEnd=function(e,t,n){this.setNextCallback(n),e?(this.props.addEndListener&&this.props.addEndListener(e,this.nextCallback),null!=t&&setTimeout(this.nextCallback,t)):setTimeout(this.nextCallback,0)},a.render=function(){var e=this.state.status;if(e===u)return null;var t=this.props,n=t.children,r=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(t,["children"]);if(delete r.in,delete r.mountOnEnter,delete r.unmountOnExit,delete r.appear,delete r.enter,delete r.exit,delete r.timeout,delete r.addEndListener,delete r.onEnter,delete r.onEntering,delete r.onEntered,delete r.onExit,delete r.onExiting,delete r.onExited,"function"==typeof n)return n(e,r);var i=o.default.Children.only(n);return o.default.cloneElement(i,r)},r}(o.default.Component);function h(){}p.contextTypes={transitionGroup:r.object},p.childContextTypes={transitionGroup:function(){}},p.propTypes={},p.defaultProps={in:!1,mountOnEnter:!1,unmountOnExit:!1,appear:!1,enter:!0,exit:!0,onEnter:h,onEntering:h,onEntered:h,onExit:h,onExiting:h,onExited:h},p.UNMOUNTED=0,p.EXITED=1,p.ENTERING=2,p.ENTERED=3,p.EXITING=4;var d=(0,a.polyfill)(p);t.default=d},function(e,t,n){"use strict";t.__esModule=!0,t.classNamesShape=t.timeoutsShape=void 0;var r;(r=n(0))&&r.__esModule;t.timeoutsShape=null;t.classNamesShape=null},function(e,t,n){"use strict";var r={isObject:n(11)},o=n(92),i=0;e.exports={registerEffect:function(e,t){r.isObject(e)&&(t=e,e="");var n="VelocityHelper.animation."+i+++e;if(o.velocityReactServerShim)return n;if(void 0===o.RegisterEffect)throw"Velocity.RegisterEffect not found. You need to require 'velocity-animate/velocity.ui' at a top level for UI Pack.";return o.RegisterEffect(n,t),n}}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});var r=d(n(70)),o=d(n(349)),i=d(n(37)),a=d(n(42)),s=d(n(43)),u=d(n(44)),l=d(n(46)),c=d(n(1)),f=d(n(0)),p=d(n(352)),h=d(n(353));function d(e){return e&&e.__esModule?e:{default:e}}var m=function(e){function t(){return(0,a.default)(this,t),(0,u.default)(this,(t.__proto__||(0,i.default)(t)).apply(this,arguments))}return(0,l.default)(t,e),(0,s.default)(t,[{key:"shouldComponentUpdate",value:function(e){for(var t=this.props,n=(0,o.default)(e),r=0;r<n.length;r++){var i=n[r];if("animations"!==i)if(!(0,p.default)(t[i],e[i]))return!0}return!(0,h.default)(t.animations,e.animations,{strict:!0})}},{key:"render",value:function(){var e=this.props,t=e.animations,n=e.decorators,o=e.node,i=e.onClick,a=e.style,s=o.active,u=!o.children,l=[a.link,s?a.activeLink:null],f=(0,r.default)({container:l},a);return c.default.createElement(n.Container,{animations:t,decorators:n,node:o,onClick:i,style:f,terminal:u})}}]),t}(c.default.Component);m.propTypes={style:f.default.object.isRequired,decorators:f.default.object.isRequired,animations:f.default.oneOfType([f.default.object,f.default.bool]).isRequired,node:f.default.object.isRequired,onClick:f.default.func},t.default=m},function(e,t,n){e.exports={default:n(350),__esModule:!0}},function(e,t,n){n(351),e.exports=n(6).Object.keys},function(e,t,n){var r=n(38),o=n(30);n(108)("keys",function(){return function(e){return o(r(e))}})},function(e,t){e.exports=function(e,t,n,r){var o=n?n.call(r,e,t):void 0;if(void 0!==o)return!!o;if(e===t)return!0;if("object"!=typeof e||!e||"object"!=typeof t||!t)return!1;var i=Object.keys(e),a=Object.keys(t);if(i.length!==a.length)return!1;for(var s=Object.prototype.hasOwnProperty.bind(t),u=0;u<i.length;u++){var l=i[u];if(!s(l))return!1;var c=e[l],f=t[l];if(!1===(o=n?n.call(r,c,f,l):void 0)||void 0===o&&c!==f)return!1}return!0}},function(e,t,n){var r=Array.prototype.slice,o=n(354),i=n(355),a=e.exports=function(e,t,n){return n||(n={}),e===t||(e instanceof Date&&t instanceof Date?e.getTime()===t.getTime():!e||!t||"object"!=typeof e&&"object"!=typeof t?n.strict?e===t:e==t:function(e,t,n){var l,c;if(s(e)||s(t))return!1;if(e.prototype!==t.prototype)return!1;if(i(e))return!!i(t)&&(e=r.call(e),t=r.call(t),a(e,t,n));if(u(e)){if(!u(t))return!1;if(e.length!==t.length)return!1;for(l=0;l<e.length;l++)if(e[l]!==t[l])return!1;return!0}try{var f=o(e),p=o(t)}catch(e){return!1}if(f.length!=p.length)return!1;for(f.sort(),p.sort(),l=f.length-1;l>=0;l--)if(f[l]!=p[l])return!1;for(l=f.length-1;l>=0;l--)if(c=f[l],!a(e[c],t[c],n))return!1;return typeof e==typeof t}(e,t,n))};function s(e){return null==e}function u(e){return!(!e||"object"!=typeof e||"number"!=typeof e.length)&&("function"==typeof e.copy&&"function"==typeof e.slice&&!(e.length>0&&"number"!=typeof e[0]))}},function(e,t){function n(e){var t=[];for(var n in e)t.push(n);return t}(e.exports="function"==typeof Object.keys?Object.keys:n).shim=n},function(e,t){var n="[object Arguments]"==function(){return Object.prototype.toString.call(arguments)}();function r(e){return"[object Arguments]"==Object.prototype.toString.call(e)}function o(e){return e&&"object"==typeof e&&"number"==typeof e.length&&Object.prototype.hasOwnProperty.call(e,"callee")&&!Object.prototype.propertyIsEnumerable.call(e,"callee")||!1}(t=e.exports=n?r:o).supported=r,t.unsupported=o},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.ContentPanel=t.Page=void 0;var r,o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),i=n(1),a=(r=i)&&r.__esModule?r:{default:r},s=n(357),u=n(358),l=n(28);var c=t.Page={START:"START",FILE:"FILE",SOURCE_DIR:"SOURCE_DIR",SEARCH:"SEARCH",FIND:"FIND",LOADING:"LOADING",INTERNAL_ERROR:"INTERNAL_ERROR"};t.ContentPanel=function(e){function t(){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);var e=function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,(t.__proto__||Object.getPrototypeOf(t)).call(this));return e.state={page:c.LOADING},e}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,a.default.Component),o(t,[{key:"componentDidMount",value:function(){this.query_api(this.props.path)}},{key:"componentDidUpdate",value:function(){document.title="cargo src - "+this.props.path}},{key:"UNSAFE_componentWillReceiveProps",value:function(e){e.path!=this.props.path&&this.query_api(e.path)}},{key:"query_api",value:function(e){e&&"/"!==e||(e=CONFIG.workspace_root);var t=this.props.app,n=this;(0,l.request)("src/"+e,function(e){e.Directory?n.setState({page:c.SOURCE_DIR,params:{path:e.Directory.path,files:e.Directory.files}}):e.File?(t.refreshStatus(),n.setState({page:c.FILE,params:{path:e.File.path,lines:e.File.lines,rendered:e.File.rendered}})):(console.log("Unexpected source data."),console.log(e))},"Error with source request for /src"+e,t)}},{key:"render",value:function(){var e=void 0;switch(this.state.page){case c.FILE:e=a.default.createElement(u.SourceView,{app:this.props.app,path:this.state.params.path,lines:this.state.params.lines,content:this.state.params.rendered,highlight:this.props.srcHighlight});break;case c.SOURCE_DIR:e=a.default.createElement(s.DirView,{app:this.props.app,path:this.state.params.path,files:this.state.params.files});break;case c.LOADING:e=a.default.createElement("div",{id:"div_loading"},"Loading...");break;case c.INTERNAL_ERROR:e="Server error?";break;case c.START:default:e=null}return e}}]),t}()},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});const r=n(1),o=n(150);t.DirView=(e=>{const t=e.path.join("/").replace("//","/");let n=e.files.map(n=>{const o="Directory"===n.kind?"div_entry_name div_dir_entry":"div_entry_name div_file_entry";return r.createElement("div",{className:"div_entry",key:n.name},r.createElement("span",{className:o,onClick:()=>e.app.loadSource(`${t}/${n.name}`)},n.name))});return 0==n.length&&(n=r.createElement("div",{className:"div_entry"},"<Empty directory>")),r.createElement("div",{id:"src"},r.createElement(o.BreadCrumbs,{path:e.path,app:e.app}),r.createElement("div",{id:"div_dir_view"},r.createElement("div",{id:"div_dir_contents"},n)))})},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.SourceView=void 0;var r=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},i=c(n(1)),a=function(e){if(e&&e.__esModule)return e;var t={};if(null!=e)for(var n in e)Object.prototype.hasOwnProperty.call(e,n)&&(t[n]=e[n]);return t.default=e,t}(n(28)),s=n(150),u=n(359),l=c(n(360));function c(e){return e&&e.__esModule?e:{default:e}}function f(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function p(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function h(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}function d(e){var t=[];return CONFIG.edit_command&&t.push({id:"line_number_menu_edit",label:"edit",fn:v,unstable:!0}),CONFIG.vcs_link&&t.push({id:"line_number_vcs",label:"view in VCS",fn:g}),i.default.createElement(u.Menu,{id:"div_line_number_menu",items:t,location:e.location,onClose:e.onClose,target:e.target})}function m(e){var t=[],n=e.target.dataset.link.split(":"),r=n[0];if("search"!=r){var o=a.parseLink(n);t.push({id:"ref_menu_goto_def",label:"goto def",fn:function(){return e.app.loadSource(r,o)}})}var s=e.target.dataset.docLink;s&&t.push({id:"ref_menu_view_docs",label:"view docs",fn:function(){return window.open(s,"_blank")}});var l=e.target.dataset.srcLink;l&&t.push({id:"ref_menu_view_source",label:"view source",fn:window.open(l,"_blank")}),t.push({id:"ref_menu_find_uses",label:"find all uses",fn:function(){return e.app.getUses(e.id)}});var c=e.target.dataset.impls;return c&&"0"!=c&&t.push({id:"ref_menu_find_impls",label:"find impls ("+c+")",fn:function(){return e.app.getImpls(e.id)}}),i.default.createElement(u.Menu,{id:"div_ref_menu",items:t,location:e.location,onClose:e.onClose,target:e.target})}function g(e){var t=e.dataset.link,n=t.lastIndexOf(":"),r=t.substring(CONFIG.workspace_root.length+2,n),o=t.substring(n+1);window.open(CONFIG.vcs_link.replace("$file",r).replace("$line",o),"_blank")}function v(e){a.request("edit?file="+e.dataset.link,function(){console.log("edit - success")},"Error with search edit",null)}t.SourceView=function(e){function t(e){f(this,t);var n=p(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.state={refMenu:null},n}return h(t,i.default.Component),r(t,[{key:"componentDidMount",value:function(){this.componentDidUpdate()}},{key:"componentDidUpdate",value:function(){var e=this;this.props.highlight?a.highlight_spans(this.props.highlight,"src_line_number_","src_line_","selected",this.node):a.unHighlight("selected",this.node);var t,n,r=$("#div_src_view").find(".src_link");r.off("click"),r.click(function(t){t.preventDefault(),t.stopPropagation();var n=t.target.dataset.docLink;if(n)window.open(n,"_blank");else{var r=t.target.dataset.link.split(":"),o=r[0];if("search"!==o){var i=a.parseLink(r);e.props.app.loadSource(o,i)}else e.props.app.getUses(r[1])}}),function(e){var t=function(t){var n=$(t),r=t.className.split(" ").find(function(e){return e.startsWith("class_id_")});if(void 0===r)return{v:void 0};n.hover(function(){$("."+r).css("background-color","#d5f3b5")},function(){$("."+r).css("background-color","")});var o=r.slice("class_id_".length);n.off("contextmenu"),n.on("contextmenu",function(t){e.setState({refMenu:{top:t.pageY,left:t.pageX,target:t.target,id:o}}),t.preventDefault(),t.stopPropagation()}),n.addClass("hand_cursor")},n=!0,r=!1,i=void 0;try{for(var a,s=$("#div_src_view").find(".class_id")[Symbol.iterator]();!(n=(a=s.next()).done);n=!0){var u=t(a.value);if("object"===(void 0===u?"undefined":o(u)))return u.v}}catch(e){r=!0,i=e}finally{try{!n&&s.return&&s.return()}finally{if(r)throw i}}}(this),this.props.highlight&&(t=this.props.highlight.line_start,n=t*$("#src_line_number_1").outerHeight()-100,document.getElementById("src").scroll(0,n))}},{key:"render",value:function(){var e=this,t=this.props.path.join("/"),n=0,r=[],o=this.props.content,a=this.props.lines&&this.props.lines.map(function(e){return n+=1,r.push(i.default.createElement(y,{count:n,path:t,key:"num-"+n})),i.default.createElement(b,{count:n,line:e,key:"line-"+n})}),u=null;if(this.state.refMenu){u=i.default.createElement(m,{app:this.props.app,location:this.state.refMenu,onClose:function(){return e.setState({refMenu:null})},target:this.state.refMenu.target,id:this.state.refMenu.id})}var c="content",f="source",p=void 0,h=this.state.currentView,d=function(t){e.setState({currentView:t})};return o&&a?(h=h||c,p=i.default.createElement("div",{className:"div_view_selector"},"[ ",i.default.createElement("a",{href:h==f?null:"javascript:void(0)",onClick:function(){return d(f)}},"source")," | ",i.default.createElement("a",{href:h==c?null:"javascript:void(0)",onClick:function(){return d(c)}},"rendered")," ]")):h=o?c:f,i.default.createElement("div",{id:"src",ref:function(t){return e.node=t}},i.default.createElement(s.BreadCrumbs,{app:this.props.app,path:this.props.path}),p,i.default.createElement("div",{id:"div_src_view"},h==c?i.default.createElement(l.default,{id:"div_src_contents",className:"div_src_html",allowedTags:["h1","h2","h3","h4","h5","h6","blockquote","p","a","ul","ol","nl","li","b","i","img","strong","em","strike","code","hr","br","div","table","thead","caption","tbody","tr","th","td","pre"],html:this.props.content}):i.default.createElement("div",{id:"div_src_contents"},i.default.createElement("span",{className:"div_src_line_numbers"},r),i.default.createElement("span",{className:"div_src_lines"},a)),u))}}]),t}();var y=function(e){function t(e){f(this,t);var n=p(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.menuFn=d,n}return h(t,u.MenuHost),r(t,[{key:"renderInner",value:function(){var e="src_line_number_"+this.props.count,t=this.props.path+":"+this.props.count;return i.default.createElement("div",{className:"div_src_line_number hand_cursor",id:e,"data-link":t},this.props.count)}}]),t}();function b(e){var t=e.line?e.line:"&nbsp",n="src_line_"+e.count;return i.default.createElement("div",{className:"div_src_line",id:n,dangerouslySetInnerHTML:{__html:t}})}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.MenuHost=t.Menu=void 0;var r,o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),i=n(1),a=(r=i)&&r.__esModule?r:{default:r};function s(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function u(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function l(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}t.Menu=function(e){function t(){return s(this,t),u(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return l(t,a.default.Component),o(t,[{key:"componentDidUpdate",value:function(){this.didRender()}},{key:"componentDidMount",value:function(){this.didRender()}},{key:"didRender",value:function(){0!=this.items().length?$("#"+this.props.id).offset(this.props.location):this.props.onClose()}},{key:"items",value:function(){var e=this,t=this;return this.props.items.filter(function(e){return!e.unstable||CONFIG.unstable_features}).map(function(n){var r=e.props.id+"_link menu_link";return a.default.createElement("div",{className:r,id:n.id,key:n.id,onClick:function(e){t.hideMenu(e),n.fn(t.props.target,t.props.location)}},n.label)})}},{key:"hideMenu",value:function(e){this.props.onClose(),e.preventDefault(),e.stopPropagation()}},{key:"render",value:function(){var e=this,t=this.items();return 0===t.length?null:a.default.createElement("span",null,a.default.createElement("div",{id:"div_overlay",onClick:function(t){return e.hideMenu(t)}}),a.default.createElement("div",{id:this.props.id,className:"div_menu"},t))}}]),t}(),t.MenuHost=function(e){function t(e){s(this,t);var n=u(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.state={menuOpen:null},n}return l(t,a.default.Component),o(t,[{key:"render",value:function(){var e=this,t=null;if(this.state.menuOpen){t=a.default.createElement(this.menuFn,{location:this.state.menuOpen,onClose:function(){return e.setState({menuOpen:null})},target:this.state.menuOpen.target,callbacks:this.props.callbacks})}var n=function(t){e.setState({menuOpen:{top:t.pageY,left:t.pageX,target:t.target}}),t.preventDefault(),t.stopPropagation()},r=null;return this.leftClick&&(r=n,n=null),a.default.createElement("span",{onContextMenu:n,onClick:r},this.renderInner(),t)}}]),t}()},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});var r=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),o=s(n(1)),i=s(n(0)),a=s(n(361));function s(e){return e&&e.__esModule?e:{default:e}}var u=["allowProtocolRelative","allowedAttributes","allowedClasses","allowedSchemes","allowedSchemesByTag","allowedTags","exclusiveFilter","nonTextTags","parser","selfClosing","transformTags"],l=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,o.default.Component),r(t,[{key:"render",value:function(){var e=this,t=u.reduce(function(t,n){var r=e.props[n];return void 0!==r&&(t[n]=r),t},{}),n=(0,a.default)(this.props.html,t);return o.default.createElement("div",{className:this.props.className,dangerouslySetInnerHTML:{__html:n},id:this.props.id,style:this.props.style})}}]),t}();t.default=l,l.defaultProps={html:""},l.propTypes={allowProtocolRelative:i.default.bool,allowedAttributes:i.default.objectOf(i.default.arrayOf(i.default.string)),allowedClasses:i.default.objectOf(i.default.arrayOf(i.default.string)),allowedSchemes:i.default.arrayOf(i.default.string),allowedSchemesByTag:i.default.objectOf(i.default.arrayOf(i.default.string)),allowedTags:i.default.arrayOf(i.default.string),exclusiveFilter:i.default.func,html:i.default.string.isRequired,nonTextTags:i.default.arrayOf(i.default.string),parser:i.default.any,selfClosing:i.default.arrayOf(i.default.string),transformTags:i.default.objectOf(i.default.oneOf([i.default.func,i.default.string])),className:i.default.string,id:i.default.string,style:i.default.any}},function(e,t,n){"use strict";var r=n(33),o=n(388),i=n(389),a=n(390),s=n(391),u=n(392),l=n(393),c=n(394),f=n(397),p=n(418);function h(e,t){e&&Object.keys(e).forEach(function(n){t(e[n],n)})}function d(e,t){return{}.hasOwnProperty.call(e,t)}function m(e,t){var n=[];return h(e,function(e){t(e)&&n.push(e)}),n}e.exports=v;var g=/^[^\0\t\n\f\r \/<=>]+$/;function v(e,t,n){var b="";function _(e,t){var n=this;this.tag=e,this.attribs=t||{},this.tagPosition=b.length,this.text="",this.updateParentNodeText=function(){O.length&&(O[O.length-1].text+=n.text)}}t?(t=o(v.defaults,t)).parser?t.parser=o(y,t.parser):t.parser=y:(t=v.defaults).parser=y;var w,x,k=t.nonTextTags||["script","style","textarea"];t.allowedAttributes&&(w={},x={},h(t.allowedAttributes,function(e,t){w[t]=[];var n=[];e.forEach(function(e){u(e)&&e.indexOf("*")>=0?n.push(i(e).replace(/\\\*/g,".*")):w[t].push(e)}),x[t]=new RegExp("^("+n.join("|")+")$")}));var S={};h(t.allowedClasses,function(e,t){w&&(d(w,t)||(w[t]=[]),w[t].push("class")),S[t]=e});var C,E={};h(t.transformTags,function(e,t){var n;"function"==typeof e?n=e:"string"==typeof e&&(n=v.simpleTransform(e)),"*"===t?C=n:E[t]=n});var T=0,O=[],A={},P={},j=!1,R=0,N=new r.Parser({onopentag:function(e,n){if(j)R++;else{var r=new _(e,n);O.push(r);var o,i=!1,u=!!r.text;d(E,e)&&(o=E[e](e,n),r.attribs=n=o.attribs,void 0!==o.text&&(r.innerText=o.text),e!==o.tagName&&(r.name=e=o.tagName,P[T]=o.tagName)),C&&(o=C(e,n),r.attribs=n=o.attribs,e!==o.tagName&&(r.name=e=o.tagName,P[T]=o.tagName)),t.allowedTags&&-1===t.allowedTags.indexOf(e)&&(i=!0,-1!==k.indexOf(e)&&(j=!0,R=1),A[T]=!0),T++,i||(b+="<"+e,(!w||d(w,e)||w["*"])&&h(n,function(n,o){if(g.test(o)){var i,u=!1;if(!w||d(w,e)&&-1!==w[e].indexOf(o)||w["*"]&&-1!==w["*"].indexOf(o)||d(x,e)&&x[e].test(o)||x["*"]&&x["*"].test(o))u=!0;else if(w&&w[e]){var v=!0,y=!1,_=void 0;try{for(var k,C=w[e][Symbol.iterator]();!(v=(k=C.next()).done);v=!0){var E=k.value;if(l(E)&&E.name&&E.name===o){u=!0;var T="";if(!0===E.multiple){var O=n.split(" "),A=!0,P=!1,j=void 0;try{for(var R,N=O[Symbol.iterator]();!(A=(R=N.next()).done);A=!0){var I=R.value;-1!==E.values.indexOf(I)&&(""===T?T=I:T+=" "+I)}}catch(e){P=!0,j=e}finally{try{!A&&N.return&&N.return()}finally{if(P)throw j}}}else E.values.indexOf(n)>=0&&(T=n);n=T}}}catch(e){y=!0,_=e}finally{try{!v&&C.return&&C.return()}finally{if(y)throw _}}}if(u){if(-1!==t.allowedSchemesAppliedToAttributes.indexOf(o)&&L(e,n))return void delete r.attribs[o];if("iframe"===e&&"src"===o){var D=!0;try{(i=p.parse(n,!1,!0))&&null===i.host&&null===i.protocol?D=d(t,"allowIframeRelativeUrls")?t.allowIframeRelativeUrls:!t.allowedIframeHostnames:t.allowedIframeHostnames&&(D=t.allowedIframeHostnames.find(function(e){return e===i.hostname}))}catch(e){D=!1}if(!D)return void delete r.attribs[o]}if("srcset"===o)try{if(h(i=c.parse(n),function(e){L("srcset",e.url)&&(e.evil=!0)}),!(i=m(i,function(e){return!e.evil})).length)return void delete r.attribs[o];n=c.stringify(m(i,function(e){return!e.evil})),r.attribs[o]=n}catch(e){return void delete r.attribs[o]}if("class"===o&&!(n=function(e,t){if(!t)return e;return(e=e.split(/\s+/)).filter(function(e){return-1!==t.indexOf(e)}).join(" ")}(n,S[e])).length)return void delete r.attribs[o];if("style"===o)try{if(0===(n=function(e){return e.nodes[0].nodes.reduce(function(e,t){return e.push(t.prop+":"+t.value+";"),e},[]).join("")}(function(e,t){if(!t)return e;var n,r=a(e),o=e.nodes[0];n=t[o.selector]&&t["*"]?s(a(t[o.selector]),t["*"],function(e,t){if(Array.isArray(e))return e.concat(t)}):t[o.selector]||t["*"];n&&(r.nodes[0].nodes=o.nodes.reduce(function(e){return function(t,n){if(e.hasOwnProperty(n.prop)){var r=e[n.prop].some(function(e){return e.test(n.value)});r&&t.push(n)}return t}}(n),[]));return r}(f.parse(e+" {"+n+"}"),t.allowedStyles))).length)return void delete r.attribs[o]}catch(e){return void delete r.attribs[o]}b+=" "+o,n.length&&(b+='="'+M(n,!0)+'"')}else delete r.attribs[o]}else delete r.attribs[o]}),-1!==t.selfClosing.indexOf(e)?b+=" />":(b+=">",!r.innerText||u||t.textFilter||(b+=r.innerText)))}},ontext:function(e){if(!j){var n,r=O[O.length-1];if(r&&(n=r.tag,e=void 0!==r.innerText?r.innerText:e),"script"===n||"style"===n)b+=e;else{var o=M(e,!1);t.textFilter?b+=t.textFilter(o):b+=o}if(O.length)O[O.length-1].text+=e}},onclosetag:function(e){if(j){if(--R)return;j=!1}var n=O.pop();if(n){if(j=!1,A[--T])return delete A[T],void n.updateParentNodeText();P[T]&&(e=P[T],delete P[T]),t.exclusiveFilter&&t.exclusiveFilter(n)?b=b.substr(0,n.tagPosition):(n.updateParentNodeText(),-1===t.selfClosing.indexOf(e)&&(b+="</"+e+">"))}}},t.parser);return N.write(e),N.end(),b;function M(e,n){return"string"!=typeof e&&(e+=""),t.parser.decodeEntities&&(e=e.replace(/&/g,"&amp;").replace(/</g,"&lt;").replace(/\>/g,"&gt;"),n&&(e=e.replace(/\"/g,"&quot;"))),e=e.replace(/&(?![a-zA-Z0-9#]{1,20};)/g,"&amp;").replace(/</g,"&lt;").replace(/\>/g,"&gt;"),n&&(e=e.replace(/\"/g,"&quot;")),e}function L(e,n){var r=(n=(n=n.replace(/[\x00-\x20]+/g,"")).replace(/<\!\-\-.*?\-\-\>/g,"")).match(/^([a-zA-Z]+)\:/);if(!r)return!!n.match(/^[\/\\]{2}/)&&!t.allowProtocolRelative;var o=r[1].toLowerCase();return d(t.allowedSchemesByTag,e)?-1===t.allowedSchemesByTag[e].indexOf(o):!t.allowedSchemes||-1===t.allowedSchemes.indexOf(o)}}var y={decodeEntities:!0};v.defaults={allowedTags:["h3","h4","h5","h6","blockquote","p","a","ul","ol","nl","li","b","i","strong","em","strike","code","hr","br","div","table","thead","caption","tbody","tr","th","td","pre","iframe"],allowedAttributes:{a:["href","name","target"],img:["src"]},selfClosing:["img","br","hr","area","base","basefont","input","link","meta"],allowedSchemes:["http","https","ftp","mailto"],allowedSchemesByTag:{},allowedSchemesAppliedToAttributes:["href","src","cite"],allowProtocolRelative:!0},v.simpleTransform=function(e,t,n){return n=void 0===n||n,t=t||{},function(r,o){var i;if(n)for(i in t)o[i]=t[i];else o=t;return{tagName:e,attribs:o}}}},function(e){e.exports={0:65533,128:8364,130:8218,131:402,132:8222,133:8230,134:8224,135:8225,136:710,137:8240,138:352,139:8249,140:338,142:381,145:8216,146:8217,147:8220,148:8221,149:8226,150:8211,151:8212,152:732,153:8482,154:353,155:8250,156:339,158:382,159:376}},function(e,t,n){"use strict";var r,o="object"==typeof Reflect?Reflect:null,i=o&&"function"==typeof o.apply?o.apply:function(e,t,n){return Function.prototype.apply.call(e,t,n)};r=o&&"function"==typeof o.ownKeys?o.ownKeys:Object.getOwnPropertySymbols?function(e){return Object.getOwnPropertyNames(e).concat(Object.getOwnPropertySymbols(e))}:function(e){return Object.getOwnPropertyNames(e)};var a=Number.isNaN||function(e){return e!=e};function s(){s.init.call(this)}e.exports=s,s.EventEmitter=s,s.prototype._events=void 0,s.prototype._eventsCount=0,s.prototype._maxListeners=void 0;var u=10;function l(e){return void 0===e._maxListeners?s.defaultMaxListeners:e._maxListeners}function c(e,t,n,r){var o,i,a,s;if("function"!=typeof n)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof n);if(void 0===(i=e._events)?(i=e._events=Object.create(null),e._eventsCount=0):(void 0!==i.newListener&&(e.emit("newListener",t,n.listener?n.listener:n),i=e._events),a=i[t]),void 0===a)a=i[t]=n,++e._eventsCount;else if("function"==typeof a?a=i[t]=r?[n,a]:[a,n]:r?a.unshift(n):a.push(n),(o=l(e))>0&&a.length>o&&!a.warned){a.warned=!0;var u=new Error("Possible EventEmitter memory leak detected. "+a.length+" "+String(t)+" listeners added. Use emitter.setMaxListeners() to increase limit");u.name="MaxListenersExceededWarning",u.emitter=e,u.type=t,u.count=a.length,s=u,console&&console.warn&&console.warn(s)}return e}function f(e,t,n){var r={fired:!1,wrapFn:void 0,target:e,type:t,listener:n},o=function(){for(var e=[],t=0;t<arguments.length;t++)e.push(arguments[t]);this.fired||(this.target.removeListener(this.type,this.wrapFn),this.fired=!0,i(this.listener,this.target,e))}.bind(r);return o.listener=n,r.wrapFn=o,o}function p(e,t,n){var r=e._events;if(void 0===r)return[];var o=r[t];return void 0===o?[]:"function"==typeof o?n?[o.listener||o]:[o]:n?function(e){for(var t=new Array(e.length),n=0;n<t.length;++n)t[n]=e[n].listener||e[n];return t}(o):d(o,o.length)}function h(e){var t=this._events;if(void 0!==t){var n=t[e];if("function"==typeof n)return 1;if(void 0!==n)return n.length}return 0}function d(e,t){for(var n=new Array(t),r=0;r<t;++r)n[r]=e[r];return n}Object.defineProperty(s,"defaultMaxListeners",{enumerable:!0,get:function(){return u},set:function(e){if("number"!=typeof e||e<0||a(e))throw new RangeError('The value of "defaultMaxListeners" is out of range. It must be a non-negative number. Received '+e+".");u=e}}),s.init=function(){void 0!==this._events&&this._events!==Object.getPrototypeOf(this)._events||(this._events=Object.create(null),this._eventsCount=0),this._maxListeners=this._maxListeners||void 0},s.prototype.setMaxListeners=function(e){if("number"!=typeof e||e<0||a(e))throw new RangeError('The value of "n" is out of range. It must be a non-negative number. Received '+e+".");return this._maxListeners=e,this},s.prototype.getMaxListeners=function(){return l(this)},s.prototype.emit=function(e){for(var t=[],n=1;n<arguments.length;n++)t.push(arguments[n]);var r="error"===e,o=this._events;if(void 0!==o)r=r&&void 0===o.error;else if(!r)return!1;if(r){var a;if(t.length>0&&(a=t[0]),a instanceof Error)throw a;var s=new Error("Unhandled error."+(a?" ("+a.message+")":""));throw s.context=a,s}var u=o[e];if(void 0===u)return!1;if("function"==typeof u)i(u,this,t);else{var l=u.length,c=d(u,l);for(n=0;n<l;++n)i(c[n],this,t)}return!0},s.prototype.addListener=function(e,t){return c(this,e,t,!1)},s.prototype.on=s.prototype.addListener,s.prototype.prependListener=function(e,t){return c(this,e,t,!0)},s.prototype.once=function(e,t){if("function"!=typeof t)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof t);return this.on(e,f(this,e,t)),this},s.prototype.prependOnceListener=function(e,t){if("function"!=typeof t)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof t);return this.prependListener(e,f(this,e,t)),this},s.prototype.removeListener=function(e,t){var n,r,o,i,a;if("function"!=typeof t)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof t);if(void 0===(r=this._events))return this;if(void 0===(n=r[e]))return this;if(n===t||n.listener===t)0==--this._eventsCount?this._events=Object.create(null):(delete r[e],r.removeListener&&this.emit("removeListener",e,n.listener||t));else if("function"!=typeof n){for(o=-1,i=n.length-1;i>=0;i--)if(n[i]===t||n[i].listener===t){a=n[i].listener,o=i;break}if(o<0)return this;0===o?n.shift():function(e,t){for(;t+1<e.length;t++)e[t]=e[t+1];e.pop()}(n,o),1===n.length&&(r[e]=n[0]),void 0!==r.removeListener&&this.emit("removeListener",e,a||t)}return this},s.prototype.off=s.prototype.removeListener,s.prototype.removeAllListeners=function(e){var t,n,r;if(void 0===(n=this._events))return this;if(void 0===n.removeListener)return 0===arguments.length?(this._events=Object.create(null),this._eventsCount=0):void 0!==n[e]&&(0==--this._eventsCount?this._events=Object.create(null):delete n[e]),this;if(0===arguments.length){var o,i=Object.keys(n);for(r=0;r<i.length;++r)"removeListener"!==(o=i[r])&&this.removeAllListeners(o);return this.removeAllListeners("removeListener"),this._events=Object.create(null),this._eventsCount=0,this}if("function"==typeof(t=n[e]))this.removeListener(e,t);else if(void 0!==t)for(r=t.length-1;r>=0;r--)this.removeListener(e,t[r]);return this},s.prototype.listeners=function(e){return p(this,e,!0)},s.prototype.rawListeners=function(e){return p(this,e,!1)},s.listenerCount=function(e,t){return"function"==typeof e.listenerCount?e.listenerCount(t):h.call(e,t)},s.prototype.listenerCount=h,s.prototype.eventNames=function(){return this._eventsCount>0?r(this._events):[]}},function(e,t,n){var r=n(34),o=/\s+/g,i=n(155),a=n(365);function s(e,t,n){"object"==typeof e?(n=t,t=e,e=null):"function"==typeof t&&(n=t,t=u),this._callback=e,this._options=t||u,this._elementCB=n,this.dom=[],this._done=!1,this._tagStack=[],this._parser=this._parser||null}var u={normalizeWhitespace:!1,withStartIndices:!1,withEndIndices:!1};s.prototype.onparserinit=function(e){this._parser=e},s.prototype.onreset=function(){s.call(this,this._callback,this._options,this._elementCB)},s.prototype.onend=function(){this._done||(this._done=!0,this._parser=null,this._handleCallback(null))},s.prototype._handleCallback=s.prototype.onerror=function(e){if("function"==typeof this._callback)this._callback(e,this.dom);else if(e)throw e},s.prototype.onclosetag=function(){var e=this._tagStack.pop();this._options.withEndIndices&&e&&(e.endIndex=this._parser.endIndex),this._elementCB&&this._elementCB(e)},s.prototype._createDomElement=function(e){if(!this._options.withDomLvl1)return e;var t;for(var n in t="tag"===e.type?Object.create(a):Object.create(i),e)e.hasOwnProperty(n)&&(t[n]=e[n]);return t},s.prototype._addDomElement=function(e){var t=this._tagStack[this._tagStack.length-1],n=t?t.children:this.dom,r=n[n.length-1];e.next=null,this._options.withStartIndices&&(e.startIndex=this._parser.startIndex),this._options.withEndIndices&&(e.endIndex=this._parser.endIndex),r?(e.prev=r,r.next=e):e.prev=null,n.push(e),e.parent=t||null},s.prototype.onopentag=function(e,t){var n={type:"script"===e?r.Script:"style"===e?r.Style:r.Tag,name:e,attribs:t,children:[]},o=this._createDomElement(n);this._addDomElement(o),this._tagStack.push(o)},s.prototype.ontext=function(e){var t,n=this._options.normalizeWhitespace||this._options.ignoreWhitespace;if(!this._tagStack.length&&this.dom.length&&(t=this.dom[this.dom.length-1]).type===r.Text)n?t.data=(t.data+e).replace(o," "):t.data+=e;else if(this._tagStack.length&&(t=this._tagStack[this._tagStack.length-1])&&(t=t.children[t.children.length-1])&&t.type===r.Text)n?t.data=(t.data+e).replace(o," "):t.data+=e;else{n&&(e=e.replace(o," "));var i=this._createDomElement({data:e,type:r.Text});this._addDomElement(i)}},s.prototype.oncomment=function(e){var t=this._tagStack[this._tagStack.length-1];if(t&&t.type===r.Comment)t.data+=e;else{var n={data:e,type:r.Comment},o=this._createDomElement(n);this._addDomElement(o),this._tagStack.push(o)}},s.prototype.oncdatastart=function(){var e={children:[{data:"",type:r.Text}],type:r.CDATA},t=this._createDomElement(e);this._addDomElement(t),this._tagStack.push(t)},s.prototype.oncommentend=s.prototype.oncdataend=function(){this._tagStack.pop()},s.prototype.onprocessinginstruction=function(e,t){var n=this._createDomElement({name:e,data:t,type:r.Directive});this._addDomElement(n)},e.exports=s},function(e,t,n){var r=n(155),o=e.exports=Object.create(r),i={tagName:"name"};Object.keys(i).forEach(function(e){var t=i[e];Object.defineProperty(o,e,{get:function(){return this[t]||null},set:function(e){return this[t]=e,e}})})},function(e,t,n){var r=n(33),o=r.DomHandler,i=r.DomUtils;function a(e,t){this.init(e,t)}function s(e,t){return i.getElementsByTagName(e,t,!0)}function u(e,t){return i.getElementsByTagName(e,t,!0,1)[0]}function l(e,t,n){return i.getText(i.getElementsByTagName(e,t,n,1)).trim()}function c(e,t,n,r,o){var i=l(n,r,o);i&&(e[t]=i)}n(55)(a,o),a.prototype.init=o;var f=function(e){return"rss"===e||"feed"===e||"rdf:RDF"===e};a.prototype.onend=function(){var e,t,n={},r=u(f,this.dom);r&&("feed"===r.name?(t=r.children,n.type="atom",c(n,"id","id",t),c(n,"title","title",t),(e=u("link",t))&&(e=e.attribs)&&(e=e.href)&&(n.link=e),c(n,"description","subtitle",t),(e=l("updated",t))&&(n.updated=new Date(e)),c(n,"author","email",t,!0),n.items=s("entry",t).map(function(e){var t,n={};return c(n,"id","id",e=e.children),c(n,"title","title",e),(t=u("link",e))&&(t=t.attribs)&&(t=t.href)&&(n.link=t),(t=l("summary",e)||l("content",e))&&(n.description=t),(t=l("updated",e))&&(n.pubDate=new Date(t)),n})):(t=u("channel",r.children).children,n.type=r.name.substr(0,3),n.id="",c(n,"title","title",t),c(n,"link","link",t),c(n,"description","description",t),(e=l("lastBuildDate",t))&&(n.updated=new Date(e)),c(n,"author","managingEditor",t,!0),n.items=s("item",r.children).map(function(e){var t,n={};return c(n,"id","guid",e=e.children),c(n,"title","title",e),c(n,"link","link",e),c(n,"description","description",e),(t=l("pubDate",e))&&(n.pubDate=new Date(t)),n}))),this.dom=n,o.prototype._handleCallback.call(this,r?null:Error("couldn't find root of feed"))},e.exports=a},function(e,t,n){e.exports=o;var r=n(156);function o(e){r.call(this,new i(this),e)}function i(e){this.scope=e}n(55)(o,r),o.prototype.readable=!0;var a=n(33).EVENTS;Object.keys(a).forEach(function(e){if(0===a[e])i.prototype["on"+e]=function(){this.scope.emit(e)};else if(1===a[e])i.prototype["on"+e]=function(t){this.scope.emit(e,t)};else{if(2!==a[e])throw Error("wrong number of arguments!");i.prototype["on"+e]=function(t,n){this.scope.emit(e,t,n)}}})},function(e,t){},function(e,t,n){"use strict";var r=n(370).Buffer,o=r.isEncoding||function(e){switch((e=""+e)&&e.toLowerCase()){case"hex":case"utf8":case"utf-8":case"ascii":case"binary":case"base64":case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":case"raw":return!0;default:return!1}};function i(e){var t;switch(this.encoding=function(e){var t=function(e){if(!e)return"utf8";for(var t;;)switch(e){case"utf8":case"utf-8":return"utf8";case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return"utf16le";case"latin1":case"binary":return"latin1";case"base64":case"ascii":case"hex":return e;default:if(t)return;e=(""+e).toLowerCase(),t=!0}}(e);if("string"!=typeof t&&(r.isEncoding===o||!o(e)))throw new Error("Unknown encoding: "+e);return t||e}(e),this.encoding){case"utf16le":this.text=u,this.end=l,t=4;break;case"utf8":this.fillLast=s,t=4;break;case"base64":this.text=c,this.end=f,t=3;break;default:return this.write=p,void(this.end=h)}this.lastNeed=0,this.lastTotal=0,this.lastChar=r.allocUnsafe(t)}function a(e){return e<=127?0:e>>5==6?2:e>>4==14?3:e>>3==30?4:e>>6==2?-1:-2}function s(e){var t=this.lastTotal-this.lastNeed,n=function(e,t,n){if(128!=(192&t[0]))return e.lastNeed=0,"�";if(e.lastNeed>1&&t.length>1){if(128!=(192&t[1]))return e.lastNeed=1,"�";if(e.lastNeed>2&&t.length>2&&128!=(192&t[2]))return e.lastNeed=2,"�"}}(this,e);return void 0!==n?n:this.lastNeed<=e.length?(e.copy(this.lastChar,t,0,this.lastNeed),this.lastChar.toString(this.encoding,0,this.lastTotal)):(e.copy(this.lastChar,t,0,e.length),void(this.lastNeed-=e.length))}function u(e,t){if((e.length-t)%2==0){var n=e.toString("utf16le",t);if(n){var r=n.charCodeAt(n.length-1);if(r>=55296&&r<=56319)return this.lastNeed=2,this.lastTotal=4,this.lastChar[0]=e[e.length-2],this.lastChar[1]=e[e.length-1],n.slice(0,-1)}return n}return this.lastNeed=1,this.lastTotal=2,this.lastChar[0]=e[e.length-1],e.toString("utf16le",t,e.length-1)}function l(e){var t=e&&e.length?this.write(e):"";if(this.lastNeed){var n=this.lastTotal-this.lastNeed;return t+this.lastChar.toString("utf16le",0,n)}return t}function c(e,t){var n=(e.length-t)%3;return 0===n?e.toString("base64",t):(this.lastNeed=3-n,this.lastTotal=3,1===n?this.lastChar[0]=e[e.length-1]:(this.lastChar[0]=e[e.length-2],this.lastChar[1]=e[e.length-1]),e.toString("base64",t,e.length-n))}function f(e){var t=e&&e.length?this.write(e):"";return this.lastNeed?t+this.lastChar.toString("base64",0,3-this.lastNeed):t}function p(e){return e.toString(this.encoding)}function h(e){return e&&e.length?this.write(e):""}t.StringDecoder=i,i.prototype.write=function(e){if(0===e.length)return"";var t,n;if(this.lastNeed){if(void 0===(t=this.fillLast(e)))return"";n=this.lastNeed,this.lastNeed=0}else n=0;return n<e.length?t?t+this.text(e,n):this.text(e,n):t||""},i.prototype.end=function(e){var t=e&&e.length?this.write(e):"";return this.lastNeed?t+"�":t},i.prototype.text=function(e,t){var n=function(e,t,n){var r=t.length-1;if(r<n)return 0;var o=a(t[r]);if(o>=0)return o>0&&(e.lastNeed=o-1),o;if(--r<n||-2===o)return 0;if((o=a(t[r]))>=0)return o>0&&(e.lastNeed=o-2),o;if(--r<n||-2===o)return 0;if((o=a(t[r]))>=0)return o>0&&(2===o?o=0:e.lastNeed=o-3),o;return 0}(this,e,t);if(!this.lastNeed)return e.toString("utf8",t);this.lastTotal=n;var r=e.length-(n-this.lastNeed);return e.copy(this.lastChar,0,r),e.toString("utf8",t,r)},i.prototype.fillLast=function(e){if(this.lastNeed<=e.length)return e.copy(this.lastChar,this.lastTotal-this.lastNeed,0,this.lastNeed),this.lastChar.toString(this.encoding,0,this.lastTotal);e.copy(this.lastChar,this.lastTotal-this.lastNeed,0,e.length),this.lastNeed-=e.length}},function(e,t,n){var r=n(56),o=r.Buffer;function i(e,t){for(var n in e)t[n]=e[n]}function a(e,t,n){return o(e,t,n)}o.from&&o.alloc&&o.allocUnsafe&&o.allocUnsafeSlow?e.exports=r:(i(r,t),t.Buffer=a),i(o,a),a.from=function(e,t,n){if("number"==typeof e)throw new TypeError("Argument must not be a number");return o(e,t,n)},a.alloc=function(e,t,n){if("number"!=typeof e)throw new TypeError("Argument must be a number");var r=o(e);return void 0!==t?"string"==typeof n?r.fill(t,n):r.fill(t):r.fill(0),r},a.allocUnsafe=function(e){if("number"!=typeof e)throw new TypeError("Argument must be a number");return o(e)},a.allocUnsafeSlow=function(e){if("number"!=typeof e)throw new TypeError("Argument must be a number");return r.SlowBuffer(e)}},function(e,t,n){"use strict";t.byteLength=function(e){var t=l(e),n=t[0],r=t[1];return 3*(n+r)/4-r},t.toByteArray=function(e){for(var t,n=l(e),r=n[0],a=n[1],s=new i(function(e,t,n){return 3*(t+n)/4-n}(0,r,a)),u=0,c=a>0?r-4:r,f=0;f<c;f+=4)t=o[e.charCodeAt(f)]<<18|o[e.charCodeAt(f+1)]<<12|o[e.charCodeAt(f+2)]<<6|o[e.charCodeAt(f+3)],s[u++]=t>>16&255,s[u++]=t>>8&255,s[u++]=255&t;2===a&&(t=o[e.charCodeAt(f)]<<2|o[e.charCodeAt(f+1)]>>4,s[u++]=255&t);1===a&&(t=o[e.charCodeAt(f)]<<10|o[e.charCodeAt(f+1)]<<4|o[e.charCodeAt(f+2)]>>2,s[u++]=t>>8&255,s[u++]=255&t);return s},t.fromByteArray=function(e){for(var t,n=e.length,o=n%3,i=[],a=0,s=n-o;a<s;a+=16383)i.push(c(e,a,a+16383>s?s:a+16383));1===o?(t=e[n-1],i.push(r[t>>2]+r[t<<4&63]+"==")):2===o&&(t=(e[n-2]<<8)+e[n-1],i.push(r[t>>10]+r[t>>4&63]+r[t<<2&63]+"="));return i.join("")};for(var r=[],o=[],i="undefined"!=typeof Uint8Array?Uint8Array:Array,a="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",s=0,u=a.length;s<u;++s)r[s]=a[s],o[a.charCodeAt(s)]=s;function l(e){var t=e.length;if(t%4>0)throw new Error("Invalid string. Length must be a multiple of 4");var n=e.indexOf("=");return-1===n&&(n=t),[n,n===t?0:4-n%4]}function c(e,t,n){for(var o,i,a=[],s=t;s<n;s+=3)o=(e[s]<<16&16711680)+(e[s+1]<<8&65280)+(255&e[s+2]),a.push(r[(i=o)>>18&63]+r[i>>12&63]+r[i>>6&63]+r[63&i]);return a.join("")}o["-".charCodeAt(0)]=62,o["_".charCodeAt(0)]=63},function(e,t){t.read=function(e,t,n,r,o){var i,a,s=8*o-r-1,u=(1<<s)-1,l=u>>1,c=-7,f=n?o-1:0,p=n?-1:1,h=e[t+f];for(f+=p,i=h&(1<<-c)-1,h>>=-c,c+=s;c>0;i=256*i+e[t+f],f+=p,c-=8);for(a=i&(1<<-c)-1,i>>=-c,c+=r;c>0;a=256*a+e[t+f],f+=p,c-=8);if(0===i)i=1-l;else{if(i===u)return a?NaN:1/0*(h?-1:1);a+=Math.pow(2,r),i-=l}return(h?-1:1)*a*Math.pow(2,i-r)},t.write=function(e,t,n,r,o,i){var a,s,u,l=8*i-o-1,c=(1<<l)-1,f=c>>1,p=23===o?Math.pow(2,-24)-Math.pow(2,-77):0,h=r?0:i-1,d=r?1:-1,m=t<0||0===t&&1/t<0?1:0;for(t=Math.abs(t),isNaN(t)||t===1/0?(s=isNaN(t)?1:0,a=c):(a=Math.floor(Math.log(t)/Math.LN2),t*(u=Math.pow(2,-a))<1&&(a--,u*=2),(t+=a+f>=1?p/u:p*Math.pow(2,1-f))*u>=2&&(a++,u/=2),a+f>=c?(s=0,a=c):a+f>=1?(s=(t*u-1)*Math.pow(2,o),a+=f):(s=t*Math.pow(2,f-1)*Math.pow(2,o),a=0));o>=8;e[n+h]=255&s,h+=d,s/=256,o-=8);for(a=a<<o|s,l+=o;l>0;e[n+h]=255&a,h+=d,a/=256,l-=8);e[n+h-d]|=128*m}},function(e,t){var n={}.toString;e.exports=Array.isArray||function(e){return"[object Array]"==n.call(e)}},function(e,t,n){function r(e){this._cbs=e||{}}e.exports=r;var o=n(33).EVENTS;Object.keys(o).forEach(function(e){if(0===o[e])e="on"+e,r.prototype[e]=function(){this._cbs[e]&&this._cbs[e]()};else if(1===o[e])e="on"+e,r.prototype[e]=function(t){this._cbs[e]&&this._cbs[e](t)};else{if(2!==o[e])throw Error("wrong number of arguments");e="on"+e,r.prototype[e]=function(t,n){this._cbs[e]&&this._cbs[e](t,n)}}})},function(e,t,n){var r=e.exports;[n(376),n(382),n(383),n(384),n(385),n(386)].forEach(function(e){Object.keys(e).forEach(function(t){r[t]=e[t].bind(r)})})},function(e,t,n){var r=n(34),o=n(377),i=r.isTag;e.exports={getInnerHTML:function(e,t){return e.children?e.children.map(function(e){return o(e,t)}).join(""):""},getOuterHTML:o,getText:function e(t){if(Array.isArray(t))return t.map(e).join("");if(i(t))return"br"===t.name?"\n":e(t.children);if(t.type===r.CDATA)return e(t.children);if(t.type===r.Text)return t.data;return""}}},function(e,t,n){var r=n(378),o=n(379),i={__proto__:null,allowfullscreen:!0,async:!0,autofocus:!0,autoplay:!0,checked:!0,controls:!0,default:!0,defer:!0,disabled:!0,hidden:!0,ismap:!0,loop:!0,multiple:!0,muted:!0,open:!0,readonly:!0,required:!0,reversed:!0,scoped:!0,seamless:!0,selected:!0,typemustmatch:!0},a={__proto__:null,style:!0,script:!0,xmp:!0,iframe:!0,noembed:!0,noframes:!0,plaintext:!0,noscript:!0};var s={__proto__:null,area:!0,base:!0,basefont:!0,br:!0,col:!0,command:!0,embed:!0,frame:!0,hr:!0,img:!0,input:!0,isindex:!0,keygen:!0,link:!0,meta:!0,param:!0,source:!0,track:!0,wbr:!0},u=e.exports=function(e,t){Array.isArray(e)||e.cheerio||(e=[e]),t=t||{};for(var n="",o=0;o<e.length;o++){var i=e[o];"root"===i.type?n+=u(i.children,t):r.isTag(i)?n+=l(i,t):i.type===r.Directive?n+=c(i):i.type===r.Comment?n+=h(i):i.type===r.CDATA?n+=p(i):n+=f(i,t)}return n};function l(e,t){"svg"===e.name&&(t={decodeEntities:t.decodeEntities,xmlMode:!0});var n="<"+e.name,r=function(e,t){if(e){var n,r="";for(var a in e)r&&(r+=" "),!(n=e[a])&&i[a]?r+=a:r+=a+'="'+(t.decodeEntities?o.encodeXML(n):n)+'"';return r}}(e.attribs,t);return r&&(n+=" "+r),!t.xmlMode||e.children&&0!==e.children.length?(n+=">",e.children&&(n+=u(e.children,t)),s[e.name]&&!t.xmlMode||(n+="</"+e.name+">")):n+="/>",n}function c(e){return"<"+e.data+">"}function f(e,t){var n=e.data||"";return!t.decodeEntities||e.parent&&e.parent.name in a||(n=o.encodeXML(n)),n}function p(e){return"<![CDATA["+e.children[0].data+"]]>"}function h(e){return"\x3c!--"+e.data+"--\x3e"}},function(e,t){e.exports={Text:"text",Directive:"directive",Comment:"comment",Script:"script",Style:"style",Tag:"tag",CDATA:"cdata",isTag:function(e){return"tag"===e.type||"script"===e.type||"style"===e.type}}},function(e,t,n){var r=n(380),o=n(381);t.decode=function(e,t){return(!t||t<=0?o.XML:o.HTML)(e)},t.decodeStrict=function(e,t){return(!t||t<=0?o.XML:o.HTMLStrict)(e)},t.encode=function(e,t){return(!t||t<=0?r.XML:r.HTML)(e)},t.encodeXML=r.XML,t.encodeHTML4=t.encodeHTML5=t.encodeHTML=r.HTML,t.decodeXML=t.decodeXMLStrict=o.XML,t.decodeHTML4=t.decodeHTML5=t.decodeHTML=o.HTML,t.decodeHTML4Strict=t.decodeHTML5Strict=t.decodeHTMLStrict=o.HTMLStrict,t.escape=r.escape},function(e,t,n){var r=s(n(94)),o=u(r);t.XML=h(r,o);var i=s(n(93)),a=u(i);function s(e){return Object.keys(e).sort().reduce(function(t,n){return t[e[n]]="&"+n+";",t},{})}function u(e){var t=[],n=[];return Object.keys(e).forEach(function(e){1===e.length?t.push("\\"+e):n.push(e)}),n.unshift("["+t.join("")+"]"),new RegExp(n.join("|"),"g")}t.HTML=h(i,a);var l=/[^\0-\x7F]/g,c=/[\uD800-\uDBFF][\uDC00-\uDFFF]/g;function f(e){return"&#x"+e.charCodeAt(0).toString(16).toUpperCase()+";"}function p(e){return"&#x"+(1024*(e.charCodeAt(0)-55296)+e.charCodeAt(1)-56320+65536).toString(16).toUpperCase()+";"}function h(e,t){function n(t){return e[t]}return function(e){return e.replace(t,n).replace(c,p).replace(l,f)}}var d=u(r);t.escape=function(e){return e.replace(d,f).replace(c,p).replace(l,f)}},function(e,t,n){var r=n(93),o=n(154),i=n(94),a=n(153),s=l(i),u=l(r);function l(e){var t=Object.keys(e).join("|"),n=p(e),r=new RegExp("&(?:"+(t+="|#[xX][\\da-fA-F]+|#\\d+")+");","g");return function(e){return String(e).replace(r,n)}}var c=function(){for(var e=Object.keys(o).sort(f),t=Object.keys(r).sort(f),n=0,i=0;n<t.length;n++)e[i]===t[n]?(t[n]+=";?",i++):t[n]+=";";var a=new RegExp("&(?:"+t.join("|")+"|#[xX][\\da-fA-F]+;?|#\\d+;?)","g"),s=p(r);function u(e){return";"!==e.substr(-1)&&(e+=";"),s(e)}return function(e){return String(e).replace(a,u)}}();function f(e,t){return e<t?1:-1}function p(e){return function(t){return"#"===t.charAt(1)?"X"===t.charAt(2)||"x"===t.charAt(2)?a(parseInt(t.substr(3),16)):a(parseInt(t.substr(2),10)):e[t.slice(1,-1)]}}e.exports={XML:s,HTML:c,HTMLStrict:u}},function(e,t){var n=t.getChildren=function(e){return e.children},r=t.getParent=function(e){return e.parent};t.getSiblings=function(e){var t=r(e);return t?n(t):[e]},t.getAttributeValue=function(e,t){return e.attribs&&e.attribs[t]},t.hasAttrib=function(e,t){return!!e.attribs&&hasOwnProperty.call(e.attribs,t)},t.getName=function(e){return e.name}},function(e,t){t.removeElement=function(e){if(e.prev&&(e.prev.next=e.next),e.next&&(e.next.prev=e.prev),e.parent){var t=e.parent.children;t.splice(t.lastIndexOf(e),1)}},t.replaceElement=function(e,t){var n=t.prev=e.prev;n&&(n.next=t);var r=t.next=e.next;r&&(r.prev=t);var o=t.parent=e.parent;if(o){var i=o.children;i[i.lastIndexOf(e)]=t}},t.appendChild=function(e,t){if(t.parent=e,1!==e.children.push(t)){var n=e.children[e.children.length-2];n.next=t,t.prev=n,t.next=null}},t.append=function(e,t){var n=e.parent,r=e.next;if(t.next=r,t.prev=e,e.next=t,t.parent=n,r){if(r.prev=t,n){var o=n.children;o.splice(o.lastIndexOf(r),0,t)}}else n&&n.children.push(t)},t.prepend=function(e,t){var n=e.parent;if(n){var r=n.children;r.splice(r.lastIndexOf(e),0,t)}e.prev&&(e.prev.next=t),t.parent=n,t.prev=e.prev,t.next=e,e.prev=t}},function(e,t,n){var r=n(34).isTag;function o(e,t,n,r){for(var i,a=[],s=0,u=t.length;s<u&&!(e(t[s])&&(a.push(t[s]),--r<=0))&&(i=t[s].children,!(n&&i&&i.length>0&&(i=o(e,i,n,r),a=a.concat(i),(r-=i.length)<=0)));s++);return a}e.exports={filter:function(e,t,n,r){Array.isArray(t)||(t=[t]);"number"==typeof r&&isFinite(r)||(r=1/0);return o(e,t,!1!==n,r)},find:o,findOneChild:function(e,t){for(var n=0,r=t.length;n<r;n++)if(e(t[n]))return t[n];return null},findOne:function e(t,n){var o=null;for(var i=0,a=n.length;i<a&&!o;i++)r(n[i])&&(t(n[i])?o=n[i]:n[i].children.length>0&&(o=e(t,n[i].children)));return o},existsOne:function e(t,n){for(var o=0,i=n.length;o<i;o++)if(r(n[o])&&(t(n[o])||n[o].children.length>0&&e(t,n[o].children)))return!0;return!1},findAll:function(e,t){var n=[],o=t.slice();for(;o.length;){var i=o.shift();r(i)&&(i.children&&i.children.length>0&&o.unshift.apply(o,i.children),e(i)&&n.push(i))}return n}}},function(e,t,n){var r=n(34),o=t.isTag=r.isTag;t.testElement=function(e,t){for(var n in e)if(e.hasOwnProperty(n)){if("tag_name"===n){if(!o(t)||!e.tag_name(t.name))return!1}else if("tag_type"===n){if(!e.tag_type(t.type))return!1}else if("tag_contains"===n){if(o(t)||!e.tag_contains(t.data))return!1}else if(!t.attribs||!e[n](t.attribs[n]))return!1}else;return!0};var i={tag_name:function(e){return"function"==typeof e?function(t){return o(t)&&e(t.name)}:"*"===e?o:function(t){return o(t)&&t.name===e}},tag_type:function(e){return"function"==typeof e?function(t){return e(t.type)}:function(t){return t.type===e}},tag_contains:function(e){return"function"==typeof e?function(t){return!o(t)&&e(t.data)}:function(t){return!o(t)&&t.data===e}}};function a(e,t){return"function"==typeof t?function(n){return n.attribs&&t(n.attribs[e])}:function(n){return n.attribs&&n.attribs[e]===t}}function s(e,t){return function(n){return e(n)||t(n)}}t.getElements=function(e,t,n,r){var o=Object.keys(e).map(function(t){var n=e[t];return t in i?i[t](n):a(t,n)});return 0===o.length?[]:this.filter(o.reduce(s),t,n,r)},t.getElementById=function(e,t,n){return Array.isArray(t)||(t=[t]),this.findOne(a("id",e),t,!1!==n)},t.getElementsByTagName=function(e,t,n,r){return this.filter(i.tag_name(e),t,n,r)},t.getElementsByTagType=function(e,t,n,r){return this.filter(i.tag_type(e),t,n,r)}},function(e,t){t.removeSubsets=function(e){for(var t,n,r,o=e.length;--o>-1;){for(t=n=e[o],e[o]=null,r=!0;n;){if(e.indexOf(n)>-1){r=!1,e.splice(o,1);break}n=n.parent}r&&(e[o]=t)}return e};var n=1,r=2,o=4,i=8,a=16,s=t.compareDocumentPosition=function(e,t){var s,u,l,c,f,p,h=[],d=[];if(e===t)return 0;for(s=e;s;)h.unshift(s),s=s.parent;for(s=t;s;)d.unshift(s),s=s.parent;for(p=0;h[p]===d[p];)p++;return 0===p?n:(l=(u=h[p-1]).children,c=h[p],f=d[p],l.indexOf(c)>l.indexOf(f)?u===t?o|a:o:u===e?r|i:r)};t.uniqueSort=function(e){var t,n,i=e.length;for(e=e.slice();--i>-1;)t=e[i],(n=e.indexOf(t))>-1&&n<i&&e.splice(i,1);return e.sort(function(e,t){var n=s(e,t);return n&r?-1:n&o?1:0}),e}},function(e,t,n){function r(e){this._cbs=e||{},this.events=[]}e.exports=r;var o=n(33).EVENTS;Object.keys(o).forEach(function(e){if(0===o[e])e="on"+e,r.prototype[e]=function(){this.events.push([e]),this._cbs[e]&&this._cbs[e]()};else if(1===o[e])e="on"+e,r.prototype[e]=function(t){this.events.push([e,t]),this._cbs[e]&&this._cbs[e](t)};else{if(2!==o[e])throw Error("wrong number of arguments");e="on"+e,r.prototype[e]=function(t,n){this.events.push([e,t,n]),this._cbs[e]&&this._cbs[e](t,n)}}}),r.prototype.onreset=function(){this.events=[],this._cbs.onreset&&this._cbs.onreset()},r.prototype.restart=function(){this._cbs.onreset&&this._cbs.onreset();for(var e=0,t=this.events.length;e<t;e++)if(this._cbs[this.events[e][0]]){var n=this.events[e].length;1===n?this._cbs[this.events[e][0]]():2===n?this._cbs[this.events[e][0]](this.events[e][1]):this._cbs[this.events[e][0]](this.events[e][1],this.events[e][2])}}},function(e,t){e.exports=function(){for(var e={},t=0;t<arguments.length;t++){var r=arguments[t];for(var o in r)n.call(r,o)&&(e[o]=r[o])}return e};var n=Object.prototype.hasOwnProperty},function(e,t,n){(function(t){var n=1/0,r="[object Symbol]",o=/[\\^$.*+?()[\]{}|]/g,i=RegExp(o.source),a="object"==typeof t&&t&&t.Object===Object&&t,s="object"==typeof self&&self&&self.Object===Object&&self,u=a||s||Function("return this")(),l=Object.prototype.toString,c=u.Symbol,f=c?c.prototype:void 0,p=f?f.toString:void 0;function h(e){if("string"==typeof e)return e;if(function(e){return"symbol"==typeof e||function(e){return!!e&&"object"==typeof e}(e)&&l.call(e)==r}(e))return p?p.call(e):"";var t=e+"";return"0"==t&&1/e==-n?"-0":t}e.exports=function(e){var t;return(e=null==(t=e)?"":h(t))&&i.test(e)?e.replace(o,"\\$&"):e}}).call(this,n(8))},function(e,t,n){(function(e,n){var r=200,o="__lodash_hash_undefined__",i=9007199254740991,a="[object Arguments]",s="[object Boolean]",u="[object Date]",l="[object Function]",c="[object GeneratorFunction]",f="[object Map]",p="[object Number]",h="[object Object]",d="[object RegExp]",m="[object Set]",g="[object String]",v="[object Symbol]",y="[object ArrayBuffer]",b="[object DataView]",_="[object Float32Array]",w="[object Float64Array]",x="[object Int8Array]",k="[object Int16Array]",S="[object Int32Array]",C="[object Uint8Array]",E="[object Uint8ClampedArray]",T="[object Uint16Array]",O="[object Uint32Array]",A=/\w*$/,P=/^\[object .+?Constructor\]$/,j=/^(?:0|[1-9]\d*)$/,R={};R[a]=R["[object Array]"]=R[y]=R[b]=R[s]=R[u]=R[_]=R[w]=R[x]=R[k]=R[S]=R[f]=R[p]=R[h]=R[d]=R[m]=R[g]=R[v]=R[C]=R[E]=R[T]=R[O]=!0,R["[object Error]"]=R[l]=R["[object WeakMap]"]=!1;var N="object"==typeof e&&e&&e.Object===Object&&e,M="object"==typeof self&&self&&self.Object===Object&&self,L=N||M||Function("return this")(),I=t&&!t.nodeType&&t,D=I&&"object"==typeof n&&n&&!n.nodeType&&n,F=D&&D.exports===I;function U(e,t){return e.set(t[0],t[1]),e}function q(e,t){return e.add(t),e}function B(e,t,n,r){var o=-1,i=e?e.length:0;for(r&&i&&(n=e[++o]);++o<i;)n=t(n,e[o],o,e);return n}function V(e){var t=!1;if(null!=e&&"function"!=typeof e.toString)try{t=!!(e+"")}catch(e){}return t}function z(e){var t=-1,n=Array(e.size);return e.forEach(function(e,r){n[++t]=[r,e]}),n}function H(e,t){return function(n){return e(t(n))}}function W(e){var t=-1,n=Array(e.size);return e.forEach(function(e){n[++t]=e}),n}var $,G=Array.prototype,Y=Function.prototype,Q=Object.prototype,X=L["__core-js_shared__"],K=($=/[^.]+$/.exec(X&&X.keys&&X.keys.IE_PROTO||""))?"Symbol(src)_1."+$:"",J=Y.toString,Z=Q.hasOwnProperty,ee=Q.toString,te=RegExp("^"+J.call(Z).replace(/[\\^$.*+?()[\]{}|]/g,"\\$&").replace(/hasOwnProperty|(function).*?(?=\\\()| for .+?(?=\\\])/g,"$1.*?")+"$"),ne=F?L.Buffer:void 0,re=L.Symbol,oe=L.Uint8Array,ie=H(Object.getPrototypeOf,Object),ae=Object.create,se=Q.propertyIsEnumerable,ue=G.splice,le=Object.getOwnPropertySymbols,ce=ne?ne.isBuffer:void 0,fe=H(Object.keys,Object),pe=De(L,"DataView"),he=De(L,"Map"),de=De(L,"Promise"),me=De(L,"Set"),ge=De(L,"WeakMap"),ve=De(Object,"create"),ye=Ve(pe),be=Ve(he),_e=Ve(de),we=Ve(me),xe=Ve(ge),ke=re?re.prototype:void 0,Se=ke?ke.valueOf:void 0;function Ce(e){var t=-1,n=e?e.length:0;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function Ee(e){var t=-1,n=e?e.length:0;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function Te(e){var t=-1,n=e?e.length:0;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function Oe(e){this.__data__=new Ee(e)}function Ae(e,t){var n=He(e)||function(e){return function(e){return function(e){return!!e&&"object"==typeof e}(e)&&We(e)}(e)&&Z.call(e,"callee")&&(!se.call(e,"callee")||ee.call(e)==a)}(e)?function(e,t){for(var n=-1,r=Array(e);++n<e;)r[n]=t(n);return r}(e.length,String):[],r=n.length,o=!!r;for(var i in e)!t&&!Z.call(e,i)||o&&("length"==i||qe(i,r))||n.push(i);return n}function Pe(e,t,n){var r=e[t];Z.call(e,t)&&ze(r,n)&&(void 0!==n||t in e)||(e[t]=n)}function je(e,t){for(var n=e.length;n--;)if(ze(e[n][0],t))return n;return-1}function Re(e,t,n,r,o,i,P){var j;if(r&&(j=i?r(e,o,i,P):r(e)),void 0!==j)return j;if(!Ye(e))return e;var N=He(e);if(N){if(j=function(e){var t=e.length,n=e.constructor(t);t&&"string"==typeof e[0]&&Z.call(e,"index")&&(n.index=e.index,n.input=e.input);return n}(e),!t)return function(e,t){var n=-1,r=e.length;t||(t=Array(r));for(;++n<r;)t[n]=e[n];return t}(e,j)}else{var M=Ue(e),L=M==l||M==c;if($e(e))return function(e,t){if(t)return e.slice();var n=new e.constructor(e.length);return e.copy(n),n}(e,t);if(M==h||M==a||L&&!i){if(V(e))return i?e:{};if(j=function(e){return"function"!=typeof e.constructor||Be(e)?{}:(t=ie(e),Ye(t)?ae(t):{});var t}(L?{}:e),!t)return function(e,t){return Le(e,Fe(e),t)}(e,function(e,t){return e&&Le(t,Qe(t),e)}(j,e))}else{if(!R[M])return i?e:{};j=function(e,t,n,r){var o=e.constructor;switch(t){case y:return Me(e);case s:case u:return new o(+e);case b:return function(e,t){var n=t?Me(e.buffer):e.buffer;return new e.constructor(n,e.byteOffset,e.byteLength)}(e,r);case _:case w:case x:case k:case S:case C:case E:case T:case O:return function(e,t){var n=t?Me(e.buffer):e.buffer;return new e.constructor(n,e.byteOffset,e.length)}(e,r);case f:return function(e,t,n){return B(t?n(z(e),!0):z(e),U,new e.constructor)}(e,r,n);case p:case g:return new o(e);case d:return(l=new(a=e).constructor(a.source,A.exec(a))).lastIndex=a.lastIndex,l;case m:return function(e,t,n){return B(t?n(W(e),!0):W(e),q,new e.constructor)}(e,r,n);case v:return i=e,Se?Object(Se.call(i)):{}}var i;var a,l}(e,M,Re,t)}}P||(P=new Oe);var I=P.get(e);if(I)return I;if(P.set(e,j),!N)var D=n?function(e){return function(e,t,n){var r=t(e);return He(e)?r:function(e,t){for(var n=-1,r=t.length,o=e.length;++n<r;)e[o+n]=t[n];return e}(r,n(e))}(e,Qe,Fe)}(e):Qe(e);return function(e,t){for(var n=-1,r=e?e.length:0;++n<r&&!1!==t(e[n],n,e););}(D||e,function(o,i){D&&(o=e[i=o]),Pe(j,i,Re(o,t,n,r,i,e,P))}),j}function Ne(e){return!(!Ye(e)||(t=e,K&&K in t))&&(Ge(e)||V(e)?te:P).test(Ve(e));var t}function Me(e){var t=new e.constructor(e.byteLength);return new oe(t).set(new oe(e)),t}function Le(e,t,n,r){n||(n={});for(var o=-1,i=t.length;++o<i;){var a=t[o],s=r?r(n[a],e[a],a,n,e):void 0;Pe(n,a,void 0===s?e[a]:s)}return n}function Ie(e,t){var n,r,o=e.__data__;return("string"==(r=typeof(n=t))||"number"==r||"symbol"==r||"boolean"==r?"__proto__"!==n:null===n)?o["string"==typeof t?"string":"hash"]:o.map}function De(e,t){var n=function(e,t){return null==e?void 0:e[t]}(e,t);return Ne(n)?n:void 0}Ce.prototype.clear=function(){this.__data__=ve?ve(null):{}},Ce.prototype.delete=function(e){return this.has(e)&&delete this.__data__[e]},Ce.prototype.get=function(e){var t=this.__data__;if(ve){var n=t[e];return n===o?void 0:n}return Z.call(t,e)?t[e]:void 0},Ce.prototype.has=function(e){var t=this.__data__;return ve?void 0!==t[e]:Z.call(t,e)},Ce.prototype.set=function(e,t){return this.__data__[e]=ve&&void 0===t?o:t,this},Ee.prototype.clear=function(){this.__data__=[]},Ee.prototype.delete=function(e){var t=this.__data__,n=je(t,e);return!(n<0||(n==t.length-1?t.pop():ue.call(t,n,1),0))},Ee.prototype.get=function(e){var t=this.__data__,n=je(t,e);return n<0?void 0:t[n][1]},Ee.prototype.has=function(e){return je(this.__data__,e)>-1},Ee.prototype.set=function(e,t){var n=this.__data__,r=je(n,e);return r<0?n.push([e,t]):n[r][1]=t,this},Te.prototype.clear=function(){this.__data__={hash:new Ce,map:new(he||Ee),string:new Ce}},Te.prototype.delete=function(e){return Ie(this,e).delete(e)},Te.prototype.get=function(e){return Ie(this,e).get(e)},Te.prototype.has=function(e){return Ie(this,e).has(e)},Te.prototype.set=function(e,t){return Ie(this,e).set(e,t),this},Oe.prototype.clear=function(){this.__data__=new Ee},Oe.prototype.delete=function(e){return this.__data__.delete(e)},Oe.prototype.get=function(e){return this.__data__.get(e)},Oe.prototype.has=function(e){return this.__data__.has(e)},Oe.prototype.set=function(e,t){var n=this.__data__;if(n instanceof Ee){var o=n.__data__;if(!he||o.length<r-1)return o.push([e,t]),this;n=this.__data__=new Te(o)}return n.set(e,t),this};var Fe=le?H(le,Object):function(){return[]},Ue=function(e){return ee.call(e)};function qe(e,t){return!!(t=null==t?i:t)&&("number"==typeof e||j.test(e))&&e>-1&&e%1==0&&e<t}function Be(e){var t=e&&e.constructor;return e===("function"==typeof t&&t.prototype||Q)}function Ve(e){if(null!=e){try{return J.call(e)}catch(e){}try{return e+""}catch(e){}}return""}function ze(e,t){return e===t||e!=e&&t!=t}(pe&&Ue(new pe(new ArrayBuffer(1)))!=b||he&&Ue(new he)!=f||de&&"[object Promise]"!=Ue(de.resolve())||me&&Ue(new me)!=m||ge&&"[object WeakMap]"!=Ue(new ge))&&(Ue=function(e){var t=ee.call(e),n=t==h?e.constructor:void 0,r=n?Ve(n):void 0;if(r)switch(r){case ye:return b;case be:return f;case _e:return"[object Promise]";case we:return m;case xe:return"[object WeakMap]"}return t});var He=Array.isArray;function We(e){return null!=e&&function(e){return"number"==typeof e&&e>-1&&e%1==0&&e<=i}(e.length)&&!Ge(e)}var $e=ce||function(){return!1};function Ge(e){var t=Ye(e)?ee.call(e):"";return t==l||t==c}function Ye(e){var t=typeof e;return!!e&&("object"==t||"function"==t)}function Qe(e){return We(e)?Ae(e):function(e){if(!Be(e))return fe(e);var t=[];for(var n in Object(e))Z.call(e,n)&&"constructor"!=n&&t.push(n);return t}(e)}n.exports=function(e){return Re(e,!0,!0)}}).call(this,n(8),n(26)(e))},function(e,t,n){(function(e,n){var r=200,o="__lodash_hash_undefined__",i=800,a=16,s=9007199254740991,u="[object Arguments]",l="[object AsyncFunction]",c="[object Function]",f="[object GeneratorFunction]",p="[object Null]",h="[object Object]",d="[object Proxy]",m="[object Undefined]",g=/^\[object .+?Constructor\]$/,v=/^(?:0|[1-9]\d*)$/,y={};y["[object Float32Array]"]=y["[object Float64Array]"]=y["[object Int8Array]"]=y["[object Int16Array]"]=y["[object Int32Array]"]=y["[object Uint8Array]"]=y["[object Uint8ClampedArray]"]=y["[object Uint16Array]"]=y["[object Uint32Array]"]=!0,y[u]=y["[object Array]"]=y["[object ArrayBuffer]"]=y["[object Boolean]"]=y["[object DataView]"]=y["[object Date]"]=y["[object Error]"]=y[c]=y["[object Map]"]=y["[object Number]"]=y[h]=y["[object RegExp]"]=y["[object Set]"]=y["[object String]"]=y["[object WeakMap]"]=!1;var b="object"==typeof e&&e&&e.Object===Object&&e,_="object"==typeof self&&self&&self.Object===Object&&self,w=b||_||Function("return this")(),x=t&&!t.nodeType&&t,k=x&&"object"==typeof n&&n&&!n.nodeType&&n,S=k&&k.exports===x,C=S&&b.process,E=function(){try{var e=k&&k.require&&k.require("util").types;return e||C&&C.binding&&C.binding("util")}catch(e){}}(),T=E&&E.isTypedArray;var O,A,P,j=Array.prototype,R=Function.prototype,N=Object.prototype,M=w["__core-js_shared__"],L=R.toString,I=N.hasOwnProperty,D=(O=/[^.]+$/.exec(M&&M.keys&&M.keys.IE_PROTO||""))?"Symbol(src)_1."+O:"",F=N.toString,U=L.call(Object),q=RegExp("^"+L.call(I).replace(/[\\^$.*+?()[\]{}|]/g,"\\$&").replace(/hasOwnProperty|(function).*?(?=\\\()| for .+?(?=\\\])/g,"$1.*?")+"$"),B=S?w.Buffer:void 0,V=w.Symbol,z=w.Uint8Array,H=B?B.allocUnsafe:void 0,W=(A=Object.getPrototypeOf,P=Object,function(e){return A(P(e))}),$=Object.create,G=N.propertyIsEnumerable,Y=j.splice,Q=V?V.toStringTag:void 0,X=function(){try{var e=we(Object,"defineProperty");return e({},"",{}),e}catch(e){}}(),K=B?B.isBuffer:void 0,J=Math.max,Z=Date.now,ee=we(w,"Map"),te=we(Object,"create"),ne=function(){function e(){}return function(t){if(!Ne(t))return{};if($)return $(t);e.prototype=t;var n=new e;return e.prototype=void 0,n}}();function re(e){var t=-1,n=null==e?0:e.length;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function oe(e){var t=-1,n=null==e?0:e.length;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function ie(e){var t=-1,n=null==e?0:e.length;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function ae(e){var t=this.__data__=new oe(e);this.size=t.size}function se(e,t){var n=Oe(e),r=!n&&Te(e),o=!n&&!r&&Pe(e),i=!n&&!r&&!o&&Le(e),a=n||r||o||i,s=a?function(e,t){for(var n=-1,r=Array(e);++n<e;)r[n]=t(n);return r}(e.length,String):[],u=s.length;for(var l in e)!t&&!I.call(e,l)||a&&("length"==l||o&&("offset"==l||"parent"==l)||i&&("buffer"==l||"byteLength"==l||"byteOffset"==l)||xe(l,u))||s.push(l);return s}function ue(e,t,n){(void 0===n||Ee(e[t],n))&&(void 0!==n||t in e)||fe(e,t,n)}function le(e,t,n){var r=e[t];I.call(e,t)&&Ee(r,n)&&(void 0!==n||t in e)||fe(e,t,n)}function ce(e,t){for(var n=e.length;n--;)if(Ee(e[n][0],t))return n;return-1}function fe(e,t,n){"__proto__"==t&&X?X(e,t,{configurable:!0,enumerable:!0,value:n,writable:!0}):e[t]=n}re.prototype.clear=function(){this.__data__=te?te(null):{},this.size=0},re.prototype.delete=function(e){var t=this.has(e)&&delete this.__data__[e];return this.size-=t?1:0,t},re.prototype.get=function(e){var t=this.__data__;if(te){var n=t[e];return n===o?void 0:n}return I.call(t,e)?t[e]:void 0},re.prototype.has=function(e){var t=this.__data__;return te?void 0!==t[e]:I.call(t,e)},re.prototype.set=function(e,t){var n=this.__data__;return this.size+=this.has(e)?0:1,n[e]=te&&void 0===t?o:t,this},oe.prototype.clear=function(){this.__data__=[],this.size=0},oe.prototype.delete=function(e){var t=this.__data__,n=ce(t,e);return!(n<0||(n==t.length-1?t.pop():Y.call(t,n,1),--this.size,0))},oe.prototype.get=function(e){var t=this.__data__,n=ce(t,e);return n<0?void 0:t[n][1]},oe.prototype.has=function(e){return ce(this.__data__,e)>-1},oe.prototype.set=function(e,t){var n=this.__data__,r=ce(n,e);return r<0?(++this.size,n.push([e,t])):n[r][1]=t,this},ie.prototype.clear=function(){this.size=0,this.__data__={hash:new re,map:new(ee||oe),string:new re}},ie.prototype.delete=function(e){var t=_e(this,e).delete(e);return this.size-=t?1:0,t},ie.prototype.get=function(e){return _e(this,e).get(e)},ie.prototype.has=function(e){return _e(this,e).has(e)},ie.prototype.set=function(e,t){var n=_e(this,e),r=n.size;return n.set(e,t),this.size+=n.size==r?0:1,this},ae.prototype.clear=function(){this.__data__=new oe,this.size=0},ae.prototype.delete=function(e){var t=this.__data__,n=t.delete(e);return this.size=t.size,n},ae.prototype.get=function(e){return this.__data__.get(e)},ae.prototype.has=function(e){return this.__data__.has(e)},ae.prototype.set=function(e,t){var n=this.__data__;if(n instanceof oe){var o=n.__data__;if(!ee||o.length<r-1)return o.push([e,t]),this.size=++n.size,this;n=this.__data__=new ie(o)}return n.set(e,t),this.size=n.size,this};var pe,he=function(e,t,n){for(var r=-1,o=Object(e),i=n(e),a=i.length;a--;){var s=i[pe?a:++r];if(!1===t(o[s],s,o))break}return e};function de(e){return null==e?void 0===e?m:p:Q&&Q in Object(e)?function(e){var t=I.call(e,Q),n=e[Q];try{e[Q]=void 0;var r=!0}catch(e){}var o=F.call(e);r&&(t?e[Q]=n:delete e[Q]);return o}(e):function(e){return F.call(e)}(e)}function me(e){return Me(e)&&de(e)==u}function ge(e){return!(!Ne(e)||(t=e,D&&D in t))&&(je(e)?q:g).test(function(e){if(null!=e){try{return L.call(e)}catch(e){}try{return e+""}catch(e){}}return""}(e));var t}function ve(e){if(!Ne(e))return function(e){var t=[];if(null!=e)for(var n in Object(e))t.push(n);return t}(e);var t=ke(e),n=[];for(var r in e)("constructor"!=r||!t&&I.call(e,r))&&n.push(r);return n}function ye(e,t,n,r,o){e!==t&&he(t,function(i,a){if(o||(o=new ae),Ne(i))!function(e,t,n,r,o,i,a){var s=Se(e,n),u=Se(t,n),l=a.get(u);if(l)return void ue(e,n,l);var c=i?i(s,u,n+"",e,t,a):void 0,f=void 0===c;if(f){var p=Oe(u),d=!p&&Pe(u),m=!p&&!d&&Le(u);c=u,p||d||m?Oe(s)?c=s:Me(_=s)&&Ae(_)?c=function(e,t){var n=-1,r=e.length;t||(t=Array(r));for(;++n<r;)t[n]=e[n];return t}(s):d?(f=!1,c=function(e,t){if(t)return e.slice();var n=e.length,r=H?H(n):new e.constructor(n);return e.copy(r),r}(u,!0)):m?(f=!1,g=u,v=!0?(y=g.buffer,b=new y.constructor(y.byteLength),new z(b).set(new z(y)),b):g.buffer,c=new g.constructor(v,g.byteOffset,g.length)):c=[]:function(e){if(!Me(e)||de(e)!=h)return!1;var t=W(e);if(null===t)return!0;var n=I.call(t,"constructor")&&t.constructor;return"function"==typeof n&&n instanceof n&&L.call(n)==U}(u)||Te(u)?(c=s,Te(s)?c=function(e){return function(e,t,n,r){var o=!n;n||(n={});var i=-1,a=t.length;for(;++i<a;){var s=t[i],u=r?r(n[s],e[s],s,n,e):void 0;void 0===u&&(u=e[s]),o?fe(n,s,u):le(n,s,u)}return n}(e,Ie(e))}(s):Ne(s)&&!je(s)||(c=function(e){return"function"!=typeof e.constructor||ke(e)?{}:ne(W(e))}(u))):f=!1}var g,v,y,b;var _;f&&(a.set(u,c),o(c,u,r,i,a),a.delete(u));ue(e,n,c)}(e,t,a,n,ye,r,o);else{var s=r?r(Se(e,a),i,a+"",e,t,o):void 0;void 0===s&&(s=i),ue(e,a,s)}},Ie)}function be(e,t){return Ce(function(e,t,n){return t=J(void 0===t?e.length-1:t,0),function(){for(var r=arguments,o=-1,i=J(r.length-t,0),a=Array(i);++o<i;)a[o]=r[t+o];o=-1;for(var s=Array(t+1);++o<t;)s[o]=r[o];return s[t]=n(a),function(e,t,n){switch(n.length){case 0:return e.call(t);case 1:return e.call(t,n[0]);case 2:return e.call(t,n[0],n[1]);case 3:return e.call(t,n[0],n[1],n[2])}return e.apply(t,n)}(e,this,s)}}(e,t,Ue),e+"")}function _e(e,t){var n,r,o=e.__data__;return("string"==(r=typeof(n=t))||"number"==r||"symbol"==r||"boolean"==r?"__proto__"!==n:null===n)?o["string"==typeof t?"string":"hash"]:o.map}function we(e,t){var n=function(e,t){return null==e?void 0:e[t]}(e,t);return ge(n)?n:void 0}function xe(e,t){var n=typeof e;return!!(t=null==t?s:t)&&("number"==n||"symbol"!=n&&v.test(e))&&e>-1&&e%1==0&&e<t}function ke(e){var t=e&&e.constructor;return e===("function"==typeof t&&t.prototype||N)}function Se(e,t){if(("constructor"!==t||"function"!=typeof e[t])&&"__proto__"!=t)return e[t]}var Ce=function(e){var t=0,n=0;return function(){var r=Z(),o=a-(r-n);if(n=r,o>0){if(++t>=i)return arguments[0]}else t=0;return e.apply(void 0,arguments)}}(X?function(e,t){return X(e,"toString",{configurable:!0,enumerable:!1,value:(n=t,function(){return n}),writable:!0});var n}:Ue);function Ee(e,t){return e===t||e!=e&&t!=t}var Te=me(function(){return arguments}())?me:function(e){return Me(e)&&I.call(e,"callee")&&!G.call(e,"callee")},Oe=Array.isArray;function Ae(e){return null!=e&&Re(e.length)&&!je(e)}var Pe=K||function(){return!1};function je(e){if(!Ne(e))return!1;var t=de(e);return t==c||t==f||t==l||t==d}function Re(e){return"number"==typeof e&&e>-1&&e%1==0&&e<=s}function Ne(e){var t=typeof e;return null!=e&&("object"==t||"function"==t)}function Me(e){return null!=e&&"object"==typeof e}var Le=T?function(e){return function(t){return e(t)}}(T):function(e){return Me(e)&&Re(e.length)&&!!y[de(e)]};function Ie(e){return Ae(e)?se(e,!0):ve(e)}var De,Fe=(De=function(e,t,n,r){ye(e,t,n,r)},be(function(e,t){var n=-1,r=t.length,o=r>1?t[r-1]:void 0,i=r>2?t[2]:void 0;for(o=De.length>3&&"function"==typeof o?(r--,o):void 0,i&&function(e,t,n){if(!Ne(n))return!1;var r=typeof t;return!!("number"==r?Ae(n)&&xe(t,n.length):"string"==r&&t in n)&&Ee(n[t],e)}(t[0],t[1],i)&&(o=r<3?void 0:o,r=1),e=Object(e);++n<r;){var a=t[n];a&&De(e,a,n,o)}return e}));function Ue(e){return e}n.exports=Fe}).call(this,n(8),n(26)(e))},function(e,t){var n="[object String]",r=Object.prototype.toString,o=Array.isArray;e.exports=function(e){return"string"==typeof e||!o(e)&&function(e){return!!e&&"object"==typeof e}(e)&&r.call(e)==n}},function(e,t){var n="[object Object]";var r,o,i=Function.prototype,a=Object.prototype,s=i.toString,u=a.hasOwnProperty,l=s.call(Object),c=a.toString,f=(r=Object.getPrototypeOf,o=Object,function(e){return r(o(e))});e.exports=function(e){if(!function(e){return!!e&&"object"==typeof e}(e)||c.call(e)!=n||function(e){var t=!1;if(null!=e&&"function"!=typeof e.toString)try{t=!!(e+"")}catch(e){}return t}(e))return!1;var t=f(e);if(null===t)return!0;var r=u.call(t,"constructor")&&t.constructor;return"function"==typeof r&&r instanceof r&&s.call(r)==l}},function(e,t,n){"use strict";var r=n(395),o=n(396),i=/^\d+$/;t.parse=function(e){return(t=e.split(",").map(function(e){var t={};return e.trim().split(/\s+/).forEach(function(e,n){if(0===n)return t.url=e;var o=e.substring(0,e.length-1),a=e[e.length-1],s=parseInt(o,10),u=parseFloat(o);if("w"===a&&i.test(o))t.width=s;else if("h"===a&&i.test(o))t.height=s;else{if("x"!==a||r(u))throw new Error("Invalid srcset descriptor: "+e+".");t.density=u}}),t})).sort().filter(function(e,n){return JSON.stringify(e)!==JSON.stringify(t[n-1])});var t},t.stringify=function(e){return o(e.map(function(e){if(!e.url)throw new Error("URL is required.");var t=[e.url];return e.width&&t.push(e.width+"w"),e.height&&t.push(e.height+"h"),e.density&&t.push(e.density+"x"),t.join(" ")})).join(", ")}},function(e,t,n){"use strict";e.exports=Number.isNaN||function(e){return e!=e}},function(e,t,n){"use strict";(function(t){var n;"Set"in t?"function"==typeof Set.prototype.forEach&&(n=!1,new Set([!0]).forEach(function(e){n=e}),!0===n)?e.exports=function(e){var t=[];return new Set(e).forEach(function(e){t.push(e)}),t}:e.exports=function(e){var t=new Set;return e.filter(function(e){return!t.has(e)&&(t.add(e),!0)})}:e.exports=function(e){for(var t=[],n=0;n<e.length;n++)-1===t.indexOf(e[n])&&t.push(e[n]);return t}}).call(this,n(8))},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=h(n(95)),o=h(n(159)),i=h(n(97)),a=h(n(100)),s=h(n(101)),u=h(n(417)),l=h(n(99)),c=h(n(165)),f=h(n(103)),p=h(n(166));function h(e){return e&&e.__esModule?e:{default:e}}function d(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return 1===t.length&&Array.isArray(t[0])&&(t=t[0]),new o.default(t)}d.plugin=function(e,t){function n(){var n=t.apply(void 0,arguments);return n.postcssPlugin=e,n.postcssVersion=(new o.default).version,n}var r;return Object.defineProperty(n,"postcss",{get:function(){return r||(r=n()),r}}),n.process=function(e,t,r){return d([n(r)]).process(e,t)},n},d.stringify=i.default,d.parse=l.default,d.vendor=u.default,d.list=c.default,d.comment=function(e){return new a.default(e)},d.atRule=function(e){return new s.default(e)},d.decl=function(e){return new r.default(e)},d.rule=function(e){return new f.default(e)},d.root=function(e){return new p.default(e)};var m=d;t.default=m,e.exports=t.default},function(e,t){},function(e,t){},function(e,t){},function(e,t,n){"use strict";(function(r){t.__esModule=!0,t.default=void 0;var o=a(n(161)),i=a(n(98));function a(e){return e&&e.__esModule?e:{default:e}}var s=function(){function e(e,t,n){this.stringify=e,this.mapOpts=n.map||{},this.root=t,this.opts=n}var t=e.prototype;return t.isMap=function(){return void 0!==this.opts.map?!!this.opts.map:this.previous().length>0},t.previous=function(){var e=this;return this.previousMaps||(this.previousMaps=[],this.root.walk(function(t){if(t.source&&t.source.input.map){var n=t.source.input.map;-1===e.previousMaps.indexOf(n)&&e.previousMaps.push(n)}})),this.previousMaps},t.isInline=function(){if(void 0!==this.mapOpts.inline)return this.mapOpts.inline;var e=this.mapOpts.annotation;return(void 0===e||!0===e)&&(!this.previous().length||this.previous().some(function(e){return e.inline}))},t.isSourcesContent=function(){return void 0!==this.mapOpts.sourcesContent?this.mapOpts.sourcesContent:!this.previous().length||this.previous().some(function(e){return e.withContent()})},t.clearAnnotation=function(){if(!1!==this.mapOpts.annotation)for(var e,t=this.root.nodes.length-1;t>=0;t--)"comment"===(e=this.root.nodes[t]).type&&0===e.text.indexOf("# sourceMappingURL=")&&this.root.removeChild(t)},t.setSourcesContent=function(){var e=this,t={};this.root.walk(function(n){if(n.source){var r=n.source.input.from;if(r&&!t[r]){t[r]=!0;var o=e.relative(r);e.map.setSourceContent(o,n.source.input.css)}}})},t.applyPrevMaps=function(){var e=this.previous(),t=Array.isArray(e),n=0;for(e=t?e:e[Symbol.iterator]();;){var r;if(t){if(n>=e.length)break;r=e[n++]}else{if((n=e.next()).done)break;r=n.value}var a=r,s=this.relative(a.file),u=a.root||i.default.dirname(a.file),l=void 0;!1===this.mapOpts.sourcesContent?(l=new o.default.SourceMapConsumer(a.text)).sourcesContent&&(l.sourcesContent=l.sourcesContent.map(function(){return null})):l=a.consumer(),this.map.applySourceMap(l,s,this.relative(u))}},t.isAnnotation=function(){return!!this.isInline()||(void 0!==this.mapOpts.annotation?this.mapOpts.annotation:!this.previous().length||this.previous().some(function(e){return e.annotation}))},t.toBase64=function(e){return r?r.from(e).toString("base64"):window.btoa(unescape(encodeURIComponent(e)))},t.addAnnotation=function(){var e;e=this.isInline()?"data:application/json;base64,"+this.toBase64(this.map.toString()):"string"==typeof this.mapOpts.annotation?this.mapOpts.annotation:this.outputFile()+".map";var t="\n";-1!==this.css.indexOf("\r\n")&&(t="\r\n"),this.css+=t+"/*# sourceMappingURL="+e+" */"},t.outputFile=function(){return this.opts.to?this.relative(this.opts.to):this.opts.from?this.relative(this.opts.from):"to.css"},t.generateMap=function(){return this.generateString(),this.isSourcesContent()&&this.setSourcesContent(),this.previous().length>0&&this.applyPrevMaps(),this.isAnnotation()&&this.addAnnotation(),this.isInline()?[this.css]:[this.css,this.map]},t.relative=function(e){if(0===e.indexOf("<"))return e;if(/^\w+:\/\//.test(e))return e;var t=this.opts.to?i.default.dirname(this.opts.to):".";return"string"==typeof this.mapOpts.annotation&&(t=i.default.dirname(i.default.resolve(t,this.mapOpts.annotation))),e=i.default.relative(t,e),"\\"===i.default.sep?e.replace(/\\/g,"/"):e},t.sourcePath=function(e){return this.mapOpts.from?this.mapOpts.from:this.relative(e.source.input.from)},t.generateString=function(){var e=this;this.css="",this.map=new o.default.SourceMapGenerator({file:this.outputFile()});var t,n,r=1,i=1;this.stringify(this.root,function(o,a,s){if(e.css+=o,a&&"end"!==s&&(a.source&&a.source.start?e.map.addMapping({source:e.sourcePath(a),generated:{line:r,column:i-1},original:{line:a.source.start.line,column:a.source.start.column-1}}):e.map.addMapping({source:"<no source>",original:{line:1,column:0},generated:{line:r,column:i-1}})),(t=o.match(/\n/g))?(r+=t.length,n=o.lastIndexOf("\n"),i=o.length-n):i+=o.length,a&&"start"!==s){var u=a.parent||{raws:{}};("decl"!==a.type||a!==u.last||u.raws.semicolon)&&(a.source&&a.source.end?e.map.addMapping({source:e.sourcePath(a),generated:{line:r,column:i-2},original:{line:a.source.end.line,column:a.source.end.column-1}}):e.map.addMapping({source:"<no source>",original:{line:1,column:0},generated:{line:r,column:i-1}}))}})},t.generate=function(){if(this.clearAnnotation(),this.isMap())return this.generateMap();var e="";return this.stringify(this.root,function(t){e+=t}),[e]},e}();t.default=s,e.exports=t.default}).call(this,n(56).Buffer)},function(e,t){var n="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/".split("");t.encode=function(e){if(0<=e&&e<n.length)return n[e];throw new TypeError("Must be between 0 and 63: "+e)},t.decode=function(e){return 65<=e&&e<=90?e-65:97<=e&&e<=122?e-97+26:48<=e&&e<=57?e-48+52:43==e?62:47==e?63:-1}},function(e,t,n){var r=n(35);function o(){this._array=[],this._sorted=!0,this._last={generatedLine:-1,generatedColumn:0}}o.prototype.unsortedForEach=function(e,t){this._array.forEach(e,t)},o.prototype.add=function(e){var t,n,o,i,a,s;t=this._last,n=e,o=t.generatedLine,i=n.generatedLine,a=t.generatedColumn,s=n.generatedColumn,i>o||i==o&&s>=a||r.compareByGeneratedPositionsInflated(t,n)<=0?(this._last=e,this._array.push(e)):(this._sorted=!1,this._array.push(e))},o.prototype.toArray=function(){return this._sorted||(this._array.sort(r.compareByGeneratedPositionsInflated),this._sorted=!0),this._array},t.MappingList=o},function(e,t,n){var r=n(35),o=n(405),i=n(164).ArraySet,a=n(163),s=n(406).quickSort;function u(e,t){var n=e;return"string"==typeof e&&(n=r.parseSourceMapInput(e)),null!=n.sections?new f(n,t):new l(n,t)}function l(e,t){var n=e;"string"==typeof e&&(n=r.parseSourceMapInput(e));var o=r.getArg(n,"version"),a=r.getArg(n,"sources"),s=r.getArg(n,"names",[]),u=r.getArg(n,"sourceRoot",null),l=r.getArg(n,"sourcesContent",null),c=r.getArg(n,"mappings"),f=r.getArg(n,"file",null);if(o!=this._version)throw new Error("Unsupported version: "+o);u&&(u=r.normalize(u)),a=a.map(String).map(r.normalize).map(function(e){return u&&r.isAbsolute(u)&&r.isAbsolute(e)?r.relative(u,e):e}),this._names=i.fromArray(s.map(String),!0),this._sources=i.fromArray(a,!0),this._absoluteSources=this._sources.toArray().map(function(e){return r.computeSourceURL(u,e,t)}),this.sourceRoot=u,this.sourcesContent=l,this._mappings=c,this._sourceMapURL=t,this.file=f}function c(){this.generatedLine=0,this.generatedColumn=0,this.source=null,this.originalLine=null,this.originalColumn=null,this.name=null}function f(e,t){var n=e;"string"==typeof e&&(n=r.parseSourceMapInput(e));var o=r.getArg(n,"version"),a=r.getArg(n,"sections");if(o!=this._version)throw new Error("Unsupported version: "+o);this._sources=new i,this._names=new i;var s={line:-1,column:0};this._sections=a.map(function(e){if(e.url)throw new Error("Support for url field in sections not implemented.");var n=r.getArg(e,"offset"),o=r.getArg(n,"line"),i=r.getArg(n,"column");if(o<s.line||o===s.line&&i<s.column)throw new Error("Section offsets must be ordered and non-overlapping.");return s=n,{generatedOffset:{generatedLine:o+1,generatedColumn:i+1},consumer:new u(r.getArg(e,"map"),t)}})}u.fromSourceMap=function(e,t){return l.fromSourceMap(e,t)},u.prototype._version=3,u.prototype.__generatedMappings=null,Object.defineProperty(u.prototype,"_generatedMappings",{configurable:!0,enumerable:!0,get:function(){return this.__generatedMappings||this._parseMappings(this._mappings,this.sourceRoot),this.__generatedMappings}}),u.prototype.__originalMappings=null,Object.defineProperty(u.prototype,"_originalMappings",{configurable:!0,enumerable:!0,get:function(){return this.__originalMappings||this._parseMappings(this._mappings,this.sourceRoot),this.__originalMappings}}),u.prototype._charIsMappingSeparator=function(e,t){var n=e.charAt(t);return";"===n||","===n},u.prototype._parseMappings=function(e,t){throw new Error("Subclasses must implement _parseMappings")},u.GENERATED_ORDER=1,u.ORIGINAL_ORDER=2,u.GREATEST_LOWER_BOUND=1,u.LEAST_UPPER_BOUND=2,u.prototype.eachMapping=function(e,t,n){var o,i=t||null;switch(n||u.GENERATED_ORDER){case u.GENERATED_ORDER:o=this._generatedMappings;break;case u.ORIGINAL_ORDER:o=this._originalMappings;break;default:throw new Error("Unknown order of iteration.")}var a=this.sourceRoot;o.map(function(e){var t=null===e.source?null:this._sources.at(e.source);return{source:t=r.computeSourceURL(a,t,this._sourceMapURL),generatedLine:e.generatedLine,generatedColumn:e.generatedColumn,originalLine:e.originalLine,originalColumn:e.originalColumn,name:null===e.name?null:this._names.at(e.name)}},this).forEach(e,i)},u.prototype.allGeneratedPositionsFor=function(e){var t=r.getArg(e,"line"),n={source:r.getArg(e,"source"),originalLine:t,originalColumn:r.getArg(e,"column",0)};if(n.source=this._findSourceIndex(n.source),n.source<0)return[];var i=[],a=this._findMapping(n,this._originalMappings,"originalLine","originalColumn",r.compareByOriginalPositions,o.LEAST_UPPER_BOUND);if(a>=0){var s=this._originalMappings[a];if(void 0===e.column)for(var u=s.originalLine;s&&s.originalLine===u;)i.push({line:r.getArg(s,"generatedLine",null),column:r.getArg(s,"generatedColumn",null),lastColumn:r.getArg(s,"lastGeneratedColumn",null)}),s=this._originalMappings[++a];else for(var l=s.originalColumn;s&&s.originalLine===t&&s.originalColumn==l;)i.push({line:r.getArg(s,"generatedLine",null),column:r.getArg(s,"generatedColumn",null),lastColumn:r.getArg(s,"lastGeneratedColumn",null)}),s=this._originalMappings[++a]}return i},t.SourceMapConsumer=u,l.prototype=Object.create(u.prototype),l.prototype.consumer=u,l.prototype._findSourceIndex=function(e){var t,n=e;if(null!=this.sourceRoot&&(n=r.relative(this.sourceRoot,n)),this._sources.has(n))return this._sources.indexOf(n);for(t=0;t<this._absoluteSources.length;++t)if(this._absoluteSources[t]==e)return t;return-1},l.fromSourceMap=function(e,t){var n=Object.create(l.prototype),o=n._names=i.fromArray(e._names.toArray(),!0),a=n._sources=i.fromArray(e._sources.toArray(),!0);n.sourceRoot=e._sourceRoot,n.sourcesContent=e._generateSourcesContent(n._sources.toArray(),n.sourceRoot),n.file=e._file,n._sourceMapURL=t,n._absoluteSources=n._sources.toArray().map(function(e){return r.computeSourceURL(n.sourceRoot,e,t)});for(var u=e._mappings.toArray().slice(),f=n.__generatedMappings=[],p=n.__originalMappings=[],h=0,d=u.length;h<d;h++){var m=u[h],g=new c;g.generatedLine=m.generatedLine,g.generatedColumn=m.generatedColumn,m.source&&(g.source=a.indexOf(m.source),g.originalLine=m.originalLine,g.originalColumn=m.originalColumn,m.name&&(g.name=o.indexOf(m.name)),p.push(g)),f.push(g)}return s(n.__originalMappings,r.compareByOriginalPositions),n},l.prototype._version=3,Object.defineProperty(l.prototype,"sources",{get:function(){return this._absoluteSources.slice()}}),l.prototype._parseMappings=function(e,t){for(var n,o,i,u,l,f=1,p=0,h=0,d=0,m=0,g=0,v=e.length,y=0,b={},_={},w=[],x=[];y<v;)if(";"===e.charAt(y))f++,y++,p=0;else if(","===e.charAt(y))y++;else{for((n=new c).generatedLine=f,u=y;u<v&&!this._charIsMappingSeparator(e,u);u++);if(i=b[o=e.slice(y,u)])y+=o.length;else{for(i=[];y<u;)a.decode(e,y,_),l=_.value,y=_.rest,i.push(l);if(2===i.length)throw new Error("Found a source, but no line and column");if(3===i.length)throw new Error("Found a source and line, but no column");b[o]=i}n.generatedColumn=p+i[0],p=n.generatedColumn,i.length>1&&(n.source=m+i[1],m+=i[1],n.originalLine=h+i[2],h=n.originalLine,n.originalLine+=1,n.originalColumn=d+i[3],d=n.originalColumn,i.length>4&&(n.name=g+i[4],g+=i[4])),x.push(n),"number"==typeof n.originalLine&&w.push(n)}s(x,r.compareByGeneratedPositionsDeflated),this.__generatedMappings=x,s(w,r.compareByOriginalPositions),this.__originalMappings=w},l.prototype._findMapping=function(e,t,n,r,i,a){if(e[n]<=0)throw new TypeError("Line must be greater than or equal to 1, got "+e[n]);if(e[r]<0)throw new TypeError("Column must be greater than or equal to 0, got "+e[r]);return o.search(e,t,i,a)},l.prototype.computeColumnSpans=function(){for(var e=0;e<this._generatedMappings.length;++e){var t=this._generatedMappings[e];if(e+1<this._generatedMappings.length){var n=this._generatedMappings[e+1];if(t.generatedLine===n.generatedLine){t.lastGeneratedColumn=n.generatedColumn-1;continue}}t.lastGeneratedColumn=1/0}},l.prototype.originalPositionFor=function(e){var t={generatedLine:r.getArg(e,"line"),generatedColumn:r.getArg(e,"column")},n=this._findMapping(t,this._generatedMappings,"generatedLine","generatedColumn",r.compareByGeneratedPositionsDeflated,r.getArg(e,"bias",u.GREATEST_LOWER_BOUND));if(n>=0){var o=this._generatedMappings[n];if(o.generatedLine===t.generatedLine){var i=r.getArg(o,"source",null);null!==i&&(i=this._sources.at(i),i=r.computeSourceURL(this.sourceRoot,i,this._sourceMapURL));var a=r.getArg(o,"name",null);return null!==a&&(a=this._names.at(a)),{source:i,line:r.getArg(o,"originalLine",null),column:r.getArg(o,"originalColumn",null),name:a}}}return{source:null,line:null,column:null,name:null}},l.prototype.hasContentsOfAllSources=function(){return!!this.sourcesContent&&(this.sourcesContent.length>=this._sources.size()&&!this.sourcesContent.some(function(e){return null==e}))},l.prototype.sourceContentFor=function(e,t){if(!this.sourcesContent)return null;var n=this._findSourceIndex(e);if(n>=0)return this.sourcesContent[n];var o,i=e;if(null!=this.sourceRoot&&(i=r.relative(this.sourceRoot,i)),null!=this.sourceRoot&&(o=r.urlParse(this.sourceRoot))){var a=i.replace(/^file:\/\//,"");if("file"==o.scheme&&this._sources.has(a))return this.sourcesContent[this._sources.indexOf(a)];if((!o.path||"/"==o.path)&&this._sources.has("/"+i))return this.sourcesContent[this._sources.indexOf("/"+i)]}if(t)return null;throw new Error('"'+i+'" is not in the SourceMap.')},l.prototype.generatedPositionFor=function(e){var t=r.getArg(e,"source");if((t=this._findSourceIndex(t))<0)return{line:null,column:null,lastColumn:null};var n={source:t,originalLine:r.getArg(e,"line"),originalColumn:r.getArg(e,"column")},o=this._findMapping(n,this._originalMappings,"originalLine","originalColumn",r.compareByOriginalPositions,r.getArg(e,"bias",u.GREATEST_LOWER_BOUND));if(o>=0){var i=this._originalMappings[o];if(i.source===n.source)return{line:r.getArg(i,"generatedLine",null),column:r.getArg(i,"generatedColumn",null),lastColumn:r.getArg(i,"lastGeneratedColumn",null)}}return{line:null,column:null,lastColumn:null}},t.BasicSourceMapConsumer=l,f.prototype=Object.create(u.prototype),f.prototype.constructor=u,f.prototype._version=3,Object.defineProperty(f.prototype,"sources",{get:function(){for(var e=[],t=0;t<this._sections.length;t++)for(var n=0;n<this._sections[t].consumer.sources.length;n++)e.push(this._sections[t].consumer.sources[n]);return e}}),f.prototype.originalPositionFor=function(e){var t={generatedLine:r.getArg(e,"line"),generatedColumn:r.getArg(e,"column")},n=o.search(t,this._sections,function(e,t){var n=e.generatedLine-t.generatedOffset.generatedLine;return n||e.generatedColumn-t.generatedOffset.generatedColumn}),i=this._sections[n];return i?i.consumer.originalPositionFor({line:t.generatedLine-(i.generatedOffset.generatedLine-1),column:t.generatedColumn-(i.generatedOffset.generatedLine===t.generatedLine?i.generatedOffset.generatedColumn-1:0),bias:e.bias}):{source:null,line:null,column:null,name:null}},f.prototype.hasContentsOfAllSources=function(){return this._sections.every(function(e){return e.consumer.hasContentsOfAllSources()})},f.prototype.sourceContentFor=function(e,t){for(var n=0;n<this._sections.length;n++){var r=this._sections[n].consumer.sourceContentFor(e,!0);if(r)return r}if(t)return null;throw new Error('"'+e+'" is not in the SourceMap.')},f.prototype.generatedPositionFor=function(e){for(var t=0;t<this._sections.length;t++){var n=this._sections[t];if(-1!==n.consumer._findSourceIndex(r.getArg(e,"source"))){var o=n.consumer.generatedPositionFor(e);if(o)return{line:o.line+(n.generatedOffset.generatedLine-1),column:o.column+(n.generatedOffset.generatedLine===o.line?n.generatedOffset.generatedColumn-1:0)}}}return{line:null,column:null}},f.prototype._parseMappings=function(e,t){this.__generatedMappings=[],this.__originalMappings=[];for(var n=0;n<this._sections.length;n++)for(var o=this._sections[n],i=o.consumer._generatedMappings,a=0;a<i.length;a++){var u=i[a],l=o.consumer._sources.at(u.source);l=r.computeSourceURL(o.consumer.sourceRoot,l,this._sourceMapURL),this._sources.add(l),l=this._sources.indexOf(l);var c=null;u.name&&(c=o.consumer._names.at(u.name),this._names.add(c),c=this._names.indexOf(c));var f={source:l,generatedLine:u.generatedLine+(o.generatedOffset.generatedLine-1),generatedColumn:u.generatedColumn+(o.generatedOffset.generatedLine===u.generatedLine?o.generatedOffset.generatedColumn-1:0),originalLine:u.originalLine,originalColumn:u.originalColumn,name:c};this.__generatedMappings.push(f),"number"==typeof f.originalLine&&this.__originalMappings.push(f)}s(this.__generatedMappings,r.compareByGeneratedPositionsDeflated),s(this.__originalMappings,r.compareByOriginalPositions)},t.IndexedSourceMapConsumer=f},function(e,t){t.GREATEST_LOWER_BOUND=1,t.LEAST_UPPER_BOUND=2,t.search=function(e,n,r,o){if(0===n.length)return-1;var i=function e(n,r,o,i,a,s){var u=Math.floor((r-n)/2)+n,l=a(o,i[u],!0);return 0===l?u:l>0?r-u>1?e(u,r,o,i,a,s):s==t.LEAST_UPPER_BOUND?r<i.length?r:-1:u:u-n>1?e(n,u,o,i,a,s):s==t.LEAST_UPPER_BOUND?u:n<0?-1:n}(-1,n.length,e,n,r,o||t.GREATEST_LOWER_BOUND);if(i<0)return-1;for(;i-1>=0&&0===r(n[i],n[i-1],!0);)--i;return i}},function(e,t){function n(e,t,n){var r=e[t];e[t]=e[n],e[n]=r}function r(e,t,o,i){if(o<i){var a=o-1;n(e,(c=o,f=i,Math.round(c+Math.random()*(f-c))),i);for(var s=e[i],u=o;u<i;u++)t(e[u],s)<=0&&n(e,a+=1,u);n(e,a+1,u);var l=a+1;r(e,t,o,l-1),r(e,t,l+1,i)}var c,f}t.quickSort=function(e,t){r(e,t,0,e.length-1)}},function(e,t,n){var r=n(162).SourceMapGenerator,o=n(35),i=/(\r?\n)/,a="$$$isSourceNode$$$";function s(e,t,n,r,o){this.children=[],this.sourceContents={},this.line=null==e?null:e,this.column=null==t?null:t,this.source=null==n?null:n,this.name=null==o?null:o,this[a]=!0,null!=r&&this.add(r)}s.fromStringWithSourceMap=function(e,t,n){var r=new s,a=e.split(i),u=0,l=function(){return e()+(e()||"");function e(){return u<a.length?a[u++]:void 0}},c=1,f=0,p=null;return t.eachMapping(function(e){if(null!==p){if(!(c<e.generatedLine)){var t=(n=a[u]||"").substr(0,e.generatedColumn-f);return a[u]=n.substr(e.generatedColumn-f),f=e.generatedColumn,h(p,t),void(p=e)}h(p,l()),c++,f=0}for(;c<e.generatedLine;)r.add(l()),c++;if(f<e.generatedColumn){var n=a[u]||"";r.add(n.substr(0,e.generatedColumn)),a[u]=n.substr(e.generatedColumn),f=e.generatedColumn}p=e},this),u<a.length&&(p&&h(p,l()),r.add(a.splice(u).join(""))),t.sources.forEach(function(e){var i=t.sourceContentFor(e);null!=i&&(null!=n&&(e=o.join(n,e)),r.setSourceContent(e,i))}),r;function h(e,t){if(null===e||void 0===e.source)r.add(t);else{var i=n?o.join(n,e.source):e.source;r.add(new s(e.originalLine,e.originalColumn,i,t,e.name))}}},s.prototype.add=function(e){if(Array.isArray(e))e.forEach(function(e){this.add(e)},this);else{if(!e[a]&&"string"!=typeof e)throw new TypeError("Expected a SourceNode, string, or an array of SourceNodes and strings. Got "+e);e&&this.children.push(e)}return this},s.prototype.prepend=function(e){if(Array.isArray(e))for(var t=e.length-1;t>=0;t--)this.prepend(e[t]);else{if(!e[a]&&"string"!=typeof e)throw new TypeError("Expected a SourceNode, string, or an array of SourceNodes and strings. Got "+e);this.children.unshift(e)}return this},s.prototype.walk=function(e){for(var t,n=0,r=this.children.length;n<r;n++)(t=this.children[n])[a]?t.walk(e):""!==t&&e(t,{source:this.source,line:this.line,column:this.column,name:this.name})},s.prototype.join=function(e){var t,n,r=this.children.length;if(r>0){for(t=[],n=0;n<r-1;n++)t.push(this.children[n]),t.push(e);t.push(this.children[n]),this.children=t}return this},s.prototype.replaceRight=function(e,t){var n=this.children[this.children.length-1];return n[a]?n.replaceRight(e,t):"string"==typeof n?this.children[this.children.length-1]=n.replace(e,t):this.children.push("".replace(e,t)),this},s.prototype.setSourceContent=function(e,t){this.sourceContents[o.toSetString(e)]=t},s.prototype.walkSourceContents=function(e){for(var t=0,n=this.children.length;t<n;t++)this.children[t][a]&&this.children[t].walkSourceContents(e);var r=Object.keys(this.sourceContents);for(t=0,n=r.length;t<n;t++)e(o.fromSetString(r[t]),this.sourceContents[r[t]])},s.prototype.toString=function(){var e="";return this.walk(function(t){e+=t}),e},s.prototype.toStringWithSourceMap=function(e){var t={code:"",line:1,column:0},n=new r(e),o=!1,i=null,a=null,s=null,u=null;return this.walk(function(e,r){t.code+=e,null!==r.source&&null!==r.line&&null!==r.column?(i===r.source&&a===r.line&&s===r.column&&u===r.name||n.addMapping({source:r.source,original:{line:r.line,column:r.column},generated:{line:t.line,column:t.column},name:r.name}),i=r.source,a=r.line,s=r.column,u=r.name,o=!0):o&&(n.addMapping({generated:{line:t.line,column:t.column}}),i=null,o=!1);for(var l=0,c=e.length;l<c;l++)10===e.charCodeAt(l)?(t.line++,t.column=0,l+1===c?(i=null,o=!1):o&&n.addMapping({source:r.source,original:{line:r.line,column:r.column},generated:{line:t.line,column:t.column},name:r.name})):t.column++}),this.walkSourceContents(function(e,t){n.setSourceContent(e,t)}),{code:t.code,map:n}},t.SourceNode=s},function(e,t){var n,r,o=e.exports={};function i(){throw new Error("setTimeout has not been defined")}function a(){throw new Error("clearTimeout has not been defined")}function s(e){if(n===setTimeout)return setTimeout(e,0);if((n===i||!n)&&setTimeout)return n=setTimeout,setTimeout(e,0);try{return n(e,0)}catch(t){try{return n.call(null,e,0)}catch(t){return n.call(this,e,0)}}}!function(){try{n="function"==typeof setTimeout?setTimeout:i}catch(e){n=i}try{r="function"==typeof clearTimeout?clearTimeout:a}catch(e){r=a}}();var u,l=[],c=!1,f=-1;function p(){c&&u&&(c=!1,u.length?l=u.concat(l):f=-1,l.length&&h())}function h(){if(!c){var e=s(p);c=!0;for(var t=l.length;t;){for(u=l,l=[];++f<t;)u&&u[f].run();f=-1,t=l.length}u=null,c=!1,function(e){if(r===clearTimeout)return clearTimeout(e);if((r===a||!r)&&clearTimeout)return r=clearTimeout,clearTimeout(e);try{r(e)}catch(t){try{return r.call(null,e)}catch(t){return r.call(this,e)}}}(e)}}function d(e,t){this.fun=e,this.array=t}function m(){}o.nextTick=function(e){var t=new Array(arguments.length-1);if(arguments.length>1)for(var n=1;n<arguments.length;n++)t[n-1]=arguments[n];l.push(new d(e,t)),1!==l.length||c||s(h)},d.prototype.run=function(){this.fun.apply(null,this.array)},o.title="browser",o.browser=!0,o.env={},o.argv=[],o.version="",o.versions={},o.on=m,o.addListener=m,o.once=m,o.off=m,o.removeListener=m,o.removeAllListeners=m,o.emit=m,o.prependListener=m,o.prependOnceListener=m,o.listeners=function(e){return[]},o.binding=function(e){throw new Error("process.binding is not supported")},o.cwd=function(){return"/"},o.chdir=function(e){throw new Error("process.chdir is not supported")},o.umask=function(){return 0}},function(e,t,n){"use strict";t.__esModule=!0,t.default=function(e){if(r[e])return;r[e]=!0,"undefined"!=typeof console&&console.warn&&console.warn(e)};var r={};e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r,o=(r=n(411))&&r.__esModule?r:{default:r};function i(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}var a=function(){function e(e,t,n){this.processor=e,this.messages=[],this.root=t,this.opts=n,this.css=void 0,this.map=void 0}var t,n,r,a=e.prototype;return a.toString=function(){return this.css},a.warn=function(e,t){void 0===t&&(t={}),t.plugin||this.lastPlugin&&this.lastPlugin.postcssPlugin&&(t.plugin=this.lastPlugin.postcssPlugin);var n=new o.default(e,t);return this.messages.push(n),n},a.warnings=function(){return this.messages.filter(function(e){return"warning"===e.type})},t=e,(n=[{key:"content",get:function(){return this.css}}])&&i(t.prototype,n),r&&i(t,r),e}();t.default=a,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=function(){function e(e,t){if(void 0===t&&(t={}),this.type="warning",this.text=e,t.node&&t.node.source){var n=t.node.positionBy(t);this.line=n.line,this.column=n.column}for(var r in t)this[r]=t[r]}return e.prototype.toString=function(){return this.node?this.node.error(this.text,{plugin:this.plugin,index:this.index,word:this.word}).message:this.plugin?this.plugin+": "+this.text:this.text},e}();t.default=r,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=l(n(95)),o=l(n(413)),i=l(n(100)),a=l(n(101)),s=l(n(166)),u=l(n(103));function l(e){return e&&e.__esModule?e:{default:e}}var c=function(){function e(e){this.input=e,this.root=new s.default,this.current=this.root,this.spaces="",this.semicolon=!1,this.createTokenizer(),this.root.source={input:e,start:{line:1,column:1}}}var t=e.prototype;return t.createTokenizer=function(){this.tokenizer=(0,o.default)(this.input)},t.parse=function(){for(var e;!this.tokenizer.endOfFile();)switch((e=this.tokenizer.nextToken())[0]){case"space":this.spaces+=e[1];break;case";":this.freeSemicolon(e);break;case"}":this.end(e);break;case"comment":this.comment(e);break;case"at-word":this.atrule(e);break;case"{":this.emptyRule(e);break;default:this.other(e)}this.endFile()},t.comment=function(e){var t=new i.default;this.init(t,e[2],e[3]),t.source.end={line:e[4],column:e[5]};var n=e[1].slice(2,-2);if(/^\s*$/.test(n))t.text="",t.raws.left=n,t.raws.right="";else{var r=n.match(/^(\s*)([^]*[^\s])(\s*)$/);t.text=r[2],t.raws.left=r[1],t.raws.right=r[3]}},t.emptyRule=function(e){var t=new u.default;this.init(t,e[2],e[3]),t.selector="",t.raws.between="",this.current=t},t.other=function(e){for(var t=!1,n=null,r=!1,o=null,i=[],a=[],s=e;s;){if(n=s[0],a.push(s),"("===n||"["===n)o||(o=s),i.push("("===n?")":"]");else if(0===i.length){if(";"===n){if(r)return void this.decl(a);break}if("{"===n)return void this.rule(a);if("}"===n){this.tokenizer.back(a.pop()),t=!0;break}":"===n&&(r=!0)}else n===i[i.length-1]&&(i.pop(),0===i.length&&(o=null));s=this.tokenizer.nextToken()}if(this.tokenizer.endOfFile()&&(t=!0),i.length>0&&this.unclosedBracket(o),t&&r){for(;a.length&&("space"===(s=a[a.length-1][0])||"comment"===s);)this.tokenizer.back(a.pop());this.decl(a)}else this.unknownWord(a)},t.rule=function(e){e.pop();var t=new u.default;this.init(t,e[0][2],e[0][3]),t.raws.between=this.spacesAndCommentsFromEnd(e),this.raw(t,"selector",e),this.current=t},t.decl=function(e){var t=new r.default;this.init(t);var n,o=e[e.length-1];for(";"===o[0]&&(this.semicolon=!0,e.pop()),o[4]?t.source.end={line:o[4],column:o[5]}:t.source.end={line:o[2],column:o[3]};"word"!==e[0][0];)1===e.length&&this.unknownWord(e),t.raws.before+=e.shift()[1];for(t.source.start={line:e[0][2],column:e[0][3]},t.prop="";e.length;){var i=e[0][0];if(":"===i||"space"===i||"comment"===i)break;t.prop+=e.shift()[1]}for(t.raws.between="";e.length;){if(":"===(n=e.shift())[0]){t.raws.between+=n[1];break}"word"===n[0]&&/\w/.test(n[1])&&this.unknownWord([n]),t.raws.between+=n[1]}"_"!==t.prop[0]&&"*"!==t.prop[0]||(t.raws.before+=t.prop[0],t.prop=t.prop.slice(1)),t.raws.between+=this.spacesAndCommentsFromStart(e),this.precheckMissedSemicolon(e);for(var a=e.length-1;a>0;a--){if("!important"===(n=e[a])[1].toLowerCase()){t.important=!0;var s=this.stringFrom(e,a);" !important"!==(s=this.spacesFromEnd(e)+s)&&(t.raws.important=s);break}if("important"===n[1].toLowerCase()){for(var u=e.slice(0),l="",c=a;c>0;c--){var f=u[c][0];if(0===l.trim().indexOf("!")&&"space"!==f)break;l=u.pop()[1]+l}0===l.trim().indexOf("!")&&(t.important=!0,t.raws.important=l,e=u)}if("space"!==n[0]&&"comment"!==n[0])break}this.raw(t,"value",e),-1!==t.value.indexOf(":")&&this.checkMissedSemicolon(e)},t.atrule=function(e){var t,n,r=new a.default;r.name=e[1].slice(1),""===r.name&&this.unnamedAtrule(r,e),this.init(r,e[2],e[3]);for(var o=!1,i=!1,s=[];!this.tokenizer.endOfFile();){if(";"===(e=this.tokenizer.nextToken())[0]){r.source.end={line:e[2],column:e[3]},this.semicolon=!0;break}if("{"===e[0]){i=!0;break}if("}"===e[0]){if(s.length>0){for(t=s[n=s.length-1];t&&"space"===t[0];)t=s[--n];t&&(r.source.end={line:t[4],column:t[5]})}this.end(e);break}if(s.push(e),this.tokenizer.endOfFile()){o=!0;break}}r.raws.between=this.spacesAndCommentsFromEnd(s),s.length?(r.raws.afterName=this.spacesAndCommentsFromStart(s),this.raw(r,"params",s),o&&(e=s[s.length-1],r.source.end={line:e[4],column:e[5]},this.spaces=r.raws.between,r.raws.between="")):(r.raws.afterName="",r.params=""),i&&(r.nodes=[],this.current=r)},t.end=function(e){this.current.nodes&&this.current.nodes.length&&(this.current.raws.semicolon=this.semicolon),this.semicolon=!1,this.current.raws.after=(this.current.raws.after||"")+this.spaces,this.spaces="",this.current.parent?(this.current.source.end={line:e[2],column:e[3]},this.current=this.current.parent):this.unexpectedClose(e)},t.endFile=function(){this.current.parent&&this.unclosedBlock(),this.current.nodes&&this.current.nodes.length&&(this.current.raws.semicolon=this.semicolon),this.current.raws.after=(this.current.raws.after||"")+this.spaces},t.freeSemicolon=function(e){if(this.spaces+=e[1],this.current.nodes){var t=this.current.nodes[this.current.nodes.length-1];t&&"rule"===t.type&&!t.raws.ownSemicolon&&(t.raws.ownSemicolon=this.spaces,this.spaces="")}},t.init=function(e,t,n){this.current.push(e),e.source={start:{line:t,column:n},input:this.input},e.raws.before=this.spaces,this.spaces="","comment"!==e.type&&(this.semicolon=!1)},t.raw=function(e,t,n){for(var r,o,i,a,s=n.length,u="",l=!0,c=/^([.|#])?([\w])+/i,f=0;f<s;f+=1)"comment"!==(o=(r=n[f])[0])||"rule"!==e.type?"comment"===o||"space"===o&&f===s-1?l=!1:u+=r[1]:(a=n[f-1],i=n[f+1],"space"!==a[0]&&"space"!==i[0]&&c.test(a[1])&&c.test(i[1])?u+=r[1]:l=!1);if(!l){var p=n.reduce(function(e,t){return e+t[1]},"");e.raws[t]={value:u,raw:p}}e[t]=u},t.spacesAndCommentsFromEnd=function(e){for(var t,n="";e.length&&("space"===(t=e[e.length-1][0])||"comment"===t);)n=e.pop()[1]+n;return n},t.spacesAndCommentsFromStart=function(e){for(var t,n="";e.length&&("space"===(t=e[0][0])||"comment"===t);)n+=e.shift()[1];return n},t.spacesFromEnd=function(e){for(var t="";e.length&&"space"===e[e.length-1][0];)t=e.pop()[1]+t;return t},t.stringFrom=function(e,t){for(var n="",r=t;r<e.length;r++)n+=e[r][1];return e.splice(t,e.length-t),n},t.colon=function(e){for(var t,n,r,o=0,i=0;i<e.length;i++){if("("===(n=(t=e[i])[0])&&(o+=1),")"===n&&(o-=1),0===o&&":"===n){if(r){if("word"===r[0]&&"progid"===r[1])continue;return i}this.doubleColon(t)}r=t}return!1},t.unclosedBracket=function(e){throw this.input.error("Unclosed bracket",e[2],e[3])},t.unknownWord=function(e){throw this.input.error("Unknown word",e[0][2],e[0][3])},t.unexpectedClose=function(e){throw this.input.error("Unexpected }",e[2],e[3])},t.unclosedBlock=function(){var e=this.current.source.start;throw this.input.error("Unclosed block",e.line,e.column)},t.doubleColon=function(e){throw this.input.error("Double colon",e[2],e[3])},t.unnamedAtrule=function(e,t){throw this.input.error("At-rule without name",t[2],t[3])},t.precheckMissedSemicolon=function(){},t.checkMissedSemicolon=function(e){var t=this.colon(e);if(!1!==t){for(var n,r=0,o=t-1;o>=0&&("space"===(n=e[o])[0]||2!==(r+=1));o--);throw this.input.error("Missed semicolon",n[2],n[3])}},e}();t.default=c,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=function(e,t){void 0===t&&(t={});var n,E,T,O,A,P,j,R,N,M,L,I,D,F,U=e.css.valueOf(),q=t.ignoreErrors,B=U.length,V=-1,z=1,H=0,W=[],$=[];function G(t){throw e.error("Unclosed "+t,z,H-V)}return{back:function(e){$.push(e)},nextToken:function(e){if($.length)return $.pop();if(H>=B)return;var t=!!e&&e.ignoreUnclosed;((n=U.charCodeAt(H))===s||n===l||n===f&&U.charCodeAt(H+1)!==s)&&(V=H,z+=1);switch(n){case s:case u:case c:case f:case l:E=H;do{E+=1,(n=U.charCodeAt(E))===s&&(V=E,z+=1)}while(n===u||n===s||n===c||n===f||n===l);F=["space",U.slice(H,E)],H=E-1;break;case p:case h:case g:case v:case _:case y:case m:var Y=String.fromCharCode(n);F=[Y,Y,z,H-V];break;case d:if(I=W.length?W.pop()[1]:"",D=U.charCodeAt(H+1),"url"===I&&D!==r&&D!==o&&D!==u&&D!==s&&D!==c&&D!==l&&D!==f){E=H;do{if(M=!1,-1===(E=U.indexOf(")",E+1))){if(q||t){E=H;break}G("bracket")}for(L=E;U.charCodeAt(L-1)===i;)L-=1,M=!M}while(M);F=["brackets",U.slice(H,E+1),z,H-V,z,E-V],H=E}else E=U.indexOf(")",H+1),P=U.slice(H,E+1),-1===E||S.test(P)?F=["(","(",z,H-V]:(F=["brackets",P,z,H-V,z,E-V],H=E);break;case r:case o:T=n===r?"'":'"',E=H;do{if(M=!1,-1===(E=U.indexOf(T,E+1))){if(q||t){E=H+1;break}G("string")}for(L=E;U.charCodeAt(L-1)===i;)L-=1,M=!M}while(M);P=U.slice(H,E+1),O=P.split("\n"),(A=O.length-1)>0?(R=z+A,N=E-O[A].length):(R=z,N=V),F=["string",U.slice(H,E+1),z,H-V,R,E-N],V=N,z=R,H=E;break;case w:x.lastIndex=H+1,x.test(U),E=0===x.lastIndex?U.length-1:x.lastIndex-2,F=["at-word",U.slice(H,E+1),z,H-V,z,E-V],H=E;break;case i:for(E=H,j=!0;U.charCodeAt(E+1)===i;)E+=1,j=!j;if(n=U.charCodeAt(E+1),j&&n!==a&&n!==u&&n!==s&&n!==c&&n!==f&&n!==l&&(E+=1,C.test(U.charAt(E)))){for(;C.test(U.charAt(E+1));)E+=1;U.charCodeAt(E+1)===u&&(E+=1)}F=["word",U.slice(H,E+1),z,H-V,z,E-V],H=E;break;default:n===a&&U.charCodeAt(H+1)===b?(0===(E=U.indexOf("*/",H+2)+1)&&(q||t?E=U.length:G("comment")),P=U.slice(H,E+1),O=P.split("\n"),(A=O.length-1)>0?(R=z+A,N=E-O[A].length):(R=z,N=V),F=["comment",P,z,H-V,R,E-N],V=N,z=R,H=E):(k.lastIndex=H+1,k.test(U),E=0===k.lastIndex?U.length-1:k.lastIndex-2,F=["word",U.slice(H,E+1),z,H-V,z,E-V],W.push(F),H=E)}return H++,F},endOfFile:function(){return 0===$.length&&H>=B},position:function(){return H}}};var r="'".charCodeAt(0),o='"'.charCodeAt(0),i="\\".charCodeAt(0),a="/".charCodeAt(0),s="\n".charCodeAt(0),u=" ".charCodeAt(0),l="\f".charCodeAt(0),c="\t".charCodeAt(0),f="\r".charCodeAt(0),p="[".charCodeAt(0),h="]".charCodeAt(0),d="(".charCodeAt(0),m=")".charCodeAt(0),g="{".charCodeAt(0),v="}".charCodeAt(0),y=";".charCodeAt(0),b="*".charCodeAt(0),_=":".charCodeAt(0),w="@".charCodeAt(0),x=/[ \n\t\r\f{}()'"\\;\/[\]#]/g,k=/[ \n\t\r\f(){}:;@!'"\\\][#]|\/(?=\*)/g,S=/.[\\\/("'\n]/,C=/[a-f0-9]/i;e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=a(n(157)),o=a(n(415)),i=a(n(98));function a(e){return e&&e.__esModule?e:{default:e}}function s(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}var u=0,l=function(){function e(e,t){if(void 0===t&&(t={}),null===e||"object"==typeof e&&!e.toString)throw new Error("PostCSS received "+e+" instead of CSS string");this.css=e.toString(),"\ufeff"===this.css[0]||"￾"===this.css[0]?(this.hasBOM=!0,this.css=this.css.slice(1)):this.hasBOM=!1,t.from&&(/^\w+:\/\//.test(t.from)?this.file=t.from:this.file=i.default.resolve(t.from));var n=new o.default(this.css,t);if(n.text){this.map=n;var r=n.consumer().file;!this.file&&r&&(this.file=this.mapResolve(r))}this.file||(u+=1,this.id="<input css "+u+">"),this.map&&(this.map.file=this.from)}var t,n,a,l=e.prototype;return l.error=function(e,t,n,o){var i;void 0===o&&(o={});var a=this.origin(t,n);return(i=a?new r.default(e,a.line,a.column,a.source,a.file,o.plugin):new r.default(e,t,n,this.css,this.file,o.plugin)).input={line:t,column:n,source:this.css},this.file&&(i.input.file=this.file),i},l.origin=function(e,t){if(!this.map)return!1;var n=this.map.consumer(),r=n.originalPositionFor({line:e,column:t});if(!r.source)return!1;var o={file:this.mapResolve(r.source),line:r.line,column:r.column},i=n.sourceContentFor(r.source);return i&&(o.source=i),o},l.mapResolve=function(e){return/^\w+:\/\//.test(e)?e:i.default.resolve(this.map.consumer().sourceRoot||".",e)},t=e,(n=[{key:"from",get:function(){return this.file||this.id}}])&&s(t.prototype,n),a&&s(t,a),e}();t.default=l,e.exports=t.default},function(e,t,n){"use strict";(function(r){t.__esModule=!0,t.default=void 0;var o=s(n(161)),i=s(n(98)),a=s(n(416));function s(e){return e&&e.__esModule?e:{default:e}}var u=function(){function e(e,t){this.loadAnnotation(e),this.inline=this.startWith(this.annotation,"data:");var n=t.map?t.map.prev:void 0,r=this.loadMap(t.from,n);r&&(this.text=r)}var t=e.prototype;return t.consumer=function(){return this.consumerCache||(this.consumerCache=new o.default.SourceMapConsumer(this.text)),this.consumerCache},t.withContent=function(){return!!(this.consumer().sourcesContent&&this.consumer().sourcesContent.length>0)},t.startWith=function(e,t){return!!e&&e.substr(0,t.length)===t},t.loadAnnotation=function(e){var t=e.match(/\/\*\s*# sourceMappingURL=(.*)\s*\*\//);t&&(this.annotation=t[1].trim())},t.decodeInline=function(e){var t,n="data:application/json,";if(this.startWith(e,n))return decodeURIComponent(e.substr(n.length));if(/^data:application\/json;charset=utf-?8;base64,/.test(e)||/^data:application\/json;base64,/.test(e))return t=e.substr(RegExp.lastMatch.length),r?r.from(t,"base64").toString():window.atob(t);var o=e.match(/data:application\/json;([^,]+),/)[1];throw new Error("Unsupported source map encoding "+o)},t.loadMap=function(e,t){if(!1===t)return!1;if(t){if("string"==typeof t)return t;if("function"==typeof t){var n=t(e);if(n&&a.default.existsSync&&a.default.existsSync(n))return a.default.readFileSync(n,"utf-8").toString().trim();throw new Error("Unable to load previous source map: "+n.toString())}if(t instanceof o.default.SourceMapConsumer)return o.default.SourceMapGenerator.fromSourceMap(t).toString();if(t instanceof o.default.SourceMapGenerator)return t.toString();if(this.isMap(t))return JSON.stringify(t);throw new Error("Unsupported previous source map format: "+t.toString())}if(this.inline)return this.decodeInline(this.annotation);if(this.annotation){var r=this.annotation;return e&&(r=i.default.join(i.default.dirname(e),r)),this.root=i.default.dirname(r),!(!a.default.existsSync||!a.default.existsSync(r))&&a.default.readFileSync(r,"utf-8").toString().trim()}},t.isMap=function(e){return"object"==typeof e&&("string"==typeof e.mappings||"string"==typeof e._mappings)},e}();t.default=u,e.exports=t.default}).call(this,n(56).Buffer)},function(e,t){},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r={prefix:function(e){var t=e.match(/^(-\w+-)/);return t?t[0]:""},unprefixed:function(e){return e.replace(/^-\w+-/,"")}};t.default=r,e.exports=t.default},function(e,t,n){"use strict";var r=n(419),o=n(420);function i(){this.protocol=null,this.slashes=null,this.auth=null,this.host=null,this.port=null,this.hostname=null,this.hash=null,this.search=null,this.query=null,this.pathname=null,this.path=null,this.href=null}t.parse=b,t.resolve=function(e,t){return b(e,!1,!0).resolve(t)},t.resolveObject=function(e,t){return e?b(e,!1,!0).resolveObject(t):t},t.format=function(e){o.isString(e)&&(e=b(e));return e instanceof i?e.format():i.prototype.format.call(e)},t.Url=i;var a=/^([a-z0-9.+-]+:)/i,s=/:[0-9]*$/,u=/^(\/\/?(?!\/)[^\?\s]*)(\?[^\s]*)?$/,l=["{","}","|","\\","^","`"].concat(["<",">",'"',"`"," ","\r","\n","\t"]),c=["'"].concat(l),f=["%","/","?",";","#"].concat(c),p=["/","?","#"],h=/^[+a-z0-9A-Z_-]{0,63}$/,d=/^([+a-z0-9A-Z_-]{0,63})(.*)$/,m={javascript:!0,"javascript:":!0},g={javascript:!0,"javascript:":!0},v={http:!0,https:!0,ftp:!0,gopher:!0,file:!0,"http:":!0,"https:":!0,"ftp:":!0,"gopher:":!0,"file:":!0},y=n(421);function b(e,t,n){if(e&&o.isObject(e)&&e instanceof i)return e;var r=new i;return r.parse(e,t,n),r}i.prototype.parse=function(e,t,n){if(!o.isString(e))throw new TypeError("Parameter 'url' must be a string, not "+typeof e);var i=e.indexOf("?"),s=-1!==i&&i<e.indexOf("#")?"?":"#",l=e.split(s);l[0]=l[0].replace(/\\/g,"/");var b=e=l.join(s);if(b=b.trim(),!n&&1===e.split("#").length){var _=u.exec(b);if(_)return this.path=b,this.href=b,this.pathname=_[1],_[2]?(this.search=_[2],this.query=t?y.parse(this.search.substr(1)):this.search.substr(1)):t&&(this.search="",this.query={}),this}var w=a.exec(b);if(w){var x=(w=w[0]).toLowerCase();this.protocol=x,b=b.substr(w.length)}if(n||w||b.match(/^\/\/[^@\/]+@[^@\/]+/)){var k="//"===b.substr(0,2);!k||w&&g[w]||(b=b.substr(2),this.slashes=!0)}if(!g[w]&&(k||w&&!v[w])){for(var S,C,E=-1,T=0;T<p.length;T++){-1!==(O=b.indexOf(p[T]))&&(-1===E||O<E)&&(E=O)}-1!==(C=-1===E?b.lastIndexOf("@"):b.lastIndexOf("@",E))&&(S=b.slice(0,C),b=b.slice(C+1),this.auth=decodeURIComponent(S)),E=-1;for(T=0;T<f.length;T++){var O;-1!==(O=b.indexOf(f[T]))&&(-1===E||O<E)&&(E=O)}-1===E&&(E=b.length),this.host=b.slice(0,E),b=b.slice(E),this.parseHost(),this.hostname=this.hostname||"";var A="["===this.hostname[0]&&"]"===this.hostname[this.hostname.length-1];if(!A)for(var P=this.hostname.split(/\./),j=(T=0,P.length);T<j;T++){var R=P[T];if(R&&!R.match(h)){for(var N="",M=0,L=R.length;M<L;M++)R.charCodeAt(M)>127?N+="x":N+=R[M];if(!N.match(h)){var I=P.slice(0,T),D=P.slice(T+1),F=R.match(d);F&&(I.push(F[1]),D.unshift(F[2])),D.length&&(b="/"+D.join(".")+b),this.hostname=I.join(".");break}}}this.hostname.length>255?this.hostname="":this.hostname=this.hostname.toLowerCase(),A||(this.hostname=r.toASCII(this.hostname));var U=this.port?":"+this.port:"",q=this.hostname||"";this.host=q+U,this.href+=this.host,A&&(this.hostname=this.hostname.substr(1,this.hostname.length-2),"/"!==b[0]&&(b="/"+b))}if(!m[x])for(T=0,j=c.length;T<j;T++){var B=c[T];if(-1!==b.indexOf(B)){var V=encodeURIComponent(B);V===B&&(V=escape(B)),b=b.split(B).join(V)}}var z=b.indexOf("#");-1!==z&&(this.hash=b.substr(z),b=b.slice(0,z));var H=b.indexOf("?");if(-1!==H?(this.search=b.substr(H),this.query=b.substr(H+1),t&&(this.query=y.parse(this.query)),b=b.slice(0,H)):t&&(this.search="",this.query={}),b&&(this.pathname=b),v[x]&&this.hostname&&!this.pathname&&(this.pathname="/"),this.pathname||this.search){U=this.pathname||"";var W=this.search||"";this.path=U+W}return this.href=this.format(),this},i.prototype.format=function(){var e=this.auth||"";e&&(e=(e=encodeURIComponent(e)).replace(/%3A/i,":"),e+="@");var t=this.protocol||"",n=this.pathname||"",r=this.hash||"",i=!1,a="";this.host?i=e+this.host:this.hostname&&(i=e+(-1===this.hostname.indexOf(":")?this.hostname:"["+this.hostname+"]"),this.port&&(i+=":"+this.port)),this.query&&o.isObject(this.query)&&Object.keys(this.query).length&&(a=y.stringify(this.query));var s=this.search||a&&"?"+a||"";return t&&":"!==t.substr(-1)&&(t+=":"),this.slashes||(!t||v[t])&&!1!==i?(i="//"+(i||""),n&&"/"!==n.charAt(0)&&(n="/"+n)):i||(i=""),r&&"#"!==r.charAt(0)&&(r="#"+r),s&&"?"!==s.charAt(0)&&(s="?"+s),t+i+(n=n.replace(/[?#]/g,function(e){return encodeURIComponent(e)}))+(s=s.replace("#","%23"))+r},i.prototype.resolve=function(e){return this.resolveObject(b(e,!1,!0)).format()},i.prototype.resolveObject=function(e){if(o.isString(e)){var t=new i;t.parse(e,!1,!0),e=t}for(var n=new i,r=Object.keys(this),a=0;a<r.length;a++){var s=r[a];n[s]=this[s]}if(n.hash=e.hash,""===e.href)return n.href=n.format(),n;if(e.slashes&&!e.protocol){for(var u=Object.keys(e),l=0;l<u.length;l++){var c=u[l];"protocol"!==c&&(n[c]=e[c])}return v[n.protocol]&&n.hostname&&!n.pathname&&(n.path=n.pathname="/"),n.href=n.format(),n}if(e.protocol&&e.protocol!==n.protocol){if(!v[e.protocol]){for(var f=Object.keys(e),p=0;p<f.length;p++){var h=f[p];n[h]=e[h]}return n.href=n.format(),n}if(n.protocol=e.protocol,e.host||g[e.protocol])n.pathname=e.pathname;else{for(var d=(e.pathname||"").split("/");d.length&&!(e.host=d.shift()););e.host||(e.host=""),e.hostname||(e.hostname=""),""!==d[0]&&d.unshift(""),d.length<2&&d.unshift(""),n.pathname=d.join("/")}if(n.search=e.search,n.query=e.query,n.host=e.host||"",n.auth=e.auth,n.hostname=e.hostname||e.host,n.port=e.port,n.pathname||n.search){var m=n.pathname||"",y=n.search||"";n.path=m+y}return n.slashes=n.slashes||e.slashes,n.href=n.format(),n}var b=n.pathname&&"/"===n.pathname.charAt(0),_=e.host||e.pathname&&"/"===e.pathname.charAt(0),w=_||b||n.host&&e.pathname,x=w,k=n.pathname&&n.pathname.split("/")||[],S=(d=e.pathname&&e.pathname.split("/")||[],n.protocol&&!v[n.protocol]);if(S&&(n.hostname="",n.port=null,n.host&&(""===k[0]?k[0]=n.host:k.unshift(n.host)),n.host="",e.protocol&&(e.hostname=null,e.port=null,e.host&&(""===d[0]?d[0]=e.host:d.unshift(e.host)),e.host=null),w=w&&(""===d[0]||""===k[0])),_)n.host=e.host||""===e.host?e.host:n.host,n.hostname=e.hostname||""===e.hostname?e.hostname:n.hostname,n.search=e.search,n.query=e.query,k=d;else if(d.length)k||(k=[]),k.pop(),k=k.concat(d),n.search=e.search,n.query=e.query;else if(!o.isNullOrUndefined(e.search)){if(S)n.hostname=n.host=k.shift(),(A=!!(n.host&&n.host.indexOf("@")>0)&&n.host.split("@"))&&(n.auth=A.shift(),n.host=n.hostname=A.shift());return n.search=e.search,n.query=e.query,o.isNull(n.pathname)&&o.isNull(n.search)||(n.path=(n.pathname?n.pathname:"")+(n.search?n.search:"")),n.href=n.format(),n}if(!k.length)return n.pathname=null,n.search?n.path="/"+n.search:n.path=null,n.href=n.format(),n;for(var C=k.slice(-1)[0],E=(n.host||e.host||k.length>1)&&("."===C||".."===C)||""===C,T=0,O=k.length;O>=0;O--)"."===(C=k[O])?k.splice(O,1):".."===C?(k.splice(O,1),T++):T&&(k.splice(O,1),T--);if(!w&&!x)for(;T--;T)k.unshift("..");!w||""===k[0]||k[0]&&"/"===k[0].charAt(0)||k.unshift(""),E&&"/"!==k.join("/").substr(-1)&&k.push("");var A,P=""===k[0]||k[0]&&"/"===k[0].charAt(0);S&&(n.hostname=n.host=P?"":k.length?k.shift():"",(A=!!(n.host&&n.host.indexOf("@")>0)&&n.host.split("@"))&&(n.auth=A.shift(),n.host=n.hostname=A.shift()));return(w=w||n.host&&k.length)&&!P&&k.unshift(""),k.length?n.pathname=k.join("/"):(n.pathname=null,n.path=null),o.isNull(n.pathname)&&o.isNull(n.search)||(n.path=(n.pathname?n.pathname:"")+(n.search?n.search:"")),n.auth=e.auth||n.auth,n.slashes=n.slashes||e.slashes,n.href=n.format(),n},i.prototype.parseHost=function(){var e=this.host,t=s.exec(e);t&&(":"!==(t=t[0])&&(this.port=t.substr(1)),e=e.substr(0,e.length-t.length)),e&&(this.hostname=e)}},function(e,t,n){(function(e,r){var o;/*! https://mths.be/punycode v1.4.1 by @mathias */!function(i){t&&t.nodeType,e&&e.nodeType;var a="object"==typeof r&&r;a.global!==a&&a.window!==a&&a.self;var s,u=2147483647,l=36,c=1,f=26,p=38,h=700,d=72,m=128,g="-",v=/^xn--/,y=/[^\x20-\x7E]/,b=/[\x2E\u3002\uFF0E\uFF61]/g,_={overflow:"Overflow: input needs wider integers to process","not-basic":"Illegal input >= 0x80 (not a basic code point)","invalid-input":"Invalid input"},w=l-c,x=Math.floor,k=String.fromCharCode;function S(e){throw new RangeError(_[e])}function C(e,t){for(var n=e.length,r=[];n--;)r[n]=t(e[n]);return r}function E(e,t){var n=e.split("@"),r="";return n.length>1&&(r=n[0]+"@",e=n[1]),r+C((e=e.replace(b,".")).split("."),t).join(".")}function T(e){for(var t,n,r=[],o=0,i=e.length;o<i;)(t=e.charCodeAt(o++))>=55296&&t<=56319&&o<i?56320==(64512&(n=e.charCodeAt(o++)))?r.push(((1023&t)<<10)+(1023&n)+65536):(r.push(t),o--):r.push(t);return r}function O(e){return C(e,function(e){var t="";return e>65535&&(t+=k((e-=65536)>>>10&1023|55296),e=56320|1023&e),t+=k(e)}).join("")}function A(e,t){return e+22+75*(e<26)-((0!=t)<<5)}function P(e,t,n){var r=0;for(e=n?x(e/h):e>>1,e+=x(e/t);e>w*f>>1;r+=l)e=x(e/w);return x(r+(w+1)*e/(e+p))}function j(e){var t,n,r,o,i,a,s,p,h,v,y,b=[],_=e.length,w=0,k=m,C=d;for((n=e.lastIndexOf(g))<0&&(n=0),r=0;r<n;++r)e.charCodeAt(r)>=128&&S("not-basic"),b.push(e.charCodeAt(r));for(o=n>0?n+1:0;o<_;){for(i=w,a=1,s=l;o>=_&&S("invalid-input"),((p=(y=e.charCodeAt(o++))-48<10?y-22:y-65<26?y-65:y-97<26?y-97:l)>=l||p>x((u-w)/a))&&S("overflow"),w+=p*a,!(p<(h=s<=C?c:s>=C+f?f:s-C));s+=l)a>x(u/(v=l-h))&&S("overflow"),a*=v;C=P(w-i,t=b.length+1,0==i),x(w/t)>u-k&&S("overflow"),k+=x(w/t),w%=t,b.splice(w++,0,k)}return O(b)}function R(e){var t,n,r,o,i,a,s,p,h,v,y,b,_,w,C,E=[];for(b=(e=T(e)).length,t=m,n=0,i=d,a=0;a<b;++a)(y=e[a])<128&&E.push(k(y));for(r=o=E.length,o&&E.push(g);r<b;){for(s=u,a=0;a<b;++a)(y=e[a])>=t&&y<s&&(s=y);for(s-t>x((u-n)/(_=r+1))&&S("overflow"),n+=(s-t)*_,t=s,a=0;a<b;++a)if((y=e[a])<t&&++n>u&&S("overflow"),y==t){for(p=n,h=l;!(p<(v=h<=i?c:h>=i+f?f:h-i));h+=l)C=p-v,w=l-v,E.push(k(A(v+C%w,0))),p=x(C/w);E.push(k(A(p,0))),i=P(n,_,r==o),n=0,++r}++n,++t}return E.join("")}s={version:"1.4.1",ucs2:{decode:T,encode:O},decode:j,encode:R,toASCII:function(e){return E(e,function(e){return y.test(e)?"xn--"+R(e):e})},toUnicode:function(e){return E(e,function(e){return v.test(e)?j(e.slice(4).toLowerCase()):e})}},void 0===(o=function(){return s}.call(t,n,t,e))||(e.exports=o)}()}).call(this,n(26)(e),n(8))},function(e,t,n){"use strict";e.exports={isString:function(e){return"string"==typeof e},isObject:function(e){return"object"==typeof e&&null!==e},isNull:function(e){return null===e},isNullOrUndefined:function(e){return null==e}}},function(e,t,n){"use strict";t.decode=t.parse=n(422),t.encode=t.stringify=n(423)},function(e,t,n){"use strict";function r(e,t){return Object.prototype.hasOwnProperty.call(e,t)}e.exports=function(e,t,n,i){t=t||"&",n=n||"=";var a={};if("string"!=typeof e||0===e.length)return a;var s=/\+/g;e=e.split(t);var u=1e3;i&&"number"==typeof i.maxKeys&&(u=i.maxKeys);var l=e.length;u>0&&l>u&&(l=u);for(var c=0;c<l;++c){var f,p,h,d,m=e[c].replace(s,"%20"),g=m.indexOf(n);g>=0?(f=m.substr(0,g),p=m.substr(g+1)):(f=m,p=""),h=decodeURIComponent(f),d=decodeURIComponent(p),r(a,h)?o(a[h])?a[h].push(d):a[h]=[a[h],d]:a[h]=d}return a};var o=Array.isArray||function(e){return"[object Array]"===Object.prototype.toString.call(e)}},function(e,t,n){"use strict";var r=function(e){switch(typeof e){case"string":return e;case"boolean":return e?"true":"false";case"number":return isFinite(e)?e:"";default:return""}};e.exports=function(e,t,n,s){return t=t||"&",n=n||"=",null===e&&(e=void 0),"object"==typeof e?i(a(e),function(a){var s=encodeURIComponent(r(a))+n;return o(e[a])?i(e[a],function(e){return s+encodeURIComponent(r(e))}).join(t):s+encodeURIComponent(r(e[a]))}).join(t):s?encodeURIComponent(r(s))+n+encodeURIComponent(r(e)):""};var o=Array.isArray||function(e){return"[object Array]"===Object.prototype.toString.call(e)};function i(e,t){if(e.map)return e.map(t);for(var n=[],r=0;r<e.length;r++)n.push(t(e[r],r));return n}var a=Object.keys||function(e){var t=[];for(var n in e)Object.prototype.hasOwnProperty.call(e,n)&&t.push(n);return t}},function(e,t,n){"use strict";n.r(t);var r=n(2),o=n.n(r),i=n(1),a=n.n(i),s=n(0),u=n.n(s),l=n(4),c=n.n(l),f=n(3),p=n.n(f);function h(e){return"/"===e.charAt(0)}function d(e,t){for(var n=t,r=n+1,o=e.length;r<o;n+=1,r+=1)e[n]=e[r];e.pop()}var m=function(e){var t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"",n=e&&e.split("/")||[],r=t&&t.split("/")||[],o=e&&h(e),i=t&&h(t),a=o||i;if(e&&h(e)?r=n:n.length&&(r.pop(),r=r.concat(n)),!r.length)return"/";var s=void 0;if(r.length){var u=r[r.length-1];s="."===u||".."===u||""===u}else s=!1;for(var l=0,c=r.length;c>=0;c--){var f=r[c];"."===f?d(r,c):".."===f?(d(r,c),l++):l&&(d(r,c),l--)}if(!a)for(;l--;l)r.unshift("..");!a||""===r[0]||r[0]&&h(r[0])||r.unshift("");var p=r.join("/");return s&&"/"!==p.substr(-1)&&(p+="/"),p},g="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e};var v=function e(t,n){if(t===n)return!0;if(null==t||null==n)return!1;if(Array.isArray(t))return Array.isArray(n)&&t.length===n.length&&t.every(function(t,r){return e(t,n[r])});var r=void 0===t?"undefined":g(t);if(r!==(void 0===n?"undefined":g(n)))return!1;if("object"===r){var o=t.valueOf(),i=n.valueOf();if(o!==t||i!==n)return e(o,i);var a=Object.keys(t),s=Object.keys(n);return a.length===s.length&&a.every(function(r){return e(t[r],n[r])})}return!1},y=function(e){return"/"===e.charAt(0)?e:"/"+e},b=function(e){return"/"===e.charAt(0)?e.substr(1):e},_=function(e,t){return new RegExp("^"+t+"(\\/|\\?|#|$)","i").test(e)},w=function(e,t){return _(e,t)?e.substr(t.length):e},x=function(e){return"/"===e.charAt(e.length-1)?e.slice(0,-1):e},k=function(e){var t=e.pathname,n=e.search,r=e.hash,o=t||"/";return n&&"?"!==n&&(o+="?"===n.charAt(0)?n:"?"+n),r&&"#"!==r&&(o+="#"===r.charAt(0)?r:"#"+r),o},S=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},C=function(e,t,n,r){var o=void 0;"string"==typeof e?(o=function(e){var t=e||"/",n="",r="",o=t.indexOf("#");-1!==o&&(r=t.substr(o),t=t.substr(0,o));var i=t.indexOf("?");return-1!==i&&(n=t.substr(i),t=t.substr(0,i)),{pathname:t,search:"?"===n?"":n,hash:"#"===r?"":r}}(e)).state=t:(void 0===(o=S({},e)).pathname&&(o.pathname=""),o.search?"?"!==o.search.charAt(0)&&(o.search="?"+o.search):o.search="",o.hash?"#"!==o.hash.charAt(0)&&(o.hash="#"+o.hash):o.hash="",void 0!==t&&void 0===o.state&&(o.state=t));try{o.pathname=decodeURI(o.pathname)}catch(e){throw e instanceof URIError?new URIError('Pathname "'+o.pathname+'" could not be decoded. This is likely caused by an invalid percent-encoding.'):e}return n&&(o.key=n),r?o.pathname?"/"!==o.pathname.charAt(0)&&(o.pathname=m(o.pathname,r.pathname)):o.pathname=r.pathname:o.pathname||(o.pathname="/"),o},E=function(e,t){return e.pathname===t.pathname&&e.search===t.search&&e.hash===t.hash&&e.key===t.key&&v(e.state,t.state)},T=function(){var e=null,t=[];return{setPrompt:function(t){return c()(null==e,"A history supports only one prompt at a time"),e=t,function(){e===t&&(e=null)}},confirmTransitionTo:function(t,n,r,o){if(null!=e){var i="function"==typeof e?e(t,n):e;"string"==typeof i?"function"==typeof r?r(i,o):(c()(!1,"A history needs a getUserConfirmation function in order to use a prompt message"),o(!0)):o(!1!==i)}else o(!0)},appendListener:function(e){var n=!0,r=function(){n&&e.apply(void 0,arguments)};return t.push(r),function(){n=!1,t=t.filter(function(e){return e!==r})}},notifyListeners:function(){for(var e=arguments.length,n=Array(e),r=0;r<e;r++)n[r]=arguments[r];t.forEach(function(e){return e.apply(void 0,n)})}}},O=!("undefined"==typeof window||!window.document||!window.document.createElement),A=function(e,t,n){return e.addEventListener?e.addEventListener(t,n,!1):e.attachEvent("on"+t,n)},P=function(e,t,n){return e.removeEventListener?e.removeEventListener(t,n,!1):e.detachEvent("on"+t,n)},j=function(e,t){return t(window.confirm(e))},R="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},N=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},M=function(){try{return window.history.state||{}}catch(e){return{}}},L=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};p()(O,"Browser history needs a DOM");var t,n=window.history,r=(-1===(t=window.navigator.userAgent).indexOf("Android 2.")&&-1===t.indexOf("Android 4.0")||-1===t.indexOf("Mobile Safari")||-1!==t.indexOf("Chrome")||-1!==t.indexOf("Windows Phone"))&&window.history&&"pushState"in window.history,o=!(-1===window.navigator.userAgent.indexOf("Trident")),i=e.forceRefresh,a=void 0!==i&&i,s=e.getUserConfirmation,u=void 0===s?j:s,l=e.keyLength,f=void 0===l?6:l,h=e.basename?x(y(e.basename)):"",d=function(e){var t=e||{},n=t.key,r=t.state,o=window.location,i=o.pathname+o.search+o.hash;return c()(!h||_(i,h),'You are attempting to use a basename on a page whose URL path does not begin with the basename. Expected path "'+i+'" to begin with "'+h+'".'),h&&(i=w(i,h)),C(i,r,n)},m=function(){return Math.random().toString(36).substr(2,f)},g=T(),v=function(e){N(H,e),H.length=n.length,g.notifyListeners(H.location,H.action)},b=function(e){(function(e){return void 0===e.state&&-1===navigator.userAgent.indexOf("CriOS")})(e)||L(d(e.state))},S=function(){L(d(M()))},E=!1,L=function(e){E?(E=!1,v()):g.confirmTransitionTo(e,"POP",u,function(t){t?v({action:"POP",location:e}):I(e)})},I=function(e){var t=H.location,n=F.indexOf(t.key);-1===n&&(n=0);var r=F.indexOf(e.key);-1===r&&(r=0);var o=n-r;o&&(E=!0,q(o))},D=d(M()),F=[D.key],U=function(e){return h+k(e)},q=function(e){n.go(e)},B=0,V=function(e){1===(B+=e)?(A(window,"popstate",b),o&&A(window,"hashchange",S)):0===B&&(P(window,"popstate",b),o&&P(window,"hashchange",S))},z=!1,H={length:n.length,action:"POP",location:D,createHref:U,push:function(e,t){c()(!("object"===(void 0===e?"undefined":R(e))&&void 0!==e.state&&void 0!==t),"You should avoid providing a 2nd state argument to push when the 1st argument is a location-like object that already has state; it is ignored");var o=C(e,t,m(),H.location);g.confirmTransitionTo(o,"PUSH",u,function(e){if(e){var t=U(o),i=o.key,s=o.state;if(r)if(n.pushState({key:i,state:s},null,t),a)window.location.href=t;else{var u=F.indexOf(H.location.key),l=F.slice(0,-1===u?0:u+1);l.push(o.key),F=l,v({action:"PUSH",location:o})}else c()(void 0===s,"Browser history cannot push state in browsers that do not support HTML5 history"),window.location.href=t}})},replace:function(e,t){c()(!("object"===(void 0===e?"undefined":R(e))&&void 0!==e.state&&void 0!==t),"You should avoid providing a 2nd state argument to replace when the 1st argument is a location-like object that already has state; it is ignored");var o=C(e,t,m(),H.location);g.confirmTransitionTo(o,"REPLACE",u,function(e){if(e){var t=U(o),i=o.key,s=o.state;if(r)if(n.replaceState({key:i,state:s},null,t),a)window.location.replace(t);else{var u=F.indexOf(H.location.key);-1!==u&&(F[u]=o.key),v({action:"REPLACE",location:o})}else c()(void 0===s,"Browser history cannot replace state in browsers that do not support HTML5 history"),window.location.replace(t)}})},go:q,goBack:function(){return q(-1)},goForward:function(){return q(1)},block:function(){var e=arguments.length>0&&void 0!==arguments[0]&&arguments[0],t=g.setPrompt(e);return z||(V(1),z=!0),function(){return z&&(z=!1,V(-1)),t()}},listen:function(e){var t=g.appendListener(e);return V(1),function(){V(-1),t()}}};return H},I=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},D={hashbang:{encodePath:function(e){return"!"===e.charAt(0)?e:"!/"+b(e)},decodePath:function(e){return"!"===e.charAt(0)?e.substr(1):e}},noslash:{encodePath:b,decodePath:y},slash:{encodePath:y,decodePath:y}},F=function(){var e=window.location.href,t=e.indexOf("#");return-1===t?"":e.substring(t+1)},U=function(e){var t=window.location.href.indexOf("#");window.location.replace(window.location.href.slice(0,t>=0?t:0)+"#"+e)},q=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};p()(O,"Hash history needs a DOM");var t=window.history,n=-1===window.navigator.userAgent.indexOf("Firefox"),r=e.getUserConfirmation,o=void 0===r?j:r,i=e.hashType,a=void 0===i?"slash":i,s=e.basename?x(y(e.basename)):"",u=D[a],l=u.encodePath,f=u.decodePath,h=function(){var e=f(F());return c()(!s||_(e,s),'You are attempting to use a basename on a page whose URL path does not begin with the basename. Expected path "'+e+'" to begin with "'+s+'".'),s&&(e=w(e,s)),C(e)},d=T(),m=function(e){I(W,e),W.length=t.length,d.notifyListeners(W.location,W.action)},g=!1,v=null,b=function(){var e=F(),t=l(e);if(e!==t)U(t);else{var n=h(),r=W.location;if(!g&&E(r,n))return;if(v===k(n))return;v=null,S(n)}},S=function(e){g?(g=!1,m()):d.confirmTransitionTo(e,"POP",o,function(t){t?m({action:"POP",location:e}):R(e)})},R=function(e){var t=W.location,n=q.lastIndexOf(k(t));-1===n&&(n=0);var r=q.lastIndexOf(k(e));-1===r&&(r=0);var o=n-r;o&&(g=!0,B(o))},N=F(),M=l(N);N!==M&&U(M);var L=h(),q=[k(L)],B=function(e){c()(n,"Hash history go(n) causes a full page reload in this browser"),t.go(e)},V=0,z=function(e){1===(V+=e)?A(window,"hashchange",b):0===V&&P(window,"hashchange",b)},H=!1,W={length:t.length,action:"POP",location:L,createHref:function(e){return"#"+l(s+k(e))},push:function(e,t){c()(void 0===t,"Hash history cannot push state; it is ignored");var n=C(e,void 0,void 0,W.location);d.confirmTransitionTo(n,"PUSH",o,function(e){if(e){var t=k(n),r=l(s+t);if(F()!==r){v=t,function(e){window.location.hash=e}(r);var o=q.lastIndexOf(k(W.location)),i=q.slice(0,-1===o?0:o+1);i.push(t),q=i,m({action:"PUSH",location:n})}else c()(!1,"Hash history cannot PUSH the same path; a new entry will not be added to the history stack"),m()}})},replace:function(e,t){c()(void 0===t,"Hash history cannot replace state; it is ignored");var n=C(e,void 0,void 0,W.location);d.confirmTransitionTo(n,"REPLACE",o,function(e){if(e){var t=k(n),r=l(s+t);F()!==r&&(v=t,U(r));var o=q.indexOf(k(W.location));-1!==o&&(q[o]=t),m({action:"REPLACE",location:n})}})},go:B,goBack:function(){return B(-1)},goForward:function(){return B(1)},block:function(){var e=arguments.length>0&&void 0!==arguments[0]&&arguments[0],t=d.setPrompt(e);return H||(z(1),H=!0),function(){return H&&(H=!1,z(-1)),t()}},listen:function(e){var t=d.appendListener(e);return z(1),function(){z(-1),t()}}};return W},B="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},V=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},z=function(e,t,n){return Math.min(Math.max(e,t),n)},H=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{},t=e.getUserConfirmation,n=e.initialEntries,r=void 0===n?["/"]:n,o=e.initialIndex,i=void 0===o?0:o,a=e.keyLength,s=void 0===a?6:a,u=T(),l=function(e){V(g,e),g.length=g.entries.length,u.notifyListeners(g.location,g.action)},f=function(){return Math.random().toString(36).substr(2,s)},p=z(i,0,r.length-1),h=r.map(function(e){return C(e,void 0,"string"==typeof e?f():e.key||f())}),d=k,m=function(e){var n=z(g.index+e,0,g.entries.length-1),r=g.entries[n];u.confirmTransitionTo(r,"POP",t,function(e){e?l({action:"POP",location:r,index:n}):l()})},g={length:h.length,action:"POP",location:h[p],index:p,entries:h,createHref:d,push:function(e,n){c()(!("object"===(void 0===e?"undefined":B(e))&&void 0!==e.state&&void 0!==n),"You should avoid providing a 2nd state argument to push when the 1st argument is a location-like object that already has state; it is ignored");var r=C(e,n,f(),g.location);u.confirmTransitionTo(r,"PUSH",t,function(e){if(e){var t=g.index+1,n=g.entries.slice(0);n.length>t?n.splice(t,n.length-t,r):n.push(r),l({action:"PUSH",location:r,index:t,entries:n})}})},replace:function(e,n){c()(!("object"===(void 0===e?"undefined":B(e))&&void 0!==e.state&&void 0!==n),"You should avoid providing a 2nd state argument to replace when the 1st argument is a location-like object that already has state; it is ignored");var r=C(e,n,f(),g.location);u.confirmTransitionTo(r,"REPLACE",t,function(e){e&&(g.entries[g.index]=r,l({action:"REPLACE",location:r}))})},go:m,goBack:function(){return m(-1)},goForward:function(){return m(1)},canGo:function(e){var t=g.index+e;return t>=0&&t<g.entries.length},block:function(){var e=arguments.length>0&&void 0!==arguments[0]&&arguments[0];return u.setPrompt(e)},listen:function(e){return u.appendListener(e)}};return g},W=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function $(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var G=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=$(this,e.call.apply(e,[this].concat(i))),r.state={match:r.computeMatch(r.props.history.location.pathname)},$(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.getChildContext=function(){return{router:W({},this.context.router,{history:this.props.history,route:{location:this.props.history.location,match:this.state.match}})}},t.prototype.computeMatch=function(e){return{path:"/",url:"/",params:{},isExact:"/"===e}},t.prototype.componentWillMount=function(){var e=this,t=this.props,n=t.children,r=t.history;p()(null==n||1===a.a.Children.count(n),"A <Router> may have only one child element"),this.unlisten=r.listen(function(){e.setState({match:e.computeMatch(r.location.pathname)})})},t.prototype.componentWillReceiveProps=function(e){o()(this.props.history===e.history,"You cannot change <Router history>")},t.prototype.componentWillUnmount=function(){this.unlisten()},t.prototype.render=function(){var e=this.props.children;return e?a.a.Children.only(e):null},t}(a.a.Component);G.propTypes={history:u.a.object.isRequired,children:u.a.node},G.contextTypes={router:u.a.object},G.childContextTypes={router:u.a.object.isRequired};var Y=G,Q=Y;function X(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var K=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=X(this,e.call.apply(e,[this].concat(i))),r.history=L(r.props),X(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){o()(!this.props.history,"<BrowserRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { BrowserRouter as Router }`.")},t.prototype.render=function(){return a.a.createElement(Q,{history:this.history,children:this.props.children})},t}(a.a.Component);K.propTypes={basename:u.a.string,forceRefresh:u.a.bool,getUserConfirmation:u.a.func,keyLength:u.a.number,children:u.a.node};var J=K;function Z(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var ee=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=Z(this,e.call.apply(e,[this].concat(i))),r.history=q(r.props),Z(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){o()(!this.props.history,"<HashRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { HashRouter as Router }`.")},t.prototype.render=function(){return a.a.createElement(Q,{history:this.history,children:this.props.children})},t}(a.a.Component);ee.propTypes={basename:u.a.string,getUserConfirmation:u.a.func,hashType:u.a.oneOf(["hashbang","noslash","slash"]),children:u.a.node};var te=ee,ne=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function re(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var oe=function(e){return!!(e.metaKey||e.altKey||e.ctrlKey||e.shiftKey)},ie=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=re(this,e.call.apply(e,[this].concat(i))),r.handleClick=function(e){if(r.props.onClick&&r.props.onClick(e),!e.defaultPrevented&&0===e.button&&!r.props.target&&!oe(e)){e.preventDefault();var t=r.context.router.history,n=r.props,o=n.replace,i=n.to;o?t.replace(i):t.push(i)}},re(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.render=function(){var e=this.props,t=(e.replace,e.to),n=e.innerRef,r=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(e,["replace","to","innerRef"]);p()(this.context.router,"You should not use <Link> outside a <Router>"),p()(void 0!==t,'You must specify the "to" property');var o=this.context.router.history,i="string"==typeof t?C(t,null,null,o.location):t,s=o.createHref(i);return a.a.createElement("a",ne({},r,{onClick:this.handleClick,href:s,ref:n}))},t}(a.a.Component);ie.propTypes={onClick:u.a.func,target:u.a.string,replace:u.a.bool,to:u.a.oneOfType([u.a.string,u.a.object]).isRequired,innerRef:u.a.oneOfType([u.a.string,u.a.func])},ie.defaultProps={replace:!1},ie.contextTypes={router:u.a.shape({history:u.a.shape({push:u.a.func.isRequired,replace:u.a.func.isRequired,createHref:u.a.func.isRequired}).isRequired}).isRequired};var ae=ie;function se(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var ue=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=se(this,e.call.apply(e,[this].concat(i))),r.history=H(r.props),se(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){o()(!this.props.history,"<MemoryRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { MemoryRouter as Router }`.")},t.prototype.render=function(){return a.a.createElement(Y,{history:this.history,children:this.props.children})},t}(a.a.Component);ue.propTypes={initialEntries:u.a.array,initialIndex:u.a.number,getUserConfirmation:u.a.func,keyLength:u.a.number,children:u.a.node};var le=ue,ce=n(57),fe=n.n(ce),pe={},he=0,de=function(e){var t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{},n=arguments[2];"string"==typeof t&&(t={path:t});var r=t,o=r.path,i=r.exact,a=void 0!==i&&i,s=r.strict,u=void 0!==s&&s,l=r.sensitive;if(null==o)return n;var c=function(e,t){var n=""+t.end+t.strict+t.sensitive,r=pe[n]||(pe[n]={});if(r[e])return r[e];var o=[],i={re:fe()(e,o,t),keys:o};return he<1e4&&(r[e]=i,he++),i}(o,{end:a,strict:u,sensitive:void 0!==l&&l}),f=c.re,p=c.keys,h=f.exec(e);if(!h)return null;var d=h[0],m=h.slice(1),g=e===d;return a&&!g?null:{path:o,url:"/"===o&&""===d?"/":d,isExact:g,params:p.reduce(function(e,t,n){return e[t.name]=m[n],e},{})}},me=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function ge(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var ve=function(e){return 0===a.a.Children.count(e)},ye=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=ge(this,e.call.apply(e,[this].concat(i))),r.state={match:r.computeMatch(r.props,r.context.router)},ge(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.getChildContext=function(){return{router:me({},this.context.router,{route:{location:this.props.location||this.context.router.route.location,match:this.state.match}})}},t.prototype.computeMatch=function(e,t){var n=e.computedMatch,r=e.location,o=e.path,i=e.strict,a=e.exact,s=e.sensitive;if(n)return n;p()(t,"You should not use <Route> or withRouter() outside a <Router>");var u=t.route,l=(r||u.location).pathname;return de(l,{path:o,strict:i,exact:a,sensitive:s},u.match)},t.prototype.componentWillMount=function(){o()(!(this.props.component&&this.props.render),"You should not use <Route component> and <Route render> in the same route; <Route render> will be ignored"),o()(!(this.props.component&&this.props.children&&!ve(this.props.children)),"You should not use <Route component> and <Route children> in the same route; <Route children> will be ignored"),o()(!(this.props.render&&this.props.children&&!ve(this.props.children)),"You should not use <Route render> and <Route children> in the same route; <Route children> will be ignored")},t.prototype.componentWillReceiveProps=function(e,t){o()(!(e.location&&!this.props.location),'<Route> elements should not change from uncontrolled to controlled (or vice versa). You initially used no "location" prop and then provided one on a subsequent render.'),o()(!(!e.location&&this.props.location),'<Route> elements should not change from controlled to uncontrolled (or vice versa). You provided a "location" prop initially but omitted it on a subsequent render.'),this.setState({match:this.computeMatch(e,t.router)})},t.prototype.render=function(){var e=this.state.match,t=this.props,n=t.children,r=t.component,o=t.render,i=this.context.router,s=i.history,u=i.route,l=i.staticContext,c={match:e,location:this.props.location||u.location,history:s,staticContext:l};return r?e?a.a.createElement(r,c):null:o?e?o(c):null:"function"==typeof n?n(c):n&&!ve(n)?a.a.Children.only(n):null},t}(a.a.Component);ye.propTypes={computedMatch:u.a.object,path:u.a.string,exact:u.a.bool,strict:u.a.bool,sensitive:u.a.bool,component:u.a.func,render:u.a.func,children:u.a.oneOfType([u.a.func,u.a.node]),location:u.a.object},ye.contextTypes={router:u.a.shape({history:u.a.object.isRequired,route:u.a.object.isRequired,staticContext:u.a.object})},ye.childContextTypes={router:u.a.object.isRequired};var be=ye,_e=be,we=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},xe="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e};var ke=function(e){var t=e.to,n=e.exact,r=e.strict,o=e.location,i=e.activeClassName,s=e.className,u=e.activeStyle,l=e.style,c=e.isActive,f=e["aria-current"],p=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(e,["to","exact","strict","location","activeClassName","className","activeStyle","style","isActive","aria-current"]),h="object"===(void 0===t?"undefined":xe(t))?t.pathname:t,d=h&&h.replace(/([.+*?=^!:${}()[\]|\/\\])/g,"\\$1");return a.a.createElement(_e,{path:d,exact:n,strict:r,location:o,children:function(e){var n=e.location,r=e.match,o=!!(c?c(r,n):r);return a.a.createElement(ae,we({to:t,className:o?[s,i].filter(function(e){return e}).join(" "):s,style:o?we({},l,u):l,"aria-current":o&&f||null},p))}})};ke.propTypes={to:ae.propTypes.to,exact:u.a.bool,strict:u.a.bool,location:u.a.object,activeClassName:u.a.string,className:u.a.string,activeStyle:u.a.object,style:u.a.object,isActive:u.a.func,"aria-current":u.a.oneOf(["page","step","location","date","time","true"])},ke.defaultProps={activeClassName:"active","aria-current":"page"};var Se=ke;var Ce=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,e.apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.enable=function(e){this.unblock&&this.unblock(),this.unblock=this.context.router.history.block(e)},t.prototype.disable=function(){this.unblock&&(this.unblock(),this.unblock=null)},t.prototype.componentWillMount=function(){p()(this.context.router,"You should not use <Prompt> outside a <Router>"),this.props.when&&this.enable(this.props.message)},t.prototype.componentWillReceiveProps=function(e){e.when?this.props.when&&this.props.message===e.message||this.enable(e.message):this.disable()},t.prototype.componentWillUnmount=function(){this.disable()},t.prototype.render=function(){return null},t}(a.a.Component);Ce.propTypes={when:u.a.bool,message:u.a.oneOfType([u.a.func,u.a.string]).isRequired},Ce.defaultProps={when:!0},Ce.contextTypes={router:u.a.shape({history:u.a.shape({block:u.a.func.isRequired}).isRequired}).isRequired};var Ee=Ce,Te={},Oe=0,Ae=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:"/",t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};return"/"===e?e:function(e){var t=e,n=Te[t]||(Te[t]={});if(n[e])return n[e];var r=fe.a.compile(e);return Oe<1e4&&(n[e]=r,Oe++),r}(e)(t,{pretty:!0})},Pe=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};var je=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,e.apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.isStatic=function(){return this.context.router&&this.context.router.staticContext},t.prototype.componentWillMount=function(){p()(this.context.router,"You should not use <Redirect> outside a <Router>"),this.isStatic()&&this.perform()},t.prototype.componentDidMount=function(){this.isStatic()||this.perform()},t.prototype.componentDidUpdate=function(e){var t=C(e.to),n=C(this.props.to);E(t,n)?o()(!1,"You tried to redirect to the same route you're currently on: \""+n.pathname+n.search+'"'):this.perform()},t.prototype.computeTo=function(e){var t=e.computedMatch,n=e.to;return t?"string"==typeof n?Ae(n,t.params):Pe({},n,{pathname:Ae(n.pathname,t.params)}):n},t.prototype.perform=function(){var e=this.context.router.history,t=this.props.push,n=this.computeTo(this.props);t?e.push(n):e.replace(n)},t.prototype.render=function(){return null},t}(a.a.Component);je.propTypes={computedMatch:u.a.object,push:u.a.bool,from:u.a.string,to:u.a.oneOfType([u.a.string,u.a.object]).isRequired},je.defaultProps={push:!1},je.contextTypes={router:u.a.shape({history:u.a.shape({push:u.a.func.isRequired,replace:u.a.func.isRequired}).isRequired,staticContext:u.a.object}).isRequired};var Re=je,Ne=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function Me(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var Le=function(e){return"/"===e.charAt(0)?e:"/"+e},Ie=function(e,t){return e?Ne({},t,{pathname:Le(e)+t.pathname}):t},De=function(e,t){if(!e)return t;var n=Le(e);return 0!==t.pathname.indexOf(n)?t:Ne({},t,{pathname:t.pathname.substr(n.length)})},Fe=function(e){return"string"==typeof e?e:k(e)},Ue=function(e){return function(){p()(!1,"You cannot %s with <StaticRouter>",e)}},qe=function(){},Be=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=Me(this,e.call.apply(e,[this].concat(i))),r.createHref=function(e){return Le(r.props.basename+Fe(e))},r.handlePush=function(e){var t=r.props,n=t.basename,o=t.context;o.action="PUSH",o.location=Ie(n,C(e)),o.url=Fe(o.location)},r.handleReplace=function(e){var t=r.props,n=t.basename,o=t.context;o.action="REPLACE",o.location=Ie(n,C(e)),o.url=Fe(o.location)},r.handleListen=function(){return qe},r.handleBlock=function(){return qe},Me(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.getChildContext=function(){return{router:{staticContext:this.props.context}}},t.prototype.componentWillMount=function(){o()(!this.props.history,"<StaticRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { StaticRouter as Router }`.")},t.prototype.render=function(){var e=this.props,t=e.basename,n=(e.context,e.location),r=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(e,["basename","context","location"]),o={createHref:this.createHref,action:"POP",location:De(t,C(n)),push:this.handlePush,replace:this.handleReplace,go:Ue("go"),goBack:Ue("goBack"),goForward:Ue("goForward"),listen:this.handleListen,block:this.handleBlock};return a.a.createElement(Y,Ne({},r,{history:o}))},t}(a.a.Component);Be.propTypes={basename:u.a.string,context:u.a.object.isRequired,location:u.a.oneOfType([u.a.string,u.a.object])},Be.defaultProps={basename:"",location:"/"},Be.childContextTypes={router:u.a.object.isRequired};var Ve=Be;var ze=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,e.apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){p()(this.context.router,"You should not use <Switch> outside a <Router>")},t.prototype.componentWillReceiveProps=function(e){o()(!(e.location&&!this.props.location),'<Switch> elements should not change from uncontrolled to controlled (or vice versa). You initially used no "location" prop and then provided one on a subsequent render.'),o()(!(!e.location&&this.props.location),'<Switch> elements should not change from controlled to uncontrolled (or vice versa). You provided a "location" prop initially but omitted it on a subsequent render.')},t.prototype.render=function(){var e=this.context.router.route,t=this.props.children,n=this.props.location||e.location,r=void 0,o=void 0;return a.a.Children.forEach(t,function(t){if(null==r&&a.a.isValidElement(t)){var i=t.props,s=i.path,u=i.exact,l=i.strict,c=i.sensitive,f=i.from,p=s||f;o=t,r=de(n.pathname,{path:p,exact:u,strict:l,sensitive:c},e.match)}}),r?a.a.cloneElement(o,{location:n,computedMatch:r}):null},t}(a.a.Component);ze.contextTypes={router:u.a.shape({route:u.a.object.isRequired}).isRequired},ze.propTypes={children:u.a.node,location:u.a.object};var He=ze,We=Ae,$e=de,Ge=n(167),Ye=n.n(Ge),Qe=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};var Xe=function(e){var t=function(t){var n=t.wrappedComponentRef,r=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(t,["wrappedComponentRef"]);return a.a.createElement(be,{children:function(t){return a.a.createElement(e,Qe({},r,t,{ref:n}))}})};return t.displayName="withRouter("+(e.displayName||e.name)+")",t.WrappedComponent=e,t.propTypes={wrappedComponentRef:u.a.func},Ye()(t,e)};n.d(t,"BrowserRouter",function(){return J}),n.d(t,"HashRouter",function(){return te}),n.d(t,"Link",function(){return ae}),n.d(t,"MemoryRouter",function(){return le}),n.d(t,"NavLink",function(){return Se}),n.d(t,"Prompt",function(){return Ee}),n.d(t,"Redirect",function(){return Re}),n.d(t,"Route",function(){return _e}),n.d(t,"Router",function(){return Q}),n.d(t,"StaticRouter",function(){return Ve}),n.d(t,"Switch",function(){return He}),n.d(t,"generatePath",function(){return We}),n.d(t,"matchPath",function(){return $e}),n.d(t,"withRouter",function(){return Xe})},function(e,t,n){"use strict";n.r(t);n(0);var r=n(1),o=n.n(r);function i(e){return e.type&&"Tab"===e.type.tabsRole}function a(e){return e.type&&"TabPanel"===e.type.tabsRole}function s(e){return e.type&&"TabList"===e.type.tabsRole}function u(e,t,n){return t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function l(e,t){return r.Children.map(e,function(e){return null===e?null:function(e){return i(e)||s(e)||a(e)}(e)?t(e):e.props&&e.props.children&&"object"==typeof e.props.children?Object(r.cloneElement)(e,function(e){for(var t=1;t<arguments.length;t++){var n=null!=arguments[t]?arguments[t]:{},r=Object.keys(n);"function"==typeof Object.getOwnPropertySymbols&&(r=r.concat(Object.getOwnPropertySymbols(n).filter(function(e){return Object.getOwnPropertyDescriptor(n,e).enumerable}))),r.forEach(function(t){u(e,t,n[t])})}return e}({},e.props,{children:l(e.props.children,t)})):e})}function c(e,t){return r.Children.forEach(e,function(e){null!==e&&(i(e)||a(e)?t(e):e.props&&e.props.children&&"object"==typeof e.props.children&&(s(e)&&t(e),c(e.props.children,t)))})}var f,p=n(12),h=n.n(p),d=0;function m(){return"react-tabs-"+d++}function g(){d=0}function v(e){var t=0;return c(e,function(e){i(e)&&t++}),t}function y(){return(y=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}function b(e){return e&&"getAttribute"in e}function _(e){return b(e)&&"tab"===e.getAttribute("role")}function w(e){return b(e)&&"true"===e.getAttribute("aria-disabled")}try{f=!("undefined"==typeof window||!window.document||!window.document.activeElement)}catch(e){f=!1}var x=function(e){var t,n;function u(){for(var t,n=arguments.length,r=new Array(n),o=0;o<n;o++)r[o]=arguments[o];return(t=e.call.apply(e,[this].concat(r))||this).tabNodes=[],t.handleKeyDown=function(e){if(t.isTabFromContainer(e.target)){var n=t.props.selectedIndex,r=!1,o=!1;32!==e.keyCode&&13!==e.keyCode||(r=!0,o=!1,t.handleClick(e)),37===e.keyCode||38===e.keyCode?(n=t.getPrevTab(n),r=!0,o=!0):39===e.keyCode||40===e.keyCode?(n=t.getNextTab(n),r=!0,o=!0):35===e.keyCode?(n=t.getLastTab(),r=!0,o=!0):36===e.keyCode&&(n=t.getFirstTab(),r=!0,o=!0),r&&e.preventDefault(),o&&t.setSelected(n,e)}},t.handleClick=function(e){var n=e.target;do{if(t.isTabFromContainer(n)){if(w(n))return;var r=[].slice.call(n.parentNode.children).filter(_).indexOf(n);return void t.setSelected(r,e)}}while(null!=(n=n.parentNode))},t}n=e,(t=u).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var p=u.prototype;return p.setSelected=function(e,t){if(!(e<0||e>=this.getTabsCount())){var n=this.props;(0,n.onSelect)(e,n.selectedIndex,t)}},p.getNextTab=function(e){for(var t=this.getTabsCount(),n=e+1;n<t;n++)if(!w(this.getTab(n)))return n;for(var r=0;r<e;r++)if(!w(this.getTab(r)))return r;return e},p.getPrevTab=function(e){for(var t=e;t--;)if(!w(this.getTab(t)))return t;for(t=this.getTabsCount();t-- >e;)if(!w(this.getTab(t)))return t;return e},p.getFirstTab=function(){for(var e=this.getTabsCount(),t=0;t<e;t++)if(!w(this.getTab(t)))return t;return null},p.getLastTab=function(){for(var e=this.getTabsCount();e--;)if(!w(this.getTab(e)))return e;return null},p.getTabsCount=function(){return v(this.props.children)},p.getPanelsCount=function(){return function(e){var t=0;return c(e,function(e){a(e)&&t++}),t}(this.props.children)},p.getTab=function(e){return this.tabNodes["tabs-"+e]},p.getChildren=function(){var e=this,t=0,n=this.props,u=n.children,c=n.disabledTabClassName,p=n.focus,h=n.forceRenderTabPanel,d=n.selectedIndex,g=n.selectedTabClassName,v=n.selectedTabPanelClassName;this.tabIds=this.tabIds||[],this.panelIds=this.panelIds||[];for(var y=this.tabIds.length-this.getTabsCount();y++<0;)this.tabIds.push(m()),this.panelIds.push(m());return l(u,function(n){var u=n;if(s(n)){var m=0,y=!1;f&&(y=o.a.Children.toArray(n.props.children).filter(i).some(function(t,n){return document.activeElement===e.getTab(n)})),u=Object(r.cloneElement)(n,{children:l(n.props.children,function(t){var n="tabs-"+m,o=d===m,i={tabRef:function(t){e.tabNodes[n]=t},id:e.tabIds[m],panelId:e.panelIds[m],selected:o,focus:o&&(p||y)};return g&&(i.selectedClassName=g),c&&(i.disabledClassName=c),m++,Object(r.cloneElement)(t,i)})})}else if(a(n)){var b={id:e.panelIds[t],tabId:e.tabIds[t],selected:d===t};h&&(b.forceRender=h),v&&(b.selectedClassName=v),t++,u=Object(r.cloneElement)(n,b)}return u})},p.isTabFromContainer=function(e){if(!_(e))return!1;var t=e.parentElement;do{if(t===this.node)return!0;if(t.getAttribute("data-tabs"))break;t=t.parentElement}while(t);return!1},p.render=function(){var e=this,t=this.props,n=(t.children,t.className),r=(t.disabledTabClassName,t.domRef),i=(t.focus,t.forceRenderTabPanel,t.onSelect,t.selectedIndex,t.selectedTabClassName,t.selectedTabPanelClassName,function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(t,["children","className","disabledTabClassName","domRef","focus","forceRenderTabPanel","onSelect","selectedIndex","selectedTabClassName","selectedTabPanelClassName"]));return o.a.createElement("div",y({},i,{className:h()(n),onClick:this.handleClick,onKeyDown:this.handleKeyDown,ref:function(t){e.node=t,r&&r(t)},"data-tabs":!0}),this.getChildren())},u}(r.Component);x.defaultProps={className:"react-tabs",focus:!1},x.propTypes={};var k=1,S=function(e){var t,n;function r(t){var n;return(n=e.call(this,t)||this).handleSelected=function(e,t,r){var o=n.props.onSelect,i=n.state.mode;if("function"!=typeof o||!1!==o(e,t,r)){var a={focus:"keydown"===r.type};i===k&&(a.selectedIndex=e),n.setState(a)}},n.state=r.copyPropsToState(n.props,{},t.defaultFocus),n}return n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n,r.getDerivedStateFromProps=function(e,t){return r.copyPropsToState(e,t)},r.getModeFromProps=function(e){return null===e.selectedIndex?k:0},r.copyPropsToState=function(e,t,n){void 0===n&&(n=!1);var o={focus:n,mode:r.getModeFromProps(e)};if(o.mode===k){var i=v(e.children)-1,a=null;a=null!=t.selectedIndex?Math.min(t.selectedIndex,i):e.defaultIndex||0,o.selectedIndex=a}return o},r.prototype.render=function(){var e=this.props,t=e.children,n=(e.defaultIndex,e.defaultFocus,function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(e,["children","defaultIndex","defaultFocus"])),r=this.state,i=r.focus,a=r.selectedIndex;return n.focus=i,n.onSelect=this.handleSelected,null!=a&&(n.selectedIndex=a),o.a.createElement(x,n,t)},r}(r.Component);function C(){return(C=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}S.defaultProps={defaultFocus:!1,forceRenderTabPanel:!1,selectedIndex:null,defaultIndex:null},S.propTypes={},S.tabsRole="Tabs";var E=function(e){var t,n;function r(){return e.apply(this,arguments)||this}return n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n,r.prototype.render=function(){var e=this.props,t=e.children,n=e.className,r=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(e,["children","className"]);return o.a.createElement("ul",C({},r,{className:h()(n),role:"tablist"}),t)},r}(r.Component);function T(){return(T=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}E.defaultProps={className:"react-tabs__tab-list"},E.propTypes={},E.tabsRole="TabList";var O=function(e){var t,n;function r(){return e.apply(this,arguments)||this}n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var i=r.prototype;return i.componentDidMount=function(){this.checkFocus()},i.componentDidUpdate=function(){this.checkFocus()},i.checkFocus=function(){var e=this.props,t=e.selected,n=e.focus;t&&n&&this.node.focus()},i.render=function(){var e,t=this,n=this.props,r=n.children,i=n.className,a=n.disabled,s=n.disabledClassName,u=(n.focus,n.id),l=n.panelId,c=n.selected,f=n.selectedClassName,p=n.tabIndex,d=n.tabRef,m=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(n,["children","className","disabled","disabledClassName","focus","id","panelId","selected","selectedClassName","tabIndex","tabRef"]);return o.a.createElement("li",T({},m,{className:h()(i,(e={},e[f]=c,e[s]=a,e)),ref:function(e){t.node=e,d&&d(e)},role:"tab",id:u,"aria-selected":c?"true":"false","aria-disabled":a?"true":"false","aria-controls":l,tabIndex:p||(c?"0":null)}),r)},r}(r.Component);function A(){return(A=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}O.defaultProps={className:"react-tabs__tab",disabledClassName:"react-tabs__tab--disabled",focus:!1,id:null,panelId:null,selected:!1,selectedClassName:"react-tabs__tab--selected"},O.propTypes={},O.tabsRole="Tab";var P=function(e){var t,n;function r(){return e.apply(this,arguments)||this}return n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n,r.prototype.render=function(){var e,t=this.props,n=t.children,r=t.className,i=t.forceRender,a=t.id,s=t.selected,u=t.selectedClassName,l=t.tabId,c=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(t,["children","className","forceRender","id","selected","selectedClassName","tabId"]);return o.a.createElement("div",A({},c,{className:h()(r,(e={},e[u]=s,e)),role:"tabpanel",id:a,"aria-labelledby":l}),i||s?n:null)},r}(r.Component);P.defaultProps={className:"react-tabs__tab-panel",forceRender:!1,selectedClassName:"react-tabs__tab-panel--selected"},P.propTypes={},P.tabsRole="TabPanel",n.d(t,"Tabs",function(){return S}),n.d(t,"TabList",function(){return E}),n.d(t,"Tab",function(){return O}),n.d(t,"TabPanel",function(){return P}),n.d(t,"resetIdCounter",function(){return g})}]);
+!function(e){"use strict";if(!e.jQuery){var t=function(e,n){return new t.fn.init(e,n)};t.isWindow=function(e){return e&&e===e.window},t.type=function(e){return e?"object"==typeof e||"function"==typeof e?r[i.call(e)]||"object":typeof e:e+""},t.isArray=Array.isArray||function(e){return"array"===t.type(e)},t.isPlainObject=function(e){var n;if(!e||"object"!==t.type(e)||e.nodeType||t.isWindow(e))return!1;try{if(e.constructor&&!o.call(e,"constructor")&&!o.call(e.constructor.prototype,"isPrototypeOf"))return!1}catch(e){return!1}for(n in e);return void 0===n||o.call(e,n)},t.each=function(e,t,n){var r=0,o=e.length,i=u(e);if(n){if(i)for(;r<o&&!1!==t.apply(e[r],n);r++);else for(r in e)if(e.hasOwnProperty(r)&&!1===t.apply(e[r],n))break}else if(i)for(;r<o&&!1!==t.call(e[r],r,e[r]);r++);else for(r in e)if(e.hasOwnProperty(r)&&!1===t.call(e[r],r,e[r]))break;return e},t.data=function(e,r,o){if(void 0===o){var i=e[t.expando],a=i&&n[i];if(void 0===r)return a;if(a&&r in a)return a[r]}else if(void 0!==r){var s=e[t.expando]||(e[t.expando]=++t.uuid);return n[s]=n[s]||{},n[s][r]=o,o}},t.removeData=function(e,r){var o=e[t.expando],i=o&&n[o];i&&(r?t.each(r,function(e,t){delete i[t]}):delete n[o])},t.extend=function(){var e,n,r,o,i,a,s=arguments[0]||{},u=1,l=arguments.length,c=!1;for("boolean"==typeof s&&(c=s,s=arguments[u]||{},u++),"object"!=typeof s&&"function"!==t.type(s)&&(s={}),u===l&&(s=this,u--);u<l;u++)if(i=arguments[u])for(o in i)i.hasOwnProperty(o)&&(e=s[o],s!==(r=i[o])&&(c&&r&&(t.isPlainObject(r)||(n=t.isArray(r)))?(n?(n=!1,a=e&&t.isArray(e)?e:[]):a=e&&t.isPlainObject(e)?e:{},s[o]=t.extend(c,a,r)):void 0!==r&&(s[o]=r)));return s},t.queue=function(e,n,r){if(e){n=(n||"fx")+"queue";var o,i,a,s=t.data(e,n);return r?(!s||t.isArray(r)?s=t.data(e,n,(a=i||[],(o=r)&&(u(Object(o))?function(e,t){for(var n=+t.length,r=0,o=e.length;r<n;)e[o++]=t[r++];if(n!=n)for(;void 0!==t[r];)e[o++]=t[r++];e.length=o}(a,"string"==typeof o?[o]:o):[].push.call(a,o)),a)):s.push(r),s):s||[]}},t.dequeue=function(e,n){t.each(e.nodeType?[e]:e,function(e,r){n=n||"fx";var o=t.queue(r,n),i=o.shift();"inprogress"===i&&(i=o.shift()),i&&("fx"===n&&o.unshift("inprogress"),i.call(r,function(){t.dequeue(r,n)}))})},t.fn=t.prototype={init:function(e){if(e.nodeType)return this[0]=e,this;throw new Error("Not a DOM node.")},offset:function(){var t=this[0].getBoundingClientRect?this[0].getBoundingClientRect():{top:0,left:0};return{top:t.top+(e.pageYOffset||document.scrollTop||0)-(document.clientTop||0),left:t.left+(e.pageXOffset||document.scrollLeft||0)-(document.clientLeft||0)}},position:function(){var e=this[0],n=function(e){for(var t=e.offsetParent;t&&"html"!==t.nodeName.toLowerCase()&&t.style&&"static"===t.style.position.toLowerCase();)t=t.offsetParent;return t||document}(e),r=this.offset(),o=/^(?:body|html)$/i.test(n.nodeName)?{top:0,left:0}:t(n).offset();return r.top-=parseFloat(e.style.marginTop)||0,r.left-=parseFloat(e.style.marginLeft)||0,n.style&&(o.top+=parseFloat(n.style.borderTopWidth)||0,o.left+=parseFloat(n.style.borderLeftWidth)||0),{top:r.top-o.top,left:r.left-o.left}}};var n={};t.expando="velocity"+(new Date).getTime(),t.uuid=0;for(var r={},o=r.hasOwnProperty,i=r.toString,a="Boolean Number String Function Array Date RegExp Object Error".split(" "),s=0;s<a.length;s++)r["[object "+a[s]+"]"]=a[s].toLowerCase();t.fn.init.prototype=t.fn,e.Velocity={Utilities:t}}function u(e){var n=e.length,r=t.type(e);return"function"!==r&&!t.isWindow(e)&&(!(1!==e.nodeType||!n)||("array"===r||0===n||"number"==typeof n&&n>0&&n-1 in e))}}(window),function(i){"use strict";"object"==typeof e.exports?e.exports=i():void 0===(o="function"==typeof(r=i)?r.call(t,n,t,e):r)||(e.exports=o)}(function(){"use strict";return function(e,t,n,r){var o,i=function(){if(n.documentMode)return n.documentMode;for(var e=7;e>4;e--){var t=n.createElement("div");if(t.innerHTML="\x3c!--[if IE "+e+"]><span></span><![endif]--\x3e",t.getElementsByTagName("span").length)return t=null,e}return r}(),a=(o=0,t.webkitRequestAnimationFrame||t.mozRequestAnimationFrame||function(e){var t,n=(new Date).getTime();return t=Math.max(0,16-(n-o)),o=n+t,setTimeout(function(){e(n+t)},t)}),s=function(){var e=t.performance||{};if("function"!=typeof e.now){var n=e.timing&&e.timing.navigationStart?e.timing.navigationStart:(new Date).getTime();e.now=function(){return(new Date).getTime()-n}}return e}();var u=function(){var e=Array.prototype.slice;try{return e.call(n.documentElement),e}catch(t){return function(t,n){var r=this.length;if("number"!=typeof t&&(t=0),"number"!=typeof n&&(n=r),this.slice)return e.call(this,t,n);var o,i=[],a=t>=0?t:Math.max(0,r+t),s=(n<0?r+n:Math.min(n,r))-a;if(s>0)if(i=new Array(s),this.charAt)for(o=0;o<s;o++)i[o]=this.charAt(a+o);else for(o=0;o<s;o++)i[o]=this[a+o];return i}}}(),l=function(){return Array.prototype.includes?function(e,t){return e.includes(t)}:Array.prototype.indexOf?function(e,t){return e.indexOf(t)>=0}:function(e,t){for(var n=0;n<e.length;n++)if(e[n]===t)return!0;return!1}};function c(e){return p.isWrapped(e)?e=u.call(e):p.isNode(e)&&(e=[e]),e}var f,p={isNumber:function(e){return"number"==typeof e},isString:function(e){return"string"==typeof e},isArray:Array.isArray||function(e){return"[object Array]"===Object.prototype.toString.call(e)},isFunction:function(e){return"[object Function]"===Object.prototype.toString.call(e)},isNode:function(e){return e&&e.nodeType},isWrapped:function(e){return e&&e!==t&&p.isNumber(e.length)&&!p.isString(e)&&!p.isFunction(e)&&!p.isNode(e)&&(0===e.length||p.isNode(e[0]))},isSVG:function(e){return t.SVGElement&&e instanceof t.SVGElement},isEmptyObject:function(e){for(var t in e)if(e.hasOwnProperty(t))return!1;return!0}},h=!1;if(e.fn&&e.fn.jquery?(f=e,h=!0):f=t.Velocity.Utilities,i<=8&&!h)throw new Error("Velocity: IE8 and below require jQuery to be loaded before Velocity.");if(!(i<=7)){var d=400,m="swing",g={State:{isMobile:/Android|webOS|iPhone|iPad|iPod|BlackBerry|IEMobile|Opera Mini/i.test(t.navigator.userAgent),isAndroid:/Android/i.test(t.navigator.userAgent),isGingerbread:/Android 2\.3\.[3-7]/i.test(t.navigator.userAgent),isChrome:t.chrome,isFirefox:/Firefox/i.test(t.navigator.userAgent),prefixElement:n.createElement("div"),prefixMatches:{},scrollAnchor:null,scrollPropertyLeft:null,scrollPropertyTop:null,isTicking:!1,calls:[],delayedElements:{count:0}},CSS:{},Utilities:f,Redirects:{},Easings:{},Promise:t.Promise,defaults:{queue:"",duration:d,easing:m,begin:r,complete:r,progress:r,display:r,visibility:r,loop:!1,delay:!1,mobileHA:!0,_cacheValues:!0,promiseRejectEmpty:!0},init:function(e){f.data(e,"velocity",{isSVG:p.isSVG(e),isAnimating:!1,computedStyle:null,tweensContainer:null,rootPropertyValueCache:{},transformCache:{}})},hook:null,mock:!1,version:{major:1,minor:5,patch:2},debug:!1,timestamp:!0,pauseAll:function(e){var t=(new Date).getTime();f.each(g.State.calls,function(t,n){if(n){if(e!==r&&(n[2].queue!==e||!1===n[2].queue))return!0;n[5]={resume:!1}}}),f.each(g.State.delayedElements,function(e,n){n&&k(n,t)})},resumeAll:function(e){var t=(new Date).getTime();f.each(g.State.calls,function(t,n){if(n){if(e!==r&&(n[2].queue!==e||!1===n[2].queue))return!0;n[5]&&(n[5].resume=!0)}}),f.each(g.State.delayedElements,function(e,n){n&&S(n,t)})}};t.pageYOffset!==r?(g.State.scrollAnchor=t,g.State.scrollPropertyLeft="pageXOffset",g.State.scrollPropertyTop="pageYOffset"):(g.State.scrollAnchor=n.documentElement||n.body.parentNode||n.body,g.State.scrollPropertyLeft="scrollLeft",g.State.scrollPropertyTop="scrollTop");var v=function(){function e(e){return-e.tension*e.x-e.friction*e.v}function t(t,n,r){var o={x:t.x+r.dx*n,v:t.v+r.dv*n,tension:t.tension,friction:t.friction};return{dx:o.v,dv:e(o)}}function n(n,r){var o={dx:n.v,dv:e(n)},i=t(n,.5*r,o),a=t(n,.5*r,i),s=t(n,r,a),u=1/6*(o.dx+2*(i.dx+a.dx)+s.dx),l=1/6*(o.dv+2*(i.dv+a.dv)+s.dv);return n.x=n.x+u*r,n.v=n.v+l*r,n}return function e(t,r,o){var i,a,s,u={x:-1,v:0,tension:null,friction:null},l=[0],c=0;for(t=parseFloat(t)||500,r=parseFloat(r)||20,o=o||null,u.tension=t,u.friction=r,a=(i=null!==o)?(c=e(t,r))/o*.016:.016;s=n(s||u,a),l.push(1+s.x),c+=16,Math.abs(s.x)>1e-4&&Math.abs(s.v)>1e-4;);return i?function(e){return l[e*(l.length-1)|0]}:c}}();g.Easings={linear:function(e){return e},swing:function(e){return.5-Math.cos(e*Math.PI)/2},spring:function(e){return 1-Math.cos(4.5*e*Math.PI)*Math.exp(6*-e)}},f.each([["ease",[.25,.1,.25,1]],["ease-in",[.42,0,1,1]],["ease-out",[0,0,.58,1]],["ease-in-out",[.42,0,.58,1]],["easeInSine",[.47,0,.745,.715]],["easeOutSine",[.39,.575,.565,1]],["easeInOutSine",[.445,.05,.55,.95]],["easeInQuad",[.55,.085,.68,.53]],["easeOutQuad",[.25,.46,.45,.94]],["easeInOutQuad",[.455,.03,.515,.955]],["easeInCubic",[.55,.055,.675,.19]],["easeOutCubic",[.215,.61,.355,1]],["easeInOutCubic",[.645,.045,.355,1]],["easeInQuart",[.895,.03,.685,.22]],["easeOutQuart",[.165,.84,.44,1]],["easeInOutQuart",[.77,0,.175,1]],["easeInQuint",[.755,.05,.855,.06]],["easeOutQuint",[.23,1,.32,1]],["easeInOutQuint",[.86,0,.07,1]],["easeInExpo",[.95,.05,.795,.035]],["easeOutExpo",[.19,1,.22,1]],["easeInOutExpo",[1,0,0,1]],["easeInCirc",[.6,.04,.98,.335]],["easeOutCirc",[.075,.82,.165,1]],["easeInOutCirc",[.785,.135,.15,.86]]],function(e,t){g.Easings[t[0]]=C.apply(null,t[1])});var y=g.CSS={RegEx:{isHex:/^#([A-f\d]{3}){1,2}$/i,valueUnwrap:/^[A-z]+\((.*)\)$/i,wrappedValueAlreadyExtracted:/[0-9.]+ [0-9.]+ [0-9.]+( [0-9.]+)?/,valueSplit:/([A-z]+\(.+\))|(([A-z0-9#-.]+?)(?=\s|$))/gi},Lists:{colors:["fill","stroke","stopColor","color","backgroundColor","borderColor","borderTopColor","borderRightColor","borderBottomColor","borderLeftColor","outlineColor"],transformsBase:["translateX","translateY","scale","scaleX","scaleY","skewX","skewY","rotateZ"],transforms3D:["transformPerspective","translateZ","scaleZ","rotateX","rotateY"],units:["%","em","ex","ch","rem","vw","vh","vmin","vmax","cm","mm","Q","in","pc","pt","px","deg","grad","rad","turn","s","ms"],colorNames:{aliceblue:"240,248,255",antiquewhite:"250,235,215",aquamarine:"127,255,212",aqua:"0,255,255",azure:"240,255,255",beige:"245,245,220",bisque:"255,228,196",black:"0,0,0",blanchedalmond:"255,235,205",blueviolet:"138,43,226",blue:"0,0,255",brown:"165,42,42",burlywood:"222,184,135",cadetblue:"95,158,160",chartreuse:"127,255,0",chocolate:"210,105,30",coral:"255,127,80",cornflowerblue:"100,149,237",cornsilk:"255,248,220",crimson:"220,20,60",cyan:"0,255,255",darkblue:"0,0,139",darkcyan:"0,139,139",darkgoldenrod:"184,134,11",darkgray:"169,169,169",darkgrey:"169,169,169",darkgreen:"0,100,0",darkkhaki:"189,183,107",darkmagenta:"139,0,139",darkolivegreen:"85,107,47",darkorange:"255,140,0",darkorchid:"153,50,204",darkred:"139,0,0",darksalmon:"233,150,122",darkseagreen:"143,188,143",darkslateblue:"72,61,139",darkslategray:"47,79,79",darkturquoise:"0,206,209",darkviolet:"148,0,211",deeppink:"255,20,147",deepskyblue:"0,191,255",dimgray:"105,105,105",dimgrey:"105,105,105",dodgerblue:"30,144,255",firebrick:"178,34,34",floralwhite:"255,250,240",forestgreen:"34,139,34",fuchsia:"255,0,255",gainsboro:"220,220,220",ghostwhite:"248,248,255",gold:"255,215,0",goldenrod:"218,165,32",gray:"128,128,128",grey:"128,128,128",greenyellow:"173,255,47",green:"0,128,0",honeydew:"240,255,240",hotpink:"255,105,180",indianred:"205,92,92",indigo:"75,0,130",ivory:"255,255,240",khaki:"240,230,140",lavenderblush:"255,240,245",lavender:"230,230,250",lawngreen:"124,252,0",lemonchiffon:"255,250,205",lightblue:"173,216,230",lightcoral:"240,128,128",lightcyan:"224,255,255",lightgoldenrodyellow:"250,250,210",lightgray:"211,211,211",lightgrey:"211,211,211",lightgreen:"144,238,144",lightpink:"255,182,193",lightsalmon:"255,160,122",lightseagreen:"32,178,170",lightskyblue:"135,206,250",lightslategray:"119,136,153",lightsteelblue:"176,196,222",lightyellow:"255,255,224",limegreen:"50,205,50",lime:"0,255,0",linen:"250,240,230",magenta:"255,0,255",maroon:"128,0,0",mediumaquamarine:"102,205,170",mediumblue:"0,0,205",mediumorchid:"186,85,211",mediumpurple:"147,112,219",mediumseagreen:"60,179,113",mediumslateblue:"123,104,238",mediumspringgreen:"0,250,154",mediumturquoise:"72,209,204",mediumvioletred:"199,21,133",midnightblue:"25,25,112",mintcream:"245,255,250",mistyrose:"255,228,225",moccasin:"255,228,181",navajowhite:"255,222,173",navy:"0,0,128",oldlace:"253,245,230",olivedrab:"107,142,35",olive:"128,128,0",orangered:"255,69,0",orange:"255,165,0",orchid:"218,112,214",palegoldenrod:"238,232,170",palegreen:"152,251,152",paleturquoise:"175,238,238",palevioletred:"219,112,147",papayawhip:"255,239,213",peachpuff:"255,218,185",peru:"205,133,63",pink:"255,192,203",plum:"221,160,221",powderblue:"176,224,230",purple:"128,0,128",red:"255,0,0",rosybrown:"188,143,143",royalblue:"65,105,225",saddlebrown:"139,69,19",salmon:"250,128,114",sandybrown:"244,164,96",seagreen:"46,139,87",seashell:"255,245,238",sienna:"160,82,45",silver:"192,192,192",skyblue:"135,206,235",slateblue:"106,90,205",slategray:"112,128,144",snow:"255,250,250",springgreen:"0,255,127",steelblue:"70,130,180",tan:"210,180,140",teal:"0,128,128",thistle:"216,191,216",tomato:"255,99,71",turquoise:"64,224,208",violet:"238,130,238",wheat:"245,222,179",whitesmoke:"245,245,245",white:"255,255,255",yellowgreen:"154,205,50",yellow:"255,255,0"}},Hooks:{templates:{textShadow:["Color X Y Blur","black 0px 0px 0px"],boxShadow:["Color X Y Blur Spread","black 0px 0px 0px 0px"],clip:["Top Right Bottom Left","0px 0px 0px 0px"],backgroundPosition:["X Y","0% 0%"],transformOrigin:["X Y Z","50% 50% 0px"],perspectiveOrigin:["X Y","50% 50%"]},registered:{},register:function(){for(var e=0;e<y.Lists.colors.length;e++){var t="color"===y.Lists.colors[e]?"0 0 0 1":"255 255 255 1";y.Hooks.templates[y.Lists.colors[e]]=["Red Green Blue Alpha",t]}var n,r,o;if(i)for(n in y.Hooks.templates)if(y.Hooks.templates.hasOwnProperty(n)){o=(r=y.Hooks.templates[n])[0].split(" ");var a=r[1].match(y.RegEx.valueSplit);"Color"===o[0]&&(o.push(o.shift()),a.push(a.shift()),y.Hooks.templates[n]=[o.join(" "),a.join(" ")])}for(n in y.Hooks.templates)if(y.Hooks.templates.hasOwnProperty(n))for(var s in o=(r=y.Hooks.templates[n])[0].split(" "))if(o.hasOwnProperty(s)){var u=n+o[s],l=s;y.Hooks.registered[u]=[n,l]}},getRoot:function(e){var t=y.Hooks.registered[e];return t?t[0]:e},getUnit:function(e,t){var n=(e.substr(t||0,5).match(/^[a-z%]+/)||[])[0]||"";return n&&l(y.Lists.units,n)?n:""},fixColors:function(e){return e.replace(/(rgba?\(\s*)?(\b[a-z]+\b)/g,function(e,t,n){return y.Lists.colorNames.hasOwnProperty(n)?(t||"rgba(")+y.Lists.colorNames[n]+(t?"":",1)"):t+n})},cleanRootPropertyValue:function(e,t){return y.RegEx.valueUnwrap.test(t)&&(t=t.match(y.RegEx.valueUnwrap)[1]),y.Values.isCSSNullValue(t)&&(t=y.Hooks.templates[e][1]),t},extractValue:function(e,t){var n=y.Hooks.registered[e];if(n){var r=n[0],o=n[1];return(t=y.Hooks.cleanRootPropertyValue(r,t)).toString().match(y.RegEx.valueSplit)[o]}return t},injectValue:function(e,t,n){var r=y.Hooks.registered[e];if(r){var o,i=r[0],a=r[1];return(o=(n=y.Hooks.cleanRootPropertyValue(i,n)).toString().match(y.RegEx.valueSplit))[a]=t,o.join(" ")}return n}},Normalizations:{registered:{clip:function(e,t,n){switch(e){case"name":return"clip";case"extract":var r;return r=y.RegEx.wrappedValueAlreadyExtracted.test(n)?n:(r=n.toString().match(y.RegEx.valueUnwrap))?r[1].replace(/,(\s+)?/g," "):n;case"inject":return"rect("+n+")"}},blur:function(e,t,n){switch(e){case"name":return g.State.isFirefox?"filter":"-webkit-filter";case"extract":var r=parseFloat(n);if(!r&&0!==r){var o=n.toString().match(/blur\(([0-9]+[A-z]+)\)/i);r=o?o[1]:0}return r;case"inject":return parseFloat(n)?"blur("+n+")":"none"}},opacity:function(e,t,n){if(i<=8)switch(e){case"name":return"filter";case"extract":var r=n.toString().match(/alpha\(opacity=(.*)\)/i);return n=r?r[1]/100:1;case"inject":return t.style.zoom=1,parseFloat(n)>=1?"":"alpha(opacity="+parseInt(100*parseFloat(n),10)+")"}else switch(e){case"name":return"opacity";case"extract":case"inject":return n}}},register:function(){i&&!(i>9)||g.State.isGingerbread||(y.Lists.transformsBase=y.Lists.transformsBase.concat(y.Lists.transforms3D));for(var e=0;e<y.Lists.transformsBase.length;e++)!function(){var t=y.Lists.transformsBase[e];y.Normalizations.registered[t]=function(e,n,o){switch(e){case"name":return"transform";case"extract":return x(n)===r||x(n).transformCache[t]===r?/^scale/i.test(t)?1:0:x(n).transformCache[t].replace(/[()]/g,"");case"inject":var i=!1;switch(t.substr(0,t.length-1)){case"translate":i=!/(%|px|em|rem|vw|vh|\d)$/i.test(o);break;case"scal":case"scale":g.State.isAndroid&&x(n).transformCache[t]===r&&o<1&&(o=1),i=!/(\d)$/i.test(o);break;case"skew":case"rotate":i=!/(deg|\d)$/i.test(o)}return i||(x(n).transformCache[t]="("+o+")"),x(n).transformCache[t]}}}();for(var t=0;t<y.Lists.colors.length;t++)!function(){var e=y.Lists.colors[t];y.Normalizations.registered[e]=function(t,n,o){switch(t){case"name":return e;case"extract":var a;if(y.RegEx.wrappedValueAlreadyExtracted.test(o))a=o;else{var s,u={black:"rgb(0, 0, 0)",blue:"rgb(0, 0, 255)",gray:"rgb(128, 128, 128)",green:"rgb(0, 128, 0)",red:"rgb(255, 0, 0)",white:"rgb(255, 255, 255)"};/^[A-z]+$/i.test(o)?s=u[o]!==r?u[o]:u.black:y.RegEx.isHex.test(o)?s="rgb("+y.Values.hexToRgb(o).join(" ")+")":/^rgba?\(/i.test(o)||(s=u.black),a=(s||o).toString().match(y.RegEx.valueUnwrap)[1].replace(/,(\s+)?/g," ")}return(!i||i>8)&&3===a.split(" ").length&&(a+=" 1"),a;case"inject":return/^rgb/.test(o)?o:(i<=8?4===o.split(" ").length&&(o=o.split(/\s+/).slice(0,3).join(" ")):3===o.split(" ").length&&(o+=" 1"),(i<=8?"rgb":"rgba")+"("+o.replace(/\s+/g,",").replace(/\.(\d)+(?=,)/g,"")+")")}}}();function n(e,t,n){if("border-box"===y.getPropertyValue(t,"boxSizing").toString().toLowerCase()===(n||!1)){var r,o,i=0,a="width"===e?["Left","Right"]:["Top","Bottom"],s=["padding"+a[0],"padding"+a[1],"border"+a[0]+"Width","border"+a[1]+"Width"];for(r=0;r<s.length;r++)o=parseFloat(y.getPropertyValue(t,s[r])),isNaN(o)||(i+=o);return n?-i:i}return 0}function o(e,t){return function(r,o,i){switch(r){case"name":return e;case"extract":return parseFloat(i)+n(e,o,t);case"inject":return parseFloat(i)-n(e,o,t)+"px"}}}y.Normalizations.registered.innerWidth=o("width",!0),y.Normalizations.registered.innerHeight=o("height",!0),y.Normalizations.registered.outerWidth=o("width"),y.Normalizations.registered.outerHeight=o("height")}},Names:{camelCase:function(e){return e.replace(/-(\w)/g,function(e,t){return t.toUpperCase()})},SVGAttribute:function(e){var t="width|height|x|y|cx|cy|r|rx|ry|x1|x2|y1|y2";return(i||g.State.isAndroid&&!g.State.isChrome)&&(t+="|transform"),new RegExp("^("+t+")$","i").test(e)},prefixCheck:function(e){if(g.State.prefixMatches[e])return[g.State.prefixMatches[e],!0];for(var t=["","Webkit","Moz","ms","O"],n=0,r=t.length;n<r;n++){var o;if(o=0===n?e:t[n]+e.replace(/^\w/,function(e){return e.toUpperCase()}),p.isString(g.State.prefixElement.style[o]))return g.State.prefixMatches[e]=o,[o,!0]}return[e,!1]}},Values:{hexToRgb:function(e){var t;return e=e.replace(/^#?([a-f\d])([a-f\d])([a-f\d])$/i,function(e,t,n,r){return t+t+n+n+r+r}),(t=/^#?([a-f\d]{2})([a-f\d]{2})([a-f\d]{2})$/i.exec(e))?[parseInt(t[1],16),parseInt(t[2],16),parseInt(t[3],16)]:[0,0,0]},isCSSNullValue:function(e){return!e||/^(none|auto|transparent|(rgba\(0, ?0, ?0, ?0\)))$/i.test(e)},getUnitType:function(e){return/^(rotate|skew)/i.test(e)?"deg":/(^(scale|scaleX|scaleY|scaleZ|alpha|flexGrow|flexHeight|zIndex|fontWeight)$)|((opacity|red|green|blue|alpha)$)/i.test(e)?"":"px"},getDisplayType:function(e){var t=e&&e.tagName.toString().toLowerCase();return/^(b|big|i|small|tt|abbr|acronym|cite|code|dfn|em|kbd|strong|samp|var|a|bdo|br|img|map|object|q|script|span|sub|sup|button|input|label|select|textarea)$/i.test(t)?"inline":/^(li)$/i.test(t)?"list-item":/^(tr)$/i.test(t)?"table-row":/^(table)$/i.test(t)?"table":/^(tbody)$/i.test(t)?"table-row-group":"block"},addClass:function(e,t){if(e)if(e.classList)e.classList.add(t);else if(p.isString(e.className))e.className+=(e.className.length?" ":"")+t;else{var n=e.getAttribute(i<=7?"className":"class")||"";e.setAttribute("class",n+(n?" ":"")+t)}},removeClass:function(e,t){if(e)if(e.classList)e.classList.remove(t);else if(p.isString(e.className))e.className=e.className.toString().replace(new RegExp("(^|\\s)"+t.split(" ").join("|")+"(\\s|$)","gi")," ");else{var n=e.getAttribute(i<=7?"className":"class")||"";e.setAttribute("class",n.replace(new RegExp("(^|s)"+t.split(" ").join("|")+"(s|$)","gi")," "))}}},getPropertyValue:function(e,n,o,a){function s(e,n){var o=0;if(i<=8)o=f.css(e,n);else{var u=!1;/^(width|height)$/.test(n)&&0===y.getPropertyValue(e,"display")&&(u=!0,y.setPropertyValue(e,"display",y.Values.getDisplayType(e)));var l,c=function(){u&&y.setPropertyValue(e,"display","none")};if(!a){if("height"===n&&"border-box"!==y.getPropertyValue(e,"boxSizing").toString().toLowerCase()){var p=e.offsetHeight-(parseFloat(y.getPropertyValue(e,"borderTopWidth"))||0)-(parseFloat(y.getPropertyValue(e,"borderBottomWidth"))||0)-(parseFloat(y.getPropertyValue(e,"paddingTop"))||0)-(parseFloat(y.getPropertyValue(e,"paddingBottom"))||0);return c(),p}if("width"===n&&"border-box"!==y.getPropertyValue(e,"boxSizing").toString().toLowerCase()){var h=e.offsetWidth-(parseFloat(y.getPropertyValue(e,"borderLeftWidth"))||0)-(parseFloat(y.getPropertyValue(e,"borderRightWidth"))||0)-(parseFloat(y.getPropertyValue(e,"paddingLeft"))||0)-(parseFloat(y.getPropertyValue(e,"paddingRight"))||0);return c(),h}}l=x(e)===r?t.getComputedStyle(e,null):x(e).computedStyle?x(e).computedStyle:x(e).computedStyle=t.getComputedStyle(e,null),"borderColor"===n&&(n="borderTopColor"),""!==(o=9===i&&"filter"===n?l.getPropertyValue(n):l[n])&&null!==o||(o=e.style[n]),c()}if("auto"===o&&/^(top|right|bottom|left)$/i.test(n)){var d=s(e,"position");("fixed"===d||"absolute"===d&&/top|left/i.test(n))&&(o=f(e).position()[n]+"px")}return o}var u;if(y.Hooks.registered[n]){var l=n,c=y.Hooks.getRoot(l);o===r&&(o=y.getPropertyValue(e,y.Names.prefixCheck(c)[0])),y.Normalizations.registered[c]&&(o=y.Normalizations.registered[c]("extract",e,o)),u=y.Hooks.extractValue(l,o)}else if(y.Normalizations.registered[n]){var p,h;"transform"!==(p=y.Normalizations.registered[n]("name",e))&&(h=s(e,y.Names.prefixCheck(p)[0]),y.Values.isCSSNullValue(h)&&y.Hooks.templates[n]&&(h=y.Hooks.templates[n][1])),u=y.Normalizations.registered[n]("extract",e,h)}if(!/^[\d-]/.test(u)){var d=x(e);if(d&&d.isSVG&&y.Names.SVGAttribute(n))if(/^(height|width)$/i.test(n))try{u=e.getBBox()[n]}catch(e){u=0}else u=e.getAttribute(n);else u=s(e,y.Names.prefixCheck(n)[0])}return y.Values.isCSSNullValue(u)&&(u=0),g.debug>=2&&console.log("Get "+n+": "+u),u},setPropertyValue:function(e,n,r,o,a){var s=n;if("scroll"===n)a.container?a.container["scroll"+a.direction]=r:"Left"===a.direction?t.scrollTo(r,a.alternateValue):t.scrollTo(a.alternateValue,r);else if(y.Normalizations.registered[n]&&"transform"===y.Normalizations.registered[n]("name",e))y.Normalizations.registered[n]("inject",e,r),s="transform",r=x(e).transformCache[n];else{if(y.Hooks.registered[n]){var u=n,l=y.Hooks.getRoot(n);o=o||y.getPropertyValue(e,l),r=y.Hooks.injectValue(u,r,o),n=l}if(y.Normalizations.registered[n]&&(r=y.Normalizations.registered[n]("inject",e,r),n=y.Normalizations.registered[n]("name",e)),s=y.Names.prefixCheck(n)[0],i<=8)try{e.style[s]=r}catch(e){g.debug&&console.log("Browser does not support ["+r+"] for ["+s+"]")}else{var c=x(e);c&&c.isSVG&&y.Names.SVGAttribute(n)?e.setAttribute(n,r):e.style[s]=r}g.debug>=2&&console.log("Set "+n+" ("+s+"): "+r)}return[s,r]},flushTransformCache:function(e){var t="",n=x(e);if((i||g.State.isAndroid&&!g.State.isChrome)&&n&&n.isSVG){var r=function(t){return parseFloat(y.getPropertyValue(e,t))},o={translate:[r("translateX"),r("translateY")],skewX:[r("skewX")],skewY:[r("skewY")],scale:1!==r("scale")?[r("scale"),r("scale")]:[r("scaleX"),r("scaleY")],rotate:[r("rotateZ"),0,0]};f.each(x(e).transformCache,function(e){/^translate/i.test(e)?e="translate":/^scale/i.test(e)?e="scale":/^rotate/i.test(e)&&(e="rotate"),o[e]&&(t+=e+"("+o[e].join(" ")+") ",delete o[e])})}else{var a,s;f.each(x(e).transformCache,function(n){if(a=x(e).transformCache[n],"transformPerspective"===n)return s=a,!0;9===i&&"rotateZ"===n&&(n="rotate"),t+=n+a+" "}),s&&(t="perspective"+s+" "+t)}y.setPropertyValue(e,"transform",t)}};y.Hooks.register(),y.Normalizations.register(),g.hook=function(e,t,n){var o;return e=c(e),f.each(e,function(e,i){if(x(i)===r&&g.init(i),n===r)o===r&&(o=y.getPropertyValue(i,t));else{var a=y.setPropertyValue(i,t,n);"transform"===a[0]&&g.CSS.flushTransformCache(i),o=a}}),o};var b=function(){var e;function o(){return i?_.promise||null:a}var i,a,s,u,h,m,v=arguments[0]&&(arguments[0].p||f.isPlainObject(arguments[0].properties)&&!arguments[0].properties.names||p.isString(arguments[0].properties));p.isWrapped(this)?(i=!1,s=0,u=this,a=this):(i=!0,s=1,u=v?arguments[0].elements||arguments[0].e:arguments[0]);var _={promise:null,resolver:null,rejecter:null};if(i&&g.Promise&&(_.promise=new g.Promise(function(e,t){_.resolver=e,_.rejecter=t})),v?(h=arguments[0].properties||arguments[0].p,m=arguments[0].options||arguments[0].o):(h=arguments[s],m=arguments[s+1]),u=c(u)){var w,C=u.length,A=0;if(!/^(stop|finish|finishAll|pause|resume)$/i.test(h)&&!f.isPlainObject(m)){m={};for(var P=s+1;P<arguments.length;P++)p.isArray(arguments[P])||!/^(fast|normal|slow)$/i.test(arguments[P])&&!/^\d/.test(arguments[P])?p.isString(arguments[P])||p.isArray(arguments[P])?m.easing=arguments[P]:p.isFunction(arguments[P])&&(m.complete=arguments[P]):m.duration=arguments[P]}switch(h){case"scroll":w="scroll";break;case"reverse":w="reverse";break;case"pause":var j=(new Date).getTime();return f.each(u,function(e,t){k(t,j)}),f.each(g.State.calls,function(e,t){var n=!1;t&&f.each(t[1],function(e,o){var i=m===r?"":m;return!0!==i&&t[2].queue!==i&&(m!==r||!1!==t[2].queue)||(f.each(u,function(e,r){if(r===o)return t[5]={resume:!1},n=!0,!1}),!n&&void 0)})}),o();case"resume":return f.each(u,function(e,t){S(t)}),f.each(g.State.calls,function(e,t){var n=!1;t&&f.each(t[1],function(e,o){var i=m===r?"":m;return!0!==i&&t[2].queue!==i&&(m!==r||!1!==t[2].queue)||(!t[5]||(f.each(u,function(e,r){if(r===o)return t[5].resume=!0,n=!0,!1}),!n&&void 0))})}),o();case"finish":case"finishAll":case"stop":f.each(u,function(e,t){x(t)&&x(t).delayTimer&&(clearTimeout(x(t).delayTimer.setTimeout),x(t).delayTimer.next&&x(t).delayTimer.next(),delete x(t).delayTimer),"finishAll"!==h||!0!==m&&!p.isString(m)||(f.each(f.queue(t,p.isString(m)?m:""),function(e,t){p.isFunction(t)&&t()}),f.queue(t,p.isString(m)?m:"",[]))});var R=[];return f.each(g.State.calls,function(e,t){t&&f.each(t[1],function(n,o){var i=m===r?"":m;if(!0!==i&&t[2].queue!==i&&(m!==r||!1!==t[2].queue))return!0;f.each(u,function(n,r){if(r===o)if((!0===m||p.isString(m))&&(f.each(f.queue(r,p.isString(m)?m:""),function(e,t){p.isFunction(t)&&t(null,!0)}),f.queue(r,p.isString(m)?m:"",[])),"stop"===h){var a=x(r);a&&a.tweensContainer&&(!0===i||""===i)&&f.each(a.tweensContainer,function(e,t){t.endValue=t.currentValue}),R.push(e)}else"finish"!==h&&"finishAll"!==h||(t[2].duration=1)})})}),"stop"===h&&(f.each(R,function(e,t){O(t,!0)}),_.promise&&_.resolver(u)),o();default:if(!f.isPlainObject(h)||p.isEmptyObject(h)){if(p.isString(h)&&g.Redirects[h]){var N=(e=f.extend({},m)).duration,M=e.delay||0;return!0===e.backwards&&(u=f.extend(!0,[],u).reverse()),f.each(u,function(t,n){parseFloat(e.stagger)?e.delay=M+parseFloat(e.stagger)*t:p.isFunction(e.stagger)&&(e.delay=M+e.stagger.call(n,t,C)),e.drag&&(e.duration=parseFloat(N)||(/^(callout|transition)/.test(h)?1e3:d),e.duration=Math.max(e.duration*(e.backwards?1-t/C:(t+1)/C),.75*e.duration,200)),g.Redirects[h].call(n,n,e||{},t,C,u,_.promise?_:r)}),o()}var L="Velocity: First argument ("+h+") was not a property map, a known action, or a registered redirect. Aborting.";return _.promise?_.rejecter(new Error(L)):t.console&&console.log(L),o()}w="start"}var I={lastParent:null,lastPosition:null,lastFontSize:null,lastPercentToPxWidth:null,lastPercentToPxHeight:null,lastEmToPx:null,remToPx:null,vwToPx:null,vhToPx:null},D=[];f.each(u,function(e,o){p.isNode(o)&&function(e,o){var i,a,s=f.extend({},g.defaults,m),c={};switch(x(e)===r&&g.init(e),parseFloat(s.delay)&&!1!==s.queue&&f.queue(e,s.queue,function(t,n){if(!0===n)return!0;g.velocityQueueEntryFlag=!0;var r=g.State.delayedElements.count++;g.State.delayedElements[r]=e;var o,i=(o=r,function(){g.State.delayedElements[o]=!1,t()});x(e).delayBegin=(new Date).getTime(),x(e).delay=parseFloat(s.delay),x(e).delayTimer={setTimeout:setTimeout(t,parseFloat(s.delay)),next:i}}),s.duration.toString().toLowerCase()){case"fast":s.duration=200;break;case"normal":s.duration=d;break;case"slow":s.duration=600;break;default:s.duration=parseFloat(s.duration)||1}function v(a){var d,v;if(s.begin&&0===A)try{s.begin.call(u,u)}catch(e){setTimeout(function(){throw e},1)}if("scroll"===w){var b,k,S,O=/^x$/i.test(s.axis)?"Left":"Top",P=parseFloat(s.offset)||0;s.container?p.isWrapped(s.container)||p.isNode(s.container)?(s.container=s.container[0]||s.container,S=(b=s.container["scroll"+O])+f(e).position()[O.toLowerCase()]+P):s.container=null:(b=g.State.scrollAnchor[g.State["scrollProperty"+O]],k=g.State.scrollAnchor[g.State["scrollProperty"+("Left"===O?"Top":"Left")]],S=f(e).offset()[O.toLowerCase()]+P),c={scroll:{rootPropertyValue:!1,startValue:b,currentValue:b,endValue:S,unitType:"",easing:s.easing,scrollData:{container:s.container,direction:O,alternateValue:k}},element:e},g.debug&&console.log("tweensContainer (scroll): ",c.scroll,e)}else if("reverse"===w){if(!(d=x(e)))return;if(!d.tweensContainer)return void f.dequeue(e,s.queue);for(var j in"none"===d.opts.display&&(d.opts.display="auto"),"hidden"===d.opts.visibility&&(d.opts.visibility="visible"),d.opts.loop=!1,d.opts.begin=null,d.opts.complete=null,m.easing||delete s.easing,m.duration||delete s.duration,s=f.extend({},d.opts,s),v=f.extend(!0,{},d?d.tweensContainer:null))if(v.hasOwnProperty(j)&&"element"!==j){var R=v[j].startValue;v[j].startValue=v[j].currentValue=v[j].endValue,v[j].endValue=R,p.isEmptyObject(m)||(v[j].easing=s.easing),g.debug&&console.log("reverse tweensContainer ("+j+"): "+JSON.stringify(v[j]),e)}c=v}else if("start"===w){(d=x(e))&&d.tweensContainer&&!0===d.isAnimating&&(v=d.tweensContainer);var N=function(t,n){var r,i,a;return p.isFunction(t)&&(t=t.call(e,o,C)),p.isArray(t)?(r=t[0],!p.isArray(t[1])&&/^[\d-]/.test(t[1])||p.isFunction(t[1])||y.RegEx.isHex.test(t[1])?a=t[1]:p.isString(t[1])&&!y.RegEx.isHex.test(t[1])&&g.Easings[t[1]]||p.isArray(t[1])?(i=n?t[1]:E(t[1],s.duration),a=t[2]):a=t[1]||t[2]):r=t,n||(i=i||s.easing),p.isFunction(r)&&(r=r.call(e,o,C)),p.isFunction(a)&&(a=a.call(e,o,C)),[r||0,i,a]},M=function(o,a){var u,l=y.Hooks.getRoot(o),h=!1,m=a[0],b=a[1],_=a[2];if(d&&d.isSVG||"tween"===l||!1!==y.Names.prefixCheck(l)[1]||y.Normalizations.registered[l]!==r){(s.display!==r&&null!==s.display&&"none"!==s.display||s.visibility!==r&&"hidden"!==s.visibility)&&/opacity|filter/.test(o)&&!_&&0!==m&&(_=0),s._cacheValues&&v&&v[o]?(_===r&&(_=v[o].endValue+v[o].unitType),h=d.rootPropertyValueCache[l]):y.Hooks.registered[o]?_===r?(h=y.getPropertyValue(e,l),_=y.getPropertyValue(e,o,h)):h=y.Hooks.templates[l][1]:_===r&&(_=y.getPropertyValue(e,o));var w,x,k,S=!1,C=function(e,t){var n,r;return r=(t||"0").toString().toLowerCase().replace(/[%A-z]+$/,function(e){return n=e,""}),n||(n=y.Values.getUnitType(e)),[r,n]};if(_!==m&&p.isString(_)&&p.isString(m)){u="";var E=0,T=0,O=[],A=[],P=0,j=0,R=0;for(_=y.Hooks.fixColors(_),m=y.Hooks.fixColors(m);E<_.length&&T<m.length;){var N=_[E],M=m[T];if(/[\d\.-]/.test(N)&&/[\d\.-]/.test(M)){for(var L=N,D=M,F=".",U=".";++E<_.length;){if((N=_[E])===F)F="..";else if(!/\d/.test(N))break;L+=N}for(;++T<m.length;){if((M=m[T])===U)U="..";else if(!/\d/.test(M))break;D+=M}var q=y.Hooks.getUnit(_,E),B=y.Hooks.getUnit(m,T);if(E+=q.length,T+=B.length,q===B)L===D?u+=L+q:(u+="{"+O.length+(j?"!":"")+"}"+q,O.push(parseFloat(L)),A.push(parseFloat(D)));else{var V=parseFloat(L),z=parseFloat(D);u+=(P<5?"calc":"")+"("+(V?"{"+O.length+(j?"!":"")+"}":"0")+q+" + "+(z?"{"+(O.length+(V?1:0))+(j?"!":"")+"}":"0")+B+")",V&&(O.push(V),A.push(0)),z&&(O.push(0),A.push(z))}}else{if(N!==M){P=0;break}u+=N,E++,T++,0===P&&"c"===N||1===P&&"a"===N||2===P&&"l"===N||3===P&&"c"===N||P>=4&&"("===N?P++:(P&&P<5||P>=4&&")"===N&&--P<5)&&(P=0),0===j&&"r"===N||1===j&&"g"===N||2===j&&"b"===N||3===j&&"a"===N||j>=3&&"("===N?(3===j&&"a"===N&&(R=1),j++):R&&","===N?++R>3&&(j=R=0):(R&&j<(R?5:4)||j>=(R?4:3)&&")"===N&&--j<(R?5:4))&&(j=R=0)}}E===_.length&&T===m.length||(g.debug&&console.error('Trying to pattern match mis-matched strings ["'+m+'", "'+_+'"]'),u=r),u&&(O.length?(g.debug&&console.log('Pattern found "'+u+'" -> ',O,A,"["+_+","+m+"]"),_=O,m=A,x=k=""):u=r)}if(u||(_=(w=C(o,_))[0],k=w[1],m=(w=C(o,m))[0].replace(/^([+-\/*])=/,function(e,t){return S=t,""}),x=w[1],_=parseFloat(_)||0,m=parseFloat(m)||0,"%"===x&&(/^(fontSize|lineHeight)$/.test(o)?(m/=100,x="em"):/^scale/.test(o)?(m/=100,x=""):/(Red|Green|Blue)$/i.test(o)&&(m=m/100*255,x=""))),/[\/*]/.test(S))x=k;else if(k!==x&&0!==_)if(0===m)x=k;else{i=i||function(){var r={myParent:e.parentNode||n.body,position:y.getPropertyValue(e,"position"),fontSize:y.getPropertyValue(e,"fontSize")},o=r.position===I.lastPosition&&r.myParent===I.lastParent,i=r.fontSize===I.lastFontSize;I.lastParent=r.myParent,I.lastPosition=r.position,I.lastFontSize=r.fontSize;var a={};if(i&&o)a.emToPx=I.lastEmToPx,a.percentToPxWidth=I.lastPercentToPxWidth,a.percentToPxHeight=I.lastPercentToPxHeight;else{var s=d&&d.isSVG?n.createElementNS("http://www.w3.org/2000/svg","rect"):n.createElement("div");g.init(s),r.myParent.appendChild(s),f.each(["overflow","overflowX","overflowY"],function(e,t){g.CSS.setPropertyValue(s,t,"hidden")}),g.CSS.setPropertyValue(s,"position",r.position),g.CSS.setPropertyValue(s,"fontSize",r.fontSize),g.CSS.setPropertyValue(s,"boxSizing","content-box"),f.each(["minWidth","maxWidth","width","minHeight","maxHeight","height"],function(e,t){g.CSS.setPropertyValue(s,t,"100%")}),g.CSS.setPropertyValue(s,"paddingLeft","100em"),a.percentToPxWidth=I.lastPercentToPxWidth=(parseFloat(y.getPropertyValue(s,"width",null,!0))||1)/100,a.percentToPxHeight=I.lastPercentToPxHeight=(parseFloat(y.getPropertyValue(s,"height",null,!0))||1)/100,a.emToPx=I.lastEmToPx=(parseFloat(y.getPropertyValue(s,"paddingLeft"))||1)/100,r.myParent.removeChild(s)}return null===I.remToPx&&(I.remToPx=parseFloat(y.getPropertyValue(n.body,"fontSize"))||16),null===I.vwToPx&&(I.vwToPx=parseFloat(t.innerWidth)/100,I.vhToPx=parseFloat(t.innerHeight)/100),a.remToPx=I.remToPx,a.vwToPx=I.vwToPx,a.vhToPx=I.vhToPx,g.debug>=1&&console.log("Unit ratios: "+JSON.stringify(a),e),a}();var H=/margin|padding|left|right|width|text|word|letter/i.test(o)||/X$/.test(o)||"x"===o?"x":"y";switch(k){case"%":_*="x"===H?i.percentToPxWidth:i.percentToPxHeight;break;case"px":break;default:_*=i[k+"ToPx"]}switch(x){case"%":_*=1/("x"===H?i.percentToPxWidth:i.percentToPxHeight);break;case"px":break;default:_*=1/i[x+"ToPx"]}}switch(S){case"+":m=_+m;break;case"-":m=_-m;break;case"*":m*=_;break;case"/":m=_/m}c[o]={rootPropertyValue:h,startValue:_,currentValue:_,endValue:m,unitType:x,easing:b},u&&(c[o].pattern=u),g.debug&&console.log("tweensContainer ("+o+"): "+JSON.stringify(c[o]),e)}else g.debug&&console.log("Skipping ["+l+"] due to a lack of browser support.")};for(var L in h)if(h.hasOwnProperty(L)){var F=y.Names.camelCase(L),U=N(h[L]);if(l(y.Lists.colors,F)){var q=U[0],B=U[1],V=U[2];if(y.RegEx.isHex.test(q)){for(var z=["Red","Green","Blue"],H=y.Values.hexToRgb(q),W=V?y.Values.hexToRgb(V):r,$=0;$<z.length;$++){var G=[H[$]];B&&G.push(B),W!==r&&G.push(W[$]),M(F+z[$],G)}continue}}M(F,U)}c.element=e}c.element&&(y.Values.addClass(e,"velocity-animating"),D.push(c),(d=x(e))&&(""===s.queue&&(d.tweensContainer=c,d.opts=s),d.isAnimating=!0),A===C-1?(g.State.calls.push([D,u,s,null,_.resolver,null,0]),!1===g.State.isTicking&&(g.State.isTicking=!0,T())):A++)}if(!1!==g.mock&&(!0===g.mock?s.duration=s.delay=1:(s.duration*=parseFloat(g.mock)||1,s.delay*=parseFloat(g.mock)||1)),s.easing=E(s.easing,s.duration),s.begin&&!p.isFunction(s.begin)&&(s.begin=null),s.progress&&!p.isFunction(s.progress)&&(s.progress=null),s.complete&&!p.isFunction(s.complete)&&(s.complete=null),s.display!==r&&null!==s.display&&(s.display=s.display.toString().toLowerCase(),"auto"===s.display&&(s.display=g.CSS.Values.getDisplayType(e))),s.visibility!==r&&null!==s.visibility&&(s.visibility=s.visibility.toString().toLowerCase()),s.mobileHA=s.mobileHA&&g.State.isMobile&&!g.State.isGingerbread,!1===s.queue)if(s.delay){var b=g.State.delayedElements.count++;g.State.delayedElements[b]=e;var k=(a=b,function(){g.State.delayedElements[a]=!1,v()});x(e).delayBegin=(new Date).getTime(),x(e).delay=parseFloat(s.delay),x(e).delayTimer={setTimeout:setTimeout(v,parseFloat(s.delay)),next:k}}else v();else f.queue(e,s.queue,function(e,t){if(!0===t)return _.promise&&_.resolver(u),!0;g.velocityQueueEntryFlag=!0,v()});""!==s.queue&&"fx"!==s.queue||"inprogress"===f.queue(e)[0]||f.dequeue(e)}(o,e)}),(e=f.extend({},g.defaults,m)).loop=parseInt(e.loop,10);var F=2*e.loop-1;if(e.loop)for(var U=0;U<F;U++){var q={delay:e.delay,progress:e.progress};U===F-1&&(q.display=e.display,q.visibility=e.visibility,q.complete=e.complete),b(u,"reverse",q)}return o()}_.promise&&(h&&m&&!1===m.promiseRejectEmpty?_.resolver():_.rejecter())};(g=f.extend(b,g)).animate=b;var _=t.requestAnimationFrame||a;if(!g.State.isMobile&&n.hidden!==r){var w=function(){n.hidden?(_=function(e){return setTimeout(function(){e(!0)},16)},T()):_=t.requestAnimationFrame||a};w(),n.addEventListener("visibilitychange",w)}return e.Velocity=g,e!==t&&(e.fn.velocity=b,e.fn.velocity.defaults=g.defaults),f.each(["Down","Up"],function(e,t){g.Redirects["slide"+t]=function(e,n,o,i,a,s){var u=f.extend({},n),l=u.begin,c=u.complete,p={},h={height:"",marginTop:"",marginBottom:"",paddingTop:"",paddingBottom:""};u.display===r&&(u.display="Down"===t?"inline"===g.CSS.Values.getDisplayType(e)?"inline-block":"block":"none"),u.begin=function(){for(var n in 0===o&&l&&l.call(a,a),h)if(h.hasOwnProperty(n)){p[n]=e.style[n];var r=y.getPropertyValue(e,n);h[n]="Down"===t?[r,0]:[0,r]}p.overflow=e.style.overflow,e.style.overflow="hidden"},u.complete=function(){for(var t in p)p.hasOwnProperty(t)&&(e.style[t]=p[t]);o===i-1&&(c&&c.call(a,a),s&&s.resolver(a))},g(e,h,u)}}),f.each(["In","Out"],function(e,t){g.Redirects["fade"+t]=function(e,n,o,i,a,s){var u=f.extend({},n),l=u.complete,c={opacity:"In"===t?1:0};0!==o&&(u.begin=null),u.complete=o!==i-1?null:function(){l&&l.call(a,a),s&&s.resolver(a)},u.display===r&&(u.display="In"===t?"auto":"none"),g(this,c,u)}}),g}function x(e){var t=f.data(e,"velocity");return null===t?r:t}function k(e,t){var n=x(e);n&&n.delayTimer&&!n.delayPaused&&(n.delayRemaining=n.delay-t+n.delayBegin,n.delayPaused=!0,clearTimeout(n.delayTimer.setTimeout))}function S(e,t){var n=x(e);n&&n.delayTimer&&n.delayPaused&&(n.delayPaused=!1,n.delayTimer.setTimeout=setTimeout(n.delayTimer.next,n.delayRemaining))}function C(e,n,r,o){var i=4,a=.001,s=1e-7,u=10,l=11,c=1/(l-1),f="Float32Array"in t;if(4!==arguments.length)return!1;for(var p=0;p<4;++p)if("number"!=typeof arguments[p]||isNaN(arguments[p])||!isFinite(arguments[p]))return!1;e=Math.min(e,1),r=Math.min(r,1),e=Math.max(e,0),r=Math.max(r,0);var h=f?new Float32Array(l):new Array(l);function d(e,t){return 1-3*t+3*e}function m(e,t){return 3*t-6*e}function g(e){return 3*e}function v(e,t,n){return((d(t,n)*e+m(t,n))*e+g(t))*e}function y(e,t,n){return 3*d(t,n)*e*e+2*m(t,n)*e+g(t)}function b(t){for(var n=0,o=1,f=l-1;o!==f&&h[o]<=t;++o)n+=c;var p=n+(t-h[--o])/(h[o+1]-h[o])*c,d=y(p,e,r);return d>=a?function(t,n){for(var o=0;o<i;++o){var a=y(n,e,r);if(0===a)return n;n-=(v(n,e,r)-t)/a}return n}(t,p):0===d?p:function(t,n,o){var i,a,l=0;do{(i=v(a=n+(o-n)/2,e,r)-t)>0?o=a:n=a}while(Math.abs(i)>s&&++l<u);return a}(t,n,n+c)}var _=!1;function w(){_=!0,e===n&&r===o||function(){for(var t=0;t<l;++t)h[t]=v(t*c,e,r)}()}var x=function(t){return _||w(),e===n&&r===o?t:0===t?0:1===t?1:v(b(t),n,o)};x.getControlPoints=function(){return[{x:e,y:n},{x:r,y:o}]};var k="generateBezier("+[e,n,r,o]+")";return x.toString=function(){return k},x}function E(e,t){var n=e;return p.isString(e)?g.Easings[e]||(n=!1):n=p.isArray(e)&&1===e.length?function(e){return function(t){return Math.round(t*e)*(1/e)}}.apply(null,e):p.isArray(e)&&2===e.length?v.apply(null,e.concat([t])):!(!p.isArray(e)||4!==e.length)&&C.apply(null,e),!1===n&&(n=g.Easings[g.defaults.easing]?g.defaults.easing:m),n}function T(e){if(e){var t=g.timestamp&&!0!==e?e:s.now(),n=g.State.calls.length;n>1e4&&(g.State.calls=function(e){for(var t=-1,n=e?e.length:0,r=[];++t<n;){var o=e[t];o&&r.push(o)}return r}(g.State.calls),n=g.State.calls.length);for(var o=0;o<n;o++)if(g.State.calls[o]){var a=g.State.calls[o],u=a[0],l=a[2],c=a[3],h=!c,d=null,m=a[5],v=a[6];if(c||(c=g.State.calls[o][3]=t-16),m){if(!0!==m.resume)continue;c=a[3]=Math.round(t-v-16),a[5]=null}v=a[6]=t-c;for(var b=Math.min(v/l.duration,1),w=0,k=u.length;w<k;w++){var S=u[w],C=S.element;if(x(C)){var E=!1;if(l.display!==r&&null!==l.display&&"none"!==l.display){if("flex"===l.display){f.each(["-webkit-box","-moz-box","-ms-flexbox","-webkit-flex"],function(e,t){y.setPropertyValue(C,"display",t)})}y.setPropertyValue(C,"display",l.display)}for(var A in l.visibility!==r&&"hidden"!==l.visibility&&y.setPropertyValue(C,"visibility",l.visibility),S)if(S.hasOwnProperty(A)&&"element"!==A){var P,j=S[A],R=p.isString(j.easing)?g.Easings[j.easing]:j.easing;if(p.isString(j.pattern)){var N=1===b?function(e,t,n){var r=j.endValue[t];return n?Math.round(r):r}:function(e,t,n){var r=j.startValue[t],o=j.endValue[t]-r,i=r+o*R(b,l,o);return n?Math.round(i):i};P=j.pattern.replace(/{(\d+)(!)?}/g,N)}else if(1===b)P=j.endValue;else{var M=j.endValue-j.startValue;P=j.startValue+M*R(b,l,M)}if(!h&&P===j.currentValue)continue;if(j.currentValue=P,"tween"===A)d=P;else{var L;if(y.Hooks.registered[A]){L=y.Hooks.getRoot(A);var I=x(C).rootPropertyValueCache[L];I&&(j.rootPropertyValue=I)}var D=y.setPropertyValue(C,A,j.currentValue+(i<9&&0===parseFloat(P)?"":j.unitType),j.rootPropertyValue,j.scrollData);y.Hooks.registered[A]&&(y.Normalizations.registered[L]?x(C).rootPropertyValueCache[L]=y.Normalizations.registered[L]("extract",null,D[1]):x(C).rootPropertyValueCache[L]=D[1]),"transform"===D[0]&&(E=!0)}}l.mobileHA&&x(C).transformCache.translate3d===r&&(x(C).transformCache.translate3d="(0px, 0px, 0px)",E=!0),E&&y.flushTransformCache(C)}}l.display!==r&&"none"!==l.display&&(g.State.calls[o][2].display=!1),l.visibility!==r&&"hidden"!==l.visibility&&(g.State.calls[o][2].visibility=!1),l.progress&&l.progress.call(a[1],a[1],b,Math.max(0,c+l.duration-t),c,d),1===b&&O(o)}}g.State.isTicking&&_(T)}function O(e,t){if(!g.State.calls[e])return!1;for(var n=g.State.calls[e][0],o=g.State.calls[e][1],i=g.State.calls[e][2],a=g.State.calls[e][4],s=!1,u=0,l=n.length;u<l;u++){var c=n[u].element;t||i.loop||("none"===i.display&&y.setPropertyValue(c,"display",i.display),"hidden"===i.visibility&&y.setPropertyValue(c,"visibility",i.visibility));var p=x(c);if(!0!==i.loop&&(f.queue(c)[1]===r||!/\.velocityQueueEntryFlag/i.test(f.queue(c)[1]))&&p){p.isAnimating=!1,p.rootPropertyValueCache={};var h=!1;f.each(y.Lists.transforms3D,function(e,t){var n=/^scale/.test(t)?1:0,o=p.transformCache[t];p.transformCache[t]!==r&&new RegExp("^\\("+n+"[^.]").test(o)&&(h=!0,delete p.transformCache[t])}),i.mobileHA&&(h=!0,delete p.transformCache.translate3d),h&&y.flushTransformCache(c),y.Values.removeClass(c,"velocity-animating")}if(!t&&i.complete&&!i.loop&&u===l-1)try{i.complete.call(o,o)}catch(e){setTimeout(function(){throw e},1)}a&&!0!==i.loop&&a(o),p&&!0===i.loop&&!t&&(f.each(p.tweensContainer,function(e,t){if(/^rotate/.test(e)&&(parseFloat(t.startValue)-parseFloat(t.endValue))%360==0){var n=t.startValue;t.startValue=t.endValue,t.endValue=n}/^backgroundPosition/.test(e)&&100===parseFloat(t.endValue)&&"%"===t.unitType&&(t.endValue=0,t.startValue=100)}),g(c,"reverse",{loop:!0,delay:i.delay})),!1!==i.queue&&f.dequeue(c,i.queue)}g.State.calls[e]=!1;for(var d=0,m=g.State.calls.length;d<m;d++)if(!1!==g.State.calls[d]){s=!0;break}!1===s&&(g.State.isTicking=!1,delete g.State.calls,g.State.calls=[])}jQuery.fn.velocity=jQuery.fn.animate}(window.jQuery||window.Zepto||window,window,window?window.document:void 0)})},function(e,t,n){"use strict";var r=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}();function i(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function a(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function s(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}var u={each:n(323),extend:n(324),forEach:n(71),isEqual:n(128),keys:n(17),omit:n(135),map:n(329)},l=n(1),c=n(36),f=n(0),p=n(343),h=n(345).default,d=n(92),m="undefined"!=typeof window&&(window.requestAnimationFrame||window.webkitRequestAnimationFrame||window.mozRequestAnimationFrame||function(e){window.setTimeout(e,0)});m="undefined"!=typeof window&&m.bind(window);var g="undefined"!=typeof window&&(window.cancelAnimationFrame||window.webkitCancelAnimationFrame||window.mozCancelAnimationFrame||function(e){window.clearTimeout(e)});g="undefined"!=typeof window&&g.bind(window);var v=function(e){function t(){var e,n,r;i(this,t);for(var o=arguments.length,s=Array(o),u=0;u<o;u++)s[u]=arguments[u];return n=r=a(this,(e=t.__proto__||Object.getPrototypeOf(t)).call.apply(e,[this].concat(s))),r.lastState="appear",r.componentWillEnter=function(e,t){r.lastState=t?"appear":"enter"},r.componentWillExit=function(){r.lastState="exit"},r.endListener=function(e,t){switch(r.lastState){case"appear":r.props.willAppearFunc(e,t);break;case"enter":r.props.willEnterFunc(e,t);break;case"exit":r.props.willLeaveFunc(e,t)}},a(r,n)}return s(t,l.Component),o(t,[{key:"componentWillUnmount",value:function(){d.Utilities.removeData(c.findDOMNode(this),["velocity","fxqueue"])}},{key:"render",value:function(){var e=u.omit(this.props,u.keys(t.propTypes));return l.createElement(h,r({},e,{timeout:null,addEndListener:this.endListener,appear:!0,onEnter:this.componentWillEnter,onExit:this.componentWillExit}),this.props.children)}}]),t}();v.propTypes={children:f.element.isRequired,willAppearFunc:f.func.isRequired,willEnterFunc:f.func.isRequired,willLeaveFunc:f.func.isRequired};var y=function(e){function t(e){i(this,t);var n=a(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n._scheduledAnimationFrame=null,n._scheduledAnimationRunFrames=[],n._entering=[],n._leaving=[],n._timers=[],n._unmounted=!1,n.childWillAppear=n.childWillAppear.bind(n),n.childWillEnter=n.childWillEnter.bind(n),n.childWillLeave=n.childWillLeave.bind(n),n._runAnimations=n._runAnimations.bind(n),n._wrapChild=n._wrapChild.bind(n),n}return s(t,l.Component),o(t,[{key:"componentWillUnmount",value:function(){this._scheduledAnimationFrame&&g(this._scheduledAnimationFrame),u.forEach(this._timers,function(e){clearTimeout(e)}),u.forEach(this._scheduledAnimationRunFrames,function(e){g(e)}),this._unmounted=!0}},{key:"render",value:function(){var e=u.omit(this.props,u.keys(t.propTypes));return l.createElement(p,e,this.constructor.disabledForTest||d.velocityReactServerShim?this.props.children:l.Children.map(this.props.children,this._wrapChild))}},{key:"childWillAppear",value:function(e,t){var n=this;if(this.props.runOnMount)this.childWillEnter(e,t);else{this._finishAnimation(e,this.props.enter);var r=setTimeout(function(){var e=n._timers.indexOf(r);e>=0&&n._timers.splice(e,1),t()},0);this._timers.push(r)}}},{key:"childWillEnter",value:function(e,t){this._shortCircuitAnimation(this.props.enter,t)||(this._finishAnimation(e,this.props.leave,{begin:void 0,complete:void 0}),u.forEach(this.props.enterHideStyle,function(t,n){d.CSS.setPropertyValue(e,n,t)}),this._entering.push({node:e,doneFn:t}),this._schedule())}},{key:"childWillLeave",value:function(e,t){this._shortCircuitAnimation(this.props.leave,t)||(this._leaving.push({node:e,doneFn:t}),this._schedule())}},{key:"_shortCircuitAnimation",value:function(e,t){return!(!document.hidden&&null!=this._parseAnimationProp(e).animation)&&(t(),!0)}},{key:"_schedule",value:function(){this._scheduledAnimationFrame||(this._scheduledAnimationFrame=m(this._runAnimations))}},{key:"_runAnimations",value:function(){this._scheduledAnimationFrame=null,this._runAnimation(!0,this._entering,this.props.enter),this._runAnimation(!1,this._leaving,this.props.leave),this._entering=[],this._leaving=[]}},{key:"_parseAnimationProp",value:function(e){var t,n,r;return"string"==typeof e?(t=e,r=null,n={}):(t=null!=e?e.animation:null,r=null!=e?e.style:null,n=u.omit(e,"animation","style")),{animation:t,style:r,opts:n}}},{key:"_runAnimation",value:function(e,t,n){var r=this;if(0!==t.length){var o=u.map(t,"node"),i=u.map(t,"doneFn"),a=this._parseAnimationProp(n),s=a.animation,l=a.style,c=a.opts;e&&(u.isEqual(this.props.enterShowStyle,{display:""})&&(/^(fade|slide)/.test(s)||/In$/.test(s))||(l=u.extend({},this.props.enterShowStyle,l))),null!=l&&u.each(l,function(e,t){d.hook(o,t,e)});var f,p=function(){r._unmounted||i.map(function(e){e()})};if(e?(p(),p=null):d(o,"stop"),p&&c.complete){var h=c.complete;f=function(){p(),h.apply(this,arguments)}}else f=p||c.complete;var g=m(function(){var e=r._scheduledAnimationRunFrames.indexOf(g);e>=0&&r._scheduledAnimationRunFrames.splice(e,1),d(o,s,u.extend({},c,{complete:f}))});this._scheduledAnimationRunFrames.push(g)}}},{key:"_finishAnimation",value:function(e,t,n){var r=this._parseAnimationProp(t),o=r.animation,i=r.style,a=u.extend({},r.opts,n);null!=i&&u.each(i,function(t,n){d.hook(e,n,t)}),null!=o&&(d(e,o,a),d(e,"finishAll",!0))}},{key:"_wrapChild",value:function(e){return e?l.createElement(v,{key:e.key,willAppearFunc:this.childWillAppear,willEnterFunc:this.childWillEnter,willLeaveFunc:this.childWillLeave},e):null}}]),t}();y.disabledForTest=!1,y.propTypes={runOnMount:f.bool,enter:f.any,leave:f.any,children:f.any,enterHideStyle:f.object,enterShowStyle:f.object},y.defaultProps={runOnMount:!1,enter:null,leave:null,enterHideStyle:{display:"none"},enterShowStyle:{display:""}},e.exports=y},function(e,t,n){e.exports=n(71)},function(e,t,n){e.exports=n(325)},function(e,t,n){var r=n(27),o=n(326),i=n(86),a=o(function(e,t){r(t,i(t),e)});e.exports=a},function(e,t,n){var r=n(327),o=n(328);e.exports=function(e){return r(function(t,n){var r=-1,i=n.length,a=i>1?n[i-1]:void 0,s=i>2?n[2]:void 0;for(a=e.length>3&&"function"==typeof a?(i--,a):void 0,s&&o(n[0],n[1],s)&&(a=i<3?void 0:a,i=1),t=Object(t);++r<i;){var u=n[r];u&&e(t,u,r,a)}return t})}},function(e,t,n){var r=n(47),o=n(141),i=n(142);e.exports=function(e,t){return i(o(e,t,r),e+"")}},function(e,t,n){var r=n(50),o=n(31),i=n(74),a=n(11);e.exports=function(e,t,n){if(!a(n))return!1;var s=typeof t;return!!("number"==s?o(n)&&i(t,n.length):"string"==s&&t in n)&&r(n[t],e)}},function(e,t,n){var r=n(85),o=n(330),i=n(342),a=n(5);e.exports=function(e,t){return(a(e)?r:i)(e,o(t,3))}},function(e,t,n){var r=n(331),o=n(334),i=n(47),a=n(5),s=n(339);e.exports=function(e){return"function"==typeof e?e:null==e?i:"object"==typeof e?a(e)?o(e[0],e[1]):r(e):s(e)}},function(e,t,n){var r=n(332),o=n(333),i=n(144);e.exports=function(e){var t=o(e);return 1==t.length&&t[0][2]?i(t[0][0],t[0][1]):function(n){return n===e||r(n,e,t)}}},function(e,t,n){var r=n(80),o=n(79),i=1,a=2;e.exports=function(e,t,n,s){var u=n.length,l=u,c=!s;if(null==e)return!l;for(e=Object(e);u--;){var f=n[u];if(c&&f[2]?f[1]!==e[f[0]]:!(f[0]in e))return!1}for(;++u<l;){var p=(f=n[u])[0],h=e[p],d=f[1];if(c&&f[2]){if(void 0===h&&!(p in e))return!1}else{var m=new r;if(s)var g=s(h,d,p,e,t,m);if(!(void 0===g?o(d,h,i|a,s,m):g))return!1}}return!0}},function(e,t,n){var r=n(143),o=n(17);e.exports=function(e){for(var t=o(e),n=t.length;n--;){var i=t[n],a=e[i];t[n]=[i,a,r(a)]}return t}},function(e,t,n){var r=n(79),o=n(335),i=n(336),a=n(89),s=n(143),u=n(144),l=n(32),c=1,f=2;e.exports=function(e,t){return a(e)&&s(t)?u(l(e),t):function(n){var a=o(n,e);return void 0===a&&a===t?i(n,e):r(t,a,c|f)}}},function(e,t,n){var r=n(91);e.exports=function(e,t,n){var o=null==e?void 0:r(e,t);return void 0===o?n:o}},function(e,t,n){var r=n(337),o=n(338);e.exports=function(e,t){return null!=e&&o(e,t,r)}},function(e,t){e.exports=function(e,t){return null!=e&&t in Object(e)}},function(e,t,n){var r=n(54),o=n(72),i=n(5),a=n(74),s=n(75),u=n(32);e.exports=function(e,t,n){for(var l=-1,c=(t=r(t,e)).length,f=!1;++l<c;){var p=u(t[l]);if(!(f=null!=e&&n(e,p)))break;e=e[p]}return f||++l!=c?f:!!(c=null==e?0:e.length)&&s(c)&&a(p,c)&&(i(e)||o(e))}},function(e,t,n){var r=n(340),o=n(341),i=n(89),a=n(32);e.exports=function(e){return i(e)?r(a(e)):o(e)}},function(e,t){e.exports=function(e){return function(t){return null==t?void 0:t[e]}}},function(e,t,n){var r=n(91);e.exports=function(e){return function(t){return r(t,e)}}},function(e,t,n){var r=n(122),o=n(31);e.exports=function(e,t){var n=-1,i=o(e)?Array(e.length):[];return r(e,function(e,r,o){i[++n]=t(e,r,o)}),i}},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=s(n(0)),o=s(n(1)),i=n(145),a=n(344);function s(e){return e&&e.__esModule?e:{default:e}}function u(){return(u=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}function l(e){if(void 0===e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}var c=Object.values||function(e){return Object.keys(e).map(function(t){return e[t]})},f=function(e){var t,n;function r(t,n){var r,o=(r=e.call(this,t,n)||this).handleExited.bind(l(l(r)));return r.state={handleExited:o,firstRender:!0},r}n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var i=r.prototype;return i.getChildContext=function(){return{transitionGroup:{isMounting:!this.appeared}}},i.componentDidMount=function(){this.appeared=!0,this.mounted=!0},i.componentWillUnmount=function(){this.mounted=!1},r.getDerivedStateFromProps=function(e,t){var n=t.children,r=t.handleExited;return{children:t.firstRender?(0,a.getInitialChildMapping)(e,r):(0,a.getNextChildMapping)(e,n,r),firstRender:!1}},i.handleExited=function(e,t){var n=(0,a.getChildMapping)(this.props.children);e.key in n||(e.props.onExited&&e.props.onExited(t),this.mounted&&this.setState(function(t){var n=u({},t.children);return delete n[e.key],{children:n}}))},i.render=function(){var e=this.props,t=e.component,n=e.childFactory,r=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(e,["component","childFactory"]),i=c(this.state.children).map(n);return delete r.appear,delete r.enter,delete r.exit,null===t?i:o.default.createElement(t,r,i)},r}(o.default.Component);f.childContextTypes={transitionGroup:r.default.object.isRequired},f.propTypes={},f.defaultProps={component:"div",childFactory:function(e){return e}};var p=(0,i.polyfill)(f);t.default=p,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.getChildMapping=o,t.mergeChildMappings=i,t.getInitialChildMapping=function(e,t){return o(e.children,function(n){return(0,r.cloneElement)(n,{onExited:t.bind(null,n),in:!0,appear:a(n,"appear",e),enter:a(n,"enter",e),exit:a(n,"exit",e)})})},t.getNextChildMapping=function(e,t,n){var s=o(e.children),u=i(t,s);return Object.keys(u).forEach(function(o){var i=u[o];if((0,r.isValidElement)(i)){var l=o in t,c=o in s,f=t[o],p=(0,r.isValidElement)(f)&&!f.props.in;!c||l&&!p?c||!l||p?c&&l&&(0,r.isValidElement)(f)&&(u[o]=(0,r.cloneElement)(i,{onExited:n.bind(null,i),in:f.props.in,exit:a(i,"exit",e),enter:a(i,"enter",e)})):u[o]=(0,r.cloneElement)(i,{in:!1}):u[o]=(0,r.cloneElement)(i,{onExited:n.bind(null,i),in:!0,exit:a(i,"exit",e),enter:a(i,"enter",e)})}}),u};var r=n(1);function o(e,t){var n=Object.create(null);return e&&r.Children.map(e,function(e){return e}).forEach(function(e){n[e.key]=function(e){return t&&(0,r.isValidElement)(e)?t(e):e}(e)}),n}function i(e,t){function n(n){return n in t?t[n]:e[n]}e=e||{},t=t||{};var r,o=Object.create(null),i=[];for(var a in e)a in t?i.length&&(o[a]=i,i=[]):i.push(a);var s={};for(var u in t){if(o[u])for(r=0;r<o[u].length;r++){var l=o[u][r];s[o[u][r]]=n(l)}s[u]=n(u)}for(r=0;r<i.length;r++)s[i[r]]=n(i[r]);return s}function a(e,t,n){return null!=n[t]?n[t]:e.props[t]}},function(e,t,n){"use strict";t.__esModule=!0,t.default=t.EXITING=t.ENTERED=t.ENTERING=t.EXITED=t.UNMOUNTED=void 0;var r=function(e){if(e&&e.__esModule)return e;var t={};if(null!=e)for(var n in e)if(Object.prototype.hasOwnProperty.call(e,n)){var r=Object.defineProperty&&Object.getOwnPropertyDescriptor?Object.getOwnPropertyDescriptor(e,n):{};r.get||r.set?Object.defineProperty(t,n,r):t[n]=e[n]}return t.default=e,t}(n(0)),o=s(n(1)),i=s(n(36)),a=n(145);n(346);function s(e){return e&&e.__esModule?e:{default:e}}var u="unmounted";t.UNMOUNTED=u;var l="exited";t.EXITED=l;var c="entering";t.ENTERING=c;var f="entered";t.ENTERED=f;t.EXITING="exiting";var p=function(e){var t,n;function r(t,n){var r;r=e.call(this,t,n)||this;var o,i=n.transitionGroup,a=i&&!i.isMounting?t.enter:t.appear;return r.appearStatus=null,t.in?a?(o=l,r.appearStatus=c):o=f:o=t.unmountOnExit||t.mountOnEnter?u:l,r.state={status:o},r.nextCallback=null,r}n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var a=r.prototype;return a.getChildContext=function(){return{transitionGroup:null}},r.getDerivedStateFromProps=function(e,t){return e.in&&t.status===u?{status:l}:null},a.componentDidMount=function(){this.updateStatus(!0,this.appearStatus)},a.componentDidUpdate=function(e){var t=null;if(e!==this.props){var n=this.state.status;this.props.in?n!==c&&n!==f&&(t=c):n!==c&&n!==f||(t="exiting")}this.updateStatus(!1,t)},a.componentWillUnmount=function(){this.cancelNextCallback()},a.getTimeouts=function(){var e,t,n,r=this.props.timeout;return e=t=n=r,null!=r&&"number"!=typeof r&&(e=r.exit,t=r.enter,n=r.appear),{exit:e,enter:t,appear:n}},a.updateStatus=function(e,t){if(void 0===e&&(e=!1),null!==t){this.cancelNextCallback();var n=i.default.findDOMNode(this);t===c?this.performEnter(n,e):this.performExit(n)}else this.props.unmountOnExit&&this.state.status===l&&this.setState({status:u})},a.performEnter=function(e,t){var n=this,r=this.props.enter,o=this.context.transitionGroup?this.context.transitionGroup.isMounting:t,i=this.getTimeouts();t||r?(this.props.onEnter(e,o),this.safeSetState({status:c},function(){n.props.onEntering(e,o),n.onTransitionEnd(e,i.enter,function(){n.safeSetState({status:f},function(){n.props.onEntered(e,o)})})})):this.safeSetState({status:f},function(){n.props.onEntered(e)})},a.performExit=function(e){var t=this,n=this.props.exit,r=this.getTimeouts();n?(this.props.onExit(e),this.safeSetState({status:"exiting"},function(){t.props.onExiting(e),t.onTransitionEnd(e,r.exit,function(){t.safeSetState({status:l},function(){t.props.onExited(e)})})})):this.safeSetState({status:l},function(){t.props.onExited(e)})},a.cancelNextCallback=function(){null!==this.nextCallback&&(this.nextCallback.cancel(),this.nextCallback=null)},a.safeSetState=function(e,t){t=this.setNextCallback(t),this.setState(e,t)},a.setNextCallback=function(e){var t=this,n=!0;return this.nextCallback=function(r){n&&(n=!1,t.nextCallback=null,e(r))},this.nextCallback.cancel=function(){n=!1},this.nextCallback},a.onTransitionEnd=function(e,t,n){this.setNextCallback(n),e?(this.props.addEndListener&&this.props.addEndListener(e,this.nextCallback),null!=t&&setTimeout(this.nextCallback,t)):setTimeout(this.nextCallback,0)},a.render=function(){var e=this.state.status;if(e===u)return null;var t=this.props,n=t.children,r=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(t,["children"]);if(delete r.in,delete r.mountOnEnter,delete r.unmountOnExit,delete r.appear,delete r.enter,delete r.exit,delete r.timeout,delete r.addEndListener,delete r.onEnter,delete r.onEntering,delete r.onEntered,delete r.onExit,delete r.onExiting,delete r.onExited,"function"==typeof n)return n(e,r);var i=o.default.Children.only(n);return o.default.cloneElement(i,r)},r}(o.default.Component);function h(){}p.contextTypes={transitionGroup:r.object},p.childContextTypes={transitionGroup:function(){}},p.propTypes={},p.defaultProps={in:!1,mountOnEnter:!1,unmountOnExit:!1,appear:!1,enter:!0,exit:!0,onEnter:h,onEntering:h,onEntered:h,onExit:h,onExiting:h,onExited:h},p.UNMOUNTED=0,p.EXITED=1,p.ENTERING=2,p.ENTERED=3,p.EXITING=4;var d=(0,a.polyfill)(p);t.default=d},function(e,t,n){"use strict";t.__esModule=!0,t.classNamesShape=t.timeoutsShape=void 0;var r;(r=n(0))&&r.__esModule;t.timeoutsShape=null;t.classNamesShape=null},function(e,t,n){"use strict";var r={isObject:n(11)},o=n(92),i=0;e.exports={registerEffect:function(e,t){r.isObject(e)&&(t=e,e="");var n="VelocityHelper.animation."+i+++e;if(o.velocityReactServerShim)return n;if(void 0===o.RegisterEffect)throw"Velocity.RegisterEffect not found. You need to require 'velocity-animate/velocity.ui' at a top level for UI Pack.";return o.RegisterEffect(n,t),n}}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});var r=d(n(70)),o=d(n(349)),i=d(n(37)),a=d(n(42)),s=d(n(43)),u=d(n(44)),l=d(n(46)),c=d(n(1)),f=d(n(0)),p=d(n(352)),h=d(n(353));function d(e){return e&&e.__esModule?e:{default:e}}var m=function(e){function t(){return(0,a.default)(this,t),(0,u.default)(this,(t.__proto__||(0,i.default)(t)).apply(this,arguments))}return(0,l.default)(t,e),(0,s.default)(t,[{key:"shouldComponentUpdate",value:function(e){for(var t=this.props,n=(0,o.default)(e),r=0;r<n.length;r++){var i=n[r];if("animations"!==i)if(!(0,p.default)(t[i],e[i]))return!0}return!(0,h.default)(t.animations,e.animations,{strict:!0})}},{key:"render",value:function(){var e=this.props,t=e.animations,n=e.decorators,o=e.node,i=e.onClick,a=e.style,s=o.active,u=!o.children,l=[a.link,s?a.activeLink:null],f=(0,r.default)({container:l},a);return c.default.createElement(n.Container,{animations:t,decorators:n,node:o,onClick:i,style:f,terminal:u})}}]),t}(c.default.Component);m.propTypes={style:f.default.object.isRequired,decorators:f.default.object.isRequired,animations:f.default.oneOfType([f.default.object,f.default.bool]).isRequired,node:f.default.object.isRequired,onClick:f.default.func},t.default=m},function(e,t,n){e.exports={default:n(350),__esModule:!0}},function(e,t,n){n(351),e.exports=n(6).Object.keys},function(e,t,n){var r=n(38),o=n(30);n(108)("keys",function(){return function(e){return o(r(e))}})},function(e,t){e.exports=function(e,t,n,r){var o=n?n.call(r,e,t):void 0;if(void 0!==o)return!!o;if(e===t)return!0;if("object"!=typeof e||!e||"object"!=typeof t||!t)return!1;var i=Object.keys(e),a=Object.keys(t);if(i.length!==a.length)return!1;for(var s=Object.prototype.hasOwnProperty.bind(t),u=0;u<i.length;u++){var l=i[u];if(!s(l))return!1;var c=e[l],f=t[l];if(!1===(o=n?n.call(r,c,f,l):void 0)||void 0===o&&c!==f)return!1}return!0}},function(e,t,n){var r=Array.prototype.slice,o=n(354),i=n(355),a=e.exports=function(e,t,n){return n||(n={}),e===t||(e instanceof Date&&t instanceof Date?e.getTime()===t.getTime():!e||!t||"object"!=typeof e&&"object"!=typeof t?n.strict?e===t:e==t:function(e,t,n){var l,c;if(s(e)||s(t))return!1;if(e.prototype!==t.prototype)return!1;if(i(e))return!!i(t)&&(e=r.call(e),t=r.call(t),a(e,t,n));if(u(e)){if(!u(t))return!1;if(e.length!==t.length)return!1;for(l=0;l<e.length;l++)if(e[l]!==t[l])return!1;return!0}try{var f=o(e),p=o(t)}catch(e){return!1}if(f.length!=p.length)return!1;for(f.sort(),p.sort(),l=f.length-1;l>=0;l--)if(f[l]!=p[l])return!1;for(l=f.length-1;l>=0;l--)if(c=f[l],!a(e[c],t[c],n))return!1;return typeof e==typeof t}(e,t,n))};function s(e){return null==e}function u(e){return!(!e||"object"!=typeof e||"number"!=typeof e.length)&&("function"==typeof e.copy&&"function"==typeof e.slice&&!(e.length>0&&"number"!=typeof e[0]))}},function(e,t){function n(e){var t=[];for(var n in e)t.push(n);return t}(e.exports="function"==typeof Object.keys?Object.keys:n).shim=n},function(e,t){var n="[object Arguments]"==function(){return Object.prototype.toString.call(arguments)}();function r(e){return"[object Arguments]"==Object.prototype.toString.call(e)}function o(e){return e&&"object"==typeof e&&"number"==typeof e.length&&Object.prototype.hasOwnProperty.call(e,"callee")&&!Object.prototype.propertyIsEnumerable.call(e,"callee")||!1}(t=e.exports=n?r:o).supported=r,t.unsupported=o},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.ContentPanel=t.Page=void 0;var r,o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),i=n(1),a=(r=i)&&r.__esModule?r:{default:r},s=n(357),u=n(358),l=n(28);var c=t.Page={START:"START",FILE:"FILE",SOURCE_DIR:"SOURCE_DIR",SEARCH:"SEARCH",FIND:"FIND",LOADING:"LOADING",INTERNAL_ERROR:"INTERNAL_ERROR"};t.ContentPanel=function(e){function t(){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);var e=function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,(t.__proto__||Object.getPrototypeOf(t)).call(this));return e.state={page:c.LOADING},e}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,a.default.Component),o(t,[{key:"componentDidMount",value:function(){this.query_api(this.props.path)}},{key:"componentDidUpdate",value:function(){document.title="cargo src - "+this.props.path}},{key:"UNSAFE_componentWillReceiveProps",value:function(e){e.path!=this.props.path&&this.query_api(e.path)}},{key:"query_api",value:function(e){e&&"/"!==e||(e=CONFIG.workspace_root);var t=this.props.app,n=this,r=e.startsWith("/deps/")?e.substring(1):"src/"+e;(0,l.request)(r,function(e){e.Directory?n.setState({page:c.SOURCE_DIR,params:{path:e.Directory.path,files:e.Directory.files}}):e.File?(t.refreshStatus(),n.setState({page:c.FILE,params:{path:e.File.path,lines:e.File.lines,rendered:e.File.rendered}})):(console.log("Unexpected source data."),console.log(e))},"Error with source request for /"+r,t)}},{key:"render",value:function(){var e=void 0;switch(this.state.page){case c.FILE:e=a.default.createElement(u.SourceView,{app:this.props.app,path:this.state.params.path,lines:this.state.params.lines,content:this.state.params.rendered,highlight:this.props.srcHighlight});break;case c.SOURCE_DIR:e=a.default.createElement(s.DirView,{app:this.props.app,path:this.state.params.path,files:this.state.params.files});break;case c.LOADING:e=a.default.createElement("div",{id:"div_loading"},"Loading...");break;case c.INTERNAL_ERROR:e="Server error?";break;case c.START:default:e=null}return e}}]),t}()},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});const r=n(1),o=n(150);t.DirView=(e=>{const t=e.path.join("/").replace("//","/");let n=e.files.map(n=>{const o="Directory"===n.kind?"div_entry_name div_dir_entry":"div_entry_name div_file_entry";return r.createElement("div",{className:"div_entry",key:n.name},r.createElement("span",{className:o,onClick:()=>e.app.loadSource(`${t}/${n.name}`)},n.name))});return 0==n.length&&(n=r.createElement("div",{className:"div_entry"},"<Empty directory>")),r.createElement("div",{id:"src"},r.createElement(o.BreadCrumbs,{path:e.path,app:e.app}),r.createElement("div",{id:"div_dir_view"},r.createElement("div",{id:"div_dir_contents"},n)))})},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.SourceView=void 0;var r=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),o="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},i=c(n(1)),a=function(e){if(e&&e.__esModule)return e;var t={};if(null!=e)for(var n in e)Object.prototype.hasOwnProperty.call(e,n)&&(t[n]=e[n]);return t.default=e,t}(n(28)),s=n(150),u=n(359),l=c(n(360));function c(e){return e&&e.__esModule?e:{default:e}}function f(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function p(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function h(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}function d(e){var t=[];return CONFIG.edit_command&&t.push({id:"line_number_menu_edit",label:"edit",fn:v,unstable:!0}),CONFIG.vcs_link&&t.push({id:"line_number_vcs",label:"view in VCS",fn:g}),i.default.createElement(u.Menu,{id:"div_line_number_menu",items:t,location:e.location,onClose:e.onClose,target:e.target})}function m(e){var t=[],n=e.target.dataset.link.split(":"),r=n[0];if("search"!=r){var o=a.parseLink(n);t.push({id:"ref_menu_goto_def",label:"goto def",fn:function(){return e.app.loadSource(r,o)}})}var s=e.target.dataset.docLink;s&&t.push({id:"ref_menu_view_docs",label:"view docs",fn:function(){return window.open(s,"_blank")}});var l=e.target.dataset.srcLink;l&&t.push({id:"ref_menu_view_source",label:"view source",fn:window.open(l,"_blank")}),t.push({id:"ref_menu_find_uses",label:"find all uses",fn:function(){return e.app.getUses(e.id)}});var c=e.target.dataset.impls;return c&&"0"!=c&&t.push({id:"ref_menu_find_impls",label:"find impls ("+c+")",fn:function(){return e.app.getImpls(e.id)}}),i.default.createElement(u.Menu,{id:"div_ref_menu",items:t,location:e.location,onClose:e.onClose,target:e.target})}function g(e){var t=e.dataset.link,n=t.lastIndexOf(":"),r=t.substring(CONFIG.workspace_root.length+2,n),o=t.substring(n+1);window.open(CONFIG.vcs_link.replace("$file",r).replace("$line",o),"_blank")}function v(e){a.request("edit?file="+e.dataset.link,function(){console.log("edit - success")},"Error with search edit",null)}t.SourceView=function(e){function t(e){f(this,t);var n=p(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.state={refMenu:null},n}return h(t,i.default.Component),r(t,[{key:"componentDidMount",value:function(){this.componentDidUpdate()}},{key:"componentDidUpdate",value:function(){var e=this;this.props.highlight?a.highlight_spans(this.props.highlight,"src_line_number_","src_line_","selected",this.node):a.unHighlight("selected",this.node);var t,n,r=$("#div_src_view").find(".src_link");r.off("click"),r.click(function(t){t.preventDefault(),t.stopPropagation();var n=t.target.dataset.docLink;if(n)window.open(n,"_blank");else{var r=t.target.dataset.link.split(":"),o=r[0];if("search"!==o){var i=a.parseLink(r);e.props.app.loadSource(o,i)}else e.props.app.getUses(r[1])}}),function(e){var t=function(t){var n=$(t),r=t.className.split(" ").find(function(e){return e.startsWith("class_id_")});if(void 0===r)return{v:void 0};n.hover(function(){$("."+r).css("background-color","#d5f3b5")},function(){$("."+r).css("background-color","")});var o=r.slice("class_id_".length);n.off("contextmenu"),n.on("contextmenu",function(t){e.setState({refMenu:{top:t.pageY,left:t.pageX,target:t.target,id:o}}),t.preventDefault(),t.stopPropagation()}),n.addClass("hand_cursor")},n=!0,r=!1,i=void 0;try{for(var a,s=$("#div_src_view").find(".class_id")[Symbol.iterator]();!(n=(a=s.next()).done);n=!0){var u=t(a.value);if("object"===(void 0===u?"undefined":o(u)))return u.v}}catch(e){r=!0,i=e}finally{try{!n&&s.return&&s.return()}finally{if(r)throw i}}}(this),this.props.highlight&&(t=this.props.highlight.line_start,n=t*$("#src_line_number_1").outerHeight()-100,document.getElementById("src").scroll(0,n))}},{key:"render",value:function(){var e=this,t=this.props.path.join("/"),n=0,r=[],o=this.props.content,a=this.props.lines&&this.props.lines.map(function(e){return n+=1,r.push(i.default.createElement(y,{count:n,path:t,key:"num-"+n})),i.default.createElement(b,{count:n,line:e,key:"line-"+n})}),u=null;if(this.state.refMenu){u=i.default.createElement(m,{app:this.props.app,location:this.state.refMenu,onClose:function(){return e.setState({refMenu:null})},target:this.state.refMenu.target,id:this.state.refMenu.id})}var c="content",f="source",p=void 0,h=this.state.currentView,d=function(t){e.setState({currentView:t})};return o&&a?(h=h||c,p=i.default.createElement("div",{className:"div_view_selector"},"[ ",i.default.createElement("a",{href:h==f?null:"javascript:void(0)",onClick:function(){return d(f)}},"source")," | ",i.default.createElement("a",{href:h==c?null:"javascript:void(0)",onClick:function(){return d(c)}},"rendered")," ]")):h=o?c:f,i.default.createElement("div",{id:"src",ref:function(t){return e.node=t}},i.default.createElement(s.BreadCrumbs,{app:this.props.app,path:this.props.path}),p,i.default.createElement("div",{id:"div_src_view"},h==c?i.default.createElement(l.default,{id:"div_src_contents",className:"div_src_html",allowedTags:["h1","h2","h3","h4","h5","h6","blockquote","p","a","ul","ol","nl","li","b","i","img","strong","em","strike","code","hr","br","div","table","thead","caption","tbody","tr","th","td","pre"],html:this.props.content}):i.default.createElement("div",{id:"div_src_contents"},i.default.createElement("span",{className:"div_src_line_numbers"},r),i.default.createElement("span",{className:"div_src_lines"},a)),u))}}]),t}();var y=function(e){function t(e){f(this,t);var n=p(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.menuFn=d,n}return h(t,u.MenuHost),r(t,[{key:"renderInner",value:function(){var e="src_line_number_"+this.props.count,t=this.props.path+":"+this.props.count;return i.default.createElement("div",{className:"div_src_line_number hand_cursor",id:e,"data-link":t},this.props.count)}}]),t}();function b(e){var t=e.line?e.line:"&nbsp",n="src_line_"+e.count;return i.default.createElement("div",{className:"div_src_line",id:n,dangerouslySetInnerHTML:{__html:t}})}},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0}),t.MenuHost=t.Menu=void 0;var r,o=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),i=n(1),a=(r=i)&&r.__esModule?r:{default:r};function s(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}function u(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}function l(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}t.Menu=function(e){function t(){return s(this,t),u(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return l(t,a.default.Component),o(t,[{key:"componentDidUpdate",value:function(){this.didRender()}},{key:"componentDidMount",value:function(){this.didRender()}},{key:"didRender",value:function(){0!=this.items().length?$("#"+this.props.id).offset(this.props.location):this.props.onClose()}},{key:"items",value:function(){var e=this,t=this;return this.props.items.filter(function(e){return!e.unstable||CONFIG.unstable_features}).map(function(n){var r=e.props.id+"_link menu_link";return a.default.createElement("div",{className:r,id:n.id,key:n.id,onClick:function(e){t.hideMenu(e),n.fn(t.props.target,t.props.location)}},n.label)})}},{key:"hideMenu",value:function(e){this.props.onClose(),e.preventDefault(),e.stopPropagation()}},{key:"render",value:function(){var e=this,t=this.items();return 0===t.length?null:a.default.createElement("span",null,a.default.createElement("div",{id:"div_overlay",onClick:function(t){return e.hideMenu(t)}}),a.default.createElement("div",{id:this.props.id,className:"div_menu"},t))}}]),t}(),t.MenuHost=function(e){function t(e){s(this,t);var n=u(this,(t.__proto__||Object.getPrototypeOf(t)).call(this,e));return n.state={menuOpen:null},n}return l(t,a.default.Component),o(t,[{key:"render",value:function(){var e=this,t=null;if(this.state.menuOpen){t=a.default.createElement(this.menuFn,{location:this.state.menuOpen,onClose:function(){return e.setState({menuOpen:null})},target:this.state.menuOpen.target,callbacks:this.props.callbacks})}var n=function(t){e.setState({menuOpen:{top:t.pageY,left:t.pageX,target:t.target}}),t.preventDefault(),t.stopPropagation()},r=null;return this.leftClick&&(r=n,n=null),a.default.createElement("span",{onContextMenu:n,onClick:r},this.renderInner(),t)}}]),t}()},function(e,t,n){"use strict";Object.defineProperty(t,"__esModule",{value:!0});var r=function(){function e(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}return function(t,n,r){return n&&e(t.prototype,n),r&&e(t,r),t}}(),o=s(n(1)),i=s(n(0)),a=s(n(361));function s(e){return e&&e.__esModule?e:{default:e}}var u=["allowProtocolRelative","allowedAttributes","allowedClasses","allowedSchemes","allowedSchemesByTag","allowedTags","exclusiveFilter","nonTextTags","parser","selfClosing","transformTags"],l=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,(t.__proto__||Object.getPrototypeOf(t)).apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,o.default.Component),r(t,[{key:"render",value:function(){var e=this,t=u.reduce(function(t,n){var r=e.props[n];return void 0!==r&&(t[n]=r),t},{}),n=(0,a.default)(this.props.html,t);return o.default.createElement("div",{className:this.props.className,dangerouslySetInnerHTML:{__html:n},id:this.props.id,style:this.props.style})}}]),t}();t.default=l,l.defaultProps={html:""},l.propTypes={allowProtocolRelative:i.default.bool,allowedAttributes:i.default.objectOf(i.default.arrayOf(i.default.string)),allowedClasses:i.default.objectOf(i.default.arrayOf(i.default.string)),allowedSchemes:i.default.arrayOf(i.default.string),allowedSchemesByTag:i.default.objectOf(i.default.arrayOf(i.default.string)),allowedTags:i.default.arrayOf(i.default.string),exclusiveFilter:i.default.func,html:i.default.string.isRequired,nonTextTags:i.default.arrayOf(i.default.string),parser:i.default.any,selfClosing:i.default.arrayOf(i.default.string),transformTags:i.default.objectOf(i.default.oneOf([i.default.func,i.default.string])),className:i.default.string,id:i.default.string,style:i.default.any}},function(e,t,n){"use strict";var r=n(33),o=n(388),i=n(389),a=n(390),s=n(391),u=n(392),l=n(393),c=n(394),f=n(397),p=n(418);function h(e,t){e&&Object.keys(e).forEach(function(n){t(e[n],n)})}function d(e,t){return{}.hasOwnProperty.call(e,t)}function m(e,t){var n=[];return h(e,function(e){t(e)&&n.push(e)}),n}e.exports=v;var g=/^[^\0\t\n\f\r \/<=>]+$/;function v(e,t,n){var b="";function _(e,t){var n=this;this.tag=e,this.attribs=t||{},this.tagPosition=b.length,this.text="",this.updateParentNodeText=function(){O.length&&(O[O.length-1].text+=n.text)}}t?(t=o(v.defaults,t)).parser?t.parser=o(y,t.parser):t.parser=y:(t=v.defaults).parser=y;var w,x,k=t.nonTextTags||["script","style","textarea"];t.allowedAttributes&&(w={},x={},h(t.allowedAttributes,function(e,t){w[t]=[];var n=[];e.forEach(function(e){u(e)&&e.indexOf("*")>=0?n.push(i(e).replace(/\\\*/g,".*")):w[t].push(e)}),x[t]=new RegExp("^("+n.join("|")+")$")}));var S={};h(t.allowedClasses,function(e,t){w&&(d(w,t)||(w[t]=[]),w[t].push("class")),S[t]=e});var C,E={};h(t.transformTags,function(e,t){var n;"function"==typeof e?n=e:"string"==typeof e&&(n=v.simpleTransform(e)),"*"===t?C=n:E[t]=n});var T=0,O=[],A={},P={},j=!1,R=0,N=new r.Parser({onopentag:function(e,n){if(j)R++;else{var r=new _(e,n);O.push(r);var o,i=!1,u=!!r.text;d(E,e)&&(o=E[e](e,n),r.attribs=n=o.attribs,void 0!==o.text&&(r.innerText=o.text),e!==o.tagName&&(r.name=e=o.tagName,P[T]=o.tagName)),C&&(o=C(e,n),r.attribs=n=o.attribs,e!==o.tagName&&(r.name=e=o.tagName,P[T]=o.tagName)),t.allowedTags&&-1===t.allowedTags.indexOf(e)&&(i=!0,-1!==k.indexOf(e)&&(j=!0,R=1),A[T]=!0),T++,i||(b+="<"+e,(!w||d(w,e)||w["*"])&&h(n,function(n,o){if(g.test(o)){var i,u=!1;if(!w||d(w,e)&&-1!==w[e].indexOf(o)||w["*"]&&-1!==w["*"].indexOf(o)||d(x,e)&&x[e].test(o)||x["*"]&&x["*"].test(o))u=!0;else if(w&&w[e]){var v=!0,y=!1,_=void 0;try{for(var k,C=w[e][Symbol.iterator]();!(v=(k=C.next()).done);v=!0){var E=k.value;if(l(E)&&E.name&&E.name===o){u=!0;var T="";if(!0===E.multiple){var O=n.split(" "),A=!0,P=!1,j=void 0;try{for(var R,N=O[Symbol.iterator]();!(A=(R=N.next()).done);A=!0){var I=R.value;-1!==E.values.indexOf(I)&&(""===T?T=I:T+=" "+I)}}catch(e){P=!0,j=e}finally{try{!A&&N.return&&N.return()}finally{if(P)throw j}}}else E.values.indexOf(n)>=0&&(T=n);n=T}}}catch(e){y=!0,_=e}finally{try{!v&&C.return&&C.return()}finally{if(y)throw _}}}if(u){if(-1!==t.allowedSchemesAppliedToAttributes.indexOf(o)&&L(e,n))return void delete r.attribs[o];if("iframe"===e&&"src"===o){var D=!0;try{(i=p.parse(n,!1,!0))&&null===i.host&&null===i.protocol?D=d(t,"allowIframeRelativeUrls")?t.allowIframeRelativeUrls:!t.allowedIframeHostnames:t.allowedIframeHostnames&&(D=t.allowedIframeHostnames.find(function(e){return e===i.hostname}))}catch(e){D=!1}if(!D)return void delete r.attribs[o]}if("srcset"===o)try{if(h(i=c.parse(n),function(e){L("srcset",e.url)&&(e.evil=!0)}),!(i=m(i,function(e){return!e.evil})).length)return void delete r.attribs[o];n=c.stringify(m(i,function(e){return!e.evil})),r.attribs[o]=n}catch(e){return void delete r.attribs[o]}if("class"===o&&!(n=function(e,t){if(!t)return e;return(e=e.split(/\s+/)).filter(function(e){return-1!==t.indexOf(e)}).join(" ")}(n,S[e])).length)return void delete r.attribs[o];if("style"===o)try{if(0===(n=function(e){return e.nodes[0].nodes.reduce(function(e,t){return e.push(t.prop+":"+t.value+";"),e},[]).join("")}(function(e,t){if(!t)return e;var n,r=a(e),o=e.nodes[0];n=t[o.selector]&&t["*"]?s(a(t[o.selector]),t["*"],function(e,t){if(Array.isArray(e))return e.concat(t)}):t[o.selector]||t["*"];n&&(r.nodes[0].nodes=o.nodes.reduce(function(e){return function(t,n){if(e.hasOwnProperty(n.prop)){var r=e[n.prop].some(function(e){return e.test(n.value)});r&&t.push(n)}return t}}(n),[]));return r}(f.parse(e+" {"+n+"}"),t.allowedStyles))).length)return void delete r.attribs[o]}catch(e){return void delete r.attribs[o]}b+=" "+o,n.length&&(b+='="'+M(n,!0)+'"')}else delete r.attribs[o]}else delete r.attribs[o]}),-1!==t.selfClosing.indexOf(e)?b+=" />":(b+=">",!r.innerText||u||t.textFilter||(b+=r.innerText)))}},ontext:function(e){if(!j){var n,r=O[O.length-1];if(r&&(n=r.tag,e=void 0!==r.innerText?r.innerText:e),"script"===n||"style"===n)b+=e;else{var o=M(e,!1);t.textFilter?b+=t.textFilter(o):b+=o}if(O.length)O[O.length-1].text+=e}},onclosetag:function(e){if(j){if(--R)return;j=!1}var n=O.pop();if(n){if(j=!1,A[--T])return delete A[T],void n.updateParentNodeText();P[T]&&(e=P[T],delete P[T]),t.exclusiveFilter&&t.exclusiveFilter(n)?b=b.substr(0,n.tagPosition):(n.updateParentNodeText(),-1===t.selfClosing.indexOf(e)&&(b+="</"+e+">"))}}},t.parser);return N.write(e),N.end(),b;function M(e,n){return"string"!=typeof e&&(e+=""),t.parser.decodeEntities&&(e=e.replace(/&/g,"&amp;").replace(/</g,"&lt;").replace(/\>/g,"&gt;"),n&&(e=e.replace(/\"/g,"&quot;"))),e=e.replace(/&(?![a-zA-Z0-9#]{1,20};)/g,"&amp;").replace(/</g,"&lt;").replace(/\>/g,"&gt;"),n&&(e=e.replace(/\"/g,"&quot;")),e}function L(e,n){var r=(n=(n=n.replace(/[\x00-\x20]+/g,"")).replace(/<\!\-\-.*?\-\-\>/g,"")).match(/^([a-zA-Z]+)\:/);if(!r)return!!n.match(/^[\/\\]{2}/)&&!t.allowProtocolRelative;var o=r[1].toLowerCase();return d(t.allowedSchemesByTag,e)?-1===t.allowedSchemesByTag[e].indexOf(o):!t.allowedSchemes||-1===t.allowedSchemes.indexOf(o)}}var y={decodeEntities:!0};v.defaults={allowedTags:["h3","h4","h5","h6","blockquote","p","a","ul","ol","nl","li","b","i","strong","em","strike","code","hr","br","div","table","thead","caption","tbody","tr","th","td","pre","iframe"],allowedAttributes:{a:["href","name","target"],img:["src"]},selfClosing:["img","br","hr","area","base","basefont","input","link","meta"],allowedSchemes:["http","https","ftp","mailto"],allowedSchemesByTag:{},allowedSchemesAppliedToAttributes:["href","src","cite"],allowProtocolRelative:!0},v.simpleTransform=function(e,t,n){return n=void 0===n||n,t=t||{},function(r,o){var i;if(n)for(i in t)o[i]=t[i];else o=t;return{tagName:e,attribs:o}}}},function(e){e.exports={0:65533,128:8364,130:8218,131:402,132:8222,133:8230,134:8224,135:8225,136:710,137:8240,138:352,139:8249,140:338,142:381,145:8216,146:8217,147:8220,148:8221,149:8226,150:8211,151:8212,152:732,153:8482,154:353,155:8250,156:339,158:382,159:376}},function(e,t,n){"use strict";var r,o="object"==typeof Reflect?Reflect:null,i=o&&"function"==typeof o.apply?o.apply:function(e,t,n){return Function.prototype.apply.call(e,t,n)};r=o&&"function"==typeof o.ownKeys?o.ownKeys:Object.getOwnPropertySymbols?function(e){return Object.getOwnPropertyNames(e).concat(Object.getOwnPropertySymbols(e))}:function(e){return Object.getOwnPropertyNames(e)};var a=Number.isNaN||function(e){return e!=e};function s(){s.init.call(this)}e.exports=s,s.EventEmitter=s,s.prototype._events=void 0,s.prototype._eventsCount=0,s.prototype._maxListeners=void 0;var u=10;function l(e){return void 0===e._maxListeners?s.defaultMaxListeners:e._maxListeners}function c(e,t,n,r){var o,i,a,s;if("function"!=typeof n)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof n);if(void 0===(i=e._events)?(i=e._events=Object.create(null),e._eventsCount=0):(void 0!==i.newListener&&(e.emit("newListener",t,n.listener?n.listener:n),i=e._events),a=i[t]),void 0===a)a=i[t]=n,++e._eventsCount;else if("function"==typeof a?a=i[t]=r?[n,a]:[a,n]:r?a.unshift(n):a.push(n),(o=l(e))>0&&a.length>o&&!a.warned){a.warned=!0;var u=new Error("Possible EventEmitter memory leak detected. "+a.length+" "+String(t)+" listeners added. Use emitter.setMaxListeners() to increase limit");u.name="MaxListenersExceededWarning",u.emitter=e,u.type=t,u.count=a.length,s=u,console&&console.warn&&console.warn(s)}return e}function f(e,t,n){var r={fired:!1,wrapFn:void 0,target:e,type:t,listener:n},o=function(){for(var e=[],t=0;t<arguments.length;t++)e.push(arguments[t]);this.fired||(this.target.removeListener(this.type,this.wrapFn),this.fired=!0,i(this.listener,this.target,e))}.bind(r);return o.listener=n,r.wrapFn=o,o}function p(e,t,n){var r=e._events;if(void 0===r)return[];var o=r[t];return void 0===o?[]:"function"==typeof o?n?[o.listener||o]:[o]:n?function(e){for(var t=new Array(e.length),n=0;n<t.length;++n)t[n]=e[n].listener||e[n];return t}(o):d(o,o.length)}function h(e){var t=this._events;if(void 0!==t){var n=t[e];if("function"==typeof n)return 1;if(void 0!==n)return n.length}return 0}function d(e,t){for(var n=new Array(t),r=0;r<t;++r)n[r]=e[r];return n}Object.defineProperty(s,"defaultMaxListeners",{enumerable:!0,get:function(){return u},set:function(e){if("number"!=typeof e||e<0||a(e))throw new RangeError('The value of "defaultMaxListeners" is out of range. It must be a non-negative number. Received '+e+".");u=e}}),s.init=function(){void 0!==this._events&&this._events!==Object.getPrototypeOf(this)._events||(this._events=Object.create(null),this._eventsCount=0),this._maxListeners=this._maxListeners||void 0},s.prototype.setMaxListeners=function(e){if("number"!=typeof e||e<0||a(e))throw new RangeError('The value of "n" is out of range. It must be a non-negative number. Received '+e+".");return this._maxListeners=e,this},s.prototype.getMaxListeners=function(){return l(this)},s.prototype.emit=function(e){for(var t=[],n=1;n<arguments.length;n++)t.push(arguments[n]);var r="error"===e,o=this._events;if(void 0!==o)r=r&&void 0===o.error;else if(!r)return!1;if(r){var a;if(t.length>0&&(a=t[0]),a instanceof Error)throw a;var s=new Error("Unhandled error."+(a?" ("+a.message+")":""));throw s.context=a,s}var u=o[e];if(void 0===u)return!1;if("function"==typeof u)i(u,this,t);else{var l=u.length,c=d(u,l);for(n=0;n<l;++n)i(c[n],this,t)}return!0},s.prototype.addListener=function(e,t){return c(this,e,t,!1)},s.prototype.on=s.prototype.addListener,s.prototype.prependListener=function(e,t){return c(this,e,t,!0)},s.prototype.once=function(e,t){if("function"!=typeof t)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof t);return this.on(e,f(this,e,t)),this},s.prototype.prependOnceListener=function(e,t){if("function"!=typeof t)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof t);return this.prependListener(e,f(this,e,t)),this},s.prototype.removeListener=function(e,t){var n,r,o,i,a;if("function"!=typeof t)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof t);if(void 0===(r=this._events))return this;if(void 0===(n=r[e]))return this;if(n===t||n.listener===t)0==--this._eventsCount?this._events=Object.create(null):(delete r[e],r.removeListener&&this.emit("removeListener",e,n.listener||t));else if("function"!=typeof n){for(o=-1,i=n.length-1;i>=0;i--)if(n[i]===t||n[i].listener===t){a=n[i].listener,o=i;break}if(o<0)return this;0===o?n.shift():function(e,t){for(;t+1<e.length;t++)e[t]=e[t+1];e.pop()}(n,o),1===n.length&&(r[e]=n[0]),void 0!==r.removeListener&&this.emit("removeListener",e,a||t)}return this},s.prototype.off=s.prototype.removeListener,s.prototype.removeAllListeners=function(e){var t,n,r;if(void 0===(n=this._events))return this;if(void 0===n.removeListener)return 0===arguments.length?(this._events=Object.create(null),this._eventsCount=0):void 0!==n[e]&&(0==--this._eventsCount?this._events=Object.create(null):delete n[e]),this;if(0===arguments.length){var o,i=Object.keys(n);for(r=0;r<i.length;++r)"removeListener"!==(o=i[r])&&this.removeAllListeners(o);return this.removeAllListeners("removeListener"),this._events=Object.create(null),this._eventsCount=0,this}if("function"==typeof(t=n[e]))this.removeListener(e,t);else if(void 0!==t)for(r=t.length-1;r>=0;r--)this.removeListener(e,t[r]);return this},s.prototype.listeners=function(e){return p(this,e,!0)},s.prototype.rawListeners=function(e){return p(this,e,!1)},s.listenerCount=function(e,t){return"function"==typeof e.listenerCount?e.listenerCount(t):h.call(e,t)},s.prototype.listenerCount=h,s.prototype.eventNames=function(){return this._eventsCount>0?r(this._events):[]}},function(e,t,n){var r=n(34),o=/\s+/g,i=n(155),a=n(365);function s(e,t,n){"object"==typeof e?(n=t,t=e,e=null):"function"==typeof t&&(n=t,t=u),this._callback=e,this._options=t||u,this._elementCB=n,this.dom=[],this._done=!1,this._tagStack=[],this._parser=this._parser||null}var u={normalizeWhitespace:!1,withStartIndices:!1,withEndIndices:!1};s.prototype.onparserinit=function(e){this._parser=e},s.prototype.onreset=function(){s.call(this,this._callback,this._options,this._elementCB)},s.prototype.onend=function(){this._done||(this._done=!0,this._parser=null,this._handleCallback(null))},s.prototype._handleCallback=s.prototype.onerror=function(e){if("function"==typeof this._callback)this._callback(e,this.dom);else if(e)throw e},s.prototype.onclosetag=function(){var e=this._tagStack.pop();this._options.withEndIndices&&e&&(e.endIndex=this._parser.endIndex),this._elementCB&&this._elementCB(e)},s.prototype._createDomElement=function(e){if(!this._options.withDomLvl1)return e;var t;for(var n in t="tag"===e.type?Object.create(a):Object.create(i),e)e.hasOwnProperty(n)&&(t[n]=e[n]);return t},s.prototype._addDomElement=function(e){var t=this._tagStack[this._tagStack.length-1],n=t?t.children:this.dom,r=n[n.length-1];e.next=null,this._options.withStartIndices&&(e.startIndex=this._parser.startIndex),this._options.withEndIndices&&(e.endIndex=this._parser.endIndex),r?(e.prev=r,r.next=e):e.prev=null,n.push(e),e.parent=t||null},s.prototype.onopentag=function(e,t){var n={type:"script"===e?r.Script:"style"===e?r.Style:r.Tag,name:e,attribs:t,children:[]},o=this._createDomElement(n);this._addDomElement(o),this._tagStack.push(o)},s.prototype.ontext=function(e){var t,n=this._options.normalizeWhitespace||this._options.ignoreWhitespace;if(!this._tagStack.length&&this.dom.length&&(t=this.dom[this.dom.length-1]).type===r.Text)n?t.data=(t.data+e).replace(o," "):t.data+=e;else if(this._tagStack.length&&(t=this._tagStack[this._tagStack.length-1])&&(t=t.children[t.children.length-1])&&t.type===r.Text)n?t.data=(t.data+e).replace(o," "):t.data+=e;else{n&&(e=e.replace(o," "));var i=this._createDomElement({data:e,type:r.Text});this._addDomElement(i)}},s.prototype.oncomment=function(e){var t=this._tagStack[this._tagStack.length-1];if(t&&t.type===r.Comment)t.data+=e;else{var n={data:e,type:r.Comment},o=this._createDomElement(n);this._addDomElement(o),this._tagStack.push(o)}},s.prototype.oncdatastart=function(){var e={children:[{data:"",type:r.Text}],type:r.CDATA},t=this._createDomElement(e);this._addDomElement(t),this._tagStack.push(t)},s.prototype.oncommentend=s.prototype.oncdataend=function(){this._tagStack.pop()},s.prototype.onprocessinginstruction=function(e,t){var n=this._createDomElement({name:e,data:t,type:r.Directive});this._addDomElement(n)},e.exports=s},function(e,t,n){var r=n(155),o=e.exports=Object.create(r),i={tagName:"name"};Object.keys(i).forEach(function(e){var t=i[e];Object.defineProperty(o,e,{get:function(){return this[t]||null},set:function(e){return this[t]=e,e}})})},function(e,t,n){var r=n(33),o=r.DomHandler,i=r.DomUtils;function a(e,t){this.init(e,t)}function s(e,t){return i.getElementsByTagName(e,t,!0)}function u(e,t){return i.getElementsByTagName(e,t,!0,1)[0]}function l(e,t,n){return i.getText(i.getElementsByTagName(e,t,n,1)).trim()}function c(e,t,n,r,o){var i=l(n,r,o);i&&(e[t]=i)}n(55)(a,o),a.prototype.init=o;var f=function(e){return"rss"===e||"feed"===e||"rdf:RDF"===e};a.prototype.onend=function(){var e,t,n={},r=u(f,this.dom);r&&("feed"===r.name?(t=r.children,n.type="atom",c(n,"id","id",t),c(n,"title","title",t),(e=u("link",t))&&(e=e.attribs)&&(e=e.href)&&(n.link=e),c(n,"description","subtitle",t),(e=l("updated",t))&&(n.updated=new Date(e)),c(n,"author","email",t,!0),n.items=s("entry",t).map(function(e){var t,n={};return c(n,"id","id",e=e.children),c(n,"title","title",e),(t=u("link",e))&&(t=t.attribs)&&(t=t.href)&&(n.link=t),(t=l("summary",e)||l("content",e))&&(n.description=t),(t=l("updated",e))&&(n.pubDate=new Date(t)),n})):(t=u("channel",r.children).children,n.type=r.name.substr(0,3),n.id="",c(n,"title","title",t),c(n,"link","link",t),c(n,"description","description",t),(e=l("lastBuildDate",t))&&(n.updated=new Date(e)),c(n,"author","managingEditor",t,!0),n.items=s("item",r.children).map(function(e){var t,n={};return c(n,"id","guid",e=e.children),c(n,"title","title",e),c(n,"link","link",e),c(n,"description","description",e),(t=l("pubDate",e))&&(n.pubDate=new Date(t)),n}))),this.dom=n,o.prototype._handleCallback.call(this,r?null:Error("couldn't find root of feed"))},e.exports=a},function(e,t,n){e.exports=o;var r=n(156);function o(e){r.call(this,new i(this),e)}function i(e){this.scope=e}n(55)(o,r),o.prototype.readable=!0;var a=n(33).EVENTS;Object.keys(a).forEach(function(e){if(0===a[e])i.prototype["on"+e]=function(){this.scope.emit(e)};else if(1===a[e])i.prototype["on"+e]=function(t){this.scope.emit(e,t)};else{if(2!==a[e])throw Error("wrong number of arguments!");i.prototype["on"+e]=function(t,n){this.scope.emit(e,t,n)}}})},function(e,t){},function(e,t,n){"use strict";var r=n(370).Buffer,o=r.isEncoding||function(e){switch((e=""+e)&&e.toLowerCase()){case"hex":case"utf8":case"utf-8":case"ascii":case"binary":case"base64":case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":case"raw":return!0;default:return!1}};function i(e){var t;switch(this.encoding=function(e){var t=function(e){if(!e)return"utf8";for(var t;;)switch(e){case"utf8":case"utf-8":return"utf8";case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return"utf16le";case"latin1":case"binary":return"latin1";case"base64":case"ascii":case"hex":return e;default:if(t)return;e=(""+e).toLowerCase(),t=!0}}(e);if("string"!=typeof t&&(r.isEncoding===o||!o(e)))throw new Error("Unknown encoding: "+e);return t||e}(e),this.encoding){case"utf16le":this.text=u,this.end=l,t=4;break;case"utf8":this.fillLast=s,t=4;break;case"base64":this.text=c,this.end=f,t=3;break;default:return this.write=p,void(this.end=h)}this.lastNeed=0,this.lastTotal=0,this.lastChar=r.allocUnsafe(t)}function a(e){return e<=127?0:e>>5==6?2:e>>4==14?3:e>>3==30?4:e>>6==2?-1:-2}function s(e){var t=this.lastTotal-this.lastNeed,n=function(e,t,n){if(128!=(192&t[0]))return e.lastNeed=0,"�";if(e.lastNeed>1&&t.length>1){if(128!=(192&t[1]))return e.lastNeed=1,"�";if(e.lastNeed>2&&t.length>2&&128!=(192&t[2]))return e.lastNeed=2,"�"}}(this,e);return void 0!==n?n:this.lastNeed<=e.length?(e.copy(this.lastChar,t,0,this.lastNeed),this.lastChar.toString(this.encoding,0,this.lastTotal)):(e.copy(this.lastChar,t,0,e.length),void(this.lastNeed-=e.length))}function u(e,t){if((e.length-t)%2==0){var n=e.toString("utf16le",t);if(n){var r=n.charCodeAt(n.length-1);if(r>=55296&&r<=56319)return this.lastNeed=2,this.lastTotal=4,this.lastChar[0]=e[e.length-2],this.lastChar[1]=e[e.length-1],n.slice(0,-1)}return n}return this.lastNeed=1,this.lastTotal=2,this.lastChar[0]=e[e.length-1],e.toString("utf16le",t,e.length-1)}function l(e){var t=e&&e.length?this.write(e):"";if(this.lastNeed){var n=this.lastTotal-this.lastNeed;return t+this.lastChar.toString("utf16le",0,n)}return t}function c(e,t){var n=(e.length-t)%3;return 0===n?e.toString("base64",t):(this.lastNeed=3-n,this.lastTotal=3,1===n?this.lastChar[0]=e[e.length-1]:(this.lastChar[0]=e[e.length-2],this.lastChar[1]=e[e.length-1]),e.toString("base64",t,e.length-n))}function f(e){var t=e&&e.length?this.write(e):"";return this.lastNeed?t+this.lastChar.toString("base64",0,3-this.lastNeed):t}function p(e){return e.toString(this.encoding)}function h(e){return e&&e.length?this.write(e):""}t.StringDecoder=i,i.prototype.write=function(e){if(0===e.length)return"";var t,n;if(this.lastNeed){if(void 0===(t=this.fillLast(e)))return"";n=this.lastNeed,this.lastNeed=0}else n=0;return n<e.length?t?t+this.text(e,n):this.text(e,n):t||""},i.prototype.end=function(e){var t=e&&e.length?this.write(e):"";return this.lastNeed?t+"�":t},i.prototype.text=function(e,t){var n=function(e,t,n){var r=t.length-1;if(r<n)return 0;var o=a(t[r]);if(o>=0)return o>0&&(e.lastNeed=o-1),o;if(--r<n||-2===o)return 0;if((o=a(t[r]))>=0)return o>0&&(e.lastNeed=o-2),o;if(--r<n||-2===o)return 0;if((o=a(t[r]))>=0)return o>0&&(2===o?o=0:e.lastNeed=o-3),o;return 0}(this,e,t);if(!this.lastNeed)return e.toString("utf8",t);this.lastTotal=n;var r=e.length-(n-this.lastNeed);return e.copy(this.lastChar,0,r),e.toString("utf8",t,r)},i.prototype.fillLast=function(e){if(this.lastNeed<=e.length)return e.copy(this.lastChar,this.lastTotal-this.lastNeed,0,this.lastNeed),this.lastChar.toString(this.encoding,0,this.lastTotal);e.copy(this.lastChar,this.lastTotal-this.lastNeed,0,e.length),this.lastNeed-=e.length}},function(e,t,n){var r=n(56),o=r.Buffer;function i(e,t){for(var n in e)t[n]=e[n]}function a(e,t,n){return o(e,t,n)}o.from&&o.alloc&&o.allocUnsafe&&o.allocUnsafeSlow?e.exports=r:(i(r,t),t.Buffer=a),i(o,a),a.from=function(e,t,n){if("number"==typeof e)throw new TypeError("Argument must not be a number");return o(e,t,n)},a.alloc=function(e,t,n){if("number"!=typeof e)throw new TypeError("Argument must be a number");var r=o(e);return void 0!==t?"string"==typeof n?r.fill(t,n):r.fill(t):r.fill(0),r},a.allocUnsafe=function(e){if("number"!=typeof e)throw new TypeError("Argument must be a number");return o(e)},a.allocUnsafeSlow=function(e){if("number"!=typeof e)throw new TypeError("Argument must be a number");return r.SlowBuffer(e)}},function(e,t,n){"use strict";t.byteLength=function(e){var t=l(e),n=t[0],r=t[1];return 3*(n+r)/4-r},t.toByteArray=function(e){for(var t,n=l(e),r=n[0],a=n[1],s=new i(function(e,t,n){return 3*(t+n)/4-n}(0,r,a)),u=0,c=a>0?r-4:r,f=0;f<c;f+=4)t=o[e.charCodeAt(f)]<<18|o[e.charCodeAt(f+1)]<<12|o[e.charCodeAt(f+2)]<<6|o[e.charCodeAt(f+3)],s[u++]=t>>16&255,s[u++]=t>>8&255,s[u++]=255&t;2===a&&(t=o[e.charCodeAt(f)]<<2|o[e.charCodeAt(f+1)]>>4,s[u++]=255&t);1===a&&(t=o[e.charCodeAt(f)]<<10|o[e.charCodeAt(f+1)]<<4|o[e.charCodeAt(f+2)]>>2,s[u++]=t>>8&255,s[u++]=255&t);return s},t.fromByteArray=function(e){for(var t,n=e.length,o=n%3,i=[],a=0,s=n-o;a<s;a+=16383)i.push(c(e,a,a+16383>s?s:a+16383));1===o?(t=e[n-1],i.push(r[t>>2]+r[t<<4&63]+"==")):2===o&&(t=(e[n-2]<<8)+e[n-1],i.push(r[t>>10]+r[t>>4&63]+r[t<<2&63]+"="));return i.join("")};for(var r=[],o=[],i="undefined"!=typeof Uint8Array?Uint8Array:Array,a="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",s=0,u=a.length;s<u;++s)r[s]=a[s],o[a.charCodeAt(s)]=s;function l(e){var t=e.length;if(t%4>0)throw new Error("Invalid string. Length must be a multiple of 4");var n=e.indexOf("=");return-1===n&&(n=t),[n,n===t?0:4-n%4]}function c(e,t,n){for(var o,i,a=[],s=t;s<n;s+=3)o=(e[s]<<16&16711680)+(e[s+1]<<8&65280)+(255&e[s+2]),a.push(r[(i=o)>>18&63]+r[i>>12&63]+r[i>>6&63]+r[63&i]);return a.join("")}o["-".charCodeAt(0)]=62,o["_".charCodeAt(0)]=63},function(e,t){t.read=function(e,t,n,r,o){var i,a,s=8*o-r-1,u=(1<<s)-1,l=u>>1,c=-7,f=n?o-1:0,p=n?-1:1,h=e[t+f];for(f+=p,i=h&(1<<-c)-1,h>>=-c,c+=s;c>0;i=256*i+e[t+f],f+=p,c-=8);for(a=i&(1<<-c)-1,i>>=-c,c+=r;c>0;a=256*a+e[t+f],f+=p,c-=8);if(0===i)i=1-l;else{if(i===u)return a?NaN:1/0*(h?-1:1);a+=Math.pow(2,r),i-=l}return(h?-1:1)*a*Math.pow(2,i-r)},t.write=function(e,t,n,r,o,i){var a,s,u,l=8*i-o-1,c=(1<<l)-1,f=c>>1,p=23===o?Math.pow(2,-24)-Math.pow(2,-77):0,h=r?0:i-1,d=r?1:-1,m=t<0||0===t&&1/t<0?1:0;for(t=Math.abs(t),isNaN(t)||t===1/0?(s=isNaN(t)?1:0,a=c):(a=Math.floor(Math.log(t)/Math.LN2),t*(u=Math.pow(2,-a))<1&&(a--,u*=2),(t+=a+f>=1?p/u:p*Math.pow(2,1-f))*u>=2&&(a++,u/=2),a+f>=c?(s=0,a=c):a+f>=1?(s=(t*u-1)*Math.pow(2,o),a+=f):(s=t*Math.pow(2,f-1)*Math.pow(2,o),a=0));o>=8;e[n+h]=255&s,h+=d,s/=256,o-=8);for(a=a<<o|s,l+=o;l>0;e[n+h]=255&a,h+=d,a/=256,l-=8);e[n+h-d]|=128*m}},function(e,t){var n={}.toString;e.exports=Array.isArray||function(e){return"[object Array]"==n.call(e)}},function(e,t,n){function r(e){this._cbs=e||{}}e.exports=r;var o=n(33).EVENTS;Object.keys(o).forEach(function(e){if(0===o[e])e="on"+e,r.prototype[e]=function(){this._cbs[e]&&this._cbs[e]()};else if(1===o[e])e="on"+e,r.prototype[e]=function(t){this._cbs[e]&&this._cbs[e](t)};else{if(2!==o[e])throw Error("wrong number of arguments");e="on"+e,r.prototype[e]=function(t,n){this._cbs[e]&&this._cbs[e](t,n)}}})},function(e,t,n){var r=e.exports;[n(376),n(382),n(383),n(384),n(385),n(386)].forEach(function(e){Object.keys(e).forEach(function(t){r[t]=e[t].bind(r)})})},function(e,t,n){var r=n(34),o=n(377),i=r.isTag;e.exports={getInnerHTML:function(e,t){return e.children?e.children.map(function(e){return o(e,t)}).join(""):""},getOuterHTML:o,getText:function e(t){if(Array.isArray(t))return t.map(e).join("");if(i(t))return"br"===t.name?"\n":e(t.children);if(t.type===r.CDATA)return e(t.children);if(t.type===r.Text)return t.data;return""}}},function(e,t,n){var r=n(378),o=n(379),i={__proto__:null,allowfullscreen:!0,async:!0,autofocus:!0,autoplay:!0,checked:!0,controls:!0,default:!0,defer:!0,disabled:!0,hidden:!0,ismap:!0,loop:!0,multiple:!0,muted:!0,open:!0,readonly:!0,required:!0,reversed:!0,scoped:!0,seamless:!0,selected:!0,typemustmatch:!0},a={__proto__:null,style:!0,script:!0,xmp:!0,iframe:!0,noembed:!0,noframes:!0,plaintext:!0,noscript:!0};var s={__proto__:null,area:!0,base:!0,basefont:!0,br:!0,col:!0,command:!0,embed:!0,frame:!0,hr:!0,img:!0,input:!0,isindex:!0,keygen:!0,link:!0,meta:!0,param:!0,source:!0,track:!0,wbr:!0},u=e.exports=function(e,t){Array.isArray(e)||e.cheerio||(e=[e]),t=t||{};for(var n="",o=0;o<e.length;o++){var i=e[o];"root"===i.type?n+=u(i.children,t):r.isTag(i)?n+=l(i,t):i.type===r.Directive?n+=c(i):i.type===r.Comment?n+=h(i):i.type===r.CDATA?n+=p(i):n+=f(i,t)}return n};function l(e,t){"svg"===e.name&&(t={decodeEntities:t.decodeEntities,xmlMode:!0});var n="<"+e.name,r=function(e,t){if(e){var n,r="";for(var a in e)r&&(r+=" "),!(n=e[a])&&i[a]?r+=a:r+=a+'="'+(t.decodeEntities?o.encodeXML(n):n)+'"';return r}}(e.attribs,t);return r&&(n+=" "+r),!t.xmlMode||e.children&&0!==e.children.length?(n+=">",e.children&&(n+=u(e.children,t)),s[e.name]&&!t.xmlMode||(n+="</"+e.name+">")):n+="/>",n}function c(e){return"<"+e.data+">"}function f(e,t){var n=e.data||"";return!t.decodeEntities||e.parent&&e.parent.name in a||(n=o.encodeXML(n)),n}function p(e){return"<![CDATA["+e.children[0].data+"]]>"}function h(e){return"\x3c!--"+e.data+"--\x3e"}},function(e,t){e.exports={Text:"text",Directive:"directive",Comment:"comment",Script:"script",Style:"style",Tag:"tag",CDATA:"cdata",isTag:function(e){return"tag"===e.type||"script"===e.type||"style"===e.type}}},function(e,t,n){var r=n(380),o=n(381);t.decode=function(e,t){return(!t||t<=0?o.XML:o.HTML)(e)},t.decodeStrict=function(e,t){return(!t||t<=0?o.XML:o.HTMLStrict)(e)},t.encode=function(e,t){return(!t||t<=0?r.XML:r.HTML)(e)},t.encodeXML=r.XML,t.encodeHTML4=t.encodeHTML5=t.encodeHTML=r.HTML,t.decodeXML=t.decodeXMLStrict=o.XML,t.decodeHTML4=t.decodeHTML5=t.decodeHTML=o.HTML,t.decodeHTML4Strict=t.decodeHTML5Strict=t.decodeHTMLStrict=o.HTMLStrict,t.escape=r.escape},function(e,t,n){var r=s(n(94)),o=u(r);t.XML=h(r,o);var i=s(n(93)),a=u(i);function s(e){return Object.keys(e).sort().reduce(function(t,n){return t[e[n]]="&"+n+";",t},{})}function u(e){var t=[],n=[];return Object.keys(e).forEach(function(e){1===e.length?t.push("\\"+e):n.push(e)}),n.unshift("["+t.join("")+"]"),new RegExp(n.join("|"),"g")}t.HTML=h(i,a);var l=/[^\0-\x7F]/g,c=/[\uD800-\uDBFF][\uDC00-\uDFFF]/g;function f(e){return"&#x"+e.charCodeAt(0).toString(16).toUpperCase()+";"}function p(e){return"&#x"+(1024*(e.charCodeAt(0)-55296)+e.charCodeAt(1)-56320+65536).toString(16).toUpperCase()+";"}function h(e,t){function n(t){return e[t]}return function(e){return e.replace(t,n).replace(c,p).replace(l,f)}}var d=u(r);t.escape=function(e){return e.replace(d,f).replace(c,p).replace(l,f)}},function(e,t,n){var r=n(93),o=n(154),i=n(94),a=n(153),s=l(i),u=l(r);function l(e){var t=Object.keys(e).join("|"),n=p(e),r=new RegExp("&(?:"+(t+="|#[xX][\\da-fA-F]+|#\\d+")+");","g");return function(e){return String(e).replace(r,n)}}var c=function(){for(var e=Object.keys(o).sort(f),t=Object.keys(r).sort(f),n=0,i=0;n<t.length;n++)e[i]===t[n]?(t[n]+=";?",i++):t[n]+=";";var a=new RegExp("&(?:"+t.join("|")+"|#[xX][\\da-fA-F]+;?|#\\d+;?)","g"),s=p(r);function u(e){return";"!==e.substr(-1)&&(e+=";"),s(e)}return function(e){return String(e).replace(a,u)}}();function f(e,t){return e<t?1:-1}function p(e){return function(t){return"#"===t.charAt(1)?"X"===t.charAt(2)||"x"===t.charAt(2)?a(parseInt(t.substr(3),16)):a(parseInt(t.substr(2),10)):e[t.slice(1,-1)]}}e.exports={XML:s,HTML:c,HTMLStrict:u}},function(e,t){var n=t.getChildren=function(e){return e.children},r=t.getParent=function(e){return e.parent};t.getSiblings=function(e){var t=r(e);return t?n(t):[e]},t.getAttributeValue=function(e,t){return e.attribs&&e.attribs[t]},t.hasAttrib=function(e,t){return!!e.attribs&&hasOwnProperty.call(e.attribs,t)},t.getName=function(e){return e.name}},function(e,t){t.removeElement=function(e){if(e.prev&&(e.prev.next=e.next),e.next&&(e.next.prev=e.prev),e.parent){var t=e.parent.children;t.splice(t.lastIndexOf(e),1)}},t.replaceElement=function(e,t){var n=t.prev=e.prev;n&&(n.next=t);var r=t.next=e.next;r&&(r.prev=t);var o=t.parent=e.parent;if(o){var i=o.children;i[i.lastIndexOf(e)]=t}},t.appendChild=function(e,t){if(t.parent=e,1!==e.children.push(t)){var n=e.children[e.children.length-2];n.next=t,t.prev=n,t.next=null}},t.append=function(e,t){var n=e.parent,r=e.next;if(t.next=r,t.prev=e,e.next=t,t.parent=n,r){if(r.prev=t,n){var o=n.children;o.splice(o.lastIndexOf(r),0,t)}}else n&&n.children.push(t)},t.prepend=function(e,t){var n=e.parent;if(n){var r=n.children;r.splice(r.lastIndexOf(e),0,t)}e.prev&&(e.prev.next=t),t.parent=n,t.prev=e.prev,t.next=e,e.prev=t}},function(e,t,n){var r=n(34).isTag;function o(e,t,n,r){for(var i,a=[],s=0,u=t.length;s<u&&!(e(t[s])&&(a.push(t[s]),--r<=0))&&(i=t[s].children,!(n&&i&&i.length>0&&(i=o(e,i,n,r),a=a.concat(i),(r-=i.length)<=0)));s++);return a}e.exports={filter:function(e,t,n,r){Array.isArray(t)||(t=[t]);"number"==typeof r&&isFinite(r)||(r=1/0);return o(e,t,!1!==n,r)},find:o,findOneChild:function(e,t){for(var n=0,r=t.length;n<r;n++)if(e(t[n]))return t[n];return null},findOne:function e(t,n){var o=null;for(var i=0,a=n.length;i<a&&!o;i++)r(n[i])&&(t(n[i])?o=n[i]:n[i].children.length>0&&(o=e(t,n[i].children)));return o},existsOne:function e(t,n){for(var o=0,i=n.length;o<i;o++)if(r(n[o])&&(t(n[o])||n[o].children.length>0&&e(t,n[o].children)))return!0;return!1},findAll:function(e,t){var n=[],o=t.slice();for(;o.length;){var i=o.shift();r(i)&&(i.children&&i.children.length>0&&o.unshift.apply(o,i.children),e(i)&&n.push(i))}return n}}},function(e,t,n){var r=n(34),o=t.isTag=r.isTag;t.testElement=function(e,t){for(var n in e)if(e.hasOwnProperty(n)){if("tag_name"===n){if(!o(t)||!e.tag_name(t.name))return!1}else if("tag_type"===n){if(!e.tag_type(t.type))return!1}else if("tag_contains"===n){if(o(t)||!e.tag_contains(t.data))return!1}else if(!t.attribs||!e[n](t.attribs[n]))return!1}else;return!0};var i={tag_name:function(e){return"function"==typeof e?function(t){return o(t)&&e(t.name)}:"*"===e?o:function(t){return o(t)&&t.name===e}},tag_type:function(e){return"function"==typeof e?function(t){return e(t.type)}:function(t){return t.type===e}},tag_contains:function(e){return"function"==typeof e?function(t){return!o(t)&&e(t.data)}:function(t){return!o(t)&&t.data===e}}};function a(e,t){return"function"==typeof t?function(n){return n.attribs&&t(n.attribs[e])}:function(n){return n.attribs&&n.attribs[e]===t}}function s(e,t){return function(n){return e(n)||t(n)}}t.getElements=function(e,t,n,r){var o=Object.keys(e).map(function(t){var n=e[t];return t in i?i[t](n):a(t,n)});return 0===o.length?[]:this.filter(o.reduce(s),t,n,r)},t.getElementById=function(e,t,n){return Array.isArray(t)||(t=[t]),this.findOne(a("id",e),t,!1!==n)},t.getElementsByTagName=function(e,t,n,r){return this.filter(i.tag_name(e),t,n,r)},t.getElementsByTagType=function(e,t,n,r){return this.filter(i.tag_type(e),t,n,r)}},function(e,t){t.removeSubsets=function(e){for(var t,n,r,o=e.length;--o>-1;){for(t=n=e[o],e[o]=null,r=!0;n;){if(e.indexOf(n)>-1){r=!1,e.splice(o,1);break}n=n.parent}r&&(e[o]=t)}return e};var n=1,r=2,o=4,i=8,a=16,s=t.compareDocumentPosition=function(e,t){var s,u,l,c,f,p,h=[],d=[];if(e===t)return 0;for(s=e;s;)h.unshift(s),s=s.parent;for(s=t;s;)d.unshift(s),s=s.parent;for(p=0;h[p]===d[p];)p++;return 0===p?n:(l=(u=h[p-1]).children,c=h[p],f=d[p],l.indexOf(c)>l.indexOf(f)?u===t?o|a:o:u===e?r|i:r)};t.uniqueSort=function(e){var t,n,i=e.length;for(e=e.slice();--i>-1;)t=e[i],(n=e.indexOf(t))>-1&&n<i&&e.splice(i,1);return e.sort(function(e,t){var n=s(e,t);return n&r?-1:n&o?1:0}),e}},function(e,t,n){function r(e){this._cbs=e||{},this.events=[]}e.exports=r;var o=n(33).EVENTS;Object.keys(o).forEach(function(e){if(0===o[e])e="on"+e,r.prototype[e]=function(){this.events.push([e]),this._cbs[e]&&this._cbs[e]()};else if(1===o[e])e="on"+e,r.prototype[e]=function(t){this.events.push([e,t]),this._cbs[e]&&this._cbs[e](t)};else{if(2!==o[e])throw Error("wrong number of arguments");e="on"+e,r.prototype[e]=function(t,n){this.events.push([e,t,n]),this._cbs[e]&&this._cbs[e](t,n)}}}),r.prototype.onreset=function(){this.events=[],this._cbs.onreset&&this._cbs.onreset()},r.prototype.restart=function(){this._cbs.onreset&&this._cbs.onreset();for(var e=0,t=this.events.length;e<t;e++)if(this._cbs[this.events[e][0]]){var n=this.events[e].length;1===n?this._cbs[this.events[e][0]]():2===n?this._cbs[this.events[e][0]](this.events[e][1]):this._cbs[this.events[e][0]](this.events[e][1],this.events[e][2])}}},function(e,t){e.exports=function(){for(var e={},t=0;t<arguments.length;t++){var r=arguments[t];for(var o in r)n.call(r,o)&&(e[o]=r[o])}return e};var n=Object.prototype.hasOwnProperty},function(e,t,n){(function(t){var n=1/0,r="[object Symbol]",o=/[\\^$.*+?()[\]{}|]/g,i=RegExp(o.source),a="object"==typeof t&&t&&t.Object===Object&&t,s="object"==typeof self&&self&&self.Object===Object&&self,u=a||s||Function("return this")(),l=Object.prototype.toString,c=u.Symbol,f=c?c.prototype:void 0,p=f?f.toString:void 0;function h(e){if("string"==typeof e)return e;if(function(e){return"symbol"==typeof e||function(e){return!!e&&"object"==typeof e}(e)&&l.call(e)==r}(e))return p?p.call(e):"";var t=e+"";return"0"==t&&1/e==-n?"-0":t}e.exports=function(e){var t;return(e=null==(t=e)?"":h(t))&&i.test(e)?e.replace(o,"\\$&"):e}}).call(this,n(8))},function(e,t,n){(function(e,n){var r=200,o="__lodash_hash_undefined__",i=9007199254740991,a="[object Arguments]",s="[object Boolean]",u="[object Date]",l="[object Function]",c="[object GeneratorFunction]",f="[object Map]",p="[object Number]",h="[object Object]",d="[object RegExp]",m="[object Set]",g="[object String]",v="[object Symbol]",y="[object ArrayBuffer]",b="[object DataView]",_="[object Float32Array]",w="[object Float64Array]",x="[object Int8Array]",k="[object Int16Array]",S="[object Int32Array]",C="[object Uint8Array]",E="[object Uint8ClampedArray]",T="[object Uint16Array]",O="[object Uint32Array]",A=/\w*$/,P=/^\[object .+?Constructor\]$/,j=/^(?:0|[1-9]\d*)$/,R={};R[a]=R["[object Array]"]=R[y]=R[b]=R[s]=R[u]=R[_]=R[w]=R[x]=R[k]=R[S]=R[f]=R[p]=R[h]=R[d]=R[m]=R[g]=R[v]=R[C]=R[E]=R[T]=R[O]=!0,R["[object Error]"]=R[l]=R["[object WeakMap]"]=!1;var N="object"==typeof e&&e&&e.Object===Object&&e,M="object"==typeof self&&self&&self.Object===Object&&self,L=N||M||Function("return this")(),I=t&&!t.nodeType&&t,D=I&&"object"==typeof n&&n&&!n.nodeType&&n,F=D&&D.exports===I;function U(e,t){return e.set(t[0],t[1]),e}function q(e,t){return e.add(t),e}function B(e,t,n,r){var o=-1,i=e?e.length:0;for(r&&i&&(n=e[++o]);++o<i;)n=t(n,e[o],o,e);return n}function V(e){var t=!1;if(null!=e&&"function"!=typeof e.toString)try{t=!!(e+"")}catch(e){}return t}function z(e){var t=-1,n=Array(e.size);return e.forEach(function(e,r){n[++t]=[r,e]}),n}function H(e,t){return function(n){return e(t(n))}}function W(e){var t=-1,n=Array(e.size);return e.forEach(function(e){n[++t]=e}),n}var $,G=Array.prototype,Y=Function.prototype,Q=Object.prototype,X=L["__core-js_shared__"],K=($=/[^.]+$/.exec(X&&X.keys&&X.keys.IE_PROTO||""))?"Symbol(src)_1."+$:"",J=Y.toString,Z=Q.hasOwnProperty,ee=Q.toString,te=RegExp("^"+J.call(Z).replace(/[\\^$.*+?()[\]{}|]/g,"\\$&").replace(/hasOwnProperty|(function).*?(?=\\\()| for .+?(?=\\\])/g,"$1.*?")+"$"),ne=F?L.Buffer:void 0,re=L.Symbol,oe=L.Uint8Array,ie=H(Object.getPrototypeOf,Object),ae=Object.create,se=Q.propertyIsEnumerable,ue=G.splice,le=Object.getOwnPropertySymbols,ce=ne?ne.isBuffer:void 0,fe=H(Object.keys,Object),pe=De(L,"DataView"),he=De(L,"Map"),de=De(L,"Promise"),me=De(L,"Set"),ge=De(L,"WeakMap"),ve=De(Object,"create"),ye=Ve(pe),be=Ve(he),_e=Ve(de),we=Ve(me),xe=Ve(ge),ke=re?re.prototype:void 0,Se=ke?ke.valueOf:void 0;function Ce(e){var t=-1,n=e?e.length:0;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function Ee(e){var t=-1,n=e?e.length:0;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function Te(e){var t=-1,n=e?e.length:0;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function Oe(e){this.__data__=new Ee(e)}function Ae(e,t){var n=He(e)||function(e){return function(e){return function(e){return!!e&&"object"==typeof e}(e)&&We(e)}(e)&&Z.call(e,"callee")&&(!se.call(e,"callee")||ee.call(e)==a)}(e)?function(e,t){for(var n=-1,r=Array(e);++n<e;)r[n]=t(n);return r}(e.length,String):[],r=n.length,o=!!r;for(var i in e)!t&&!Z.call(e,i)||o&&("length"==i||qe(i,r))||n.push(i);return n}function Pe(e,t,n){var r=e[t];Z.call(e,t)&&ze(r,n)&&(void 0!==n||t in e)||(e[t]=n)}function je(e,t){for(var n=e.length;n--;)if(ze(e[n][0],t))return n;return-1}function Re(e,t,n,r,o,i,P){var j;if(r&&(j=i?r(e,o,i,P):r(e)),void 0!==j)return j;if(!Ye(e))return e;var N=He(e);if(N){if(j=function(e){var t=e.length,n=e.constructor(t);t&&"string"==typeof e[0]&&Z.call(e,"index")&&(n.index=e.index,n.input=e.input);return n}(e),!t)return function(e,t){var n=-1,r=e.length;t||(t=Array(r));for(;++n<r;)t[n]=e[n];return t}(e,j)}else{var M=Ue(e),L=M==l||M==c;if($e(e))return function(e,t){if(t)return e.slice();var n=new e.constructor(e.length);return e.copy(n),n}(e,t);if(M==h||M==a||L&&!i){if(V(e))return i?e:{};if(j=function(e){return"function"!=typeof e.constructor||Be(e)?{}:(t=ie(e),Ye(t)?ae(t):{});var t}(L?{}:e),!t)return function(e,t){return Le(e,Fe(e),t)}(e,function(e,t){return e&&Le(t,Qe(t),e)}(j,e))}else{if(!R[M])return i?e:{};j=function(e,t,n,r){var o=e.constructor;switch(t){case y:return Me(e);case s:case u:return new o(+e);case b:return function(e,t){var n=t?Me(e.buffer):e.buffer;return new e.constructor(n,e.byteOffset,e.byteLength)}(e,r);case _:case w:case x:case k:case S:case C:case E:case T:case O:return function(e,t){var n=t?Me(e.buffer):e.buffer;return new e.constructor(n,e.byteOffset,e.length)}(e,r);case f:return function(e,t,n){return B(t?n(z(e),!0):z(e),U,new e.constructor)}(e,r,n);case p:case g:return new o(e);case d:return(l=new(a=e).constructor(a.source,A.exec(a))).lastIndex=a.lastIndex,l;case m:return function(e,t,n){return B(t?n(W(e),!0):W(e),q,new e.constructor)}(e,r,n);case v:return i=e,Se?Object(Se.call(i)):{}}var i;var a,l}(e,M,Re,t)}}P||(P=new Oe);var I=P.get(e);if(I)return I;if(P.set(e,j),!N)var D=n?function(e){return function(e,t,n){var r=t(e);return He(e)?r:function(e,t){for(var n=-1,r=t.length,o=e.length;++n<r;)e[o+n]=t[n];return e}(r,n(e))}(e,Qe,Fe)}(e):Qe(e);return function(e,t){for(var n=-1,r=e?e.length:0;++n<r&&!1!==t(e[n],n,e););}(D||e,function(o,i){D&&(o=e[i=o]),Pe(j,i,Re(o,t,n,r,i,e,P))}),j}function Ne(e){return!(!Ye(e)||(t=e,K&&K in t))&&(Ge(e)||V(e)?te:P).test(Ve(e));var t}function Me(e){var t=new e.constructor(e.byteLength);return new oe(t).set(new oe(e)),t}function Le(e,t,n,r){n||(n={});for(var o=-1,i=t.length;++o<i;){var a=t[o],s=r?r(n[a],e[a],a,n,e):void 0;Pe(n,a,void 0===s?e[a]:s)}return n}function Ie(e,t){var n,r,o=e.__data__;return("string"==(r=typeof(n=t))||"number"==r||"symbol"==r||"boolean"==r?"__proto__"!==n:null===n)?o["string"==typeof t?"string":"hash"]:o.map}function De(e,t){var n=function(e,t){return null==e?void 0:e[t]}(e,t);return Ne(n)?n:void 0}Ce.prototype.clear=function(){this.__data__=ve?ve(null):{}},Ce.prototype.delete=function(e){return this.has(e)&&delete this.__data__[e]},Ce.prototype.get=function(e){var t=this.__data__;if(ve){var n=t[e];return n===o?void 0:n}return Z.call(t,e)?t[e]:void 0},Ce.prototype.has=function(e){var t=this.__data__;return ve?void 0!==t[e]:Z.call(t,e)},Ce.prototype.set=function(e,t){return this.__data__[e]=ve&&void 0===t?o:t,this},Ee.prototype.clear=function(){this.__data__=[]},Ee.prototype.delete=function(e){var t=this.__data__,n=je(t,e);return!(n<0||(n==t.length-1?t.pop():ue.call(t,n,1),0))},Ee.prototype.get=function(e){var t=this.__data__,n=je(t,e);return n<0?void 0:t[n][1]},Ee.prototype.has=function(e){return je(this.__data__,e)>-1},Ee.prototype.set=function(e,t){var n=this.__data__,r=je(n,e);return r<0?n.push([e,t]):n[r][1]=t,this},Te.prototype.clear=function(){this.__data__={hash:new Ce,map:new(he||Ee),string:new Ce}},Te.prototype.delete=function(e){return Ie(this,e).delete(e)},Te.prototype.get=function(e){return Ie(this,e).get(e)},Te.prototype.has=function(e){return Ie(this,e).has(e)},Te.prototype.set=function(e,t){return Ie(this,e).set(e,t),this},Oe.prototype.clear=function(){this.__data__=new Ee},Oe.prototype.delete=function(e){return this.__data__.delete(e)},Oe.prototype.get=function(e){return this.__data__.get(e)},Oe.prototype.has=function(e){return this.__data__.has(e)},Oe.prototype.set=function(e,t){var n=this.__data__;if(n instanceof Ee){var o=n.__data__;if(!he||o.length<r-1)return o.push([e,t]),this;n=this.__data__=new Te(o)}return n.set(e,t),this};var Fe=le?H(le,Object):function(){return[]},Ue=function(e){return ee.call(e)};function qe(e,t){return!!(t=null==t?i:t)&&("number"==typeof e||j.test(e))&&e>-1&&e%1==0&&e<t}function Be(e){var t=e&&e.constructor;return e===("function"==typeof t&&t.prototype||Q)}function Ve(e){if(null!=e){try{return J.call(e)}catch(e){}try{return e+""}catch(e){}}return""}function ze(e,t){return e===t||e!=e&&t!=t}(pe&&Ue(new pe(new ArrayBuffer(1)))!=b||he&&Ue(new he)!=f||de&&"[object Promise]"!=Ue(de.resolve())||me&&Ue(new me)!=m||ge&&"[object WeakMap]"!=Ue(new ge))&&(Ue=function(e){var t=ee.call(e),n=t==h?e.constructor:void 0,r=n?Ve(n):void 0;if(r)switch(r){case ye:return b;case be:return f;case _e:return"[object Promise]";case we:return m;case xe:return"[object WeakMap]"}return t});var He=Array.isArray;function We(e){return null!=e&&function(e){return"number"==typeof e&&e>-1&&e%1==0&&e<=i}(e.length)&&!Ge(e)}var $e=ce||function(){return!1};function Ge(e){var t=Ye(e)?ee.call(e):"";return t==l||t==c}function Ye(e){var t=typeof e;return!!e&&("object"==t||"function"==t)}function Qe(e){return We(e)?Ae(e):function(e){if(!Be(e))return fe(e);var t=[];for(var n in Object(e))Z.call(e,n)&&"constructor"!=n&&t.push(n);return t}(e)}n.exports=function(e){return Re(e,!0,!0)}}).call(this,n(8),n(26)(e))},function(e,t,n){(function(e,n){var r=200,o="__lodash_hash_undefined__",i=800,a=16,s=9007199254740991,u="[object Arguments]",l="[object AsyncFunction]",c="[object Function]",f="[object GeneratorFunction]",p="[object Null]",h="[object Object]",d="[object Proxy]",m="[object Undefined]",g=/^\[object .+?Constructor\]$/,v=/^(?:0|[1-9]\d*)$/,y={};y["[object Float32Array]"]=y["[object Float64Array]"]=y["[object Int8Array]"]=y["[object Int16Array]"]=y["[object Int32Array]"]=y["[object Uint8Array]"]=y["[object Uint8ClampedArray]"]=y["[object Uint16Array]"]=y["[object Uint32Array]"]=!0,y[u]=y["[object Array]"]=y["[object ArrayBuffer]"]=y["[object Boolean]"]=y["[object DataView]"]=y["[object Date]"]=y["[object Error]"]=y[c]=y["[object Map]"]=y["[object Number]"]=y[h]=y["[object RegExp]"]=y["[object Set]"]=y["[object String]"]=y["[object WeakMap]"]=!1;var b="object"==typeof e&&e&&e.Object===Object&&e,_="object"==typeof self&&self&&self.Object===Object&&self,w=b||_||Function("return this")(),x=t&&!t.nodeType&&t,k=x&&"object"==typeof n&&n&&!n.nodeType&&n,S=k&&k.exports===x,C=S&&b.process,E=function(){try{var e=k&&k.require&&k.require("util").types;return e||C&&C.binding&&C.binding("util")}catch(e){}}(),T=E&&E.isTypedArray;var O,A,P,j=Array.prototype,R=Function.prototype,N=Object.prototype,M=w["__core-js_shared__"],L=R.toString,I=N.hasOwnProperty,D=(O=/[^.]+$/.exec(M&&M.keys&&M.keys.IE_PROTO||""))?"Symbol(src)_1."+O:"",F=N.toString,U=L.call(Object),q=RegExp("^"+L.call(I).replace(/[\\^$.*+?()[\]{}|]/g,"\\$&").replace(/hasOwnProperty|(function).*?(?=\\\()| for .+?(?=\\\])/g,"$1.*?")+"$"),B=S?w.Buffer:void 0,V=w.Symbol,z=w.Uint8Array,H=B?B.allocUnsafe:void 0,W=(A=Object.getPrototypeOf,P=Object,function(e){return A(P(e))}),$=Object.create,G=N.propertyIsEnumerable,Y=j.splice,Q=V?V.toStringTag:void 0,X=function(){try{var e=we(Object,"defineProperty");return e({},"",{}),e}catch(e){}}(),K=B?B.isBuffer:void 0,J=Math.max,Z=Date.now,ee=we(w,"Map"),te=we(Object,"create"),ne=function(){function e(){}return function(t){if(!Ne(t))return{};if($)return $(t);e.prototype=t;var n=new e;return e.prototype=void 0,n}}();function re(e){var t=-1,n=null==e?0:e.length;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function oe(e){var t=-1,n=null==e?0:e.length;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function ie(e){var t=-1,n=null==e?0:e.length;for(this.clear();++t<n;){var r=e[t];this.set(r[0],r[1])}}function ae(e){var t=this.__data__=new oe(e);this.size=t.size}function se(e,t){var n=Oe(e),r=!n&&Te(e),o=!n&&!r&&Pe(e),i=!n&&!r&&!o&&Le(e),a=n||r||o||i,s=a?function(e,t){for(var n=-1,r=Array(e);++n<e;)r[n]=t(n);return r}(e.length,String):[],u=s.length;for(var l in e)!t&&!I.call(e,l)||a&&("length"==l||o&&("offset"==l||"parent"==l)||i&&("buffer"==l||"byteLength"==l||"byteOffset"==l)||xe(l,u))||s.push(l);return s}function ue(e,t,n){(void 0===n||Ee(e[t],n))&&(void 0!==n||t in e)||fe(e,t,n)}function le(e,t,n){var r=e[t];I.call(e,t)&&Ee(r,n)&&(void 0!==n||t in e)||fe(e,t,n)}function ce(e,t){for(var n=e.length;n--;)if(Ee(e[n][0],t))return n;return-1}function fe(e,t,n){"__proto__"==t&&X?X(e,t,{configurable:!0,enumerable:!0,value:n,writable:!0}):e[t]=n}re.prototype.clear=function(){this.__data__=te?te(null):{},this.size=0},re.prototype.delete=function(e){var t=this.has(e)&&delete this.__data__[e];return this.size-=t?1:0,t},re.prototype.get=function(e){var t=this.__data__;if(te){var n=t[e];return n===o?void 0:n}return I.call(t,e)?t[e]:void 0},re.prototype.has=function(e){var t=this.__data__;return te?void 0!==t[e]:I.call(t,e)},re.prototype.set=function(e,t){var n=this.__data__;return this.size+=this.has(e)?0:1,n[e]=te&&void 0===t?o:t,this},oe.prototype.clear=function(){this.__data__=[],this.size=0},oe.prototype.delete=function(e){var t=this.__data__,n=ce(t,e);return!(n<0||(n==t.length-1?t.pop():Y.call(t,n,1),--this.size,0))},oe.prototype.get=function(e){var t=this.__data__,n=ce(t,e);return n<0?void 0:t[n][1]},oe.prototype.has=function(e){return ce(this.__data__,e)>-1},oe.prototype.set=function(e,t){var n=this.__data__,r=ce(n,e);return r<0?(++this.size,n.push([e,t])):n[r][1]=t,this},ie.prototype.clear=function(){this.size=0,this.__data__={hash:new re,map:new(ee||oe),string:new re}},ie.prototype.delete=function(e){var t=_e(this,e).delete(e);return this.size-=t?1:0,t},ie.prototype.get=function(e){return _e(this,e).get(e)},ie.prototype.has=function(e){return _e(this,e).has(e)},ie.prototype.set=function(e,t){var n=_e(this,e),r=n.size;return n.set(e,t),this.size+=n.size==r?0:1,this},ae.prototype.clear=function(){this.__data__=new oe,this.size=0},ae.prototype.delete=function(e){var t=this.__data__,n=t.delete(e);return this.size=t.size,n},ae.prototype.get=function(e){return this.__data__.get(e)},ae.prototype.has=function(e){return this.__data__.has(e)},ae.prototype.set=function(e,t){var n=this.__data__;if(n instanceof oe){var o=n.__data__;if(!ee||o.length<r-1)return o.push([e,t]),this.size=++n.size,this;n=this.__data__=new ie(o)}return n.set(e,t),this.size=n.size,this};var pe,he=function(e,t,n){for(var r=-1,o=Object(e),i=n(e),a=i.length;a--;){var s=i[pe?a:++r];if(!1===t(o[s],s,o))break}return e};function de(e){return null==e?void 0===e?m:p:Q&&Q in Object(e)?function(e){var t=I.call(e,Q),n=e[Q];try{e[Q]=void 0;var r=!0}catch(e){}var o=F.call(e);r&&(t?e[Q]=n:delete e[Q]);return o}(e):function(e){return F.call(e)}(e)}function me(e){return Me(e)&&de(e)==u}function ge(e){return!(!Ne(e)||(t=e,D&&D in t))&&(je(e)?q:g).test(function(e){if(null!=e){try{return L.call(e)}catch(e){}try{return e+""}catch(e){}}return""}(e));var t}function ve(e){if(!Ne(e))return function(e){var t=[];if(null!=e)for(var n in Object(e))t.push(n);return t}(e);var t=ke(e),n=[];for(var r in e)("constructor"!=r||!t&&I.call(e,r))&&n.push(r);return n}function ye(e,t,n,r,o){e!==t&&he(t,function(i,a){if(o||(o=new ae),Ne(i))!function(e,t,n,r,o,i,a){var s=Se(e,n),u=Se(t,n),l=a.get(u);if(l)return void ue(e,n,l);var c=i?i(s,u,n+"",e,t,a):void 0,f=void 0===c;if(f){var p=Oe(u),d=!p&&Pe(u),m=!p&&!d&&Le(u);c=u,p||d||m?Oe(s)?c=s:Me(_=s)&&Ae(_)?c=function(e,t){var n=-1,r=e.length;t||(t=Array(r));for(;++n<r;)t[n]=e[n];return t}(s):d?(f=!1,c=function(e,t){if(t)return e.slice();var n=e.length,r=H?H(n):new e.constructor(n);return e.copy(r),r}(u,!0)):m?(f=!1,g=u,v=!0?(y=g.buffer,b=new y.constructor(y.byteLength),new z(b).set(new z(y)),b):g.buffer,c=new g.constructor(v,g.byteOffset,g.length)):c=[]:function(e){if(!Me(e)||de(e)!=h)return!1;var t=W(e);if(null===t)return!0;var n=I.call(t,"constructor")&&t.constructor;return"function"==typeof n&&n instanceof n&&L.call(n)==U}(u)||Te(u)?(c=s,Te(s)?c=function(e){return function(e,t,n,r){var o=!n;n||(n={});var i=-1,a=t.length;for(;++i<a;){var s=t[i],u=r?r(n[s],e[s],s,n,e):void 0;void 0===u&&(u=e[s]),o?fe(n,s,u):le(n,s,u)}return n}(e,Ie(e))}(s):Ne(s)&&!je(s)||(c=function(e){return"function"!=typeof e.constructor||ke(e)?{}:ne(W(e))}(u))):f=!1}var g,v,y,b;var _;f&&(a.set(u,c),o(c,u,r,i,a),a.delete(u));ue(e,n,c)}(e,t,a,n,ye,r,o);else{var s=r?r(Se(e,a),i,a+"",e,t,o):void 0;void 0===s&&(s=i),ue(e,a,s)}},Ie)}function be(e,t){return Ce(function(e,t,n){return t=J(void 0===t?e.length-1:t,0),function(){for(var r=arguments,o=-1,i=J(r.length-t,0),a=Array(i);++o<i;)a[o]=r[t+o];o=-1;for(var s=Array(t+1);++o<t;)s[o]=r[o];return s[t]=n(a),function(e,t,n){switch(n.length){case 0:return e.call(t);case 1:return e.call(t,n[0]);case 2:return e.call(t,n[0],n[1]);case 3:return e.call(t,n[0],n[1],n[2])}return e.apply(t,n)}(e,this,s)}}(e,t,Ue),e+"")}function _e(e,t){var n,r,o=e.__data__;return("string"==(r=typeof(n=t))||"number"==r||"symbol"==r||"boolean"==r?"__proto__"!==n:null===n)?o["string"==typeof t?"string":"hash"]:o.map}function we(e,t){var n=function(e,t){return null==e?void 0:e[t]}(e,t);return ge(n)?n:void 0}function xe(e,t){var n=typeof e;return!!(t=null==t?s:t)&&("number"==n||"symbol"!=n&&v.test(e))&&e>-1&&e%1==0&&e<t}function ke(e){var t=e&&e.constructor;return e===("function"==typeof t&&t.prototype||N)}function Se(e,t){if(("constructor"!==t||"function"!=typeof e[t])&&"__proto__"!=t)return e[t]}var Ce=function(e){var t=0,n=0;return function(){var r=Z(),o=a-(r-n);if(n=r,o>0){if(++t>=i)return arguments[0]}else t=0;return e.apply(void 0,arguments)}}(X?function(e,t){return X(e,"toString",{configurable:!0,enumerable:!1,value:(n=t,function(){return n}),writable:!0});var n}:Ue);function Ee(e,t){return e===t||e!=e&&t!=t}var Te=me(function(){return arguments}())?me:function(e){return Me(e)&&I.call(e,"callee")&&!G.call(e,"callee")},Oe=Array.isArray;function Ae(e){return null!=e&&Re(e.length)&&!je(e)}var Pe=K||function(){return!1};function je(e){if(!Ne(e))return!1;var t=de(e);return t==c||t==f||t==l||t==d}function Re(e){return"number"==typeof e&&e>-1&&e%1==0&&e<=s}function Ne(e){var t=typeof e;return null!=e&&("object"==t||"function"==t)}function Me(e){return null!=e&&"object"==typeof e}var Le=T?function(e){return function(t){return e(t)}}(T):function(e){return Me(e)&&Re(e.length)&&!!y[de(e)]};function Ie(e){return Ae(e)?se(e,!0):ve(e)}var De,Fe=(De=function(e,t,n,r){ye(e,t,n,r)},be(function(e,t){var n=-1,r=t.length,o=r>1?t[r-1]:void 0,i=r>2?t[2]:void 0;for(o=De.length>3&&"function"==typeof o?(r--,o):void 0,i&&function(e,t,n){if(!Ne(n))return!1;var r=typeof t;return!!("number"==r?Ae(n)&&xe(t,n.length):"string"==r&&t in n)&&Ee(n[t],e)}(t[0],t[1],i)&&(o=r<3?void 0:o,r=1),e=Object(e);++n<r;){var a=t[n];a&&De(e,a,n,o)}return e}));function Ue(e){return e}n.exports=Fe}).call(this,n(8),n(26)(e))},function(e,t){var n="[object String]",r=Object.prototype.toString,o=Array.isArray;e.exports=function(e){return"string"==typeof e||!o(e)&&function(e){return!!e&&"object"==typeof e}(e)&&r.call(e)==n}},function(e,t){var n="[object Object]";var r,o,i=Function.prototype,a=Object.prototype,s=i.toString,u=a.hasOwnProperty,l=s.call(Object),c=a.toString,f=(r=Object.getPrototypeOf,o=Object,function(e){return r(o(e))});e.exports=function(e){if(!function(e){return!!e&&"object"==typeof e}(e)||c.call(e)!=n||function(e){var t=!1;if(null!=e&&"function"!=typeof e.toString)try{t=!!(e+"")}catch(e){}return t}(e))return!1;var t=f(e);if(null===t)return!0;var r=u.call(t,"constructor")&&t.constructor;return"function"==typeof r&&r instanceof r&&s.call(r)==l}},function(e,t,n){"use strict";var r=n(395),o=n(396),i=/^\d+$/;t.parse=function(e){return(t=e.split(",").map(function(e){var t={};return e.trim().split(/\s+/).forEach(function(e,n){if(0===n)return t.url=e;var o=e.substring(0,e.length-1),a=e[e.length-1],s=parseInt(o,10),u=parseFloat(o);if("w"===a&&i.test(o))t.width=s;else if("h"===a&&i.test(o))t.height=s;else{if("x"!==a||r(u))throw new Error("Invalid srcset descriptor: "+e+".");t.density=u}}),t})).sort().filter(function(e,n){return JSON.stringify(e)!==JSON.stringify(t[n-1])});var t},t.stringify=function(e){return o(e.map(function(e){if(!e.url)throw new Error("URL is required.");var t=[e.url];return e.width&&t.push(e.width+"w"),e.height&&t.push(e.height+"h"),e.density&&t.push(e.density+"x"),t.join(" ")})).join(", ")}},function(e,t,n){"use strict";e.exports=Number.isNaN||function(e){return e!=e}},function(e,t,n){"use strict";(function(t){var n;"Set"in t?"function"==typeof Set.prototype.forEach&&(n=!1,new Set([!0]).forEach(function(e){n=e}),!0===n)?e.exports=function(e){var t=[];return new Set(e).forEach(function(e){t.push(e)}),t}:e.exports=function(e){var t=new Set;return e.filter(function(e){return!t.has(e)&&(t.add(e),!0)})}:e.exports=function(e){for(var t=[],n=0;n<e.length;n++)-1===t.indexOf(e[n])&&t.push(e[n]);return t}}).call(this,n(8))},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=h(n(95)),o=h(n(159)),i=h(n(97)),a=h(n(100)),s=h(n(101)),u=h(n(417)),l=h(n(99)),c=h(n(165)),f=h(n(103)),p=h(n(166));function h(e){return e&&e.__esModule?e:{default:e}}function d(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return 1===t.length&&Array.isArray(t[0])&&(t=t[0]),new o.default(t)}d.plugin=function(e,t){function n(){var n=t.apply(void 0,arguments);return n.postcssPlugin=e,n.postcssVersion=(new o.default).version,n}var r;return Object.defineProperty(n,"postcss",{get:function(){return r||(r=n()),r}}),n.process=function(e,t,r){return d([n(r)]).process(e,t)},n},d.stringify=i.default,d.parse=l.default,d.vendor=u.default,d.list=c.default,d.comment=function(e){return new a.default(e)},d.atRule=function(e){return new s.default(e)},d.decl=function(e){return new r.default(e)},d.rule=function(e){return new f.default(e)},d.root=function(e){return new p.default(e)};var m=d;t.default=m,e.exports=t.default},function(e,t){},function(e,t){},function(e,t){},function(e,t,n){"use strict";(function(r){t.__esModule=!0,t.default=void 0;var o=a(n(161)),i=a(n(98));function a(e){return e&&e.__esModule?e:{default:e}}var s=function(){function e(e,t,n){this.stringify=e,this.mapOpts=n.map||{},this.root=t,this.opts=n}var t=e.prototype;return t.isMap=function(){return void 0!==this.opts.map?!!this.opts.map:this.previous().length>0},t.previous=function(){var e=this;return this.previousMaps||(this.previousMaps=[],this.root.walk(function(t){if(t.source&&t.source.input.map){var n=t.source.input.map;-1===e.previousMaps.indexOf(n)&&e.previousMaps.push(n)}})),this.previousMaps},t.isInline=function(){if(void 0!==this.mapOpts.inline)return this.mapOpts.inline;var e=this.mapOpts.annotation;return(void 0===e||!0===e)&&(!this.previous().length||this.previous().some(function(e){return e.inline}))},t.isSourcesContent=function(){return void 0!==this.mapOpts.sourcesContent?this.mapOpts.sourcesContent:!this.previous().length||this.previous().some(function(e){return e.withContent()})},t.clearAnnotation=function(){if(!1!==this.mapOpts.annotation)for(var e,t=this.root.nodes.length-1;t>=0;t--)"comment"===(e=this.root.nodes[t]).type&&0===e.text.indexOf("# sourceMappingURL=")&&this.root.removeChild(t)},t.setSourcesContent=function(){var e=this,t={};this.root.walk(function(n){if(n.source){var r=n.source.input.from;if(r&&!t[r]){t[r]=!0;var o=e.relative(r);e.map.setSourceContent(o,n.source.input.css)}}})},t.applyPrevMaps=function(){var e=this.previous(),t=Array.isArray(e),n=0;for(e=t?e:e[Symbol.iterator]();;){var r;if(t){if(n>=e.length)break;r=e[n++]}else{if((n=e.next()).done)break;r=n.value}var a=r,s=this.relative(a.file),u=a.root||i.default.dirname(a.file),l=void 0;!1===this.mapOpts.sourcesContent?(l=new o.default.SourceMapConsumer(a.text)).sourcesContent&&(l.sourcesContent=l.sourcesContent.map(function(){return null})):l=a.consumer(),this.map.applySourceMap(l,s,this.relative(u))}},t.isAnnotation=function(){return!!this.isInline()||(void 0!==this.mapOpts.annotation?this.mapOpts.annotation:!this.previous().length||this.previous().some(function(e){return e.annotation}))},t.toBase64=function(e){return r?r.from(e).toString("base64"):window.btoa(unescape(encodeURIComponent(e)))},t.addAnnotation=function(){var e;e=this.isInline()?"data:application/json;base64,"+this.toBase64(this.map.toString()):"string"==typeof this.mapOpts.annotation?this.mapOpts.annotation:this.outputFile()+".map";var t="\n";-1!==this.css.indexOf("\r\n")&&(t="\r\n"),this.css+=t+"/*# sourceMappingURL="+e+" */"},t.outputFile=function(){return this.opts.to?this.relative(this.opts.to):this.opts.from?this.relative(this.opts.from):"to.css"},t.generateMap=function(){return this.generateString(),this.isSourcesContent()&&this.setSourcesContent(),this.previous().length>0&&this.applyPrevMaps(),this.isAnnotation()&&this.addAnnotation(),this.isInline()?[this.css]:[this.css,this.map]},t.relative=function(e){if(0===e.indexOf("<"))return e;if(/^\w+:\/\//.test(e))return e;var t=this.opts.to?i.default.dirname(this.opts.to):".";return"string"==typeof this.mapOpts.annotation&&(t=i.default.dirname(i.default.resolve(t,this.mapOpts.annotation))),e=i.default.relative(t,e),"\\"===i.default.sep?e.replace(/\\/g,"/"):e},t.sourcePath=function(e){return this.mapOpts.from?this.mapOpts.from:this.relative(e.source.input.from)},t.generateString=function(){var e=this;this.css="",this.map=new o.default.SourceMapGenerator({file:this.outputFile()});var t,n,r=1,i=1;this.stringify(this.root,function(o,a,s){if(e.css+=o,a&&"end"!==s&&(a.source&&a.source.start?e.map.addMapping({source:e.sourcePath(a),generated:{line:r,column:i-1},original:{line:a.source.start.line,column:a.source.start.column-1}}):e.map.addMapping({source:"<no source>",original:{line:1,column:0},generated:{line:r,column:i-1}})),(t=o.match(/\n/g))?(r+=t.length,n=o.lastIndexOf("\n"),i=o.length-n):i+=o.length,a&&"start"!==s){var u=a.parent||{raws:{}};("decl"!==a.type||a!==u.last||u.raws.semicolon)&&(a.source&&a.source.end?e.map.addMapping({source:e.sourcePath(a),generated:{line:r,column:i-2},original:{line:a.source.end.line,column:a.source.end.column-1}}):e.map.addMapping({source:"<no source>",original:{line:1,column:0},generated:{line:r,column:i-1}}))}})},t.generate=function(){if(this.clearAnnotation(),this.isMap())return this.generateMap();var e="";return this.stringify(this.root,function(t){e+=t}),[e]},e}();t.default=s,e.exports=t.default}).call(this,n(56).Buffer)},function(e,t){var n="ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/".split("");t.encode=function(e){if(0<=e&&e<n.length)return n[e];throw new TypeError("Must be between 0 and 63: "+e)},t.decode=function(e){return 65<=e&&e<=90?e-65:97<=e&&e<=122?e-97+26:48<=e&&e<=57?e-48+52:43==e?62:47==e?63:-1}},function(e,t,n){var r=n(35);function o(){this._array=[],this._sorted=!0,this._last={generatedLine:-1,generatedColumn:0}}o.prototype.unsortedForEach=function(e,t){this._array.forEach(e,t)},o.prototype.add=function(e){var t,n,o,i,a,s;t=this._last,n=e,o=t.generatedLine,i=n.generatedLine,a=t.generatedColumn,s=n.generatedColumn,i>o||i==o&&s>=a||r.compareByGeneratedPositionsInflated(t,n)<=0?(this._last=e,this._array.push(e)):(this._sorted=!1,this._array.push(e))},o.prototype.toArray=function(){return this._sorted||(this._array.sort(r.compareByGeneratedPositionsInflated),this._sorted=!0),this._array},t.MappingList=o},function(e,t,n){var r=n(35),o=n(405),i=n(164).ArraySet,a=n(163),s=n(406).quickSort;function u(e,t){var n=e;return"string"==typeof e&&(n=r.parseSourceMapInput(e)),null!=n.sections?new f(n,t):new l(n,t)}function l(e,t){var n=e;"string"==typeof e&&(n=r.parseSourceMapInput(e));var o=r.getArg(n,"version"),a=r.getArg(n,"sources"),s=r.getArg(n,"names",[]),u=r.getArg(n,"sourceRoot",null),l=r.getArg(n,"sourcesContent",null),c=r.getArg(n,"mappings"),f=r.getArg(n,"file",null);if(o!=this._version)throw new Error("Unsupported version: "+o);u&&(u=r.normalize(u)),a=a.map(String).map(r.normalize).map(function(e){return u&&r.isAbsolute(u)&&r.isAbsolute(e)?r.relative(u,e):e}),this._names=i.fromArray(s.map(String),!0),this._sources=i.fromArray(a,!0),this._absoluteSources=this._sources.toArray().map(function(e){return r.computeSourceURL(u,e,t)}),this.sourceRoot=u,this.sourcesContent=l,this._mappings=c,this._sourceMapURL=t,this.file=f}function c(){this.generatedLine=0,this.generatedColumn=0,this.source=null,this.originalLine=null,this.originalColumn=null,this.name=null}function f(e,t){var n=e;"string"==typeof e&&(n=r.parseSourceMapInput(e));var o=r.getArg(n,"version"),a=r.getArg(n,"sections");if(o!=this._version)throw new Error("Unsupported version: "+o);this._sources=new i,this._names=new i;var s={line:-1,column:0};this._sections=a.map(function(e){if(e.url)throw new Error("Support for url field in sections not implemented.");var n=r.getArg(e,"offset"),o=r.getArg(n,"line"),i=r.getArg(n,"column");if(o<s.line||o===s.line&&i<s.column)throw new Error("Section offsets must be ordered and non-overlapping.");return s=n,{generatedOffset:{generatedLine:o+1,generatedColumn:i+1},consumer:new u(r.getArg(e,"map"),t)}})}u.fromSourceMap=function(e,t){return l.fromSourceMap(e,t)},u.prototype._version=3,u.prototype.__generatedMappings=null,Object.defineProperty(u.prototype,"_generatedMappings",{configurable:!0,enumerable:!0,get:function(){return this.__generatedMappings||this._parseMappings(this._mappings,this.sourceRoot),this.__generatedMappings}}),u.prototype.__originalMappings=null,Object.defineProperty(u.prototype,"_originalMappings",{configurable:!0,enumerable:!0,get:function(){return this.__originalMappings||this._parseMappings(this._mappings,this.sourceRoot),this.__originalMappings}}),u.prototype._charIsMappingSeparator=function(e,t){var n=e.charAt(t);return";"===n||","===n},u.prototype._parseMappings=function(e,t){throw new Error("Subclasses must implement _parseMappings")},u.GENERATED_ORDER=1,u.ORIGINAL_ORDER=2,u.GREATEST_LOWER_BOUND=1,u.LEAST_UPPER_BOUND=2,u.prototype.eachMapping=function(e,t,n){var o,i=t||null;switch(n||u.GENERATED_ORDER){case u.GENERATED_ORDER:o=this._generatedMappings;break;case u.ORIGINAL_ORDER:o=this._originalMappings;break;default:throw new Error("Unknown order of iteration.")}var a=this.sourceRoot;o.map(function(e){var t=null===e.source?null:this._sources.at(e.source);return{source:t=r.computeSourceURL(a,t,this._sourceMapURL),generatedLine:e.generatedLine,generatedColumn:e.generatedColumn,originalLine:e.originalLine,originalColumn:e.originalColumn,name:null===e.name?null:this._names.at(e.name)}},this).forEach(e,i)},u.prototype.allGeneratedPositionsFor=function(e){var t=r.getArg(e,"line"),n={source:r.getArg(e,"source"),originalLine:t,originalColumn:r.getArg(e,"column",0)};if(n.source=this._findSourceIndex(n.source),n.source<0)return[];var i=[],a=this._findMapping(n,this._originalMappings,"originalLine","originalColumn",r.compareByOriginalPositions,o.LEAST_UPPER_BOUND);if(a>=0){var s=this._originalMappings[a];if(void 0===e.column)for(var u=s.originalLine;s&&s.originalLine===u;)i.push({line:r.getArg(s,"generatedLine",null),column:r.getArg(s,"generatedColumn",null),lastColumn:r.getArg(s,"lastGeneratedColumn",null)}),s=this._originalMappings[++a];else for(var l=s.originalColumn;s&&s.originalLine===t&&s.originalColumn==l;)i.push({line:r.getArg(s,"generatedLine",null),column:r.getArg(s,"generatedColumn",null),lastColumn:r.getArg(s,"lastGeneratedColumn",null)}),s=this._originalMappings[++a]}return i},t.SourceMapConsumer=u,l.prototype=Object.create(u.prototype),l.prototype.consumer=u,l.prototype._findSourceIndex=function(e){var t,n=e;if(null!=this.sourceRoot&&(n=r.relative(this.sourceRoot,n)),this._sources.has(n))return this._sources.indexOf(n);for(t=0;t<this._absoluteSources.length;++t)if(this._absoluteSources[t]==e)return t;return-1},l.fromSourceMap=function(e,t){var n=Object.create(l.prototype),o=n._names=i.fromArray(e._names.toArray(),!0),a=n._sources=i.fromArray(e._sources.toArray(),!0);n.sourceRoot=e._sourceRoot,n.sourcesContent=e._generateSourcesContent(n._sources.toArray(),n.sourceRoot),n.file=e._file,n._sourceMapURL=t,n._absoluteSources=n._sources.toArray().map(function(e){return r.computeSourceURL(n.sourceRoot,e,t)});for(var u=e._mappings.toArray().slice(),f=n.__generatedMappings=[],p=n.__originalMappings=[],h=0,d=u.length;h<d;h++){var m=u[h],g=new c;g.generatedLine=m.generatedLine,g.generatedColumn=m.generatedColumn,m.source&&(g.source=a.indexOf(m.source),g.originalLine=m.originalLine,g.originalColumn=m.originalColumn,m.name&&(g.name=o.indexOf(m.name)),p.push(g)),f.push(g)}return s(n.__originalMappings,r.compareByOriginalPositions),n},l.prototype._version=3,Object.defineProperty(l.prototype,"sources",{get:function(){return this._absoluteSources.slice()}}),l.prototype._parseMappings=function(e,t){for(var n,o,i,u,l,f=1,p=0,h=0,d=0,m=0,g=0,v=e.length,y=0,b={},_={},w=[],x=[];y<v;)if(";"===e.charAt(y))f++,y++,p=0;else if(","===e.charAt(y))y++;else{for((n=new c).generatedLine=f,u=y;u<v&&!this._charIsMappingSeparator(e,u);u++);if(i=b[o=e.slice(y,u)])y+=o.length;else{for(i=[];y<u;)a.decode(e,y,_),l=_.value,y=_.rest,i.push(l);if(2===i.length)throw new Error("Found a source, but no line and column");if(3===i.length)throw new Error("Found a source and line, but no column");b[o]=i}n.generatedColumn=p+i[0],p=n.generatedColumn,i.length>1&&(n.source=m+i[1],m+=i[1],n.originalLine=h+i[2],h=n.originalLine,n.originalLine+=1,n.originalColumn=d+i[3],d=n.originalColumn,i.length>4&&(n.name=g+i[4],g+=i[4])),x.push(n),"number"==typeof n.originalLine&&w.push(n)}s(x,r.compareByGeneratedPositionsDeflated),this.__generatedMappings=x,s(w,r.compareByOriginalPositions),this.__originalMappings=w},l.prototype._findMapping=function(e,t,n,r,i,a){if(e[n]<=0)throw new TypeError("Line must be greater than or equal to 1, got "+e[n]);if(e[r]<0)throw new TypeError("Column must be greater than or equal to 0, got "+e[r]);return o.search(e,t,i,a)},l.prototype.computeColumnSpans=function(){for(var e=0;e<this._generatedMappings.length;++e){var t=this._generatedMappings[e];if(e+1<this._generatedMappings.length){var n=this._generatedMappings[e+1];if(t.generatedLine===n.generatedLine){t.lastGeneratedColumn=n.generatedColumn-1;continue}}t.lastGeneratedColumn=1/0}},l.prototype.originalPositionFor=function(e){var t={generatedLine:r.getArg(e,"line"),generatedColumn:r.getArg(e,"column")},n=this._findMapping(t,this._generatedMappings,"generatedLine","generatedColumn",r.compareByGeneratedPositionsDeflated,r.getArg(e,"bias",u.GREATEST_LOWER_BOUND));if(n>=0){var o=this._generatedMappings[n];if(o.generatedLine===t.generatedLine){var i=r.getArg(o,"source",null);null!==i&&(i=this._sources.at(i),i=r.computeSourceURL(this.sourceRoot,i,this._sourceMapURL));var a=r.getArg(o,"name",null);return null!==a&&(a=this._names.at(a)),{source:i,line:r.getArg(o,"originalLine",null),column:r.getArg(o,"originalColumn",null),name:a}}}return{source:null,line:null,column:null,name:null}},l.prototype.hasContentsOfAllSources=function(){return!!this.sourcesContent&&(this.sourcesContent.length>=this._sources.size()&&!this.sourcesContent.some(function(e){return null==e}))},l.prototype.sourceContentFor=function(e,t){if(!this.sourcesContent)return null;var n=this._findSourceIndex(e);if(n>=0)return this.sourcesContent[n];var o,i=e;if(null!=this.sourceRoot&&(i=r.relative(this.sourceRoot,i)),null!=this.sourceRoot&&(o=r.urlParse(this.sourceRoot))){var a=i.replace(/^file:\/\//,"");if("file"==o.scheme&&this._sources.has(a))return this.sourcesContent[this._sources.indexOf(a)];if((!o.path||"/"==o.path)&&this._sources.has("/"+i))return this.sourcesContent[this._sources.indexOf("/"+i)]}if(t)return null;throw new Error('"'+i+'" is not in the SourceMap.')},l.prototype.generatedPositionFor=function(e){var t=r.getArg(e,"source");if((t=this._findSourceIndex(t))<0)return{line:null,column:null,lastColumn:null};var n={source:t,originalLine:r.getArg(e,"line"),originalColumn:r.getArg(e,"column")},o=this._findMapping(n,this._originalMappings,"originalLine","originalColumn",r.compareByOriginalPositions,r.getArg(e,"bias",u.GREATEST_LOWER_BOUND));if(o>=0){var i=this._originalMappings[o];if(i.source===n.source)return{line:r.getArg(i,"generatedLine",null),column:r.getArg(i,"generatedColumn",null),lastColumn:r.getArg(i,"lastGeneratedColumn",null)}}return{line:null,column:null,lastColumn:null}},t.BasicSourceMapConsumer=l,f.prototype=Object.create(u.prototype),f.prototype.constructor=u,f.prototype._version=3,Object.defineProperty(f.prototype,"sources",{get:function(){for(var e=[],t=0;t<this._sections.length;t++)for(var n=0;n<this._sections[t].consumer.sources.length;n++)e.push(this._sections[t].consumer.sources[n]);return e}}),f.prototype.originalPositionFor=function(e){var t={generatedLine:r.getArg(e,"line"),generatedColumn:r.getArg(e,"column")},n=o.search(t,this._sections,function(e,t){var n=e.generatedLine-t.generatedOffset.generatedLine;return n||e.generatedColumn-t.generatedOffset.generatedColumn}),i=this._sections[n];return i?i.consumer.originalPositionFor({line:t.generatedLine-(i.generatedOffset.generatedLine-1),column:t.generatedColumn-(i.generatedOffset.generatedLine===t.generatedLine?i.generatedOffset.generatedColumn-1:0),bias:e.bias}):{source:null,line:null,column:null,name:null}},f.prototype.hasContentsOfAllSources=function(){return this._sections.every(function(e){return e.consumer.hasContentsOfAllSources()})},f.prototype.sourceContentFor=function(e,t){for(var n=0;n<this._sections.length;n++){var r=this._sections[n].consumer.sourceContentFor(e,!0);if(r)return r}if(t)return null;throw new Error('"'+e+'" is not in the SourceMap.')},f.prototype.generatedPositionFor=function(e){for(var t=0;t<this._sections.length;t++){var n=this._sections[t];if(-1!==n.consumer._findSourceIndex(r.getArg(e,"source"))){var o=n.consumer.generatedPositionFor(e);if(o)return{line:o.line+(n.generatedOffset.generatedLine-1),column:o.column+(n.generatedOffset.generatedLine===o.line?n.generatedOffset.generatedColumn-1:0)}}}return{line:null,column:null}},f.prototype._parseMappings=function(e,t){this.__generatedMappings=[],this.__originalMappings=[];for(var n=0;n<this._sections.length;n++)for(var o=this._sections[n],i=o.consumer._generatedMappings,a=0;a<i.length;a++){var u=i[a],l=o.consumer._sources.at(u.source);l=r.computeSourceURL(o.consumer.sourceRoot,l,this._sourceMapURL),this._sources.add(l),l=this._sources.indexOf(l);var c=null;u.name&&(c=o.consumer._names.at(u.name),this._names.add(c),c=this._names.indexOf(c));var f={source:l,generatedLine:u.generatedLine+(o.generatedOffset.generatedLine-1),generatedColumn:u.generatedColumn+(o.generatedOffset.generatedLine===u.generatedLine?o.generatedOffset.generatedColumn-1:0),originalLine:u.originalLine,originalColumn:u.originalColumn,name:c};this.__generatedMappings.push(f),"number"==typeof f.originalLine&&this.__originalMappings.push(f)}s(this.__generatedMappings,r.compareByGeneratedPositionsDeflated),s(this.__originalMappings,r.compareByOriginalPositions)},t.IndexedSourceMapConsumer=f},function(e,t){t.GREATEST_LOWER_BOUND=1,t.LEAST_UPPER_BOUND=2,t.search=function(e,n,r,o){if(0===n.length)return-1;var i=function e(n,r,o,i,a,s){var u=Math.floor((r-n)/2)+n,l=a(o,i[u],!0);return 0===l?u:l>0?r-u>1?e(u,r,o,i,a,s):s==t.LEAST_UPPER_BOUND?r<i.length?r:-1:u:u-n>1?e(n,u,o,i,a,s):s==t.LEAST_UPPER_BOUND?u:n<0?-1:n}(-1,n.length,e,n,r,o||t.GREATEST_LOWER_BOUND);if(i<0)return-1;for(;i-1>=0&&0===r(n[i],n[i-1],!0);)--i;return i}},function(e,t){function n(e,t,n){var r=e[t];e[t]=e[n],e[n]=r}function r(e,t,o,i){if(o<i){var a=o-1;n(e,(c=o,f=i,Math.round(c+Math.random()*(f-c))),i);for(var s=e[i],u=o;u<i;u++)t(e[u],s)<=0&&n(e,a+=1,u);n(e,a+1,u);var l=a+1;r(e,t,o,l-1),r(e,t,l+1,i)}var c,f}t.quickSort=function(e,t){r(e,t,0,e.length-1)}},function(e,t,n){var r=n(162).SourceMapGenerator,o=n(35),i=/(\r?\n)/,a="$$$isSourceNode$$$";function s(e,t,n,r,o){this.children=[],this.sourceContents={},this.line=null==e?null:e,this.column=null==t?null:t,this.source=null==n?null:n,this.name=null==o?null:o,this[a]=!0,null!=r&&this.add(r)}s.fromStringWithSourceMap=function(e,t,n){var r=new s,a=e.split(i),u=0,l=function(){return e()+(e()||"");function e(){return u<a.length?a[u++]:void 0}},c=1,f=0,p=null;return t.eachMapping(function(e){if(null!==p){if(!(c<e.generatedLine)){var t=(n=a[u]||"").substr(0,e.generatedColumn-f);return a[u]=n.substr(e.generatedColumn-f),f=e.generatedColumn,h(p,t),void(p=e)}h(p,l()),c++,f=0}for(;c<e.generatedLine;)r.add(l()),c++;if(f<e.generatedColumn){var n=a[u]||"";r.add(n.substr(0,e.generatedColumn)),a[u]=n.substr(e.generatedColumn),f=e.generatedColumn}p=e},this),u<a.length&&(p&&h(p,l()),r.add(a.splice(u).join(""))),t.sources.forEach(function(e){var i=t.sourceContentFor(e);null!=i&&(null!=n&&(e=o.join(n,e)),r.setSourceContent(e,i))}),r;function h(e,t){if(null===e||void 0===e.source)r.add(t);else{var i=n?o.join(n,e.source):e.source;r.add(new s(e.originalLine,e.originalColumn,i,t,e.name))}}},s.prototype.add=function(e){if(Array.isArray(e))e.forEach(function(e){this.add(e)},this);else{if(!e[a]&&"string"!=typeof e)throw new TypeError("Expected a SourceNode, string, or an array of SourceNodes and strings. Got "+e);e&&this.children.push(e)}return this},s.prototype.prepend=function(e){if(Array.isArray(e))for(var t=e.length-1;t>=0;t--)this.prepend(e[t]);else{if(!e[a]&&"string"!=typeof e)throw new TypeError("Expected a SourceNode, string, or an array of SourceNodes and strings. Got "+e);this.children.unshift(e)}return this},s.prototype.walk=function(e){for(var t,n=0,r=this.children.length;n<r;n++)(t=this.children[n])[a]?t.walk(e):""!==t&&e(t,{source:this.source,line:this.line,column:this.column,name:this.name})},s.prototype.join=function(e){var t,n,r=this.children.length;if(r>0){for(t=[],n=0;n<r-1;n++)t.push(this.children[n]),t.push(e);t.push(this.children[n]),this.children=t}return this},s.prototype.replaceRight=function(e,t){var n=this.children[this.children.length-1];return n[a]?n.replaceRight(e,t):"string"==typeof n?this.children[this.children.length-1]=n.replace(e,t):this.children.push("".replace(e,t)),this},s.prototype.setSourceContent=function(e,t){this.sourceContents[o.toSetString(e)]=t},s.prototype.walkSourceContents=function(e){for(var t=0,n=this.children.length;t<n;t++)this.children[t][a]&&this.children[t].walkSourceContents(e);var r=Object.keys(this.sourceContents);for(t=0,n=r.length;t<n;t++)e(o.fromSetString(r[t]),this.sourceContents[r[t]])},s.prototype.toString=function(){var e="";return this.walk(function(t){e+=t}),e},s.prototype.toStringWithSourceMap=function(e){var t={code:"",line:1,column:0},n=new r(e),o=!1,i=null,a=null,s=null,u=null;return this.walk(function(e,r){t.code+=e,null!==r.source&&null!==r.line&&null!==r.column?(i===r.source&&a===r.line&&s===r.column&&u===r.name||n.addMapping({source:r.source,original:{line:r.line,column:r.column},generated:{line:t.line,column:t.column},name:r.name}),i=r.source,a=r.line,s=r.column,u=r.name,o=!0):o&&(n.addMapping({generated:{line:t.line,column:t.column}}),i=null,o=!1);for(var l=0,c=e.length;l<c;l++)10===e.charCodeAt(l)?(t.line++,t.column=0,l+1===c?(i=null,o=!1):o&&n.addMapping({source:r.source,original:{line:r.line,column:r.column},generated:{line:t.line,column:t.column},name:r.name})):t.column++}),this.walkSourceContents(function(e,t){n.setSourceContent(e,t)}),{code:t.code,map:n}},t.SourceNode=s},function(e,t){var n,r,o=e.exports={};function i(){throw new Error("setTimeout has not been defined")}function a(){throw new Error("clearTimeout has not been defined")}function s(e){if(n===setTimeout)return setTimeout(e,0);if((n===i||!n)&&setTimeout)return n=setTimeout,setTimeout(e,0);try{return n(e,0)}catch(t){try{return n.call(null,e,0)}catch(t){return n.call(this,e,0)}}}!function(){try{n="function"==typeof setTimeout?setTimeout:i}catch(e){n=i}try{r="function"==typeof clearTimeout?clearTimeout:a}catch(e){r=a}}();var u,l=[],c=!1,f=-1;function p(){c&&u&&(c=!1,u.length?l=u.concat(l):f=-1,l.length&&h())}function h(){if(!c){var e=s(p);c=!0;for(var t=l.length;t;){for(u=l,l=[];++f<t;)u&&u[f].run();f=-1,t=l.length}u=null,c=!1,function(e){if(r===clearTimeout)return clearTimeout(e);if((r===a||!r)&&clearTimeout)return r=clearTimeout,clearTimeout(e);try{r(e)}catch(t){try{return r.call(null,e)}catch(t){return r.call(this,e)}}}(e)}}function d(e,t){this.fun=e,this.array=t}function m(){}o.nextTick=function(e){var t=new Array(arguments.length-1);if(arguments.length>1)for(var n=1;n<arguments.length;n++)t[n-1]=arguments[n];l.push(new d(e,t)),1!==l.length||c||s(h)},d.prototype.run=function(){this.fun.apply(null,this.array)},o.title="browser",o.browser=!0,o.env={},o.argv=[],o.version="",o.versions={},o.on=m,o.addListener=m,o.once=m,o.off=m,o.removeListener=m,o.removeAllListeners=m,o.emit=m,o.prependListener=m,o.prependOnceListener=m,o.listeners=function(e){return[]},o.binding=function(e){throw new Error("process.binding is not supported")},o.cwd=function(){return"/"},o.chdir=function(e){throw new Error("process.chdir is not supported")},o.umask=function(){return 0}},function(e,t,n){"use strict";t.__esModule=!0,t.default=function(e){if(r[e])return;r[e]=!0,"undefined"!=typeof console&&console.warn&&console.warn(e)};var r={};e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r,o=(r=n(411))&&r.__esModule?r:{default:r};function i(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}var a=function(){function e(e,t,n){this.processor=e,this.messages=[],this.root=t,this.opts=n,this.css=void 0,this.map=void 0}var t,n,r,a=e.prototype;return a.toString=function(){return this.css},a.warn=function(e,t){void 0===t&&(t={}),t.plugin||this.lastPlugin&&this.lastPlugin.postcssPlugin&&(t.plugin=this.lastPlugin.postcssPlugin);var n=new o.default(e,t);return this.messages.push(n),n},a.warnings=function(){return this.messages.filter(function(e){return"warning"===e.type})},t=e,(n=[{key:"content",get:function(){return this.css}}])&&i(t.prototype,n),r&&i(t,r),e}();t.default=a,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=function(){function e(e,t){if(void 0===t&&(t={}),this.type="warning",this.text=e,t.node&&t.node.source){var n=t.node.positionBy(t);this.line=n.line,this.column=n.column}for(var r in t)this[r]=t[r]}return e.prototype.toString=function(){return this.node?this.node.error(this.text,{plugin:this.plugin,index:this.index,word:this.word}).message:this.plugin?this.plugin+": "+this.text:this.text},e}();t.default=r,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=l(n(95)),o=l(n(413)),i=l(n(100)),a=l(n(101)),s=l(n(166)),u=l(n(103));function l(e){return e&&e.__esModule?e:{default:e}}var c=function(){function e(e){this.input=e,this.root=new s.default,this.current=this.root,this.spaces="",this.semicolon=!1,this.createTokenizer(),this.root.source={input:e,start:{line:1,column:1}}}var t=e.prototype;return t.createTokenizer=function(){this.tokenizer=(0,o.default)(this.input)},t.parse=function(){for(var e;!this.tokenizer.endOfFile();)switch((e=this.tokenizer.nextToken())[0]){case"space":this.spaces+=e[1];break;case";":this.freeSemicolon(e);break;case"}":this.end(e);break;case"comment":this.comment(e);break;case"at-word":this.atrule(e);break;case"{":this.emptyRule(e);break;default:this.other(e)}this.endFile()},t.comment=function(e){var t=new i.default;this.init(t,e[2],e[3]),t.source.end={line:e[4],column:e[5]};var n=e[1].slice(2,-2);if(/^\s*$/.test(n))t.text="",t.raws.left=n,t.raws.right="";else{var r=n.match(/^(\s*)([^]*[^\s])(\s*)$/);t.text=r[2],t.raws.left=r[1],t.raws.right=r[3]}},t.emptyRule=function(e){var t=new u.default;this.init(t,e[2],e[3]),t.selector="",t.raws.between="",this.current=t},t.other=function(e){for(var t=!1,n=null,r=!1,o=null,i=[],a=[],s=e;s;){if(n=s[0],a.push(s),"("===n||"["===n)o||(o=s),i.push("("===n?")":"]");else if(0===i.length){if(";"===n){if(r)return void this.decl(a);break}if("{"===n)return void this.rule(a);if("}"===n){this.tokenizer.back(a.pop()),t=!0;break}":"===n&&(r=!0)}else n===i[i.length-1]&&(i.pop(),0===i.length&&(o=null));s=this.tokenizer.nextToken()}if(this.tokenizer.endOfFile()&&(t=!0),i.length>0&&this.unclosedBracket(o),t&&r){for(;a.length&&("space"===(s=a[a.length-1][0])||"comment"===s);)this.tokenizer.back(a.pop());this.decl(a)}else this.unknownWord(a)},t.rule=function(e){e.pop();var t=new u.default;this.init(t,e[0][2],e[0][3]),t.raws.between=this.spacesAndCommentsFromEnd(e),this.raw(t,"selector",e),this.current=t},t.decl=function(e){var t=new r.default;this.init(t);var n,o=e[e.length-1];for(";"===o[0]&&(this.semicolon=!0,e.pop()),o[4]?t.source.end={line:o[4],column:o[5]}:t.source.end={line:o[2],column:o[3]};"word"!==e[0][0];)1===e.length&&this.unknownWord(e),t.raws.before+=e.shift()[1];for(t.source.start={line:e[0][2],column:e[0][3]},t.prop="";e.length;){var i=e[0][0];if(":"===i||"space"===i||"comment"===i)break;t.prop+=e.shift()[1]}for(t.raws.between="";e.length;){if(":"===(n=e.shift())[0]){t.raws.between+=n[1];break}"word"===n[0]&&/\w/.test(n[1])&&this.unknownWord([n]),t.raws.between+=n[1]}"_"!==t.prop[0]&&"*"!==t.prop[0]||(t.raws.before+=t.prop[0],t.prop=t.prop.slice(1)),t.raws.between+=this.spacesAndCommentsFromStart(e),this.precheckMissedSemicolon(e);for(var a=e.length-1;a>0;a--){if("!important"===(n=e[a])[1].toLowerCase()){t.important=!0;var s=this.stringFrom(e,a);" !important"!==(s=this.spacesFromEnd(e)+s)&&(t.raws.important=s);break}if("important"===n[1].toLowerCase()){for(var u=e.slice(0),l="",c=a;c>0;c--){var f=u[c][0];if(0===l.trim().indexOf("!")&&"space"!==f)break;l=u.pop()[1]+l}0===l.trim().indexOf("!")&&(t.important=!0,t.raws.important=l,e=u)}if("space"!==n[0]&&"comment"!==n[0])break}this.raw(t,"value",e),-1!==t.value.indexOf(":")&&this.checkMissedSemicolon(e)},t.atrule=function(e){var t,n,r=new a.default;r.name=e[1].slice(1),""===r.name&&this.unnamedAtrule(r,e),this.init(r,e[2],e[3]);for(var o=!1,i=!1,s=[];!this.tokenizer.endOfFile();){if(";"===(e=this.tokenizer.nextToken())[0]){r.source.end={line:e[2],column:e[3]},this.semicolon=!0;break}if("{"===e[0]){i=!0;break}if("}"===e[0]){if(s.length>0){for(t=s[n=s.length-1];t&&"space"===t[0];)t=s[--n];t&&(r.source.end={line:t[4],column:t[5]})}this.end(e);break}if(s.push(e),this.tokenizer.endOfFile()){o=!0;break}}r.raws.between=this.spacesAndCommentsFromEnd(s),s.length?(r.raws.afterName=this.spacesAndCommentsFromStart(s),this.raw(r,"params",s),o&&(e=s[s.length-1],r.source.end={line:e[4],column:e[5]},this.spaces=r.raws.between,r.raws.between="")):(r.raws.afterName="",r.params=""),i&&(r.nodes=[],this.current=r)},t.end=function(e){this.current.nodes&&this.current.nodes.length&&(this.current.raws.semicolon=this.semicolon),this.semicolon=!1,this.current.raws.after=(this.current.raws.after||"")+this.spaces,this.spaces="",this.current.parent?(this.current.source.end={line:e[2],column:e[3]},this.current=this.current.parent):this.unexpectedClose(e)},t.endFile=function(){this.current.parent&&this.unclosedBlock(),this.current.nodes&&this.current.nodes.length&&(this.current.raws.semicolon=this.semicolon),this.current.raws.after=(this.current.raws.after||"")+this.spaces},t.freeSemicolon=function(e){if(this.spaces+=e[1],this.current.nodes){var t=this.current.nodes[this.current.nodes.length-1];t&&"rule"===t.type&&!t.raws.ownSemicolon&&(t.raws.ownSemicolon=this.spaces,this.spaces="")}},t.init=function(e,t,n){this.current.push(e),e.source={start:{line:t,column:n},input:this.input},e.raws.before=this.spaces,this.spaces="","comment"!==e.type&&(this.semicolon=!1)},t.raw=function(e,t,n){for(var r,o,i,a,s=n.length,u="",l=!0,c=/^([.|#])?([\w])+/i,f=0;f<s;f+=1)"comment"!==(o=(r=n[f])[0])||"rule"!==e.type?"comment"===o||"space"===o&&f===s-1?l=!1:u+=r[1]:(a=n[f-1],i=n[f+1],"space"!==a[0]&&"space"!==i[0]&&c.test(a[1])&&c.test(i[1])?u+=r[1]:l=!1);if(!l){var p=n.reduce(function(e,t){return e+t[1]},"");e.raws[t]={value:u,raw:p}}e[t]=u},t.spacesAndCommentsFromEnd=function(e){for(var t,n="";e.length&&("space"===(t=e[e.length-1][0])||"comment"===t);)n=e.pop()[1]+n;return n},t.spacesAndCommentsFromStart=function(e){for(var t,n="";e.length&&("space"===(t=e[0][0])||"comment"===t);)n+=e.shift()[1];return n},t.spacesFromEnd=function(e){for(var t="";e.length&&"space"===e[e.length-1][0];)t=e.pop()[1]+t;return t},t.stringFrom=function(e,t){for(var n="",r=t;r<e.length;r++)n+=e[r][1];return e.splice(t,e.length-t),n},t.colon=function(e){for(var t,n,r,o=0,i=0;i<e.length;i++){if("("===(n=(t=e[i])[0])&&(o+=1),")"===n&&(o-=1),0===o&&":"===n){if(r){if("word"===r[0]&&"progid"===r[1])continue;return i}this.doubleColon(t)}r=t}return!1},t.unclosedBracket=function(e){throw this.input.error("Unclosed bracket",e[2],e[3])},t.unknownWord=function(e){throw this.input.error("Unknown word",e[0][2],e[0][3])},t.unexpectedClose=function(e){throw this.input.error("Unexpected }",e[2],e[3])},t.unclosedBlock=function(){var e=this.current.source.start;throw this.input.error("Unclosed block",e.line,e.column)},t.doubleColon=function(e){throw this.input.error("Double colon",e[2],e[3])},t.unnamedAtrule=function(e,t){throw this.input.error("At-rule without name",t[2],t[3])},t.precheckMissedSemicolon=function(){},t.checkMissedSemicolon=function(e){var t=this.colon(e);if(!1!==t){for(var n,r=0,o=t-1;o>=0&&("space"===(n=e[o])[0]||2!==(r+=1));o--);throw this.input.error("Missed semicolon",n[2],n[3])}},e}();t.default=c,e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=function(e,t){void 0===t&&(t={});var n,E,T,O,A,P,j,R,N,M,L,I,D,F,U=e.css.valueOf(),q=t.ignoreErrors,B=U.length,V=-1,z=1,H=0,W=[],$=[];function G(t){throw e.error("Unclosed "+t,z,H-V)}return{back:function(e){$.push(e)},nextToken:function(e){if($.length)return $.pop();if(H>=B)return;var t=!!e&&e.ignoreUnclosed;((n=U.charCodeAt(H))===s||n===l||n===f&&U.charCodeAt(H+1)!==s)&&(V=H,z+=1);switch(n){case s:case u:case c:case f:case l:E=H;do{E+=1,(n=U.charCodeAt(E))===s&&(V=E,z+=1)}while(n===u||n===s||n===c||n===f||n===l);F=["space",U.slice(H,E)],H=E-1;break;case p:case h:case g:case v:case _:case y:case m:var Y=String.fromCharCode(n);F=[Y,Y,z,H-V];break;case d:if(I=W.length?W.pop()[1]:"",D=U.charCodeAt(H+1),"url"===I&&D!==r&&D!==o&&D!==u&&D!==s&&D!==c&&D!==l&&D!==f){E=H;do{if(M=!1,-1===(E=U.indexOf(")",E+1))){if(q||t){E=H;break}G("bracket")}for(L=E;U.charCodeAt(L-1)===i;)L-=1,M=!M}while(M);F=["brackets",U.slice(H,E+1),z,H-V,z,E-V],H=E}else E=U.indexOf(")",H+1),P=U.slice(H,E+1),-1===E||S.test(P)?F=["(","(",z,H-V]:(F=["brackets",P,z,H-V,z,E-V],H=E);break;case r:case o:T=n===r?"'":'"',E=H;do{if(M=!1,-1===(E=U.indexOf(T,E+1))){if(q||t){E=H+1;break}G("string")}for(L=E;U.charCodeAt(L-1)===i;)L-=1,M=!M}while(M);P=U.slice(H,E+1),O=P.split("\n"),(A=O.length-1)>0?(R=z+A,N=E-O[A].length):(R=z,N=V),F=["string",U.slice(H,E+1),z,H-V,R,E-N],V=N,z=R,H=E;break;case w:x.lastIndex=H+1,x.test(U),E=0===x.lastIndex?U.length-1:x.lastIndex-2,F=["at-word",U.slice(H,E+1),z,H-V,z,E-V],H=E;break;case i:for(E=H,j=!0;U.charCodeAt(E+1)===i;)E+=1,j=!j;if(n=U.charCodeAt(E+1),j&&n!==a&&n!==u&&n!==s&&n!==c&&n!==f&&n!==l&&(E+=1,C.test(U.charAt(E)))){for(;C.test(U.charAt(E+1));)E+=1;U.charCodeAt(E+1)===u&&(E+=1)}F=["word",U.slice(H,E+1),z,H-V,z,E-V],H=E;break;default:n===a&&U.charCodeAt(H+1)===b?(0===(E=U.indexOf("*/",H+2)+1)&&(q||t?E=U.length:G("comment")),P=U.slice(H,E+1),O=P.split("\n"),(A=O.length-1)>0?(R=z+A,N=E-O[A].length):(R=z,N=V),F=["comment",P,z,H-V,R,E-N],V=N,z=R,H=E):(k.lastIndex=H+1,k.test(U),E=0===k.lastIndex?U.length-1:k.lastIndex-2,F=["word",U.slice(H,E+1),z,H-V,z,E-V],W.push(F),H=E)}return H++,F},endOfFile:function(){return 0===$.length&&H>=B},position:function(){return H}}};var r="'".charCodeAt(0),o='"'.charCodeAt(0),i="\\".charCodeAt(0),a="/".charCodeAt(0),s="\n".charCodeAt(0),u=" ".charCodeAt(0),l="\f".charCodeAt(0),c="\t".charCodeAt(0),f="\r".charCodeAt(0),p="[".charCodeAt(0),h="]".charCodeAt(0),d="(".charCodeAt(0),m=")".charCodeAt(0),g="{".charCodeAt(0),v="}".charCodeAt(0),y=";".charCodeAt(0),b="*".charCodeAt(0),_=":".charCodeAt(0),w="@".charCodeAt(0),x=/[ \n\t\r\f{}()'"\\;\/[\]#]/g,k=/[ \n\t\r\f(){}:;@!'"\\\][#]|\/(?=\*)/g,S=/.[\\\/("'\n]/,C=/[a-f0-9]/i;e.exports=t.default},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r=a(n(157)),o=a(n(415)),i=a(n(98));function a(e){return e&&e.__esModule?e:{default:e}}function s(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,r.key,r)}}var u=0,l=function(){function e(e,t){if(void 0===t&&(t={}),null===e||"object"==typeof e&&!e.toString)throw new Error("PostCSS received "+e+" instead of CSS string");this.css=e.toString(),"\ufeff"===this.css[0]||"￾"===this.css[0]?(this.hasBOM=!0,this.css=this.css.slice(1)):this.hasBOM=!1,t.from&&(/^\w+:\/\//.test(t.from)?this.file=t.from:this.file=i.default.resolve(t.from));var n=new o.default(this.css,t);if(n.text){this.map=n;var r=n.consumer().file;!this.file&&r&&(this.file=this.mapResolve(r))}this.file||(u+=1,this.id="<input css "+u+">"),this.map&&(this.map.file=this.from)}var t,n,a,l=e.prototype;return l.error=function(e,t,n,o){var i;void 0===o&&(o={});var a=this.origin(t,n);return(i=a?new r.default(e,a.line,a.column,a.source,a.file,o.plugin):new r.default(e,t,n,this.css,this.file,o.plugin)).input={line:t,column:n,source:this.css},this.file&&(i.input.file=this.file),i},l.origin=function(e,t){if(!this.map)return!1;var n=this.map.consumer(),r=n.originalPositionFor({line:e,column:t});if(!r.source)return!1;var o={file:this.mapResolve(r.source),line:r.line,column:r.column},i=n.sourceContentFor(r.source);return i&&(o.source=i),o},l.mapResolve=function(e){return/^\w+:\/\//.test(e)?e:i.default.resolve(this.map.consumer().sourceRoot||".",e)},t=e,(n=[{key:"from",get:function(){return this.file||this.id}}])&&s(t.prototype,n),a&&s(t,a),e}();t.default=l,e.exports=t.default},function(e,t,n){"use strict";(function(r){t.__esModule=!0,t.default=void 0;var o=s(n(161)),i=s(n(98)),a=s(n(416));function s(e){return e&&e.__esModule?e:{default:e}}var u=function(){function e(e,t){this.loadAnnotation(e),this.inline=this.startWith(this.annotation,"data:");var n=t.map?t.map.prev:void 0,r=this.loadMap(t.from,n);r&&(this.text=r)}var t=e.prototype;return t.consumer=function(){return this.consumerCache||(this.consumerCache=new o.default.SourceMapConsumer(this.text)),this.consumerCache},t.withContent=function(){return!!(this.consumer().sourcesContent&&this.consumer().sourcesContent.length>0)},t.startWith=function(e,t){return!!e&&e.substr(0,t.length)===t},t.loadAnnotation=function(e){var t=e.match(/\/\*\s*# sourceMappingURL=(.*)\s*\*\//);t&&(this.annotation=t[1].trim())},t.decodeInline=function(e){var t,n="data:application/json,";if(this.startWith(e,n))return decodeURIComponent(e.substr(n.length));if(/^data:application\/json;charset=utf-?8;base64,/.test(e)||/^data:application\/json;base64,/.test(e))return t=e.substr(RegExp.lastMatch.length),r?r.from(t,"base64").toString():window.atob(t);var o=e.match(/data:application\/json;([^,]+),/)[1];throw new Error("Unsupported source map encoding "+o)},t.loadMap=function(e,t){if(!1===t)return!1;if(t){if("string"==typeof t)return t;if("function"==typeof t){var n=t(e);if(n&&a.default.existsSync&&a.default.existsSync(n))return a.default.readFileSync(n,"utf-8").toString().trim();throw new Error("Unable to load previous source map: "+n.toString())}if(t instanceof o.default.SourceMapConsumer)return o.default.SourceMapGenerator.fromSourceMap(t).toString();if(t instanceof o.default.SourceMapGenerator)return t.toString();if(this.isMap(t))return JSON.stringify(t);throw new Error("Unsupported previous source map format: "+t.toString())}if(this.inline)return this.decodeInline(this.annotation);if(this.annotation){var r=this.annotation;return e&&(r=i.default.join(i.default.dirname(e),r)),this.root=i.default.dirname(r),!(!a.default.existsSync||!a.default.existsSync(r))&&a.default.readFileSync(r,"utf-8").toString().trim()}},t.isMap=function(e){return"object"==typeof e&&("string"==typeof e.mappings||"string"==typeof e._mappings)},e}();t.default=u,e.exports=t.default}).call(this,n(56).Buffer)},function(e,t){},function(e,t,n){"use strict";t.__esModule=!0,t.default=void 0;var r={prefix:function(e){var t=e.match(/^(-\w+-)/);return t?t[0]:""},unprefixed:function(e){return e.replace(/^-\w+-/,"")}};t.default=r,e.exports=t.default},function(e,t,n){"use strict";var r=n(419),o=n(420);function i(){this.protocol=null,this.slashes=null,this.auth=null,this.host=null,this.port=null,this.hostname=null,this.hash=null,this.search=null,this.query=null,this.pathname=null,this.path=null,this.href=null}t.parse=b,t.resolve=function(e,t){return b(e,!1,!0).resolve(t)},t.resolveObject=function(e,t){return e?b(e,!1,!0).resolveObject(t):t},t.format=function(e){o.isString(e)&&(e=b(e));return e instanceof i?e.format():i.prototype.format.call(e)},t.Url=i;var a=/^([a-z0-9.+-]+:)/i,s=/:[0-9]*$/,u=/^(\/\/?(?!\/)[^\?\s]*)(\?[^\s]*)?$/,l=["{","}","|","\\","^","`"].concat(["<",">",'"',"`"," ","\r","\n","\t"]),c=["'"].concat(l),f=["%","/","?",";","#"].concat(c),p=["/","?","#"],h=/^[+a-z0-9A-Z_-]{0,63}$/,d=/^([+a-z0-9A-Z_-]{0,63})(.*)$/,m={javascript:!0,"javascript:":!0},g={javascript:!0,"javascript:":!0},v={http:!0,https:!0,ftp:!0,gopher:!0,file:!0,"http:":!0,"https:":!0,"ftp:":!0,"gopher:":!0,"file:":!0},y=n(421);function b(e,t,n){if(e&&o.isObject(e)&&e instanceof i)return e;var r=new i;return r.parse(e,t,n),r}i.prototype.parse=function(e,t,n){if(!o.isString(e))throw new TypeError("Parameter 'url' must be a string, not "+typeof e);var i=e.indexOf("?"),s=-1!==i&&i<e.indexOf("#")?"?":"#",l=e.split(s);l[0]=l[0].replace(/\\/g,"/");var b=e=l.join(s);if(b=b.trim(),!n&&1===e.split("#").length){var _=u.exec(b);if(_)return this.path=b,this.href=b,this.pathname=_[1],_[2]?(this.search=_[2],this.query=t?y.parse(this.search.substr(1)):this.search.substr(1)):t&&(this.search="",this.query={}),this}var w=a.exec(b);if(w){var x=(w=w[0]).toLowerCase();this.protocol=x,b=b.substr(w.length)}if(n||w||b.match(/^\/\/[^@\/]+@[^@\/]+/)){var k="//"===b.substr(0,2);!k||w&&g[w]||(b=b.substr(2),this.slashes=!0)}if(!g[w]&&(k||w&&!v[w])){for(var S,C,E=-1,T=0;T<p.length;T++){-1!==(O=b.indexOf(p[T]))&&(-1===E||O<E)&&(E=O)}-1!==(C=-1===E?b.lastIndexOf("@"):b.lastIndexOf("@",E))&&(S=b.slice(0,C),b=b.slice(C+1),this.auth=decodeURIComponent(S)),E=-1;for(T=0;T<f.length;T++){var O;-1!==(O=b.indexOf(f[T]))&&(-1===E||O<E)&&(E=O)}-1===E&&(E=b.length),this.host=b.slice(0,E),b=b.slice(E),this.parseHost(),this.hostname=this.hostname||"";var A="["===this.hostname[0]&&"]"===this.hostname[this.hostname.length-1];if(!A)for(var P=this.hostname.split(/\./),j=(T=0,P.length);T<j;T++){var R=P[T];if(R&&!R.match(h)){for(var N="",M=0,L=R.length;M<L;M++)R.charCodeAt(M)>127?N+="x":N+=R[M];if(!N.match(h)){var I=P.slice(0,T),D=P.slice(T+1),F=R.match(d);F&&(I.push(F[1]),D.unshift(F[2])),D.length&&(b="/"+D.join(".")+b),this.hostname=I.join(".");break}}}this.hostname.length>255?this.hostname="":this.hostname=this.hostname.toLowerCase(),A||(this.hostname=r.toASCII(this.hostname));var U=this.port?":"+this.port:"",q=this.hostname||"";this.host=q+U,this.href+=this.host,A&&(this.hostname=this.hostname.substr(1,this.hostname.length-2),"/"!==b[0]&&(b="/"+b))}if(!m[x])for(T=0,j=c.length;T<j;T++){var B=c[T];if(-1!==b.indexOf(B)){var V=encodeURIComponent(B);V===B&&(V=escape(B)),b=b.split(B).join(V)}}var z=b.indexOf("#");-1!==z&&(this.hash=b.substr(z),b=b.slice(0,z));var H=b.indexOf("?");if(-1!==H?(this.search=b.substr(H),this.query=b.substr(H+1),t&&(this.query=y.parse(this.query)),b=b.slice(0,H)):t&&(this.search="",this.query={}),b&&(this.pathname=b),v[x]&&this.hostname&&!this.pathname&&(this.pathname="/"),this.pathname||this.search){U=this.pathname||"";var W=this.search||"";this.path=U+W}return this.href=this.format(),this},i.prototype.format=function(){var e=this.auth||"";e&&(e=(e=encodeURIComponent(e)).replace(/%3A/i,":"),e+="@");var t=this.protocol||"",n=this.pathname||"",r=this.hash||"",i=!1,a="";this.host?i=e+this.host:this.hostname&&(i=e+(-1===this.hostname.indexOf(":")?this.hostname:"["+this.hostname+"]"),this.port&&(i+=":"+this.port)),this.query&&o.isObject(this.query)&&Object.keys(this.query).length&&(a=y.stringify(this.query));var s=this.search||a&&"?"+a||"";return t&&":"!==t.substr(-1)&&(t+=":"),this.slashes||(!t||v[t])&&!1!==i?(i="//"+(i||""),n&&"/"!==n.charAt(0)&&(n="/"+n)):i||(i=""),r&&"#"!==r.charAt(0)&&(r="#"+r),s&&"?"!==s.charAt(0)&&(s="?"+s),t+i+(n=n.replace(/[?#]/g,function(e){return encodeURIComponent(e)}))+(s=s.replace("#","%23"))+r},i.prototype.resolve=function(e){return this.resolveObject(b(e,!1,!0)).format()},i.prototype.resolveObject=function(e){if(o.isString(e)){var t=new i;t.parse(e,!1,!0),e=t}for(var n=new i,r=Object.keys(this),a=0;a<r.length;a++){var s=r[a];n[s]=this[s]}if(n.hash=e.hash,""===e.href)return n.href=n.format(),n;if(e.slashes&&!e.protocol){for(var u=Object.keys(e),l=0;l<u.length;l++){var c=u[l];"protocol"!==c&&(n[c]=e[c])}return v[n.protocol]&&n.hostname&&!n.pathname&&(n.path=n.pathname="/"),n.href=n.format(),n}if(e.protocol&&e.protocol!==n.protocol){if(!v[e.protocol]){for(var f=Object.keys(e),p=0;p<f.length;p++){var h=f[p];n[h]=e[h]}return n.href=n.format(),n}if(n.protocol=e.protocol,e.host||g[e.protocol])n.pathname=e.pathname;else{for(var d=(e.pathname||"").split("/");d.length&&!(e.host=d.shift()););e.host||(e.host=""),e.hostname||(e.hostname=""),""!==d[0]&&d.unshift(""),d.length<2&&d.unshift(""),n.pathname=d.join("/")}if(n.search=e.search,n.query=e.query,n.host=e.host||"",n.auth=e.auth,n.hostname=e.hostname||e.host,n.port=e.port,n.pathname||n.search){var m=n.pathname||"",y=n.search||"";n.path=m+y}return n.slashes=n.slashes||e.slashes,n.href=n.format(),n}var b=n.pathname&&"/"===n.pathname.charAt(0),_=e.host||e.pathname&&"/"===e.pathname.charAt(0),w=_||b||n.host&&e.pathname,x=w,k=n.pathname&&n.pathname.split("/")||[],S=(d=e.pathname&&e.pathname.split("/")||[],n.protocol&&!v[n.protocol]);if(S&&(n.hostname="",n.port=null,n.host&&(""===k[0]?k[0]=n.host:k.unshift(n.host)),n.host="",e.protocol&&(e.hostname=null,e.port=null,e.host&&(""===d[0]?d[0]=e.host:d.unshift(e.host)),e.host=null),w=w&&(""===d[0]||""===k[0])),_)n.host=e.host||""===e.host?e.host:n.host,n.hostname=e.hostname||""===e.hostname?e.hostname:n.hostname,n.search=e.search,n.query=e.query,k=d;else if(d.length)k||(k=[]),k.pop(),k=k.concat(d),n.search=e.search,n.query=e.query;else if(!o.isNullOrUndefined(e.search)){if(S)n.hostname=n.host=k.shift(),(A=!!(n.host&&n.host.indexOf("@")>0)&&n.host.split("@"))&&(n.auth=A.shift(),n.host=n.hostname=A.shift());return n.search=e.search,n.query=e.query,o.isNull(n.pathname)&&o.isNull(n.search)||(n.path=(n.pathname?n.pathname:"")+(n.search?n.search:"")),n.href=n.format(),n}if(!k.length)return n.pathname=null,n.search?n.path="/"+n.search:n.path=null,n.href=n.format(),n;for(var C=k.slice(-1)[0],E=(n.host||e.host||k.length>1)&&("."===C||".."===C)||""===C,T=0,O=k.length;O>=0;O--)"."===(C=k[O])?k.splice(O,1):".."===C?(k.splice(O,1),T++):T&&(k.splice(O,1),T--);if(!w&&!x)for(;T--;T)k.unshift("..");!w||""===k[0]||k[0]&&"/"===k[0].charAt(0)||k.unshift(""),E&&"/"!==k.join("/").substr(-1)&&k.push("");var A,P=""===k[0]||k[0]&&"/"===k[0].charAt(0);S&&(n.hostname=n.host=P?"":k.length?k.shift():"",(A=!!(n.host&&n.host.indexOf("@")>0)&&n.host.split("@"))&&(n.auth=A.shift(),n.host=n.hostname=A.shift()));return(w=w||n.host&&k.length)&&!P&&k.unshift(""),k.length?n.pathname=k.join("/"):(n.pathname=null,n.path=null),o.isNull(n.pathname)&&o.isNull(n.search)||(n.path=(n.pathname?n.pathname:"")+(n.search?n.search:"")),n.auth=e.auth||n.auth,n.slashes=n.slashes||e.slashes,n.href=n.format(),n},i.prototype.parseHost=function(){var e=this.host,t=s.exec(e);t&&(":"!==(t=t[0])&&(this.port=t.substr(1)),e=e.substr(0,e.length-t.length)),e&&(this.hostname=e)}},function(e,t,n){(function(e,r){var o;/*! https://mths.be/punycode v1.4.1 by @mathias */!function(i){t&&t.nodeType,e&&e.nodeType;var a="object"==typeof r&&r;a.global!==a&&a.window!==a&&a.self;var s,u=2147483647,l=36,c=1,f=26,p=38,h=700,d=72,m=128,g="-",v=/^xn--/,y=/[^\x20-\x7E]/,b=/[\x2E\u3002\uFF0E\uFF61]/g,_={overflow:"Overflow: input needs wider integers to process","not-basic":"Illegal input >= 0x80 (not a basic code point)","invalid-input":"Invalid input"},w=l-c,x=Math.floor,k=String.fromCharCode;function S(e){throw new RangeError(_[e])}function C(e,t){for(var n=e.length,r=[];n--;)r[n]=t(e[n]);return r}function E(e,t){var n=e.split("@"),r="";return n.length>1&&(r=n[0]+"@",e=n[1]),r+C((e=e.replace(b,".")).split("."),t).join(".")}function T(e){for(var t,n,r=[],o=0,i=e.length;o<i;)(t=e.charCodeAt(o++))>=55296&&t<=56319&&o<i?56320==(64512&(n=e.charCodeAt(o++)))?r.push(((1023&t)<<10)+(1023&n)+65536):(r.push(t),o--):r.push(t);return r}function O(e){return C(e,function(e){var t="";return e>65535&&(t+=k((e-=65536)>>>10&1023|55296),e=56320|1023&e),t+=k(e)}).join("")}function A(e,t){return e+22+75*(e<26)-((0!=t)<<5)}function P(e,t,n){var r=0;for(e=n?x(e/h):e>>1,e+=x(e/t);e>w*f>>1;r+=l)e=x(e/w);return x(r+(w+1)*e/(e+p))}function j(e){var t,n,r,o,i,a,s,p,h,v,y,b=[],_=e.length,w=0,k=m,C=d;for((n=e.lastIndexOf(g))<0&&(n=0),r=0;r<n;++r)e.charCodeAt(r)>=128&&S("not-basic"),b.push(e.charCodeAt(r));for(o=n>0?n+1:0;o<_;){for(i=w,a=1,s=l;o>=_&&S("invalid-input"),((p=(y=e.charCodeAt(o++))-48<10?y-22:y-65<26?y-65:y-97<26?y-97:l)>=l||p>x((u-w)/a))&&S("overflow"),w+=p*a,!(p<(h=s<=C?c:s>=C+f?f:s-C));s+=l)a>x(u/(v=l-h))&&S("overflow"),a*=v;C=P(w-i,t=b.length+1,0==i),x(w/t)>u-k&&S("overflow"),k+=x(w/t),w%=t,b.splice(w++,0,k)}return O(b)}function R(e){var t,n,r,o,i,a,s,p,h,v,y,b,_,w,C,E=[];for(b=(e=T(e)).length,t=m,n=0,i=d,a=0;a<b;++a)(y=e[a])<128&&E.push(k(y));for(r=o=E.length,o&&E.push(g);r<b;){for(s=u,a=0;a<b;++a)(y=e[a])>=t&&y<s&&(s=y);for(s-t>x((u-n)/(_=r+1))&&S("overflow"),n+=(s-t)*_,t=s,a=0;a<b;++a)if((y=e[a])<t&&++n>u&&S("overflow"),y==t){for(p=n,h=l;!(p<(v=h<=i?c:h>=i+f?f:h-i));h+=l)C=p-v,w=l-v,E.push(k(A(v+C%w,0))),p=x(C/w);E.push(k(A(p,0))),i=P(n,_,r==o),n=0,++r}++n,++t}return E.join("")}s={version:"1.4.1",ucs2:{decode:T,encode:O},decode:j,encode:R,toASCII:function(e){return E(e,function(e){return y.test(e)?"xn--"+R(e):e})},toUnicode:function(e){return E(e,function(e){return v.test(e)?j(e.slice(4).toLowerCase()):e})}},void 0===(o=function(){return s}.call(t,n,t,e))||(e.exports=o)}()}).call(this,n(26)(e),n(8))},function(e,t,n){"use strict";e.exports={isString:function(e){return"string"==typeof e},isObject:function(e){return"object"==typeof e&&null!==e},isNull:function(e){return null===e},isNullOrUndefined:function(e){return null==e}}},function(e,t,n){"use strict";t.decode=t.parse=n(422),t.encode=t.stringify=n(423)},function(e,t,n){"use strict";function r(e,t){return Object.prototype.hasOwnProperty.call(e,t)}e.exports=function(e,t,n,i){t=t||"&",n=n||"=";var a={};if("string"!=typeof e||0===e.length)return a;var s=/\+/g;e=e.split(t);var u=1e3;i&&"number"==typeof i.maxKeys&&(u=i.maxKeys);var l=e.length;u>0&&l>u&&(l=u);for(var c=0;c<l;++c){var f,p,h,d,m=e[c].replace(s,"%20"),g=m.indexOf(n);g>=0?(f=m.substr(0,g),p=m.substr(g+1)):(f=m,p=""),h=decodeURIComponent(f),d=decodeURIComponent(p),r(a,h)?o(a[h])?a[h].push(d):a[h]=[a[h],d]:a[h]=d}return a};var o=Array.isArray||function(e){return"[object Array]"===Object.prototype.toString.call(e)}},function(e,t,n){"use strict";var r=function(e){switch(typeof e){case"string":return e;case"boolean":return e?"true":"false";case"number":return isFinite(e)?e:"";default:return""}};e.exports=function(e,t,n,s){return t=t||"&",n=n||"=",null===e&&(e=void 0),"object"==typeof e?i(a(e),function(a){var s=encodeURIComponent(r(a))+n;return o(e[a])?i(e[a],function(e){return s+encodeURIComponent(r(e))}).join(t):s+encodeURIComponent(r(e[a]))}).join(t):s?encodeURIComponent(r(s))+n+encodeURIComponent(r(e)):""};var o=Array.isArray||function(e){return"[object Array]"===Object.prototype.toString.call(e)};function i(e,t){if(e.map)return e.map(t);for(var n=[],r=0;r<e.length;r++)n.push(t(e[r],r));return n}var a=Object.keys||function(e){var t=[];for(var n in e)Object.prototype.hasOwnProperty.call(e,n)&&t.push(n);return t}},function(e,t,n){"use strict";n.r(t);var r=n(2),o=n.n(r),i=n(1),a=n.n(i),s=n(0),u=n.n(s),l=n(4),c=n.n(l),f=n(3),p=n.n(f);function h(e){return"/"===e.charAt(0)}function d(e,t){for(var n=t,r=n+1,o=e.length;r<o;n+=1,r+=1)e[n]=e[r];e.pop()}var m=function(e){var t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"",n=e&&e.split("/")||[],r=t&&t.split("/")||[],o=e&&h(e),i=t&&h(t),a=o||i;if(e&&h(e)?r=n:n.length&&(r.pop(),r=r.concat(n)),!r.length)return"/";var s=void 0;if(r.length){var u=r[r.length-1];s="."===u||".."===u||""===u}else s=!1;for(var l=0,c=r.length;c>=0;c--){var f=r[c];"."===f?d(r,c):".."===f?(d(r,c),l++):l&&(d(r,c),l--)}if(!a)for(;l--;l)r.unshift("..");!a||""===r[0]||r[0]&&h(r[0])||r.unshift("");var p=r.join("/");return s&&"/"!==p.substr(-1)&&(p+="/"),p},g="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e};var v=function e(t,n){if(t===n)return!0;if(null==t||null==n)return!1;if(Array.isArray(t))return Array.isArray(n)&&t.length===n.length&&t.every(function(t,r){return e(t,n[r])});var r=void 0===t?"undefined":g(t);if(r!==(void 0===n?"undefined":g(n)))return!1;if("object"===r){var o=t.valueOf(),i=n.valueOf();if(o!==t||i!==n)return e(o,i);var a=Object.keys(t),s=Object.keys(n);return a.length===s.length&&a.every(function(r){return e(t[r],n[r])})}return!1},y=function(e){return"/"===e.charAt(0)?e:"/"+e},b=function(e){return"/"===e.charAt(0)?e.substr(1):e},_=function(e,t){return new RegExp("^"+t+"(\\/|\\?|#|$)","i").test(e)},w=function(e,t){return _(e,t)?e.substr(t.length):e},x=function(e){return"/"===e.charAt(e.length-1)?e.slice(0,-1):e},k=function(e){var t=e.pathname,n=e.search,r=e.hash,o=t||"/";return n&&"?"!==n&&(o+="?"===n.charAt(0)?n:"?"+n),r&&"#"!==r&&(o+="#"===r.charAt(0)?r:"#"+r),o},S=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},C=function(e,t,n,r){var o=void 0;"string"==typeof e?(o=function(e){var t=e||"/",n="",r="",o=t.indexOf("#");-1!==o&&(r=t.substr(o),t=t.substr(0,o));var i=t.indexOf("?");return-1!==i&&(n=t.substr(i),t=t.substr(0,i)),{pathname:t,search:"?"===n?"":n,hash:"#"===r?"":r}}(e)).state=t:(void 0===(o=S({},e)).pathname&&(o.pathname=""),o.search?"?"!==o.search.charAt(0)&&(o.search="?"+o.search):o.search="",o.hash?"#"!==o.hash.charAt(0)&&(o.hash="#"+o.hash):o.hash="",void 0!==t&&void 0===o.state&&(o.state=t));try{o.pathname=decodeURI(o.pathname)}catch(e){throw e instanceof URIError?new URIError('Pathname "'+o.pathname+'" could not be decoded. This is likely caused by an invalid percent-encoding.'):e}return n&&(o.key=n),r?o.pathname?"/"!==o.pathname.charAt(0)&&(o.pathname=m(o.pathname,r.pathname)):o.pathname=r.pathname:o.pathname||(o.pathname="/"),o},E=function(e,t){return e.pathname===t.pathname&&e.search===t.search&&e.hash===t.hash&&e.key===t.key&&v(e.state,t.state)},T=function(){var e=null,t=[];return{setPrompt:function(t){return c()(null==e,"A history supports only one prompt at a time"),e=t,function(){e===t&&(e=null)}},confirmTransitionTo:function(t,n,r,o){if(null!=e){var i="function"==typeof e?e(t,n):e;"string"==typeof i?"function"==typeof r?r(i,o):(c()(!1,"A history needs a getUserConfirmation function in order to use a prompt message"),o(!0)):o(!1!==i)}else o(!0)},appendListener:function(e){var n=!0,r=function(){n&&e.apply(void 0,arguments)};return t.push(r),function(){n=!1,t=t.filter(function(e){return e!==r})}},notifyListeners:function(){for(var e=arguments.length,n=Array(e),r=0;r<e;r++)n[r]=arguments[r];t.forEach(function(e){return e.apply(void 0,n)})}}},O=!("undefined"==typeof window||!window.document||!window.document.createElement),A=function(e,t,n){return e.addEventListener?e.addEventListener(t,n,!1):e.attachEvent("on"+t,n)},P=function(e,t,n){return e.removeEventListener?e.removeEventListener(t,n,!1):e.detachEvent("on"+t,n)},j=function(e,t){return t(window.confirm(e))},R="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},N=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},M=function(){try{return window.history.state||{}}catch(e){return{}}},L=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};p()(O,"Browser history needs a DOM");var t,n=window.history,r=(-1===(t=window.navigator.userAgent).indexOf("Android 2.")&&-1===t.indexOf("Android 4.0")||-1===t.indexOf("Mobile Safari")||-1!==t.indexOf("Chrome")||-1!==t.indexOf("Windows Phone"))&&window.history&&"pushState"in window.history,o=!(-1===window.navigator.userAgent.indexOf("Trident")),i=e.forceRefresh,a=void 0!==i&&i,s=e.getUserConfirmation,u=void 0===s?j:s,l=e.keyLength,f=void 0===l?6:l,h=e.basename?x(y(e.basename)):"",d=function(e){var t=e||{},n=t.key,r=t.state,o=window.location,i=o.pathname+o.search+o.hash;return c()(!h||_(i,h),'You are attempting to use a basename on a page whose URL path does not begin with the basename. Expected path "'+i+'" to begin with "'+h+'".'),h&&(i=w(i,h)),C(i,r,n)},m=function(){return Math.random().toString(36).substr(2,f)},g=T(),v=function(e){N(H,e),H.length=n.length,g.notifyListeners(H.location,H.action)},b=function(e){(function(e){return void 0===e.state&&-1===navigator.userAgent.indexOf("CriOS")})(e)||L(d(e.state))},S=function(){L(d(M()))},E=!1,L=function(e){E?(E=!1,v()):g.confirmTransitionTo(e,"POP",u,function(t){t?v({action:"POP",location:e}):I(e)})},I=function(e){var t=H.location,n=F.indexOf(t.key);-1===n&&(n=0);var r=F.indexOf(e.key);-1===r&&(r=0);var o=n-r;o&&(E=!0,q(o))},D=d(M()),F=[D.key],U=function(e){return h+k(e)},q=function(e){n.go(e)},B=0,V=function(e){1===(B+=e)?(A(window,"popstate",b),o&&A(window,"hashchange",S)):0===B&&(P(window,"popstate",b),o&&P(window,"hashchange",S))},z=!1,H={length:n.length,action:"POP",location:D,createHref:U,push:function(e,t){c()(!("object"===(void 0===e?"undefined":R(e))&&void 0!==e.state&&void 0!==t),"You should avoid providing a 2nd state argument to push when the 1st argument is a location-like object that already has state; it is ignored");var o=C(e,t,m(),H.location);g.confirmTransitionTo(o,"PUSH",u,function(e){if(e){var t=U(o),i=o.key,s=o.state;if(r)if(n.pushState({key:i,state:s},null,t),a)window.location.href=t;else{var u=F.indexOf(H.location.key),l=F.slice(0,-1===u?0:u+1);l.push(o.key),F=l,v({action:"PUSH",location:o})}else c()(void 0===s,"Browser history cannot push state in browsers that do not support HTML5 history"),window.location.href=t}})},replace:function(e,t){c()(!("object"===(void 0===e?"undefined":R(e))&&void 0!==e.state&&void 0!==t),"You should avoid providing a 2nd state argument to replace when the 1st argument is a location-like object that already has state; it is ignored");var o=C(e,t,m(),H.location);g.confirmTransitionTo(o,"REPLACE",u,function(e){if(e){var t=U(o),i=o.key,s=o.state;if(r)if(n.replaceState({key:i,state:s},null,t),a)window.location.replace(t);else{var u=F.indexOf(H.location.key);-1!==u&&(F[u]=o.key),v({action:"REPLACE",location:o})}else c()(void 0===s,"Browser history cannot replace state in browsers that do not support HTML5 history"),window.location.replace(t)}})},go:q,goBack:function(){return q(-1)},goForward:function(){return q(1)},block:function(){var e=arguments.length>0&&void 0!==arguments[0]&&arguments[0],t=g.setPrompt(e);return z||(V(1),z=!0),function(){return z&&(z=!1,V(-1)),t()}},listen:function(e){var t=g.appendListener(e);return V(1),function(){V(-1),t()}}};return H},I=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},D={hashbang:{encodePath:function(e){return"!"===e.charAt(0)?e:"!/"+b(e)},decodePath:function(e){return"!"===e.charAt(0)?e.substr(1):e}},noslash:{encodePath:b,decodePath:y},slash:{encodePath:y,decodePath:y}},F=function(){var e=window.location.href,t=e.indexOf("#");return-1===t?"":e.substring(t+1)},U=function(e){var t=window.location.href.indexOf("#");window.location.replace(window.location.href.slice(0,t>=0?t:0)+"#"+e)},q=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};p()(O,"Hash history needs a DOM");var t=window.history,n=-1===window.navigator.userAgent.indexOf("Firefox"),r=e.getUserConfirmation,o=void 0===r?j:r,i=e.hashType,a=void 0===i?"slash":i,s=e.basename?x(y(e.basename)):"",u=D[a],l=u.encodePath,f=u.decodePath,h=function(){var e=f(F());return c()(!s||_(e,s),'You are attempting to use a basename on a page whose URL path does not begin with the basename. Expected path "'+e+'" to begin with "'+s+'".'),s&&(e=w(e,s)),C(e)},d=T(),m=function(e){I(W,e),W.length=t.length,d.notifyListeners(W.location,W.action)},g=!1,v=null,b=function(){var e=F(),t=l(e);if(e!==t)U(t);else{var n=h(),r=W.location;if(!g&&E(r,n))return;if(v===k(n))return;v=null,S(n)}},S=function(e){g?(g=!1,m()):d.confirmTransitionTo(e,"POP",o,function(t){t?m({action:"POP",location:e}):R(e)})},R=function(e){var t=W.location,n=q.lastIndexOf(k(t));-1===n&&(n=0);var r=q.lastIndexOf(k(e));-1===r&&(r=0);var o=n-r;o&&(g=!0,B(o))},N=F(),M=l(N);N!==M&&U(M);var L=h(),q=[k(L)],B=function(e){c()(n,"Hash history go(n) causes a full page reload in this browser"),t.go(e)},V=0,z=function(e){1===(V+=e)?A(window,"hashchange",b):0===V&&P(window,"hashchange",b)},H=!1,W={length:t.length,action:"POP",location:L,createHref:function(e){return"#"+l(s+k(e))},push:function(e,t){c()(void 0===t,"Hash history cannot push state; it is ignored");var n=C(e,void 0,void 0,W.location);d.confirmTransitionTo(n,"PUSH",o,function(e){if(e){var t=k(n),r=l(s+t);if(F()!==r){v=t,function(e){window.location.hash=e}(r);var o=q.lastIndexOf(k(W.location)),i=q.slice(0,-1===o?0:o+1);i.push(t),q=i,m({action:"PUSH",location:n})}else c()(!1,"Hash history cannot PUSH the same path; a new entry will not be added to the history stack"),m()}})},replace:function(e,t){c()(void 0===t,"Hash history cannot replace state; it is ignored");var n=C(e,void 0,void 0,W.location);d.confirmTransitionTo(n,"REPLACE",o,function(e){if(e){var t=k(n),r=l(s+t);F()!==r&&(v=t,U(r));var o=q.indexOf(k(W.location));-1!==o&&(q[o]=t),m({action:"REPLACE",location:n})}})},go:B,goBack:function(){return B(-1)},goForward:function(){return B(1)},block:function(){var e=arguments.length>0&&void 0!==arguments[0]&&arguments[0],t=d.setPrompt(e);return H||(z(1),H=!0),function(){return H&&(H=!1,z(-1)),t()}},listen:function(e){var t=d.appendListener(e);return z(1),function(){z(-1),t()}}};return W},B="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e},V=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},z=function(e,t,n){return Math.min(Math.max(e,t),n)},H=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{},t=e.getUserConfirmation,n=e.initialEntries,r=void 0===n?["/"]:n,o=e.initialIndex,i=void 0===o?0:o,a=e.keyLength,s=void 0===a?6:a,u=T(),l=function(e){V(g,e),g.length=g.entries.length,u.notifyListeners(g.location,g.action)},f=function(){return Math.random().toString(36).substr(2,s)},p=z(i,0,r.length-1),h=r.map(function(e){return C(e,void 0,"string"==typeof e?f():e.key||f())}),d=k,m=function(e){var n=z(g.index+e,0,g.entries.length-1),r=g.entries[n];u.confirmTransitionTo(r,"POP",t,function(e){e?l({action:"POP",location:r,index:n}):l()})},g={length:h.length,action:"POP",location:h[p],index:p,entries:h,createHref:d,push:function(e,n){c()(!("object"===(void 0===e?"undefined":B(e))&&void 0!==e.state&&void 0!==n),"You should avoid providing a 2nd state argument to push when the 1st argument is a location-like object that already has state; it is ignored");var r=C(e,n,f(),g.location);u.confirmTransitionTo(r,"PUSH",t,function(e){if(e){var t=g.index+1,n=g.entries.slice(0);n.length>t?n.splice(t,n.length-t,r):n.push(r),l({action:"PUSH",location:r,index:t,entries:n})}})},replace:function(e,n){c()(!("object"===(void 0===e?"undefined":B(e))&&void 0!==e.state&&void 0!==n),"You should avoid providing a 2nd state argument to replace when the 1st argument is a location-like object that already has state; it is ignored");var r=C(e,n,f(),g.location);u.confirmTransitionTo(r,"REPLACE",t,function(e){e&&(g.entries[g.index]=r,l({action:"REPLACE",location:r}))})},go:m,goBack:function(){return m(-1)},goForward:function(){return m(1)},canGo:function(e){var t=g.index+e;return t>=0&&t<g.entries.length},block:function(){var e=arguments.length>0&&void 0!==arguments[0]&&arguments[0];return u.setPrompt(e)},listen:function(e){return u.appendListener(e)}};return g},W=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function $(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var G=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=$(this,e.call.apply(e,[this].concat(i))),r.state={match:r.computeMatch(r.props.history.location.pathname)},$(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.getChildContext=function(){return{router:W({},this.context.router,{history:this.props.history,route:{location:this.props.history.location,match:this.state.match}})}},t.prototype.computeMatch=function(e){return{path:"/",url:"/",params:{},isExact:"/"===e}},t.prototype.componentWillMount=function(){var e=this,t=this.props,n=t.children,r=t.history;p()(null==n||1===a.a.Children.count(n),"A <Router> may have only one child element"),this.unlisten=r.listen(function(){e.setState({match:e.computeMatch(r.location.pathname)})})},t.prototype.componentWillReceiveProps=function(e){o()(this.props.history===e.history,"You cannot change <Router history>")},t.prototype.componentWillUnmount=function(){this.unlisten()},t.prototype.render=function(){var e=this.props.children;return e?a.a.Children.only(e):null},t}(a.a.Component);G.propTypes={history:u.a.object.isRequired,children:u.a.node},G.contextTypes={router:u.a.object},G.childContextTypes={router:u.a.object.isRequired};var Y=G,Q=Y;function X(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var K=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=X(this,e.call.apply(e,[this].concat(i))),r.history=L(r.props),X(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){o()(!this.props.history,"<BrowserRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { BrowserRouter as Router }`.")},t.prototype.render=function(){return a.a.createElement(Q,{history:this.history,children:this.props.children})},t}(a.a.Component);K.propTypes={basename:u.a.string,forceRefresh:u.a.bool,getUserConfirmation:u.a.func,keyLength:u.a.number,children:u.a.node};var J=K;function Z(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var ee=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=Z(this,e.call.apply(e,[this].concat(i))),r.history=q(r.props),Z(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){o()(!this.props.history,"<HashRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { HashRouter as Router }`.")},t.prototype.render=function(){return a.a.createElement(Q,{history:this.history,children:this.props.children})},t}(a.a.Component);ee.propTypes={basename:u.a.string,getUserConfirmation:u.a.func,hashType:u.a.oneOf(["hashbang","noslash","slash"]),children:u.a.node};var te=ee,ne=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function re(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var oe=function(e){return!!(e.metaKey||e.altKey||e.ctrlKey||e.shiftKey)},ie=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=re(this,e.call.apply(e,[this].concat(i))),r.handleClick=function(e){if(r.props.onClick&&r.props.onClick(e),!e.defaultPrevented&&0===e.button&&!r.props.target&&!oe(e)){e.preventDefault();var t=r.context.router.history,n=r.props,o=n.replace,i=n.to;o?t.replace(i):t.push(i)}},re(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.render=function(){var e=this.props,t=(e.replace,e.to),n=e.innerRef,r=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(e,["replace","to","innerRef"]);p()(this.context.router,"You should not use <Link> outside a <Router>"),p()(void 0!==t,'You must specify the "to" property');var o=this.context.router.history,i="string"==typeof t?C(t,null,null,o.location):t,s=o.createHref(i);return a.a.createElement("a",ne({},r,{onClick:this.handleClick,href:s,ref:n}))},t}(a.a.Component);ie.propTypes={onClick:u.a.func,target:u.a.string,replace:u.a.bool,to:u.a.oneOfType([u.a.string,u.a.object]).isRequired,innerRef:u.a.oneOfType([u.a.string,u.a.func])},ie.defaultProps={replace:!1},ie.contextTypes={router:u.a.shape({history:u.a.shape({push:u.a.func.isRequired,replace:u.a.func.isRequired,createHref:u.a.func.isRequired}).isRequired}).isRequired};var ae=ie;function se(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var ue=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=se(this,e.call.apply(e,[this].concat(i))),r.history=H(r.props),se(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){o()(!this.props.history,"<MemoryRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { MemoryRouter as Router }`.")},t.prototype.render=function(){return a.a.createElement(Y,{history:this.history,children:this.props.children})},t}(a.a.Component);ue.propTypes={initialEntries:u.a.array,initialIndex:u.a.number,getUserConfirmation:u.a.func,keyLength:u.a.number,children:u.a.node};var le=ue,ce=n(57),fe=n.n(ce),pe={},he=0,de=function(e){var t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{},n=arguments[2];"string"==typeof t&&(t={path:t});var r=t,o=r.path,i=r.exact,a=void 0!==i&&i,s=r.strict,u=void 0!==s&&s,l=r.sensitive;if(null==o)return n;var c=function(e,t){var n=""+t.end+t.strict+t.sensitive,r=pe[n]||(pe[n]={});if(r[e])return r[e];var o=[],i={re:fe()(e,o,t),keys:o};return he<1e4&&(r[e]=i,he++),i}(o,{end:a,strict:u,sensitive:void 0!==l&&l}),f=c.re,p=c.keys,h=f.exec(e);if(!h)return null;var d=h[0],m=h.slice(1),g=e===d;return a&&!g?null:{path:o,url:"/"===o&&""===d?"/":d,isExact:g,params:p.reduce(function(e,t,n){return e[t.name]=m[n],e},{})}},me=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function ge(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var ve=function(e){return 0===a.a.Children.count(e)},ye=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=ge(this,e.call.apply(e,[this].concat(i))),r.state={match:r.computeMatch(r.props,r.context.router)},ge(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.getChildContext=function(){return{router:me({},this.context.router,{route:{location:this.props.location||this.context.router.route.location,match:this.state.match}})}},t.prototype.computeMatch=function(e,t){var n=e.computedMatch,r=e.location,o=e.path,i=e.strict,a=e.exact,s=e.sensitive;if(n)return n;p()(t,"You should not use <Route> or withRouter() outside a <Router>");var u=t.route,l=(r||u.location).pathname;return de(l,{path:o,strict:i,exact:a,sensitive:s},u.match)},t.prototype.componentWillMount=function(){o()(!(this.props.component&&this.props.render),"You should not use <Route component> and <Route render> in the same route; <Route render> will be ignored"),o()(!(this.props.component&&this.props.children&&!ve(this.props.children)),"You should not use <Route component> and <Route children> in the same route; <Route children> will be ignored"),o()(!(this.props.render&&this.props.children&&!ve(this.props.children)),"You should not use <Route render> and <Route children> in the same route; <Route children> will be ignored")},t.prototype.componentWillReceiveProps=function(e,t){o()(!(e.location&&!this.props.location),'<Route> elements should not change from uncontrolled to controlled (or vice versa). You initially used no "location" prop and then provided one on a subsequent render.'),o()(!(!e.location&&this.props.location),'<Route> elements should not change from controlled to uncontrolled (or vice versa). You provided a "location" prop initially but omitted it on a subsequent render.'),this.setState({match:this.computeMatch(e,t.router)})},t.prototype.render=function(){var e=this.state.match,t=this.props,n=t.children,r=t.component,o=t.render,i=this.context.router,s=i.history,u=i.route,l=i.staticContext,c={match:e,location:this.props.location||u.location,history:s,staticContext:l};return r?e?a.a.createElement(r,c):null:o?e?o(c):null:"function"==typeof n?n(c):n&&!ve(n)?a.a.Children.only(n):null},t}(a.a.Component);ye.propTypes={computedMatch:u.a.object,path:u.a.string,exact:u.a.bool,strict:u.a.bool,sensitive:u.a.bool,component:u.a.func,render:u.a.func,children:u.a.oneOfType([u.a.func,u.a.node]),location:u.a.object},ye.contextTypes={router:u.a.shape({history:u.a.object.isRequired,route:u.a.object.isRequired,staticContext:u.a.object})},ye.childContextTypes={router:u.a.object.isRequired};var be=ye,_e=be,we=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},xe="function"==typeof Symbol&&"symbol"==typeof Symbol.iterator?function(e){return typeof e}:function(e){return e&&"function"==typeof Symbol&&e.constructor===Symbol&&e!==Symbol.prototype?"symbol":typeof e};var ke=function(e){var t=e.to,n=e.exact,r=e.strict,o=e.location,i=e.activeClassName,s=e.className,u=e.activeStyle,l=e.style,c=e.isActive,f=e["aria-current"],p=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(e,["to","exact","strict","location","activeClassName","className","activeStyle","style","isActive","aria-current"]),h="object"===(void 0===t?"undefined":xe(t))?t.pathname:t,d=h&&h.replace(/([.+*?=^!:${}()[\]|\/\\])/g,"\\$1");return a.a.createElement(_e,{path:d,exact:n,strict:r,location:o,children:function(e){var n=e.location,r=e.match,o=!!(c?c(r,n):r);return a.a.createElement(ae,we({to:t,className:o?[s,i].filter(function(e){return e}).join(" "):s,style:o?we({},l,u):l,"aria-current":o&&f||null},p))}})};ke.propTypes={to:ae.propTypes.to,exact:u.a.bool,strict:u.a.bool,location:u.a.object,activeClassName:u.a.string,className:u.a.string,activeStyle:u.a.object,style:u.a.object,isActive:u.a.func,"aria-current":u.a.oneOf(["page","step","location","date","time","true"])},ke.defaultProps={activeClassName:"active","aria-current":"page"};var Se=ke;var Ce=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,e.apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.enable=function(e){this.unblock&&this.unblock(),this.unblock=this.context.router.history.block(e)},t.prototype.disable=function(){this.unblock&&(this.unblock(),this.unblock=null)},t.prototype.componentWillMount=function(){p()(this.context.router,"You should not use <Prompt> outside a <Router>"),this.props.when&&this.enable(this.props.message)},t.prototype.componentWillReceiveProps=function(e){e.when?this.props.when&&this.props.message===e.message||this.enable(e.message):this.disable()},t.prototype.componentWillUnmount=function(){this.disable()},t.prototype.render=function(){return null},t}(a.a.Component);Ce.propTypes={when:u.a.bool,message:u.a.oneOfType([u.a.func,u.a.string]).isRequired},Ce.defaultProps={when:!0},Ce.contextTypes={router:u.a.shape({history:u.a.shape({block:u.a.func.isRequired}).isRequired}).isRequired};var Ee=Ce,Te={},Oe=0,Ae=function(){var e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:"/",t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};return"/"===e?e:function(e){var t=e,n=Te[t]||(Te[t]={});if(n[e])return n[e];var r=fe.a.compile(e);return Oe<1e4&&(n[e]=r,Oe++),r}(e)(t,{pretty:!0})},Pe=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};var je=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,e.apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.isStatic=function(){return this.context.router&&this.context.router.staticContext},t.prototype.componentWillMount=function(){p()(this.context.router,"You should not use <Redirect> outside a <Router>"),this.isStatic()&&this.perform()},t.prototype.componentDidMount=function(){this.isStatic()||this.perform()},t.prototype.componentDidUpdate=function(e){var t=C(e.to),n=C(this.props.to);E(t,n)?o()(!1,"You tried to redirect to the same route you're currently on: \""+n.pathname+n.search+'"'):this.perform()},t.prototype.computeTo=function(e){var t=e.computedMatch,n=e.to;return t?"string"==typeof n?Ae(n,t.params):Pe({},n,{pathname:Ae(n.pathname,t.params)}):n},t.prototype.perform=function(){var e=this.context.router.history,t=this.props.push,n=this.computeTo(this.props);t?e.push(n):e.replace(n)},t.prototype.render=function(){return null},t}(a.a.Component);je.propTypes={computedMatch:u.a.object,push:u.a.bool,from:u.a.string,to:u.a.oneOfType([u.a.string,u.a.object]).isRequired},je.defaultProps={push:!1},je.contextTypes={router:u.a.shape({history:u.a.shape({push:u.a.func.isRequired,replace:u.a.func.isRequired}).isRequired,staticContext:u.a.object}).isRequired};var Re=je,Ne=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};function Me(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}var Le=function(e){return"/"===e.charAt(0)?e:"/"+e},Ie=function(e,t){return e?Ne({},t,{pathname:Le(e)+t.pathname}):t},De=function(e,t){if(!e)return t;var n=Le(e);return 0!==t.pathname.indexOf(n)?t:Ne({},t,{pathname:t.pathname.substr(n.length)})},Fe=function(e){return"string"==typeof e?e:k(e)},Ue=function(e){return function(){p()(!1,"You cannot %s with <StaticRouter>",e)}},qe=function(){},Be=function(e){function t(){var n,r;!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t);for(var o=arguments.length,i=Array(o),a=0;a<o;a++)i[a]=arguments[a];return n=r=Me(this,e.call.apply(e,[this].concat(i))),r.createHref=function(e){return Le(r.props.basename+Fe(e))},r.handlePush=function(e){var t=r.props,n=t.basename,o=t.context;o.action="PUSH",o.location=Ie(n,C(e)),o.url=Fe(o.location)},r.handleReplace=function(e){var t=r.props,n=t.basename,o=t.context;o.action="REPLACE",o.location=Ie(n,C(e)),o.url=Fe(o.location)},r.handleListen=function(){return qe},r.handleBlock=function(){return qe},Me(r,n)}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.getChildContext=function(){return{router:{staticContext:this.props.context}}},t.prototype.componentWillMount=function(){o()(!this.props.history,"<StaticRouter> ignores the history prop. To use a custom history, use `import { Router }` instead of `import { StaticRouter as Router }`.")},t.prototype.render=function(){var e=this.props,t=e.basename,n=(e.context,e.location),r=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(e,["basename","context","location"]),o={createHref:this.createHref,action:"POP",location:De(t,C(n)),push:this.handlePush,replace:this.handleReplace,go:Ue("go"),goBack:Ue("goBack"),goForward:Ue("goForward"),listen:this.handleListen,block:this.handleBlock};return a.a.createElement(Y,Ne({},r,{history:o}))},t}(a.a.Component);Be.propTypes={basename:u.a.string,context:u.a.object.isRequired,location:u.a.oneOfType([u.a.string,u.a.object])},Be.defaultProps={basename:"",location:"/"},Be.childContextTypes={router:u.a.object.isRequired};var Ve=Be;var ze=function(e){function t(){return function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,t),function(e,t){if(!e)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return!t||"object"!=typeof t&&"function"!=typeof t?e:t}(this,e.apply(this,arguments))}return function(e,t){if("function"!=typeof t&&null!==t)throw new TypeError("Super expression must either be null or a function, not "+typeof t);e.prototype=Object.create(t&&t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}),t&&(Object.setPrototypeOf?Object.setPrototypeOf(e,t):e.__proto__=t)}(t,e),t.prototype.componentWillMount=function(){p()(this.context.router,"You should not use <Switch> outside a <Router>")},t.prototype.componentWillReceiveProps=function(e){o()(!(e.location&&!this.props.location),'<Switch> elements should not change from uncontrolled to controlled (or vice versa). You initially used no "location" prop and then provided one on a subsequent render.'),o()(!(!e.location&&this.props.location),'<Switch> elements should not change from controlled to uncontrolled (or vice versa). You provided a "location" prop initially but omitted it on a subsequent render.')},t.prototype.render=function(){var e=this.context.router.route,t=this.props.children,n=this.props.location||e.location,r=void 0,o=void 0;return a.a.Children.forEach(t,function(t){if(null==r&&a.a.isValidElement(t)){var i=t.props,s=i.path,u=i.exact,l=i.strict,c=i.sensitive,f=i.from,p=s||f;o=t,r=de(n.pathname,{path:p,exact:u,strict:l,sensitive:c},e.match)}}),r?a.a.cloneElement(o,{location:n,computedMatch:r}):null},t}(a.a.Component);ze.contextTypes={router:u.a.shape({route:u.a.object.isRequired}).isRequired},ze.propTypes={children:u.a.node,location:u.a.object};var He=ze,We=Ae,$e=de,Ge=n(167),Ye=n.n(Ge),Qe=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e};var Xe=function(e){var t=function(t){var n=t.wrappedComponentRef,r=function(e,t){var n={};for(var r in e)t.indexOf(r)>=0||Object.prototype.hasOwnProperty.call(e,r)&&(n[r]=e[r]);return n}(t,["wrappedComponentRef"]);return a.a.createElement(be,{children:function(t){return a.a.createElement(e,Qe({},r,t,{ref:n}))}})};return t.displayName="withRouter("+(e.displayName||e.name)+")",t.WrappedComponent=e,t.propTypes={wrappedComponentRef:u.a.func},Ye()(t,e)};n.d(t,"BrowserRouter",function(){return J}),n.d(t,"HashRouter",function(){return te}),n.d(t,"Link",function(){return ae}),n.d(t,"MemoryRouter",function(){return le}),n.d(t,"NavLink",function(){return Se}),n.d(t,"Prompt",function(){return Ee}),n.d(t,"Redirect",function(){return Re}),n.d(t,"Route",function(){return _e}),n.d(t,"Router",function(){return Q}),n.d(t,"StaticRouter",function(){return Ve}),n.d(t,"Switch",function(){return He}),n.d(t,"generatePath",function(){return We}),n.d(t,"matchPath",function(){return $e}),n.d(t,"withRouter",function(){return Xe})},function(e,t,n){"use strict";n.r(t);n(0);var r=n(1),o=n.n(r);function i(e){return e.type&&"Tab"===e.type.tabsRole}function a(e){return e.type&&"TabPanel"===e.type.tabsRole}function s(e){return e.type&&"TabList"===e.type.tabsRole}function u(e,t,n){return t in e?Object.defineProperty(e,t,{value:n,enumerable:!0,configurable:!0,writable:!0}):e[t]=n,e}function l(e,t){return r.Children.map(e,function(e){return null===e?null:function(e){return i(e)||s(e)||a(e)}(e)?t(e):e.props&&e.props.children&&"object"==typeof e.props.children?Object(r.cloneElement)(e,function(e){for(var t=1;t<arguments.length;t++){var n=null!=arguments[t]?arguments[t]:{},r=Object.keys(n);"function"==typeof Object.getOwnPropertySymbols&&(r=r.concat(Object.getOwnPropertySymbols(n).filter(function(e){return Object.getOwnPropertyDescriptor(n,e).enumerable}))),r.forEach(function(t){u(e,t,n[t])})}return e}({},e.props,{children:l(e.props.children,t)})):e})}function c(e,t){return r.Children.forEach(e,function(e){null!==e&&(i(e)||a(e)?t(e):e.props&&e.props.children&&"object"==typeof e.props.children&&(s(e)&&t(e),c(e.props.children,t)))})}var f,p=n(12),h=n.n(p),d=0;function m(){return"react-tabs-"+d++}function g(){d=0}function v(e){var t=0;return c(e,function(e){i(e)&&t++}),t}function y(){return(y=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}function b(e){return e&&"getAttribute"in e}function _(e){return b(e)&&"tab"===e.getAttribute("role")}function w(e){return b(e)&&"true"===e.getAttribute("aria-disabled")}try{f=!("undefined"==typeof window||!window.document||!window.document.activeElement)}catch(e){f=!1}var x=function(e){var t,n;function u(){for(var t,n=arguments.length,r=new Array(n),o=0;o<n;o++)r[o]=arguments[o];return(t=e.call.apply(e,[this].concat(r))||this).tabNodes=[],t.handleKeyDown=function(e){if(t.isTabFromContainer(e.target)){var n=t.props.selectedIndex,r=!1,o=!1;32!==e.keyCode&&13!==e.keyCode||(r=!0,o=!1,t.handleClick(e)),37===e.keyCode||38===e.keyCode?(n=t.getPrevTab(n),r=!0,o=!0):39===e.keyCode||40===e.keyCode?(n=t.getNextTab(n),r=!0,o=!0):35===e.keyCode?(n=t.getLastTab(),r=!0,o=!0):36===e.keyCode&&(n=t.getFirstTab(),r=!0,o=!0),r&&e.preventDefault(),o&&t.setSelected(n,e)}},t.handleClick=function(e){var n=e.target;do{if(t.isTabFromContainer(n)){if(w(n))return;var r=[].slice.call(n.parentNode.children).filter(_).indexOf(n);return void t.setSelected(r,e)}}while(null!=(n=n.parentNode))},t}n=e,(t=u).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var p=u.prototype;return p.setSelected=function(e,t){if(!(e<0||e>=this.getTabsCount())){var n=this.props;(0,n.onSelect)(e,n.selectedIndex,t)}},p.getNextTab=function(e){for(var t=this.getTabsCount(),n=e+1;n<t;n++)if(!w(this.getTab(n)))return n;for(var r=0;r<e;r++)if(!w(this.getTab(r)))return r;return e},p.getPrevTab=function(e){for(var t=e;t--;)if(!w(this.getTab(t)))return t;for(t=this.getTabsCount();t-- >e;)if(!w(this.getTab(t)))return t;return e},p.getFirstTab=function(){for(var e=this.getTabsCount(),t=0;t<e;t++)if(!w(this.getTab(t)))return t;return null},p.getLastTab=function(){for(var e=this.getTabsCount();e--;)if(!w(this.getTab(e)))return e;return null},p.getTabsCount=function(){return v(this.props.children)},p.getPanelsCount=function(){return function(e){var t=0;return c(e,function(e){a(e)&&t++}),t}(this.props.children)},p.getTab=function(e){return this.tabNodes["tabs-"+e]},p.getChildren=function(){var e=this,t=0,n=this.props,u=n.children,c=n.disabledTabClassName,p=n.focus,h=n.forceRenderTabPanel,d=n.selectedIndex,g=n.selectedTabClassName,v=n.selectedTabPanelClassName;this.tabIds=this.tabIds||[],this.panelIds=this.panelIds||[];for(var y=this.tabIds.length-this.getTabsCount();y++<0;)this.tabIds.push(m()),this.panelIds.push(m());return l(u,function(n){var u=n;if(s(n)){var m=0,y=!1;f&&(y=o.a.Children.toArray(n.props.children).filter(i).some(function(t,n){return document.activeElement===e.getTab(n)})),u=Object(r.cloneElement)(n,{children:l(n.props.children,function(t){var n="tabs-"+m,o=d===m,i={tabRef:function(t){e.tabNodes[n]=t},id:e.tabIds[m],panelId:e.panelIds[m],selected:o,focus:o&&(p||y)};return g&&(i.selectedClassName=g),c&&(i.disabledClassName=c),m++,Object(r.cloneElement)(t,i)})})}else if(a(n)){var b={id:e.panelIds[t],tabId:e.tabIds[t],selected:d===t};h&&(b.forceRender=h),v&&(b.selectedClassName=v),t++,u=Object(r.cloneElement)(n,b)}return u})},p.isTabFromContainer=function(e){if(!_(e))return!1;var t=e.parentElement;do{if(t===this.node)return!0;if(t.getAttribute("data-tabs"))break;t=t.parentElement}while(t);return!1},p.render=function(){var e=this,t=this.props,n=(t.children,t.className),r=(t.disabledTabClassName,t.domRef),i=(t.focus,t.forceRenderTabPanel,t.onSelect,t.selectedIndex,t.selectedTabClassName,t.selectedTabPanelClassName,function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(t,["children","className","disabledTabClassName","domRef","focus","forceRenderTabPanel","onSelect","selectedIndex","selectedTabClassName","selectedTabPanelClassName"]));return o.a.createElement("div",y({},i,{className:h()(n),onClick:this.handleClick,onKeyDown:this.handleKeyDown,ref:function(t){e.node=t,r&&r(t)},"data-tabs":!0}),this.getChildren())},u}(r.Component);x.defaultProps={className:"react-tabs",focus:!1},x.propTypes={};var k=1,S=function(e){var t,n;function r(t){var n;return(n=e.call(this,t)||this).handleSelected=function(e,t,r){var o=n.props.onSelect,i=n.state.mode;if("function"!=typeof o||!1!==o(e,t,r)){var a={focus:"keydown"===r.type};i===k&&(a.selectedIndex=e),n.setState(a)}},n.state=r.copyPropsToState(n.props,{},t.defaultFocus),n}return n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n,r.getDerivedStateFromProps=function(e,t){return r.copyPropsToState(e,t)},r.getModeFromProps=function(e){return null===e.selectedIndex?k:0},r.copyPropsToState=function(e,t,n){void 0===n&&(n=!1);var o={focus:n,mode:r.getModeFromProps(e)};if(o.mode===k){var i=v(e.children)-1,a=null;a=null!=t.selectedIndex?Math.min(t.selectedIndex,i):e.defaultIndex||0,o.selectedIndex=a}return o},r.prototype.render=function(){var e=this.props,t=e.children,n=(e.defaultIndex,e.defaultFocus,function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(e,["children","defaultIndex","defaultFocus"])),r=this.state,i=r.focus,a=r.selectedIndex;return n.focus=i,n.onSelect=this.handleSelected,null!=a&&(n.selectedIndex=a),o.a.createElement(x,n,t)},r}(r.Component);function C(){return(C=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}S.defaultProps={defaultFocus:!1,forceRenderTabPanel:!1,selectedIndex:null,defaultIndex:null},S.propTypes={},S.tabsRole="Tabs";var E=function(e){var t,n;function r(){return e.apply(this,arguments)||this}return n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n,r.prototype.render=function(){var e=this.props,t=e.children,n=e.className,r=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(e,["children","className"]);return o.a.createElement("ul",C({},r,{className:h()(n),role:"tablist"}),t)},r}(r.Component);function T(){return(T=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}E.defaultProps={className:"react-tabs__tab-list"},E.propTypes={},E.tabsRole="TabList";var O=function(e){var t,n;function r(){return e.apply(this,arguments)||this}n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n;var i=r.prototype;return i.componentDidMount=function(){this.checkFocus()},i.componentDidUpdate=function(){this.checkFocus()},i.checkFocus=function(){var e=this.props,t=e.selected,n=e.focus;t&&n&&this.node.focus()},i.render=function(){var e,t=this,n=this.props,r=n.children,i=n.className,a=n.disabled,s=n.disabledClassName,u=(n.focus,n.id),l=n.panelId,c=n.selected,f=n.selectedClassName,p=n.tabIndex,d=n.tabRef,m=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(n,["children","className","disabled","disabledClassName","focus","id","panelId","selected","selectedClassName","tabIndex","tabRef"]);return o.a.createElement("li",T({},m,{className:h()(i,(e={},e[f]=c,e[s]=a,e)),ref:function(e){t.node=e,d&&d(e)},role:"tab",id:u,"aria-selected":c?"true":"false","aria-disabled":a?"true":"false","aria-controls":l,tabIndex:p||(c?"0":null)}),r)},r}(r.Component);function A(){return(A=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e}).apply(this,arguments)}O.defaultProps={className:"react-tabs__tab",disabledClassName:"react-tabs__tab--disabled",focus:!1,id:null,panelId:null,selected:!1,selectedClassName:"react-tabs__tab--selected"},O.propTypes={},O.tabsRole="Tab";var P=function(e){var t,n;function r(){return e.apply(this,arguments)||this}return n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n,r.prototype.render=function(){var e,t=this.props,n=t.children,r=t.className,i=t.forceRender,a=t.id,s=t.selected,u=t.selectedClassName,l=t.tabId,c=function(e,t){if(null==e)return{};var n,r,o={},i=Object.keys(e);for(r=0;r<i.length;r++)n=i[r],t.indexOf(n)>=0||(o[n]=e[n]);return o}(t,["children","className","forceRender","id","selected","selectedClassName","tabId"]);return o.a.createElement("div",A({},c,{className:h()(r,(e={},e[u]=s,e)),role:"tabpanel",id:a,"aria-labelledby":l}),i||s?n:null)},r}(r.Component);P.defaultProps={className:"react-tabs__tab-panel",forceRender:!1,selectedClassName:"react-tabs__tab-panel--selected"},P.propTypes={},P.tabsRole="TabPanel",n.d(t,"Tabs",function(){return S}),n.d(t,"TabList",function(){return E}),n.d(t,"Tab",function(){return O}),n.d(t,"TabPanel",function(){return P}),n.d(t,"resetIdCounter",function(){return g})}]);
 //# sourceMappingURL=rustw.out.js.map
\ No newline at end of file